@@ -10,7 +10,15 @@ fn main() {
         println!("cargo:rerun-if-env-changed=RS_ODBC_LINK_SEARCH");
         println!("cargo:rustc-link-search=native={}", odbc_path);
 
-        println!("cargo:rustc-link-lib=static=odbc");
-        println!("cargo:rustc-link-lib=static=ltdl");
+        if std::env::var("CARGO_FEATURE_IODBC").is_ok() {
+            println!("cargo:rustc-link-lib=static=iodbc");
+        } else {
+            println!("cargo:rustc-link-lib=static=odbc");
+            println!("cargo:rustc-link-lib=static=ltdl");
+        }
+    }
+
+    if std::env::var("CARGO_FEATURE_IODBC").is_ok() && cfg!(target_os = "windows") {
+        panic!("iODBC is not supported for Windows");
     }
 }
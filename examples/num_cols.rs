@@ -32,8 +32,9 @@ fn main() {
     assert_eq!(res, SQL_SUCCESS);
     let hstmt = hstmt.unwrap();
 
-    let res = hstmt.SQLPrepareA(statement.as_ref());
+    let (hstmt, res) = hstmt.SQLPrepareA(statement.as_ref());
     assert_eq!(res, SQL_SUCCESS);
+    let hstmt = hstmt.unwrap();
 
     // Retrieve number of columns
     let mut num_cols = MaybeUninit::zeroed();
@@ -38,25 +38,26 @@ fn main() {
 
     // It is assumed that table Registry(id smallint, name varchar(20)) already exists
     // in the database, otherwise SQLExecDirect will return SQL_ERROR when called
-    let res = hstmt.SQLExecDirectA("SELECT id, name from Registry".as_ref());
+    let (hstmt, res) = hstmt.SQLExecDirectA("SELECT id, name from Registry".as_ref());
     assert_eq!(res, SQL_SUCCESS);
+    let hstmt = hstmt.unwrap();
 
     let res = hstmt.SQLBindCol(1, SQL_C_SSHORT, Some(&id_buffer), None);
     assert_eq!(res, SQL_SUCCESS);
     let res = hstmt.SQLBindCol(2, SQL_C_CHAR, Some(name_buffer.as_ref()), None);
     assert_eq!(res, SQL_SUCCESS);
 
-    let res = loop {
-        let res = hstmt.SQLFetch();
+    // First fetch positions the cursor on the result set
+    let (hstmt, mut res) = hstmt.SQLFetch();
+    let hstmt = hstmt.unwrap();
+
+    while SQL_SUCCEEDED(res) {
+        let name_buffer = unsafe { name_buffer.get().as_ref().expect("Non null") };
+        let name: &str = core::str::from_utf8(name_buffer).expect("Valid");
+        println!("Id: {}, Name: {}", unsafe { *id_buffer.get() }, name);
 
-        if SQL_SUCCEEDED(res) {
-            let name_buffer = unsafe { name_buffer.get().as_ref().expect("Non null") };
-            let name: &str = core::str::from_utf8(name_buffer).expect("Valid");
-            println!("Id: {}, Name: {}", unsafe { *id_buffer.get() }, name);
-        } else {
-            break res;
-        }
-    };
+        res = hstmt.SQLFetch();
+    }
 
     if res != SQL_NO_DATA {
         println!("Failed to fetch result set: {:?}", res);
@@ -1,37 +1,94 @@
+#[cfg(feature = "runtime_checks")]
+use crate::checks;
+#[cfg(feature = "std")]
+use crate::diag::DiagRecords;
 use crate::handle::*;
+#[cfg(feature = "std")]
+use crate::info::GetdataExtensions;
+#[cfg(feature = "std")]
+use crate::slice_len;
+#[cfg(feature = "std")]
+use crate::sqlreturn::{SQL_ERROR, SQL_INVALID_HANDLE};
+#[cfg(feature = "std")]
+use crate::stmt::{OutputParam, OwnedBinding, PutDataState, RowReader, UnknownOutputParam};
+#[cfg(feature = "std")]
+use crate::str::OdbcString;
 use crate::{
     BulkOperation, CompletionType, DatetimeIntervalCode, DriverCompletion, FreeStmtOption,
     FunctionId, IOType, Ident, IdentifierType, LockType, NullAllowed, Operation, RETCODE, Ref,
-    Reserved, SQLCHAR, SQLINTEGER, SQLLEN, SQLPOINTER, SQLSETPOSIROW, SQLSMALLINT, SQLULEN,
-    SQLUSMALLINT, SQLWCHAR, Scope, StrLenOrInd, Unique,
+    Reserved, SQL_PARAM_INPUT, SQL_PARAM_INPUT_OUTPUT_STREAM, SQL_PARAM_OUTPUT_STREAM, SQLCHAR,
+    SQLINTEGER, SQLLEN, SQLPOINTER, SQLSCHAR, SQLSETPOSIROW, SQLSMALLINT, SQLULEN, SQLUSMALLINT,
+    SQLWCHAR, Scope, StrLenOrInd, UnbindOption, Unique,
     attr::{AttrGet, AttrSet, StrLen},
-    c_types::CData,
     c_types::DeferredBuf,
+    c_types::OwnedBuf,
+    c_types::{CData, SQL_C_NUMERIC},
+    c_types::{ColumnBuf, DataAtExec, RowBuf},
     col::ColAttr,
     conn::{BrowseConnect, C2, C3, C4, ConnAttr, ConnState, Disconnect},
     convert::{
         AsMutPtr, AsMutRawSlice, AsMutSQLPOINTER, AsRawSlice, AsSQLHANDLE, AsSQLPOINTER,
         IntoSQLPOINTER,
     },
-    desc::{AppDesc, DescField, DescType, IPD, IRD},
+    desc::{
+        AppDesc, BindType, DescField, DescType, IPD, IRD, ParamOperation, ParamStatus, RowStatus,
+        SQL_BIND_BY_COLUMN, SQL_DESC_DATA_PTR, SQL_DESC_PRECISION, SQL_DESC_SCALE,
+    },
     diag::{DiagField, SQLSTATE},
     env::{EnvAttr, OdbcVersion, SQL_OV_ODBC3_80, SQL_OV_ODBC4},
     handle::{RefSQLHDESC, SQL_HANDLE_STMT, SQLHDBC, SQLHDESC, SQLHENV, SQLHSTMT, UnsafeSQLHSTMT},
     info::InfoType,
-    sql_types::SqlType,
-    sqlreturn::{SQL_NEED_DATA, SQL_STILL_EXECUTING, SQL_SUCCEEDED, SQLRETURN},
-    stmt::{StmtAttr, private::BaseStmtAttr},
+    sql_types::{SQL_LONGVARBINARY, SQL_LONGVARCHAR, SQL_WLONGVARCHAR, SqlType},
+    sqlreturn::{
+        SQL_NEED_DATA, SQL_NO_DATA, SQL_PARAM_DATA_AVAILABLE, SQL_STILL_EXECUTING, SQL_SUCCEEDED,
+        SQL_SUCCESS, SQLRETURN,
+    },
+    stmt::{
+        BindOffset, Bindings, ClosedState, CursorState, Execute, Prepare, S1, S2, S4, S6, S8,
+        SQL_ATTR_APP_PARAM_DESC, SQL_ATTR_APP_ROW_DESC, SQL_ATTR_PARAM_BIND_OFFSET_PTR,
+        SQL_ATTR_PARAM_BIND_TYPE, SQL_ATTR_PARAM_OPERATION_PTR, SQL_ATTR_PARAM_STATUS_PTR,
+        SQL_ATTR_PARAMS_PROCESSED_PTR, SQL_ATTR_PARAMSET_SIZE, SQL_ATTR_ROW_ARRAY_SIZE,
+        SQL_ATTR_ROW_BIND_OFFSET_PTR, SQL_ATTR_ROW_BIND_TYPE, SQL_ATTR_ROW_STATUS_PTR,
+        SQL_ATTR_ROWS_FETCHED_PTR, StmtAttr, StmtState, private::BaseStmtAttr,
+    },
     str::{Ansi, OdbcStr, Unicode},
 };
-use core::{cell::UnsafeCell, mem::MaybeUninit, ptr};
-#[cfg(test)]
-use mockall::automock;
+use core::{any::TypeId, cell::UnsafeCell, mem::ManuallyDrop, mem::MaybeUninit, ptr};
+#[cfg(feature = "std")]
+use std::{boxed::Box, vec::Vec};
+
+/// Connection returned by functions which connect to a data source. It is connected if the
+/// function succeeded and is returned in the allocated state otherwise
+pub type ConnectResult<'env, V> = Result<SQLHDBC<'env, C4, V>, SQLHDBC<'env, C2, V>>;
+
+/// Connection returned by SQLBrowseConnect. It is connected if the function succeeded, needs
+/// more connection attributes on SQL_NEED_DATA, is returned in the state `C` it was in before
+/// the call on SQL_STILL_EXECUTING and is returned in the allocated state otherwise
+pub type BrowseConnectResult<'env, C, V> = Result<
+    SQLHDBC<'env, C4, V>,
+    Result<SQLHDBC<'env, C3, V>, Result<SQLHDBC<'env, C, V>, SQLHDBC<'env, C2, V>>>,
+>;
+
+/// Statement returned by functions which execute it. It is executed if the function succeeded,
+/// needs data on SQL_NEED_DATA and is returned as `E` otherwise. `P` is the state into which
+/// the statement transitions when the cursor is closed
+pub type ExecResult<'conn, 'desc, 'buf, P, E, V> = Result<
+    SQLHSTMT<'conn, 'desc, 'buf, S4<P>, V>,
+    Result<SQLHSTMT<'conn, 'desc, 'buf, S8<P>, V>, E>,
+>;
+
+/// Statement returned by SQLMoreResults. It is executed if there are more results, its cursor
+/// is closed on SQL_NO_DATA and it is returned as `E` otherwise
+pub type MoreResultsResult<'conn, 'desc, 'buf, P, E, V> =
+    Result<SQLHSTMT<'conn, 'desc, 'buf, S4<P>, V>, Result<SQLHSTMT<'conn, 'desc, 'buf, P, V>, E>>;
 
 /// ODBC handle such as environment, connection, statement or descriptor.
 ///
 /// For complete documentation, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/develop-app/handles).
 pub trait Handle: AsSQLHANDLE + Sized {
     type Ident: Ident<Type = SQLSMALLINT>;
+    /// ODBC version of the handle which determines return codes functions can return
+    type Version: OdbcVersion;
 }
 
 #[expect(non_snake_case)]
@@ -75,11 +132,41 @@ pub trait Allocate<'src, SRC: AsSQLHANDLE>: Handle {
     ///
     /// # Panics
     ///
-    /// Panics if the DM returns value other than SQL_SUCCESS
+    /// Panics if the DM doesn't succeed in freeing the handle and the drop policy is set to
+    /// `DropPolicy::Panic`. To handle the failure explicitly use `Free::try_free` instead
     #[inline]
     fn SQLFreeHandle(self) {}
 }
 
+pub trait Free: Handle {
+    /// Frees resources associated with a specific environment, connection, statement, or descriptor handle.
+    /// Unlike when the handle is dropped, if freeing fails the handle is returned to the caller. Diagnostics
+    /// are not copied out of the handle but are retrieved from it through the `Diagnostics` trait, which
+    /// also leaves the caller free to retry freeing the handle or to drop it.
+    ///
+    /// For complete documentation on SQLFreeHandle, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlfreehandle-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    fn try_free(self) -> (Result<(), Self>, SQLRETURN) {
+        let sql_return =
+            unsafe { ffi::SQLFreeHandle(Self::Ident::IDENTIFIER, self.as_SQLHANDLE()) };
+
+        if SQL_SUCCEEDED(sql_return) {
+            #[cfg(feature = "runtime_checks")]
+            checks::free_handle(Self::Ident::IDENTIFIER, self.as_SQLHANDLE());
+
+            // Handle is freed, it must not be freed again on drop. Handles which keep
+            // state on the Rust side, i.e. statements, release it in their own impl
+            let _ = ManuallyDrop::new(self);
+            (Ok(()), sql_return)
+        } else {
+            (Err(self), sql_return)
+        }
+    }
+}
+
 #[expect(non_snake_case)]
 pub trait Diagnostics: Handle {
     /// Returns the current value of a field of a record of the diagnostic data structure (associated with a specified handle) that contains error, warning, and status information.
@@ -104,7 +191,7 @@ pub trait Diagnostics: Handle {
         MaybeUninit<T::StrLen>: StrLen<SQLSMALLINT>,
     {
         let DiagInfoPtr = DiagInfoPtr.map_or((ptr::null_mut(), 0), |DiagInfoPtr| {
-            if cfg!(feature = "odbc_debug") {
+            if cfg!(feature = "runtime_checks") {
                 DiagInfoPtr.assert_zeroed();
             }
 
@@ -146,7 +233,7 @@ pub trait Diagnostics: Handle {
         MaybeUninit<T::StrLen>: StrLen<SQLSMALLINT>,
     {
         let DiagInfoPtr = DiagInfoPtr.map_or((ptr::null_mut(), 0), |DiagInfoPtr| {
-            if cfg!(feature = "odbc_debug") {
+            if cfg!(feature = "runtime_checks") {
                 DiagInfoPtr.assert_zeroed();
             }
 
@@ -229,6 +316,75 @@ pub trait Diagnostics: Handle {
             )
         }
     }
+
+    /// Same as [`Diagnostics::SQLGetDiagRecA`] except that the buffer for the diagnostic message is
+    /// allocated by the function and reallocated, followed by a repeated call, until the whole
+    /// value fits into it.
+    ///
+    /// For complete documentation on SQLGetDiagRecA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlgetdiagrec-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    #[cfg(feature = "std")]
+    fn get_diag_rec_a(
+        &self,
+        RecNumber: core::num::NonZeroI16,
+        SQLState: &mut MaybeUninit<SQLSTATE<SQLCHAR>>,
+        NativeErrorPtr: &mut impl AsMutPtr<SQLINTEGER>,
+    ) -> (Option<OdbcString<SQLCHAR>>, SQLRETURN) {
+        retry_truncated(|MessageText| {
+            let mut TextLength = 0;
+            let sql_return = self.SQLGetDiagRecA(
+                RecNumber,
+                SQLState,
+                NativeErrorPtr,
+                MessageText,
+                &mut TextLength,
+            );
+
+            (sql_return, TextLength as SQLLEN)
+        })
+    }
+
+    /// Same as [`Diagnostics::SQLGetDiagRecW`] except that the buffer for the diagnostic message is
+    /// allocated by the function and reallocated, followed by a repeated call, until the whole
+    /// value fits into it.
+    ///
+    /// For complete documentation on SQLGetDiagRecW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlgetdiagrec-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    #[cfg(feature = "std")]
+    fn get_diag_rec_w(
+        &self,
+        RecNumber: core::num::NonZeroI16,
+        SQLState: &mut MaybeUninit<SQLSTATE<SQLWCHAR>>,
+        NativeErrorPtr: &mut impl AsMutPtr<SQLINTEGER>,
+    ) -> (Option<OdbcString<SQLWCHAR>>, SQLRETURN) {
+        retry_truncated(|MessageText| {
+            let mut TextLength = 0;
+            let sql_return = self.SQLGetDiagRecW(
+                RecNumber,
+                SQLState,
+                NativeErrorPtr,
+                MessageText,
+                &mut TextLength,
+            );
+
+            (sql_return, TextLength as SQLLEN)
+        })
+    }
+
+    /// Returns an iterator over the diagnostic records of the handle. Message of every record
+    /// is returned in full regardless of its length. Iteration stops after the last record or
+    /// if any of the records could not be retrieved
+    #[inline]
+    #[cfg(feature = "std")]
+    fn diagnostics(&self) -> DiagRecords<'_, Self> {
+        DiagRecords::new(self)
+    }
 }
 
 #[expect(non_snake_case)]
@@ -241,15 +397,29 @@ pub trait Statement<'desc, 'buf, V: OdbcVersion>: Handle {
     type ExplicitARD: Descriptor<'buf, AppDesc<'buf>, V>;
     type ExplicitAPD: Descriptor<'buf, AppDesc<'buf>, V>;
 
+    /// Options accepted by [`Statement::SQLFreeStmt`]
+    type FreeOption: Into<FreeStmtOption>;
+
     fn bind_col<TT: Ident, B: DeferredBuf<Self::ARD, TT, V> + ?Sized>(
         &self,
+        ColumnNumber: SQLUSMALLINT,
         TargetValuePtr: Option<&'buf B>,
+        StrLen_or_IndPtr: Option<&'buf UnsafeCell<StrLenOrInd>>,
     );
     fn bind_param<TT: Ident, B: DeferredBuf<Self::APD, TT, V> + ?Sized>(
         &self,
-        TargetValuePtr: Option<&'buf B>,
+        ParameterNumber: SQLUSMALLINT,
+        ParameterValuePtr: Option<&'buf B>,
+        StrLen_or_IndPtr: Option<&'buf UnsafeCell<StrLenOrInd>>,
     );
-    fn bind_strlen_or_ind(&self, StrLen_or_IndPtr: Option<&'buf UnsafeCell<StrLenOrInd>>);
+    fn unbind_cols(&self);
+    fn reset_params(&self);
+    fn binds_col_arrays(&self) -> bool;
+    fn binds_param_arrays(&self) -> bool;
+    /// Records whether an explicitly allocated ARD is associated with the statement
+    fn set_explicit_ard(&self, explicit: bool);
+    /// Records whether an explicitly allocated APD is associated with the statement
+    fn set_explicit_apd(&self, explicit: bool);
 
     /// Binds application data buffers to columns in the result set.
     ///
@@ -265,6 +435,27 @@ pub trait Statement<'desc, 'buf, V: OdbcVersion>: Handle {
         TargetValuePtr: Option<&'buf B>,
         StrLen_or_IndPtr: Option<&'buf UnsafeCell<StrLenOrInd>>,
     ) -> SQLRETURN {
+        // Driver would write past buffers of a single row when fetching a rowset of more rows
+        assert!(
+            !self.binds_col_arrays() || (TargetValuePtr.is_none() && StrLen_or_IndPtr.is_none()),
+            "Buffer of a single row bound while columns are bound as arrays"
+        );
+
+        #[cfg(feature = "runtime_checks")]
+        let ARD = match checks::app_desc::<SQL_ATTR_APP_ROW_DESC>(self.as_SQLHANDLE()) {
+            Ok(ARD) => ARD,
+            Err(sql_return) => return sql_return,
+        };
+        #[cfg(feature = "runtime_checks")]
+        let DataPtr = TargetValuePtr.map(|TargetValuePtr| {
+            (
+                TargetValuePtr.as_SQLPOINTER(),
+                TargetValuePtr.len() as usize,
+            )
+        });
+        #[cfg(feature = "runtime_checks")]
+        checks::check_bind(ARD, ColumnNumber as SQLSMALLINT, DataPtr, true);
+
         let sql_return = unsafe {
             let TargetValuePtr = TargetValuePtr.map_or((ptr::null_mut(), 0), |TargetValuePtr| {
                 (TargetValuePtr.as_SQLPOINTER(), TargetValuePtr.len())
@@ -283,8 +474,10 @@ pub trait Statement<'desc, 'buf, V: OdbcVersion>: Handle {
         };
 
         if SQL_SUCCEEDED(sql_return) {
-            self.bind_col(TargetValuePtr);
-            self.bind_strlen_or_ind(StrLen_or_IndPtr);
+            #[cfg(feature = "runtime_checks")]
+            checks::bind(ARD, ColumnNumber as SQLSMALLINT, DataPtr, true);
+
+            self.bind_col(ColumnNumber, TargetValuePtr, StrLen_or_IndPtr);
         }
 
         sql_return
@@ -314,6 +507,30 @@ pub trait Statement<'desc, 'buf, V: OdbcVersion>: Handle {
         ParameterValuePtr: Option<&'buf B>,
         StrLen_or_IndPtr: Option<&'buf UnsafeCell<StrLenOrInd>>,
     ) -> SQLRETURN {
+        // Driver would read past buffers of a single row when executing with more parameter sets
+        assert!(
+            !self.binds_param_arrays()
+                || (ParameterValuePtr.is_none() && StrLen_or_IndPtr.is_none()),
+            "Buffer of a single row bound while parameters are bound as arrays"
+        );
+
+        #[cfg(feature = "runtime_checks")]
+        let APD = match checks::app_desc::<SQL_ATTR_APP_PARAM_DESC>(self.as_SQLHANDLE()) {
+            Ok(APD) => APD,
+            Err(sql_return) => return sql_return,
+        };
+        #[cfg(feature = "runtime_checks")]
+        let DataPtr = ParameterValuePtr.map(|ParameterValuePtr| {
+            (
+                ParameterValuePtr.as_SQLPOINTER(),
+                ParameterValuePtr.len() as usize,
+            )
+        });
+        #[cfg(feature = "runtime_checks")]
+        let written = InputOutputType != SQL_PARAM_INPUT;
+        #[cfg(feature = "runtime_checks")]
+        checks::check_bind(APD, ParameterNumber as SQLSMALLINT, DataPtr, written);
+
         let sql_return = unsafe {
             let ParameterValuePtr = ParameterValuePtr
                 .map_or((ptr::null_mut(), 0), |ParameterValuePtr| {
@@ -337,32 +554,108 @@ pub trait Statement<'desc, 'buf, V: OdbcVersion>: Handle {
         };
 
         if SQL_SUCCEEDED(sql_return) {
-            self.bind_param(ParameterValuePtr);
-            self.bind_strlen_or_ind(StrLen_or_IndPtr);
+            #[cfg(feature = "runtime_checks")]
+            checks::bind(APD, ParameterNumber as SQLSMALLINT, DataPtr, written);
+
+            self.bind_param(ParameterNumber, ParameterValuePtr, StrLen_or_IndPtr);
         }
 
         sql_return
     }
-    /// Performs bulk insertions and bulk bookmark operations, including update, delete, and fetch by bookmark.
+
+    /// Binds SQL_NUMERIC_STRUCT buffers to a column in the result set and sets SQL_DESC_PRECISION
+    /// and SQL_DESC_SCALE of the corresponding ARD record. Otherwise, drivers use the default
+    /// scale of 0 and fractional digits of the fetched values are lost.
     ///
-    /// For complete documentation on SQLBulkOperations, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlbulkoperations-function).
+    /// For complete documentation on retrieving numeric data, see [API reference](https://learn.microsoft.com/en-us/sql/odbc/reference/appendixes/retrieve-numeric-data-sql-numeric-struct-kb222831).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NEED_DATA, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    fn SQLBulkOperations(&self, Operation: BulkOperation) -> SQLRETURN {
-        unsafe { ffi::SQLBulkOperations(self.as_SQLHANDLE(), Operation as SQLUSMALLINT) }
+    fn bind_col_numeric<B: DeferredBuf<Self::ARD, SQL_C_NUMERIC, V> + ?Sized>(
+        &self,
+        ColumnNumber: SQLUSMALLINT,
+        Precision: SQLCHAR,
+        Scale: SQLSCHAR,
+        TargetValuePtr: Option<&'buf B>,
+        StrLen_or_IndPtr: Option<&'buf UnsafeCell<StrLenOrInd>>,
+    ) -> SQLRETURN {
+        let sql_return = self.SQLBindCol(
+            ColumnNumber,
+            SQL_C_NUMERIC,
+            TargetValuePtr,
+            StrLen_or_IndPtr,
+        );
+
+        match TargetValuePtr {
+            Some(TargetValuePtr) if SQL_SUCCEEDED(sql_return) => {
+                set_numeric_desc::<SQL_ATTR_APP_ROW_DESC>(
+                    self.as_SQLHANDLE(),
+                    ColumnNumber,
+                    Precision,
+                    Scale,
+                    (TargetValuePtr.as_SQLPOINTER(), TargetValuePtr.len()),
+                )
+            }
+            _ => sql_return,
+        }
     }
 
-    /// Closes a cursor that has been opened on a statement and discards pending results.
+    /// Binds SQL_NUMERIC_STRUCT buffers to a parameter marker in an SQL statement. Precision and
+    /// scale are used as the column size and decimal digits of the parameter and are also set as
+    /// SQL_DESC_PRECISION and SQL_DESC_SCALE of the corresponding APD record. Otherwise, drivers
+    /// use the default scale of 0 and fractional digits of the sent values are lost.
     ///
-    /// For complete documentation on SQLCloseCursor, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlclosecursor-function).
+    /// For complete documentation on retrieving numeric data, see [API reference](https://learn.microsoft.com/en-us/sql/odbc/reference/appendixes/retrieve-numeric-data-sql-numeric-struct-kb222831).
     ///
     /// # Returns
     /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    fn SQLCloseCursor(&self) -> SQLRETURN {
-        unsafe { ffi::SQLCloseCursor(self.as_SQLHANDLE()) }
+    #[expect(clippy::too_many_arguments)]
+    fn bind_param_numeric<ST: SqlType<V>, B: DeferredBuf<Self::APD, SQL_C_NUMERIC, V> + ?Sized>(
+        &self,
+        ParameterNumber: SQLUSMALLINT,
+        InputOutputType: IOType,
+        ParameterType: ST,
+        Precision: SQLCHAR,
+        Scale: SQLSCHAR,
+        ParameterValuePtr: Option<&'buf B>,
+        StrLen_or_IndPtr: Option<&'buf UnsafeCell<StrLenOrInd>>,
+    ) -> SQLRETURN {
+        let sql_return = self.SQLBindParameter(
+            ParameterNumber,
+            InputOutputType,
+            SQL_C_NUMERIC,
+            ParameterType,
+            Precision.into(),
+            Scale.into(),
+            ParameterValuePtr,
+            StrLen_or_IndPtr,
+        );
+
+        match ParameterValuePtr {
+            Some(ParameterValuePtr) if SQL_SUCCEEDED(sql_return) => {
+                set_numeric_desc::<SQL_ATTR_APP_PARAM_DESC>(
+                    self.as_SQLHANDLE(),
+                    ParameterNumber,
+                    Precision,
+                    Scale,
+                    (ParameterValuePtr.as_SQLPOINTER(), ParameterValuePtr.len()),
+                )
+            }
+            _ => sql_return,
+        }
+    }
+
+    /// Performs bulk insertions and bulk bookmark operations, including update, delete, and fetch by bookmark.
+    ///
+    /// For complete documentation on SQLBulkOperations, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlbulkoperations-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NEED_DATA, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    fn SQLBulkOperations(&self, Operation: BulkOperation) -> SQLRETURN {
+        unsafe { ffi::SQLBulkOperations(self.as_SQLHANDLE(), Operation as SQLUSMALLINT) }
     }
 
     /// Returns descriptor information for a column in a result set. Descriptor information is returned as a character string, a descriptor-dependent value, or an integer value.
@@ -387,7 +680,7 @@ pub trait Statement<'desc, 'buf, V: OdbcVersion>: Handle {
         MaybeUninit<T::StrLen>: StrLen<SQLSMALLINT>,
     {
         // TODO: With MaybeUninit it's not possible to check that value is zeroed
-        //if cfg!(feature = "odbc_debug") {
+        //if cfg!(feature = "runtime_checks") {
         //    NumericAttributePtr.assert_zeroed();
         //}
 
@@ -434,7 +727,7 @@ pub trait Statement<'desc, 'buf, V: OdbcVersion>: Handle {
         MaybeUninit<T::StrLen>: StrLen<SQLSMALLINT>,
     {
         // TODO: With MaybeUninit it's not possible to check that value is zeroed
-        //if cfg!(feature = "odbc_debug") {
+        //if cfg!(feature = "runtime_checks") {
         //    NumericAttributePtr.assert_zeroed();
         //}
 
@@ -459,142 +752,6 @@ pub trait Statement<'desc, 'buf, V: OdbcVersion>: Handle {
         }
     }
 
-    /// Returns a list of columns and associated privileges for the specified table. The driver returns the information as a result set on the specified `self`.
-    ///
-    /// For complete documentation on SQLColumnPrivilegesA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlcolumnprivileges-function).
-    ///
-    /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
-    #[inline]
-    fn SQLColumnPrivilegesA(
-        &self,
-        CatalogName: &OdbcStr<SQLCHAR>,
-        SchemaName: &OdbcStr<SQLCHAR>,
-        TableName: &OdbcStr<SQLCHAR>,
-        ColumnName: &OdbcStr<SQLCHAR>,
-    ) -> SQLRETURN {
-        let CatalogName = CatalogName.as_raw_slice();
-        let SchemaName = SchemaName.as_raw_slice();
-        let TableName = TableName.as_raw_slice();
-        let ColumnName = ColumnName.as_raw_slice();
-
-        unsafe {
-            ffi::SQLColumnPrivilegesA(
-                self.as_SQLHANDLE(),
-                CatalogName.0,
-                CatalogName.1,
-                SchemaName.0,
-                SchemaName.1,
-                TableName.0,
-                TableName.1,
-                ColumnName.0,
-                ColumnName.1,
-            )
-        }
-    }
-
-    /// Returns a list of columns and associated privileges for the specified table. The driver returns the information as a result set on the specified `self`.
-    ///
-    /// For complete documentation on SQLColumnPrivilegesW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlcolumnprivileges-function).
-    ///
-    /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
-    #[inline]
-    fn SQLColumnPrivilegesW(
-        &self,
-        CatalogName: &OdbcStr<SQLWCHAR>,
-        SchemaName: &OdbcStr<SQLWCHAR>,
-        TableName: &OdbcStr<SQLWCHAR>,
-        ColumnName: &OdbcStr<SQLWCHAR>,
-    ) -> SQLRETURN {
-        let CatalogName = CatalogName.as_raw_slice();
-        let SchemaName = SchemaName.as_raw_slice();
-        let TableName = TableName.as_raw_slice();
-        let ColumnName = ColumnName.as_raw_slice();
-
-        unsafe {
-            ffi::SQLColumnPrivilegesW(
-                self.as_SQLHANDLE(),
-                CatalogName.0,
-                CatalogName.1,
-                SchemaName.0,
-                SchemaName.1,
-                TableName.0,
-                TableName.1,
-                ColumnName.0,
-                ColumnName.1,
-            )
-        }
-    }
-
-    /// Returns the list of column names in specified tables. The driver returns this information as a result set on the specified `self`.
-    ///
-    /// For complete documentation on SQLColumnsA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlcolumns-function).
-    ///
-    /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
-    #[inline]
-    fn SQLColumnsA(
-        &self,
-        CatalogName: &OdbcStr<SQLCHAR>,
-        SchemaName: &OdbcStr<SQLCHAR>,
-        TableName: &OdbcStr<SQLCHAR>,
-        ColumnName: &OdbcStr<SQLCHAR>,
-    ) -> SQLRETURN {
-        let CatalogName = CatalogName.as_raw_slice();
-        let SchemaName = SchemaName.as_raw_slice();
-        let TableName = TableName.as_raw_slice();
-        let ColumnName = ColumnName.as_raw_slice();
-
-        unsafe {
-            ffi::SQLColumnsA(
-                self.as_SQLHANDLE(),
-                CatalogName.0,
-                CatalogName.1,
-                SchemaName.0,
-                SchemaName.1,
-                TableName.0,
-                TableName.1,
-                ColumnName.0,
-                ColumnName.1,
-            )
-        }
-    }
-
-    /// Returns the list of column names in specified tables. The driver returns this information as a result set on the specified `self`.
-    ///
-    /// For complete documentation on SQLColumnsW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlcolumns-function).
-    ///
-    /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
-    #[inline]
-    fn SQLColumnsW(
-        &self,
-        CatalogName: &OdbcStr<SQLWCHAR>,
-        SchemaName: &OdbcStr<SQLWCHAR>,
-        TableName: &OdbcStr<SQLWCHAR>,
-        ColumnName: &OdbcStr<SQLWCHAR>,
-    ) -> SQLRETURN {
-        let CatalogName = CatalogName.as_raw_slice();
-        let SchemaName = SchemaName.as_raw_slice();
-        let TableName = TableName.as_raw_slice();
-        let ColumnName = ColumnName.as_raw_slice();
-
-        unsafe {
-            ffi::SQLColumnsW(
-                self.as_SQLHANDLE(),
-                CatalogName.0,
-                CatalogName.1,
-                SchemaName.0,
-                SchemaName.1,
-                TableName.0,
-                TableName.1,
-                ColumnName.0,
-                ColumnName.1,
-            )
-        }
-    }
-
     /// Returns the result descriptor - column name,type, column size, decimal digits, and nullability - for one column in the result set. This information also is available in the fields of the IRD.
     ///
     /// For complete documentation on SQLDescribeColA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqldescribecol-function).
@@ -665,123 +822,97 @@ pub trait Statement<'desc, 'buf, V: OdbcVersion>: Handle {
         }
     }
 
-    /// Returns the description of a parameter marker associated with a prepared SQL statement. This information is also available in the fields of the IPD.
+    /// Same as [`Statement::SQLDescribeColA`] except that the buffer for the column name is
+    /// allocated by the function and reallocated, followed by a repeated call, until the whole
+    /// value fits into it.
     ///
-    /// For complete documentation on SQLDescribeParam, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqldescribeparam-function).
+    /// For complete documentation on SQLDescribeColA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqldescribecol-function).
     ///
     /// # Returns
     /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    fn SQLDescribeParam(
+    #[cfg(feature = "std")]
+    fn describe_col_a(
         &self,
-        ParameterNumber: SQLUSMALLINT,
+        ColumnNumber: SQLUSMALLINT,
         DataTypePtr: &mut impl AsMutPtr<SQLSMALLINT>,
-        ParameterSizePtr: &mut impl AsMutPtr<SQLULEN>,
+        ColumnSizePtr: &mut impl AsMutPtr<SQLULEN>,
         DecimalDigitsPtr: &mut impl AsMutPtr<SQLSMALLINT>,
         NullablePtr: &mut impl AsMutPtr<NullAllowed>,
-    ) -> SQLRETURN {
-        unsafe {
-            ffi::SQLDescribeParam(
-                self.as_SQLHANDLE(),
-                ParameterNumber,
-                DataTypePtr.as_mut_ptr(),
-                ParameterSizePtr.as_mut_ptr(),
-                DecimalDigitsPtr.as_mut_ptr(),
-                NullablePtr.as_mut_ptr().cast(),
-            )
-        }
+    ) -> (Option<OdbcString<SQLCHAR>>, SQLRETURN) {
+        retry_truncated(|ColumnName| {
+            let mut NameLength = 0;
+            let sql_return = self.SQLDescribeColA(
+                ColumnNumber,
+                ColumnName,
+                &mut NameLength,
+                DataTypePtr,
+                ColumnSizePtr,
+                DecimalDigitsPtr,
+                NullablePtr,
+            );
+
+            (sql_return, NameLength as SQLLEN)
+        })
     }
 
-    /// Can return:
-    ///
-    /// * A list of foreign keys in the specified table (columns in the specified table that refer to primary keys in other tables).
-    /// * A list of foreign keys in other tables that refer to the primary key in the specified table.
-    ///
-    /// The driver returns each list as a result set on the specified statement.
+    /// Same as [`Statement::SQLDescribeColW`] except that the buffer for the column name is
+    /// allocated by the function and reallocated, followed by a repeated call, until the whole
+    /// value fits into it.
     ///
-    /// For complete documentation on SQLForeignKeysA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlforeignkeys-function).
+    /// For complete documentation on SQLDescribeColW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqldescribecol-function).
     ///
     /// # Returns
     /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    fn SQLForeignKeysA(
+    #[cfg(feature = "std")]
+    fn describe_col_w(
         &self,
-        PKCatalogName: &OdbcStr<SQLCHAR>,
-        PKSchemaName: &OdbcStr<SQLCHAR>,
-        PKTableName: &OdbcStr<SQLCHAR>,
-        FKCatalogName: &OdbcStr<SQLCHAR>,
-        FKSchemaName: &OdbcStr<SQLCHAR>,
-        FKTableName: &OdbcStr<SQLCHAR>,
-    ) -> SQLRETURN {
-        let PKCatalogName = PKCatalogName.as_raw_slice();
-        let PKSchemaName = PKSchemaName.as_raw_slice();
-        let PKTableName = PKTableName.as_raw_slice();
-        let FKCatalogName = FKCatalogName.as_raw_slice();
-        let FKSchemaName = FKSchemaName.as_raw_slice();
-        let FKTableName = FKTableName.as_raw_slice();
+        ColumnNumber: SQLUSMALLINT,
+        DataTypePtr: &mut impl AsMutPtr<SQLSMALLINT>,
+        ColumnSizePtr: &mut impl AsMutPtr<SQLULEN>,
+        DecimalDigitsPtr: &mut impl AsMutPtr<SQLSMALLINT>,
+        NullablePtr: &mut impl AsMutPtr<NullAllowed>,
+    ) -> (Option<OdbcString<SQLWCHAR>>, SQLRETURN) {
+        retry_truncated(|ColumnName| {
+            let mut NameLength = 0;
+            let sql_return = self.SQLDescribeColW(
+                ColumnNumber,
+                ColumnName,
+                &mut NameLength,
+                DataTypePtr,
+                ColumnSizePtr,
+                DecimalDigitsPtr,
+                NullablePtr,
+            );
 
-        unsafe {
-            ffi::SQLForeignKeysA(
-                self.as_SQLHANDLE(),
-                PKCatalogName.0,
-                PKCatalogName.1,
-                PKSchemaName.0,
-                PKSchemaName.1,
-                PKTableName.0,
-                PKTableName.1,
-                FKCatalogName.0,
-                FKCatalogName.1,
-                FKSchemaName.0,
-                FKSchemaName.1,
-                FKTableName.0,
-                FKTableName.1,
-            )
-        }
+            (sql_return, NameLength as SQLLEN)
+        })
     }
 
-    /// Can return:
-    ///
-    /// * A list of foreign keys in the specified table (columns in the specified table that refer to primary keys in other tables).
-    /// * A list of foreign keys in other tables that refer to the primary key in the specified table.
-    ///
-    /// The driver returns each list as a result set on the specified statement.
+    /// Returns the description of a parameter marker associated with a prepared SQL statement. This information is also available in the fields of the IPD.
     ///
-    /// For complete documentation on SQLForeignKeysW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlforeignkeys-function).
+    /// For complete documentation on SQLDescribeParam, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqldescribeparam-function).
     ///
     /// # Returns
     /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    fn SQLForeignKeysW(
+    fn SQLDescribeParam(
         &self,
-        PKCatalogName: &OdbcStr<SQLWCHAR>,
-        PKSchemaName: &OdbcStr<SQLWCHAR>,
-        PKTableName: &OdbcStr<SQLWCHAR>,
-        FKCatalogName: &OdbcStr<SQLWCHAR>,
-        FKSchemaName: &OdbcStr<SQLWCHAR>,
-        FKTableName: &OdbcStr<SQLWCHAR>,
+        ParameterNumber: SQLUSMALLINT,
+        DataTypePtr: &mut impl AsMutPtr<SQLSMALLINT>,
+        ParameterSizePtr: &mut impl AsMutPtr<SQLULEN>,
+        DecimalDigitsPtr: &mut impl AsMutPtr<SQLSMALLINT>,
+        NullablePtr: &mut impl AsMutPtr<NullAllowed>,
     ) -> SQLRETURN {
-        let PKCatalogName = PKCatalogName.as_raw_slice();
-        let PKSchemaName = PKSchemaName.as_raw_slice();
-        let PKTableName = PKTableName.as_raw_slice();
-        let FKCatalogName = FKCatalogName.as_raw_slice();
-        let FKSchemaName = FKSchemaName.as_raw_slice();
-        let FKTableName = FKTableName.as_raw_slice();
-
         unsafe {
-            ffi::SQLForeignKeysW(
+            ffi::SQLDescribeParam(
                 self.as_SQLHANDLE(),
-                PKCatalogName.0,
-                PKCatalogName.1,
-                PKSchemaName.0,
-                PKSchemaName.1,
-                PKTableName.0,
-                PKTableName.1,
-                FKCatalogName.0,
-                FKCatalogName.1,
-                FKSchemaName.0,
-                FKSchemaName.1,
-                FKTableName.0,
-                FKTableName.1,
+                ParameterNumber,
+                DataTypePtr.as_mut_ptr(),
+                ParameterSizePtr.as_mut_ptr(),
+                DecimalDigitsPtr.as_mut_ptr(),
+                NullablePtr.as_mut_ptr().cast(),
             )
         }
     }
@@ -790,11 +921,48 @@ pub trait Statement<'desc, 'buf, V: OdbcVersion>: Handle {
     ///
     /// For complete documentation on SQLFreeStmt, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlfreestmt-function).
     ///
+    /// SQL_CLOSE is not accepted by statements which track their state in their type. Cursor of
+    /// such statement is closed with [`SQLHSTMT::close_cursor`] which transitions it into the
+    /// closed state.
+    ///
     /// # Returns
     /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    fn SQLFreeStmt(&self, Option: FreeStmtOption) -> SQLRETURN {
-        unsafe { ffi::SQLFreeStmt(self.as_SQLHANDLE(), Option as SQLUSMALLINT) }
+    fn SQLFreeStmt(&self, Option: Self::FreeOption) -> SQLRETURN {
+        let Option = Option.into();
+
+        #[cfg(feature = "runtime_checks")]
+        let desc = match Option {
+            FreeStmtOption::SQL_CLOSE => Ok(None),
+            FreeStmtOption::SQL_UNBIND => {
+                checks::app_desc::<SQL_ATTR_APP_ROW_DESC>(self.as_SQLHANDLE()).map(Some)
+            }
+            FreeStmtOption::SQL_RESET_PARAMS => {
+                checks::app_desc::<SQL_ATTR_APP_PARAM_DESC>(self.as_SQLHANDLE()).map(Some)
+            }
+        };
+        #[cfg(feature = "runtime_checks")]
+        let desc = match desc {
+            Ok(desc) => desc,
+            Err(sql_return) => return sql_return,
+        };
+
+        let sql_return = unsafe { ffi::SQLFreeStmt(self.as_SQLHANDLE(), Option as SQLUSMALLINT) };
+
+        if SQL_SUCCEEDED(sql_return) {
+            #[cfg(feature = "runtime_checks")]
+            if let Some(desc) = desc {
+                checks::unbind(desc);
+            }
+
+            match Option {
+                FreeStmtOption::SQL_CLOSE => {}
+                FreeStmtOption::SQL_UNBIND => self.unbind_cols(),
+                FreeStmtOption::SQL_RESET_PARAMS => self.reset_params(),
+            }
+        }
+
+        sql_return
     }
 
     /// Returns the cursor name associated with a specified statement.
@@ -845,34 +1013,42 @@ pub trait Statement<'desc, 'buf, V: OdbcVersion>: Handle {
         }
     }
 
-    /// Retrieves data for a single column in the result set or for a single parameter after **SQLParamData** returns SQL_PARAM_DATA_AVAILABLE. It can be called multiple times to retrieve variable-length data in parts.
+    /// Same as [`Statement::SQLGetCursorNameA`] except that the buffer for the cursor name is
+    /// allocated by the function and reallocated, followed by a repeated call, until the whole
+    /// value fits into it.
     ///
-    /// For complete documentation on SQLGetData, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlgetdata-function).
+    /// For complete documentation on SQLGetCursorNameA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlgetcursorname-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NO_DATA, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    // TODO: This function must be unsafe if SQL_ARD_TYPE and SQL_APD_TYPE are allowed to be used
-    fn SQLGetData<TT: Ident<Type = SQLSMALLINT>, B: CData<TT, V> + AsMutSQLPOINTER + ?Sized>(
-        &self,
-        Col_or_Param_Num: SQLUSMALLINT,
-        #[expect(unused_variables)] TargetType: TT,
-        TargetValuePtr: &mut B,
-        StrLen_or_IndPtr: Option<&mut MaybeUninit<StrLenOrInd>>,
-    ) -> SQLRETURN
-    where
-        MaybeUninit<StrLenOrInd>: StrLen<SQLLEN>,
-    {
-        unsafe {
-            ffi::SQLGetData(
-                self.as_SQLHANDLE(),
-                Col_or_Param_Num,
-                TT::IDENTIFIER,
-                TargetValuePtr.as_mut_SQLPOINTER(),
-                TargetValuePtr.len(),
-                StrLen_or_IndPtr.map_or_else(ptr::null_mut, StrLen::as_mut_ptr),
-            )
-        }
+    #[cfg(feature = "std")]
+    fn get_cursor_name_a(&self) -> (Option<OdbcString<SQLCHAR>>, SQLRETURN) {
+        retry_truncated(|CursorName| {
+            let mut NameLength = 0;
+            let sql_return = self.SQLGetCursorNameA(CursorName, &mut NameLength);
+
+            (sql_return, NameLength as SQLLEN)
+        })
+    }
+
+    /// Same as [`Statement::SQLGetCursorNameW`] except that the buffer for the cursor name is
+    /// allocated by the function and reallocated, followed by a repeated call, until the whole
+    /// value fits into it.
+    ///
+    /// For complete documentation on SQLGetCursorNameW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlgetcursorname-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    #[cfg(feature = "std")]
+    fn get_cursor_name_w(&self) -> (Option<OdbcString<SQLWCHAR>>, SQLRETURN) {
+        retry_truncated(|CursorName| {
+            let mut NameLength = 0;
+            let sql_return = self.SQLGetCursorNameW(CursorName, &mut NameLength);
+
+            (sql_return, NameLength as SQLLEN)
+        })
     }
 
     /// Returns the current setting of a statement attribute.
@@ -921,40 +1097,6 @@ pub trait Statement<'desc, 'buf, V: OdbcVersion>: Handle {
         SQLGetStmtAttrW(self, Attribute, ValuePtr, StringLengthPtr)
     }
 
-    /// Returns information about data types supported by the data source. The driver returns the information in the form of an SQL result set. The data types are intended for use in Data Definition Language (DDL) statements.
-    ///
-    /// For complete documentation on SQLGetTypeInfoA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlgettypeinfo-function).
-    ///
-    /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
-    #[inline]
-    fn SQLGetTypeInfoA<ST: SqlType<V>>(&self, DataType: ST) -> SQLRETURN {
-        unsafe { ffi::SQLGetTypeInfoA(self.as_SQLHANDLE(), DataType.identifier()) }
-    }
-
-    /// Returns information about data types supported by the data source. The driver returns the information in the form of an SQL result set. The data types are intended for use in Data Definition Language (DDL) statements.
-    ///
-    /// For complete documentation on SQLGetTypeInfoW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlgettypeinfo-function).
-    ///
-    /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
-    #[inline]
-    fn SQLGetTypeInfoW<ST: SqlType<V>>(&self, DataType: ST) -> SQLRETURN {
-        unsafe { ffi::SQLGetTypeInfoW(self.as_SQLHANDLE(), DataType.identifier()) }
-    }
-
-    /// Determines whether more results are available on a statement containing **SELECT**, **UPDATE**, **INSERT**, or **DELETE** statements and, if so, initializes processing for those results.
-    ///
-    /// For complete documentation on SQLMoreResults, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlmoreresults-function).
-    ///
-    /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_NO_DATA, SQL_ERROR, SQL_INVALID_HANDLE, OR SQL_PARAM_DATA_AVAILABLE.
-    #[inline]
-    // TODO: Maybe this fn should be unsafe
-    fn SQLMoreResults(&self) -> SQLRETURN {
-        unsafe { ffi::SQLMoreResults(self.as_SQLHANDLE()) }
-    }
-
     /// Returns the number of parameters in an SQL statement.
     ///
     /// For complete documentation on SQLNumParams, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlnumparams-function).
@@ -977,642 +1119,838 @@ pub trait Statement<'desc, 'buf, V: OdbcVersion>: Handle {
         unsafe { ffi::SQLNumResultCols(self.as_SQLHANDLE(), ColumnCountPtr.as_mut_ptr()) }
     }
 
-    /// Used together with **SQLPutData** to supply parameter data at statement execution time, and with **SQLGetData** to retrieve streamed output parameter data.
+    /// Returns the number of rows affected by an **UPDATE**, **INSERT**, or **DELETE** statement; an SQL_ADD, SQL_UPDATE_BY_BOOKMARK, or SQL_DELETE_BY_BOOKMARK operation in **SQLBulkOperations**; or an SQL_UPDATE or SQL_DELETE operation in **SQLSetPos**.
     ///
-    /// For complete documentation on SQLParamData, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlparamdata-function).
+    /// For complete documentation on SQLRowCount, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlrowcount-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NEED_DATA, SQL_NO_DATA, SQL_STILL_EXECUTING, SQL_ERROR, SQL_INVALID_HANDLE, or SQL_PARAM_DATA_AVAILABLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    fn SQLParamData(&self, ValuePtrPtr: &mut MaybeUninit<SQLPOINTER>) -> SQLRETURN {
-        unsafe { ffi::SQLParamData(self.as_SQLHANDLE(), ValuePtrPtr.as_mut_ptr()) }
+    fn SQLRowCount(&self, RowCountPtr: &mut impl AsMutPtr<SQLLEN>) -> SQLRETURN {
+        unsafe { ffi::SQLRowCount(self.as_SQLHANDLE(), RowCountPtr.as_mut_ptr()) }
     }
 
-    /// Prepares an SQL string for execution.
+    /// Associates a cursor name with an active statement. If an application does not call **SQLSetCursorName**, the driver generates cursor names as needed for SQL statement processing.
     ///
-    /// For complete documentation on SQLPrepareA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlprepare-function).
+    /// For complete documentation on SQLSetCursorNameA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlsetcursorname-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    fn SQLPrepareA(&self, StatementText: &OdbcStr<SQLCHAR>) -> SQLRETURN {
-        let StatementText = StatementText.as_raw_slice();
+    fn SQLSetCursorNameA(&self, CursorName: &OdbcStr<SQLCHAR>) -> SQLRETURN {
+        let CursorName = CursorName.as_raw_slice();
 
-        unsafe { ffi::SQLPrepareA(self.as_SQLHANDLE(), StatementText.0, StatementText.1) }
+        unsafe { ffi::SQLSetCursorNameA(self.as_SQLHANDLE(), CursorName.0, CursorName.1) }
     }
 
-    /// Prepares an SQL string for execution.
+    /// Associates a cursor name with an active statement. If an application does not call **SQLSetCursorName**, the driver generates cursor names as needed for SQL statement processing.
     ///
-    /// For complete documentation on SQLPrepareW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlprepare-function).
+    /// For complete documentation on SQLSetCursorNameW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlsetcursorname-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    fn SQLPrepareW(&self, StatementText: &OdbcStr<SQLWCHAR>) -> SQLRETURN {
-        let StatementText = StatementText.as_raw_slice();
+    fn SQLSetCursorNameW(&self, CursorName: &OdbcStr<SQLWCHAR>) -> SQLRETURN {
+        let CursorName = CursorName.as_raw_slice();
 
-        unsafe { ffi::SQLPrepareW(self.as_SQLHANDLE(), StatementText.0, StatementText.1) }
+        unsafe { ffi::SQLSetCursorNameW(self.as_SQLHANDLE(), CursorName.0, CursorName.1) }
     }
 
-    /// Returns the column names that make up the primary key for a table. The driver returns the information as a result set. This function does not support returning primary keys from multiple tables in a single call.
+    /// Sets attributes related to a statement.
     ///
-    /// For complete documentation on SQLPrimaryKeysA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlprimarykeys-function).
+    /// For complete documentation on SQLSetStmtAttrA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlsetstmtattr-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    fn SQLPrimaryKeysA(
+    fn SQLSetStmtAttrA<
+        A: Ident<Type = SQLINTEGER>,
+        T: StmtAttr<'desc, 'buf, Self, A, V> + AttrSet<A> + Ansi,
+    >(
         &self,
-        CatalogName: &OdbcStr<SQLCHAR>,
-        SchemaName: &OdbcStr<SQLCHAR>,
-        TableName: &OdbcStr<SQLCHAR>,
+        Attribute: A,
+        ValuePtr: T,
     ) -> SQLRETURN {
-        let CatalogName = CatalogName.as_raw_slice();
-        let SchemaName = SchemaName.as_raw_slice();
-        let TableName = TableName.as_raw_slice();
-
-        unsafe {
-            ffi::SQLPrimaryKeysA(
-                self.as_SQLHANDLE(),
-                CatalogName.0,
-                CatalogName.1,
-                SchemaName.0,
-                SchemaName.1,
-                TableName.0,
-                TableName.1,
-            )
-        }
+        SQLSetStmtAttrA(self, Attribute, ValuePtr)
     }
 
-    /// Returns the column names that make up the primary key for a table. The driver returns the information as a result set. This function does not support returning primary keys from multiple tables in a single call.
+    /// Sets attributes related to a statement.
     ///
-    /// For complete documentation on SQLPrimaryKeysW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlprimarykeys-function).
+    /// For complete documentation on SQLSetStmtAttrW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlsetstmtattr-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    fn SQLPrimaryKeysW(
+    fn SQLSetStmtAttrW<
+        A: Ident<Type = SQLINTEGER>,
+        T: StmtAttr<'desc, 'buf, Self, A, V> + AttrSet<A> + Unicode,
+    >(
         &self,
-        CatalogName: &OdbcStr<SQLWCHAR>,
-        SchemaName: &OdbcStr<SQLWCHAR>,
-        TableName: &OdbcStr<SQLWCHAR>,
+        Attribute: A,
+        ValuePtr: T,
     ) -> SQLRETURN {
-        let CatalogName = CatalogName.as_raw_slice();
-        let SchemaName = SchemaName.as_raw_slice();
-        let TableName = TableName.as_raw_slice();
-
-        unsafe {
-            ffi::SQLPrimaryKeysW(
-                self.as_SQLHANDLE(),
-                CatalogName.0,
-                CatalogName.1,
-                SchemaName.0,
-                SchemaName.1,
-                TableName.0,
-                TableName.1,
-            )
-        }
+        SQLSetStmtAttrW(self, Attribute, ValuePtr)
     }
+}
 
-    /// Returns the list of input and output parameters, as well as the columns that make up the result set for the specified procedures. The driver returns the information as a result set on the specified statement.
+#[expect(non_snake_case)]
+pub trait Descriptor<'buf, DT, V: OdbcVersion>: Handle {
+    /// Copies descriptor information from one descriptor handle to another.
     ///
-    /// For complete documentation on SQLProcedureColumnsA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlprocedurecolumns-function).
+    /// For complete documentation on SQLCopyDesc, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlcopydesc-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    fn SQLProcedureColumnsA(
-        &self,
-        CatalogName: &OdbcStr<SQLCHAR>,
-        SchemaName: &OdbcStr<SQLCHAR>,
-        ProcName: &OdbcStr<SQLCHAR>,
-        ColumnName: &OdbcStr<SQLCHAR>,
-    ) -> SQLRETURN {
-        let CatalogName = CatalogName.as_raw_slice();
-        let SchemaName = SchemaName.as_raw_slice();
-        let ProcName = ProcName.as_raw_slice();
-        let ColumnName = ColumnName.as_raw_slice();
+    // TODO: Not sure if application and implementation descriptors can be interchangeably copied
+    // TODO: Do they have to have the same version?
+    // TODO: Is lifetime the same?
+    fn SQLCopyDesc<DT2: DescType<'buf>>(&self, TargetDescHandle: &SQLHDESC<DT2, V>) -> SQLRETURN {
+        let SourceDescHandle = self.as_SQLHANDLE();
+        let TargetDescHandle = TargetDescHandle.as_SQLHANDLE();
+        let sql_return = unsafe { ffi::SQLCopyDesc(SourceDescHandle, TargetDescHandle) };
 
-        unsafe {
-            ffi::SQLProcedureColumnsA(
-                self.as_SQLHANDLE(),
-                CatalogName.0,
-                CatalogName.1,
-                SchemaName.0,
-                SchemaName.1,
-                ProcName.0,
-                ProcName.1,
-                ColumnName.0,
-                ColumnName.1,
-            )
+        #[cfg(feature = "runtime_checks")]
+        if SQL_SUCCEEDED(sql_return) {
+            checks::copy_desc(SourceDescHandle, TargetDescHandle);
         }
+
+        sql_return
     }
 
-    /// Returns the list of input and output parameters, as well as the columns that make up the result set for the specified procedures. The driver returns the information as a result set on the specified statement.
+    /// Returns the current setting or value of a single field of a descriptor record.
     ///
-    /// For complete documentation on SQLProcedureColumnsW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlprocedurecolumns-function).
+    /// For complete documentation on SQLGetDescFieldA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlgetdescfield-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, SQL_NO_DATA, or SQL_INVALID_HANDLE.
     #[inline]
-    fn SQLProcedureColumnsW(
+    fn SQLGetDescFieldA<
+        A: Ident<Type = SQLSMALLINT>,
+        T: DescField<'buf, Self, DT, A, V> + AttrGet<A> + Ansi + ?Sized,
+    >(
         &self,
-        CatalogName: &OdbcStr<SQLWCHAR>,
-        SchemaName: &OdbcStr<SQLWCHAR>,
-        ProcName: &OdbcStr<SQLWCHAR>,
-        ColumnName: &OdbcStr<SQLWCHAR>,
-    ) -> SQLRETURN {
-        let CatalogName = CatalogName.as_raw_slice();
-        let SchemaName = SchemaName.as_raw_slice();
-        let ProcName = ProcName.as_raw_slice();
-        let ColumnName = ColumnName.as_raw_slice();
+        RecNumber: SQLSMALLINT,
+        #[expect(unused_variables)] FieldIdentifier: A,
+        ValuePtr: Option<&mut T>,
+        StringLengthPtr: Option<&mut MaybeUninit<T::StrLen>>,
+    ) -> SQLRETURN
+    where
+        MaybeUninit<T::StrLen>: StrLen<SQLINTEGER>,
+    {
+        let DescriptorHandle = self.as_SQLHANDLE();
+
+        let ValuePtr = ValuePtr.map_or((ptr::null_mut(), 0), |ValuePtr| {
+            if cfg!(feature = "runtime_checks") {
+                ValuePtr.assert_zeroed();
+            }
+
+            (ValuePtr.as_mut_SQLPOINTER(), ValuePtr.len())
+        });
 
         unsafe {
-            ffi::SQLProcedureColumnsW(
-                self.as_SQLHANDLE(),
-                CatalogName.0,
-                CatalogName.1,
-                SchemaName.0,
-                SchemaName.1,
-                ProcName.0,
-                ProcName.1,
-                ColumnName.0,
-                ColumnName.1,
+            ffi::SQLGetDescFieldA(
+                DescriptorHandle,
+                RecNumber,
+                A::IDENTIFIER,
+                ValuePtr.0,
+                ValuePtr.1,
+                StringLengthPtr.map_or_else(ptr::null_mut, StrLen::as_mut_ptr),
             )
         }
     }
 
-    /// Returns the list of procedure names stored in a specific data source. `Procedure` is a generic term used to describe an `executable object`, or a named entity that can be invoked using input and output parameters. For more information on procedures, see the Procedures.
+    /// Returns the current setting or value of a single field of a descriptor record.
     ///
-    /// For complete documentation on SQLProceduresA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlprocedures-function).
+    /// For complete documentation on SQLGetDescFieldW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlgetdescfield-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, SQL_NO_DATA, or SQL_INVALID_HANDLE.
     #[inline]
-    fn SQLProceduresA(
+    fn SQLGetDescFieldW<
+        A: Ident<Type = SQLSMALLINT>,
+        T: DescField<'buf, Self, DT, A, V> + AttrGet<A> + Unicode + ?Sized,
+    >(
         &self,
-        CatalogName: &OdbcStr<SQLCHAR>,
-        SchemaName: &OdbcStr<SQLCHAR>,
-        ProcName: &OdbcStr<SQLCHAR>,
-    ) -> SQLRETURN {
-        let CatalogName = CatalogName.as_raw_slice();
-        let SchemaName = SchemaName.as_raw_slice();
-        let ProcName = ProcName.as_raw_slice();
+        RecNumber: SQLSMALLINT,
+        #[expect(unused_variables)] FieldIdentifier: A,
+        ValuePtr: Option<&mut T>,
+        StringLengthPtr: Option<&mut MaybeUninit<T::StrLen>>,
+    ) -> SQLRETURN
+    where
+        MaybeUninit<T::StrLen>: StrLen<SQLINTEGER>,
+    {
+        let DescriptorHandle = self.as_SQLHANDLE();
+        let ValuePtr = ValuePtr.map_or((ptr::null_mut(), 0), |ValuePtr| {
+            if cfg!(feature = "runtime_checks") {
+                ValuePtr.assert_zeroed();
+            }
+
+            (ValuePtr.as_mut_SQLPOINTER(), ValuePtr.len())
+        });
 
         unsafe {
-            ffi::SQLProceduresA(
-                self.as_SQLHANDLE(),
-                CatalogName.0,
-                CatalogName.1,
-                SchemaName.0,
-                SchemaName.1,
-                ProcName.0,
-                ProcName.1,
+            ffi::SQLGetDescFieldW(
+                DescriptorHandle,
+                RecNumber,
+                A::IDENTIFIER,
+                ValuePtr.0,
+                ValuePtr.1,
+                StringLengthPtr.map_or_else(ptr::null_mut, StrLen::as_mut_ptr),
             )
         }
     }
 
-    /// Returns the list of procedure names stored in a specific data source. `Procedure` is a generic term used to describe an `executable object`, or a named entity that can be invoked using input and output parameters. For more information on procedures, see the Procedures.
+    /// Returns the current settings or values of multiple fields of a descriptor record. The fields returned describe the name, data type, and storage of column or parameter data.
     ///
-    /// For complete documentation on SQLProceduresW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlprocedures-function).
+    /// For complete documentation on SQLGetDescRecA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlgetdescrec-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, SQL_NO_DATA, or SQL_INVALID_HANDLE.
     #[inline]
-    fn SQLProceduresW(
+    #[expect(clippy::too_many_arguments)]
+    fn SQLGetDescRecA<ST: SqlType<V>>(
         &self,
-        CatalogName: &OdbcStr<SQLWCHAR>,
-        SchemaName: &OdbcStr<SQLWCHAR>,
-        ProcName: &OdbcStr<SQLWCHAR>,
+        RecNumber: SQLSMALLINT,
+        Name: Option<&mut OdbcStr<MaybeUninit<SQLCHAR>>>,
+        StringLengthPtr: &mut impl AsMutPtr<SQLSMALLINT>,
+        TypePtr: &mut impl AsMutPtr<ST>,
+        SubTypePtr: &mut impl AsMutPtr<DatetimeIntervalCode>,
+        LengthPtr: &mut impl AsMutPtr<SQLLEN>,
+        PrecisionPtr: &mut impl AsMutPtr<SQLSMALLINT>,
+        ScalePtr: &mut impl AsMutPtr<SQLSMALLINT>,
+        NullablePtr: &mut impl AsMutPtr<NullAllowed>,
     ) -> SQLRETURN {
-        let CatalogName = CatalogName.as_raw_slice();
-        let SchemaName = SchemaName.as_raw_slice();
-        let ProcName = ProcName.as_raw_slice();
+        let DescriptorHandle = self.as_SQLHANDLE();
+        let Name = Name.map_or((ptr::null_mut(), 0), AsMutRawSlice::as_mut_raw_slice);
 
         unsafe {
-            ffi::SQLProceduresW(
-                self.as_SQLHANDLE(),
-                CatalogName.0,
-                CatalogName.1,
-                SchemaName.0,
-                SchemaName.1,
-                ProcName.0,
-                ProcName.1,
+            ffi::SQLGetDescRecA(
+                DescriptorHandle,
+                RecNumber,
+                Name.0,
+                Name.1,
+                StringLengthPtr.as_mut_ptr(),
+                TypePtr.as_mut_ptr().cast(),
+                SubTypePtr.as_mut_ptr().cast(),
+                LengthPtr.as_mut_ptr(),
+                PrecisionPtr.as_mut_ptr(),
+                ScalePtr.as_mut_ptr(),
+                NullablePtr.as_mut_ptr().cast(),
             )
         }
     }
 
-    /// Allows an application to send data for a parameter or column to the driver at statement execution time. This function can be used to send character or binary data values in parts to a column with a character, binary, or data source-specific data type (for example, parameters of the SQL_LONGVARBINARY or SQL_LONGVARCHAR types). **SQLPutData** supports binding to a Unicode C data type, even if the underlying driver does not support Unicode data.
+    /// Returns the current settings or values of multiple fields of a descriptor record. The fields returned describe the name, data type, and storage of column or parameter data.
     ///
-    /// For complete documentation on SQLPutData, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlputdata-function).
+    /// For complete documentation on SQLGetDescRecW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlgetdescrec-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, SQL_NO_DATA, or SQL_INVALID_HANDLE.
     #[inline]
-    // TODO: Is it unsafe if odbc_debug is used?
-    unsafe fn SQLPutData<TT: Ident, B: CData<TT, V> + AsSQLPOINTER + ?Sized>(
+    #[expect(clippy::too_many_arguments)]
+    fn SQLGetDescRecW<ST: SqlType<V>>(
         &self,
-        DataPtr: Option<&B>,
+        RecNumber: SQLSMALLINT,
+        Name: Option<&mut OdbcStr<MaybeUninit<SQLWCHAR>>>,
+        StringLengthPtr: &mut impl AsMutPtr<SQLSMALLINT>,
+        TypePtr: &mut impl AsMutPtr<ST>,
+        SubTypePtr: &mut impl AsMutPtr<DatetimeIntervalCode>,
+        LengthPtr: &mut impl AsMutPtr<SQLLEN>,
+        PrecisionPtr: &mut impl AsMutPtr<SQLSMALLINT>,
+        ScalePtr: &mut impl AsMutPtr<SQLSMALLINT>,
+        NullablePtr: &mut impl AsMutPtr<NullAllowed>,
     ) -> SQLRETURN {
-        let DataPtr = DataPtr.map_or((ptr::null_mut(), 0), |DataPtr| {
-            (DataPtr.as_SQLPOINTER(), DataPtr.len())
-        });
+        let DescriptorHandle = self.as_SQLHANDLE();
+        let Name = Name.map_or((ptr::null_mut(), 0), AsMutRawSlice::as_mut_raw_slice);
 
-        unsafe { ffi::SQLPutData(self.as_SQLHANDLE(), DataPtr.0, DataPtr.1) }
+        unsafe {
+            ffi::SQLGetDescRecW(
+                DescriptorHandle,
+                RecNumber,
+                Name.0,
+                Name.1,
+                StringLengthPtr.as_mut_ptr(),
+                TypePtr.as_mut_ptr().cast(),
+                SubTypePtr.as_mut_ptr().cast(),
+                LengthPtr.as_mut_ptr(),
+                PrecisionPtr.as_mut_ptr(),
+                ScalePtr.as_mut_ptr(),
+                NullablePtr.as_mut_ptr().cast(),
+            )
+        }
     }
 
-    /// Returns the number of rows affected by an **UPDATE**, **INSERT**, or **DELETE** statement; an SQL_ADD, SQL_UPDATE_BY_BOOKMARK, or SQL_DELETE_BY_BOOKMARK operation in **SQLBulkOperations**; or an SQL_UPDATE or SQL_DELETE operation in **SQLSetPos**.
+    /// Sets the value of a single field of a descriptor record.
     ///
-    /// For complete documentation on SQLRowCount, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlrowcount-function).
+    /// For complete documentation on SQLSetDescFieldA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlsetdescfield-function).
     ///
     /// # Returns
     /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    fn SQLRowCount(&self, RowCountPtr: &mut impl AsMutPtr<SQLLEN>) -> SQLRETURN {
-        unsafe { ffi::SQLRowCount(self.as_SQLHANDLE(), RowCountPtr.as_mut_ptr()) }
-    }
+    fn SQLSetDescFieldA<
+        A: Ident<Type = SQLSMALLINT>,
+        T: DescField<'buf, Self, DT, A, V> + AttrSet<A> + Ansi,
+    >(
+        &self,
+        RecNumber: SQLSMALLINT,
+        #[expect(unused_variables)] FieldIdentifier: A,
+        ValuePtr: Option<T>,
+    ) -> SQLRETURN {
+        let sql_return = unsafe {
+            let ValuePtr = ValuePtr.map_or((ptr::null_mut(), 0), |ValuePtr| {
+                (ValuePtr.into_SQLPOINTER(), ValuePtr.len())
+            });
 
-    /// Associates a cursor name with an active statement. If an application does not call **SQLSetCursorName**, the driver generates cursor names as needed for SQL statement processing.
+            #[cfg(feature = "runtime_checks")]
+            checks::check_desc_field(
+                self.as_SQLHANDLE(),
+                RecNumber,
+                A::IDENTIFIER,
+                (ValuePtr.0, ValuePtr.1.max(0) as usize),
+            );
+
+            let sql_return = ffi::SQLSetDescFieldA(
+                self.as_SQLHANDLE(),
+                RecNumber,
+                A::IDENTIFIER,
+                ValuePtr.0,
+                ValuePtr.1,
+            );
+
+            #[cfg(feature = "runtime_checks")]
+            if SQL_SUCCEEDED(sql_return) {
+                checks::desc_field(
+                    self.as_SQLHANDLE(),
+                    RecNumber,
+                    A::IDENTIFIER,
+                    (ValuePtr.0, ValuePtr.1.max(0) as usize),
+                );
+            }
+
+            sql_return
+        };
+
+        if SQL_SUCCEEDED(sql_return)
+            && let Some(v) = ValuePtr
+        {
+            v.update_handle(self);
+        }
+
+        sql_return
+    }
+
+    /// Sets the value of a single field of a descriptor record.
     ///
-    /// For complete documentation on SQLSetCursorNameA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlsetcursorname-function).
+    /// For complete documentation on SQLSetDescFieldW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlsetdescfield-function).
     ///
     /// # Returns
     /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    fn SQLSetCursorNameA(&self, CursorName: &OdbcStr<SQLCHAR>) -> SQLRETURN {
-        let CursorName = CursorName.as_raw_slice();
+    fn SQLSetDescFieldW<
+        A: Ident<Type = SQLSMALLINT>,
+        T: DescField<'buf, Self, DT, A, V> + AttrSet<A> + Unicode,
+    >(
+        &self,
+        RecNumber: SQLSMALLINT,
+        #[expect(unused_variables)] FieldIdentifier: A,
+        ValuePtr: Option<T>,
+    ) -> SQLRETURN {
+        let sql_return = unsafe {
+            let ValuePtr = ValuePtr.map_or((ptr::null_mut(), 0), |ValuePtr| {
+                (ValuePtr.into_SQLPOINTER(), ValuePtr.len())
+            });
 
-        unsafe { ffi::SQLSetCursorNameA(self.as_SQLHANDLE(), CursorName.0, CursorName.1) }
+            #[cfg(feature = "runtime_checks")]
+            checks::check_desc_field(
+                self.as_SQLHANDLE(),
+                RecNumber,
+                A::IDENTIFIER,
+                (ValuePtr.0, ValuePtr.1.max(0) as usize),
+            );
+
+            let sql_return = ffi::SQLSetDescFieldW(
+                self.as_SQLHANDLE(),
+                RecNumber,
+                A::IDENTIFIER,
+                ValuePtr.0,
+                ValuePtr.1,
+            );
+
+            #[cfg(feature = "runtime_checks")]
+            if SQL_SUCCEEDED(sql_return) {
+                checks::desc_field(
+                    self.as_SQLHANDLE(),
+                    RecNumber,
+                    A::IDENTIFIER,
+                    (ValuePtr.0, ValuePtr.1.max(0) as usize),
+                );
+            }
+
+            sql_return
+        };
+
+        if SQL_SUCCEEDED(sql_return)
+            && let Some(v) = ValuePtr
+        {
+            v.update_handle(self);
+        }
+
+        sql_return
     }
 
-    /// Associates a cursor name with an active statement. If an application does not call **SQLSetCursorName**, the driver generates cursor names as needed for SQL statement processing.
+    /// Sets multiple descriptor fields that affect the data type and buffer bound to a column or parameter data.
     ///
-    /// For complete documentation on SQLSetCursorNameW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlsetcursorname-function).
+    /// For complete documentation on SQLSetDescRec, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlsetdescrec-function).
     ///
     /// # Returns
     /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    fn SQLSetCursorNameW(&self, CursorName: &OdbcStr<SQLWCHAR>) -> SQLRETURN {
-        let CursorName = CursorName.as_raw_slice();
+    #[expect(clippy::too_many_arguments)]
+    // TODO: Must not be allowed for IRD. Handle here or with DescField
+    fn SQLSetDescRec<ST: SqlType<V>, PTR>(
+        &self,
+        RecNumber: SQLSMALLINT,
+        Type: ST,
+        SubType: Option<DatetimeIntervalCode>,
+        Length: SQLLEN,
+        Precision: SQLSMALLINT,
+        Scale: SQLSMALLINT,
+        // TODO: Input or Output for both? I guess it depends on which descriptor was given
+        DataPtr: Option<&'buf PTR>,
+        // TODO: Shouldn't following two be UnsafeCell
+        StringLengthPtr: &'buf mut impl AsMutPtr<SQLLEN>,
+        IndicatorPtr: &'buf mut impl AsMutPtr<SQLLEN>,
+    ) -> SQLRETURN
+    where
+        &'buf PTR: IntoSQLPOINTER,
+    {
+        let DataPtr = DataPtr.map_or_else(ptr::null_mut, IntoSQLPOINTER::into_SQLPOINTER);
 
-        unsafe { ffi::SQLSetCursorNameW(self.as_SQLHANDLE(), CursorName.0, CursorName.1) }
+        #[cfg(feature = "runtime_checks")]
+        let DataPtr_ = (!DataPtr.is_null()).then_some((DataPtr, Length.max(0) as usize));
+        #[cfg(feature = "runtime_checks")]
+        let written = checks::desc_written(self.as_SQLHANDLE());
+        #[cfg(feature = "runtime_checks")]
+        checks::check_bind(self.as_SQLHANDLE(), RecNumber, DataPtr_, written);
+
+        let sql_return = unsafe {
+            ffi::SQLSetDescRec(
+                self.as_SQLHANDLE(),
+                RecNumber,
+                Type.identifier(),
+                SubType.map_or(0, |v| v.identifier()),
+                Length,
+                Precision,
+                Scale,
+                DataPtr,
+                StringLengthPtr.as_mut_ptr(),
+                IndicatorPtr.as_mut_ptr(),
+            )
+        };
+
+        #[cfg(feature = "runtime_checks")]
+        if SQL_SUCCEEDED(sql_return) {
+            checks::bind(self.as_SQLHANDLE(), RecNumber, DataPtr_, written);
+        }
+
+        sql_return
     }
+}
 
-    /// Sets attributes related to a statement.
+#[expect(non_snake_case)]
+pub trait Cancel<V: OdbcVersion>: Handle {
+    /// Cancels the processing on a statement.
+    /// To cancel processing on a connection or statement, use SQLCancelHandle Function.
     ///
-    /// For complete documentation on SQLSetStmtAttrA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlsetstmtattr-function).
+    /// For complete documentation on SQLCancel, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlcancel-function).
     ///
     /// # Returns
     /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    fn SQLSetStmtAttrA<
-        A: Ident<Type = SQLINTEGER>,
-        T: StmtAttr<'desc, 'buf, Self, A, V> + AttrSet<A> + Ansi,
-    >(
-        &self,
-        Attribute: A,
-        ValuePtr: T,
-    ) -> SQLRETURN {
-        SQLSetStmtAttrA(self, Attribute, ValuePtr)
+    fn SQLCancel(&self) -> SQLRETURN
+    where
+        Self: Handle<Ident = SQL_HANDLE_STMT>,
+    {
+        unsafe { ffi::SQLCancel(self.as_SQLHANDLE()) }
     }
 
-    /// Sets attributes related to a statement.
+    /// Cancels the processing on a connection or statement. The Driver Manager maps a call to **SQLCancelHandle** to a call to **SQLCancel** when `HandleType` is SQL_HANDLE_STMT.
     ///
-    /// For complete documentation on SQLSetStmtAttrW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlsetstmtattr-function).
+    /// For complete documentation on SQLCancelHandle, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlcancelhandle-function).
     ///
     /// # Returns
     /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    fn SQLSetStmtAttrW<
-        A: Ident<Type = SQLINTEGER>,
-        T: StmtAttr<'desc, 'buf, Self, A, V> + AttrSet<A> + Unicode,
-    >(
-        &self,
-        Attribute: A,
-        ValuePtr: T,
-    ) -> SQLRETURN {
-        SQLSetStmtAttrW(self, Attribute, ValuePtr)
+    fn SQLCancelHandle(&self) -> SQLRETURN {
+        unsafe { ffi::SQLCancelHandle(<Self as Handle>::Ident::IDENTIFIER, self.as_SQLHANDLE()) }
     }
+}
 
-    /// Retrieves the following information about columns within a specified table:
-    ///
-    /// * The optimal set of columns that uniquely identifies a row in the table.
-    /// * Columns that are automatically updated when any value in the row is updated by a transaction.
+#[expect(non_snake_case)]
+pub trait Async<V: OdbcVersion>: Handle {
+    /// Can be used to determine when an asynchronous function is complete using either notification- or polling-based processing. For more information about asynchronous operations, see Asynchronous Execution.
+    /// **SQLCompleteAsync** is only implemented in the ODBC Driver Manager.
+    /// In notification based asynchronous processing mode, **SQLCompleteAsync** must be called after the Driver Manager raises the event object used for notification. **SQLCompleteAsync** completes the asynchronous processing and the asynchronous function will generate a return code.
+    /// In polling based asynchronous processing mode, **SQLCompleteAsync** is an alternative to calling the original asynchronous function, without needing to specify the arguments in the original asynchronous function call. **SQLCompleteAsync** can be used regardless whether the ODBC Cursor Library is enabled.
     ///
-    /// For complete documentation on SQLSpecialColumnsA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlspecialcolumns-function).
+    /// For complete documentation on SQLCompleteAsync, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlcompleteasync-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    /// SQL_SUCCESS, SQL_ERROR, SQL_NO_DATA, or SQL_INVALID_HANDLE.
     #[inline]
-    fn SQLSpecialColumnsA(
-        &self,
-        IdentifierType: IdentifierType,
-        CatalogName: &OdbcStr<SQLCHAR>,
-        SchemaName: &OdbcStr<SQLCHAR>,
-        TableName: &OdbcStr<SQLCHAR>,
-        Scope: Scope,
-        Nullable: NullAllowed,
-    ) -> SQLRETURN {
-        let CatalogName = CatalogName.as_raw_slice();
-        let SchemaName = SchemaName.as_raw_slice();
-        let TableName = TableName.as_raw_slice();
-
+    // TODO: Should this handle be mutable or not?
+    fn SQLCompleteAsync(&mut self, AsyncRetCodePtr: &mut impl AsMutPtr<RETCODE>) -> SQLRETURN {
         unsafe {
-            ffi::SQLSpecialColumnsA(
+            ffi::SQLCompleteAsync(
+                Self::Ident::IDENTIFIER,
                 self.as_SQLHANDLE(),
-                IdentifierType as SQLSMALLINT,
-                CatalogName.0,
-                CatalogName.1,
-                SchemaName.0,
-                SchemaName.1,
-                TableName.0,
-                TableName.1,
-                Scope as SQLSMALLINT,
-                Nullable.identifier(),
+                AsyncRetCodePtr.as_mut_ptr(),
             )
         }
     }
+}
 
-    /// Retrieves the following information about columns within a specified table:
-    ///
-    /// * The optimal set of columns that uniquely identifies a row in the table.
-    /// * Columns that are automatically updated when any value in the row is updated by a transaction.
+#[expect(non_snake_case)]
+impl<V: OdbcVersion> SQLHENV<V> {
+    /// Returns information about a data source. This function is implemented only by the Driver Manager.
     ///
-    /// For complete documentation on SQLSpecialColumnsW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlspecialcolumns-function).
+    /// For complete documentation on SQLDataSourcesA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqldatasources-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NO_DATA, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    fn SQLSpecialColumnsW(
+    pub fn SQLDataSourcesA(
         &self,
-        IdentifierType: IdentifierType,
-        CatalogName: &OdbcStr<SQLWCHAR>,
-        SchemaName: &OdbcStr<SQLWCHAR>,
-        TableName: &OdbcStr<SQLWCHAR>,
-        Scope: Scope,
-        Nullable: NullAllowed,
+        Direction: SQLUSMALLINT,
+        ServerName: &mut OdbcStr<MaybeUninit<SQLCHAR>>,
+        NameLength1Ptr: &mut impl AsMutPtr<SQLSMALLINT>,
+        Description: &mut OdbcStr<MaybeUninit<SQLCHAR>>,
+        NameLength2Ptr: &mut impl AsMutPtr<SQLSMALLINT>,
     ) -> SQLRETURN {
-        let CatalogName = CatalogName.as_raw_slice();
-        let SchemaName = SchemaName.as_raw_slice();
-        let TableName = TableName.as_raw_slice();
+        let ServerName = ServerName.as_mut_raw_slice();
+        let Description = Description.as_mut_raw_slice();
 
         unsafe {
-            ffi::SQLSpecialColumnsW(
+            ffi::SQLDataSourcesA(
                 self.as_SQLHANDLE(),
-                IdentifierType as SQLSMALLINT,
-                CatalogName.0,
-                CatalogName.1,
-                SchemaName.0,
-                SchemaName.1,
-                TableName.0,
-                TableName.1,
-                Scope as SQLSMALLINT,
-                Nullable.identifier(),
+                Direction,
+                ServerName.0,
+                ServerName.1,
+                NameLength1Ptr.as_mut_ptr(),
+                Description.0,
+                Description.1,
+                NameLength2Ptr.as_mut_ptr(),
             )
         }
     }
 
-    /// Retrieves a list of statistics about a single table and the indexes associated with the table. The driver returns the information as a result set.
+    /// Returns information about a data source. This function is implemented only by the Driver Manager.
     ///
-    /// For complete documentation on SQLStatisticsA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlstatistics-function).
+    /// For complete documentation on SQLDataSourcesW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqldatasources-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NO_DATA, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    fn SQLStatisticsA(
+    pub fn SQLDataSourcesW(
         &self,
-        CatalogName: &OdbcStr<SQLCHAR>,
-        SchemaName: &OdbcStr<SQLCHAR>,
-        TableName: &OdbcStr<SQLCHAR>,
-        Unique: Unique,
-        Reserved: Reserved,
-    ) -> SQLRETURN {
-        let CatalogName = CatalogName.as_raw_slice();
-        let SchemaName = SchemaName.as_raw_slice();
-        let TableName = TableName.as_raw_slice();
+        Direction: SQLUSMALLINT,
+        ServerName: &mut OdbcStr<MaybeUninit<SQLWCHAR>>,
+        NameLength1Ptr: &mut impl AsMutPtr<SQLSMALLINT>,
+        Description: &mut OdbcStr<MaybeUninit<SQLWCHAR>>,
+        NameLength2Ptr: &mut impl AsMutPtr<SQLSMALLINT>,
+    ) -> SQLRETURN {
+        let ServerName = ServerName.as_mut_raw_slice();
+        let Description = Description.as_mut_raw_slice();
 
         unsafe {
-            ffi::SQLStatisticsA(
+            ffi::SQLDataSourcesW(
                 self.as_SQLHANDLE(),
-                CatalogName.0,
-                CatalogName.1,
-                SchemaName.0,
-                SchemaName.1,
-                TableName.0,
-                TableName.1,
-                Unique as SQLUSMALLINT,
-                Reserved as SQLUSMALLINT,
+                Direction,
+                ServerName.0,
+                ServerName.1,
+                NameLength1Ptr.as_mut_ptr(),
+                Description.0,
+                Description.1,
+                NameLength2Ptr.as_mut_ptr(),
             )
         }
     }
 
-    /// Retrieves a list of statistics about a single table and the indexes associated with the table. The driver returns the information as a result set.
+    /// Lists driver descriptions and driver attribute keywords. This function is implemented only by the Driver Manager.
     ///
-    /// For complete documentation on SQLStatisticsW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlstatistics-function).
+    /// For complete documentation on SQLDriversA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqldrivers-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NO_DATA, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    fn SQLStatisticsW(
+    pub fn SQLDriversA(
         &self,
-        CatalogName: &OdbcStr<SQLWCHAR>,
-        SchemaName: &OdbcStr<SQLWCHAR>,
-        TableName: &OdbcStr<SQLWCHAR>,
-        Unique: Unique,
-        Reserved: Reserved,
+        Direction: SQLUSMALLINT,
+        DriverDescription: &mut OdbcStr<MaybeUninit<SQLCHAR>>,
+        DescriptionLengthPtr: &mut impl AsMutPtr<SQLSMALLINT>,
+        DriverAttributes: &mut OdbcStr<MaybeUninit<SQLCHAR>>,
+        AttributesLengthPtr: &mut impl AsMutPtr<SQLSMALLINT>,
     ) -> SQLRETURN {
-        let CatalogName = CatalogName.as_raw_slice();
-        let SchemaName = SchemaName.as_raw_slice();
-        let TableName = TableName.as_raw_slice();
+        let DriverDescription = DriverDescription.as_mut_raw_slice();
+        let DriverAttributes = DriverAttributes.as_mut_raw_slice();
 
         unsafe {
-            ffi::SQLStatisticsW(
+            ffi::SQLDriversA(
                 self.as_SQLHANDLE(),
-                CatalogName.0,
-                CatalogName.1,
-                SchemaName.0,
-                SchemaName.1,
-                TableName.0,
-                TableName.1,
-                Unique as SQLUSMALLINT,
-                Reserved as SQLUSMALLINT,
+                Direction,
+                DriverDescription.0,
+                DriverDescription.1,
+                DescriptionLengthPtr.as_mut_ptr(),
+                DriverAttributes.0,
+                DriverAttributes.1,
+                AttributesLengthPtr.as_mut_ptr(),
             )
         }
     }
 
-    /// Returns a list of tables and the privileges associated with each table. The driver returns the information as a result set on the specified statement.
+    /// Lists driver descriptions and driver attribute keywords. This function is implemented only by the Driver Manager.
     ///
-    /// For complete documentation on SQLTablePrivilegesA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqltableprivileges-function).
+    /// For complete documentation on SQLDriversW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqldrivers-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NO_DATA, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    fn SQLTablePrivilegesA(
+    pub fn SQLDriversW(
         &self,
-        CatalogName: &OdbcStr<SQLCHAR>,
-        SchemaName: &OdbcStr<SQLCHAR>,
-        TableName: &OdbcStr<SQLCHAR>,
+        Direction: SQLUSMALLINT,
+        DriverDescription: &mut OdbcStr<MaybeUninit<SQLWCHAR>>,
+        DescriptionLengthPtr: &mut impl AsMutPtr<SQLSMALLINT>,
+        DriverAttributes: &mut OdbcStr<MaybeUninit<SQLWCHAR>>,
+        AttributesLengthPtr: &mut impl AsMutPtr<SQLSMALLINT>,
     ) -> SQLRETURN {
-        let CatalogName = CatalogName.as_raw_slice();
-        let SchemaName = SchemaName.as_raw_slice();
-        let TableName = TableName.as_raw_slice();
+        let DriverDescription = DriverDescription.as_mut_raw_slice();
+        let DriverAttributes = DriverAttributes.as_mut_raw_slice();
 
         unsafe {
-            ffi::SQLTablePrivilegesA(
+            ffi::SQLDriversW(
                 self.as_SQLHANDLE(),
-                CatalogName.0,
-                CatalogName.1,
-                SchemaName.0,
-                SchemaName.1,
-                TableName.0,
-                TableName.1,
+                Direction,
+                DriverDescription.0,
+                DriverDescription.1,
+                DescriptionLengthPtr.as_mut_ptr(),
+                DriverAttributes.0,
+                DriverAttributes.1,
+                AttributesLengthPtr.as_mut_ptr(),
             )
         }
     }
 
-    /// Returns a list of tables and the privileges associated with each table. The driver returns the information as a result set on the specified statement.
+    /// Returns the current setting of an environment attribute.
     ///
-    /// For complete documentation on SQLTablePrivilegesW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqltableprivileges-function).
+    /// For complete documentation on SQLGetEnvAttr, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlgetenvattr-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NO_DATA, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    fn SQLTablePrivilegesW(
+    pub fn SQLGetEnvAttr<A: Ident<Type = SQLINTEGER>, T: EnvAttr<A, V> + AttrGet<A> + ?Sized>(
         &self,
-        CatalogName: &OdbcStr<SQLWCHAR>,
-        SchemaName: &OdbcStr<SQLWCHAR>,
-        TableName: &OdbcStr<SQLWCHAR>,
-    ) -> SQLRETURN {
-        let CatalogName = CatalogName.as_raw_slice();
-        let SchemaName = SchemaName.as_raw_slice();
-        let TableName = TableName.as_raw_slice();
+        #[expect(unused_variables)] Attribute: A,
+        ValuePtr: Option<&mut T>,
+        StringLengthPtr: Option<&mut MaybeUninit<T::StrLen>>,
+    ) -> SQLRETURN
+    where
+        MaybeUninit<T::StrLen>: StrLen<SQLINTEGER>,
+    {
+        let ValuePtr = ValuePtr.map_or((ptr::null_mut(), 0), |ValuePtr| {
+            (ValuePtr.as_mut_SQLPOINTER(), ValuePtr.len())
+        });
 
         unsafe {
-            ffi::SQLTablePrivilegesW(
+            ffi::SQLGetEnvAttr(
                 self.as_SQLHANDLE(),
-                CatalogName.0,
-                CatalogName.1,
-                SchemaName.0,
-                SchemaName.1,
-                TableName.0,
-                TableName.1,
+                A::IDENTIFIER,
+                ValuePtr.0,
+                ValuePtr.1,
+                StringLengthPtr.map_or_else(ptr::null_mut, StrLen::as_mut_ptr),
             )
         }
     }
 
-    /// Returns the list of table, catalog, or schema names, and table types, stored in a specific data source. The driver returns the information as a result set.
+    /// Sets attributes that govern aspects of environments.
     ///
-    /// For complete documentation on SQLTablesA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqltables-function).
+    /// For complete documentation on SQLSetEnvAttr, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlsetenvattr-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    fn SQLTablesA(
-        &self,
-        CatalogName: &OdbcStr<SQLCHAR>,
-        SchemaName: &OdbcStr<SQLCHAR>,
-        TableName: &OdbcStr<SQLCHAR>,
-        TableType: &OdbcStr<SQLCHAR>,
+    pub fn SQLSetEnvAttr<A: Ident<Type = SQLINTEGER>, T: EnvAttr<A, V> + AttrSet<A>>(
+        // Reference to SQLHENV is mutable to make it impossible to have a connection
+        // handle allocated on the environment handle when calling this function
+        &mut self,
+        #[expect(unused_variables)] Attribute: A,
+        ValuePtr: T,
     ) -> SQLRETURN {
-        let CatalogName = CatalogName.as_raw_slice();
-        let SchemaName = SchemaName.as_raw_slice();
-        let TableName = TableName.as_raw_slice();
-        let TableType = TableType.as_raw_slice();
-
         unsafe {
-            ffi::SQLTablesA(
+            ffi::SQLSetEnvAttr(
                 self.as_SQLHANDLE(),
-                CatalogName.0,
-                CatalogName.1,
-                SchemaName.0,
-                SchemaName.1,
-                TableName.0,
-                TableName.1,
-                TableType.0,
-                TableType.1,
+                A::IDENTIFIER,
+                ValuePtr.into_SQLPOINTER(),
+                ValuePtr.len(),
             )
         }
     }
+}
 
-    /// Returns the list of table, catalog, or schema names, and table types, stored in a specific data source. The driver returns the information as a result set.
+#[expect(non_snake_case)]
+impl<'env, C: ConnState, V: OdbcVersion> SQLHDBC<'env, C, V> {
+    /// Supports an iterative method of discovering and enumerating the attributes and attribute values required to connect to a data source. Each call to **SQLBrowseConnect** returns successive levels of attributes and attribute values. When all levels have been enumerated, a connection to the data source is completed and a complete connection string is returned by **SQLBrowseConnect**. A return code of SQL_SUCCESS or SQL_SUCCESS_WITH_INFO indicates that all connection information has been specified and the application is now connected to the data source.
     ///
-    /// For complete documentation on SQLTablesW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqltables-function).
+    /// For complete documentation on SQLBrowseConnectA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlbrowseconnect-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NEED_DATA, SQL_ERROR, SQL_INVALID_HANDLE, or SQL_STILL_EXECUTING.
     #[inline]
-    fn SQLTablesW(
-        &self,
-        CatalogName: &OdbcStr<SQLWCHAR>,
-        SchemaName: &OdbcStr<SQLWCHAR>,
-        TableName: &OdbcStr<SQLWCHAR>,
-        TableType: &OdbcStr<SQLWCHAR>,
-    ) -> SQLRETURN {
-        let StatementHandle = self.as_SQLHANDLE();
-
-        let CatalogName = CatalogName.as_raw_slice();
-        let SchemaName = SchemaName.as_raw_slice();
-        let TableName = TableName.as_raw_slice();
-        let TableType = TableType.as_raw_slice();
+    pub fn SQLBrowseConnectA(
+        self,
+        InConnectionString: &OdbcStr<SQLCHAR>,
+        OutConnectionString: Option<&mut OdbcStr<MaybeUninit<SQLCHAR>>>,
+        StringLength2Ptr: &mut impl AsMutPtr<SQLSMALLINT>,
+    ) -> (BrowseConnectResult<'env, C, V>, SQLRETURN)
+    where
+        Self: BrowseConnect,
+    {
+        let InConnectionString = InConnectionString.as_raw_slice();
+        let OutConnectionString =
+            OutConnectionString.map_or((ptr::null_mut(), 0), AsMutRawSlice::as_mut_raw_slice);
 
-        unsafe {
-            ffi::SQLTablesW(
-                StatementHandle,
-                CatalogName.0,
-                CatalogName.1,
-                SchemaName.0,
-                SchemaName.1,
-                TableName.0,
-                TableName.1,
-                TableType.0,
-                TableType.1,
+        let sql_return = unsafe {
+            ffi::SQLBrowseConnectA(
+                self.as_SQLHANDLE(),
+                InConnectionString.0,
+                InConnectionString.1,
+                OutConnectionString.0,
+                OutConnectionString.1,
+                StringLength2Ptr.as_mut_ptr(),
             )
-        }
-    }
-}
+        };
 
-#[expect(non_snake_case)]
-pub trait Descriptor<'buf, DT, V: OdbcVersion>: Handle {
-    /// Copies descriptor information from one descriptor handle to another.
-    ///
-    /// For complete documentation on SQLCopyDesc, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlcopydesc-function).
-    ///
-    /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
-    #[inline]
-    // TODO: Not sure if application and implementation descriptors can be interchangeably copied
-    // TODO: Do they have to have the same version?
-    // TODO: Is lifetime the same?
-    fn SQLCopyDesc<DT2: DescType<'buf>>(&self, TargetDescHandle: &SQLHDESC<DT2, V>) -> SQLRETURN {
-        let SourceDescHandle = self.as_SQLHANDLE();
-        let TargetDescHandle = TargetDescHandle.as_SQLHANDLE();
-        unsafe { ffi::SQLCopyDesc(SourceDescHandle, TargetDescHandle) }
+        if SQL_SUCCEEDED(sql_return) {
+            (Ok(self.connect()), sql_return)
+        } else if sql_return == SQL_NEED_DATA {
+            (Err(Ok(self.need_data())), sql_return)
+        } else if sql_return == SQL_STILL_EXECUTING {
+            // Asynchronously executing function is polled by calling it again
+            (Err(Err(Ok(self))), sql_return)
+        } else {
+            (Err(Err(Err(self.disconnect()))), sql_return)
+        }
     }
 
-    /// Returns the current setting or value of a single field of a descriptor record.
+    /// Supports an iterative method of discovering and enumerating the attributes and attribute values required to connect to a data source. Each call to **SQLBrowseConnect** returns successive levels of attributes and attribute values. When all levels have been enumerated, a connection to the data source is completed and a complete connection string is returned by **SQLBrowseConnect**. A return code of SQL_SUCCESS or SQL_SUCCESS_WITH_INFO indicates that all connection information has been specified and the application is now connected to the data source.
     ///
-    /// For complete documentation on SQLGetDescFieldA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlgetdescfield-function).
+    /// For complete documentation on SQLBrowseConnectW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlbrowseconnect-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, SQL_NO_DATA, or SQL_INVALID_HANDLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NEED_DATA, SQL_ERROR, SQL_INVALID_HANDLE, or SQL_STILL_EXECUTING.
     #[inline]
-    fn SQLGetDescFieldA<
-        A: Ident<Type = SQLSMALLINT>,
-        T: DescField<'buf, Self, DT, A, V> + AttrGet<A> + Ansi + ?Sized,
+    pub fn SQLBrowseConnectW(
+        self,
+        InConnectionString: &OdbcStr<SQLWCHAR>,
+        OutConnectionString: Option<&mut OdbcStr<MaybeUninit<SQLWCHAR>>>,
+        StringLength2Ptr: &mut impl AsMutPtr<SQLSMALLINT>,
+    ) -> (BrowseConnectResult<'env, C, V>, SQLRETURN)
+    where
+        Self: BrowseConnect,
+    {
+        let InConnectionString = InConnectionString.as_raw_slice();
+        let OutConnectionString =
+            OutConnectionString.map_or((ptr::null_mut(), 0), AsMutRawSlice::as_mut_raw_slice);
+
+        let sql_return = unsafe {
+            ffi::SQLBrowseConnectW(
+                self.as_SQLHANDLE(),
+                InConnectionString.0,
+                InConnectionString.1,
+                OutConnectionString.0,
+                OutConnectionString.1,
+                StringLength2Ptr.as_mut_ptr(),
+            )
+        };
+
+        if SQL_SUCCEEDED(sql_return) {
+            (Ok(self.connect()), sql_return)
+        } else if sql_return == SQL_NEED_DATA {
+            (Err(Ok(self.need_data())), sql_return)
+        } else if sql_return == SQL_STILL_EXECUTING {
+            // Asynchronously executing function is polled by calling it again
+            (Err(Err(Ok(self))), sql_return)
+        } else {
+            (Err(Err(Err(self.disconnect()))), sql_return)
+        }
+    }
+
+    /// Closes the connection associated with a specific connection handle.
+    ///
+    /// For complete documentation on SQLDisconnect, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqldisconnect-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, SQL_INVALID_HANDLE, or SQL_STILL_EXECUTING.
+    #[inline]
+    pub fn SQLDisconnect(self) -> (Result<SQLHDBC<'env, C2, V>, Self>, SQLRETURN)
+    where
+        Self: Disconnect,
+    {
+        let sql_return = unsafe { ffi::SQLDisconnect(self.as_SQLHANDLE()) };
+
+        if SQL_SUCCEEDED(sql_return) {
+            (Ok(self.disconnect()), sql_return)
+        } else {
+            (Err(self), sql_return)
+        }
+    }
+
+    /// Closes the connection and frees the connection handle. Unlike when the handle is
+    /// dropped, if either of the two fails the handle is returned to the caller so that
+    /// diagnostics can be retrieved from it.
+    ///
+    /// For complete documentation on SQLDisconnect, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqldisconnect-function).
+    /// For complete documentation on SQLFreeHandle, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlfreehandle-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, SQL_INVALID_HANDLE, or SQL_STILL_EXECUTING.
+    #[inline]
+    #[expect(clippy::type_complexity)]
+    pub fn close(self) -> (Result<(), Result<SQLHDBC<'env, C2, V>, Self>>, SQLRETURN)
+    where
+        Self: Disconnect,
+    {
+        match self.SQLDisconnect() {
+            (Ok(conn), _) => {
+                let (conn, sql_return) = conn.try_free();
+                (conn.map_err(Ok), sql_return)
+            }
+            (Err(conn), sql_return) => (Err(Err(conn)), sql_return),
+        }
+    }
+
+    /// Returns the current setting of a connection attribute.
+    ///
+    /// For complete documentation on SQLGetConnectAttrA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlgetconnectattr-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NO_DATA, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub fn SQLGetConnectAttrA<
+        A: Ident<Type = SQLINTEGER>,
+        T: ConnAttr<C, A, V> + AttrGet<A> + Ansi + ?Sized,
     >(
+        // TODO: Not sure whether attributes should be checked when getting them with SQLGetConnectAttr
         &self,
-        RecNumber: SQLSMALLINT,
-        #[expect(unused_variables)] FieldIdentifier: A,
+        #[expect(unused_variables)] Attribute: A,
         ValuePtr: Option<&mut T>,
         StringLengthPtr: Option<&mut MaybeUninit<T::StrLen>>,
     ) -> SQLRETURN
     where
         MaybeUninit<T::StrLen>: StrLen<SQLINTEGER>,
     {
-        let DescriptorHandle = self.as_SQLHANDLE();
-
         let ValuePtr = ValuePtr.map_or((ptr::null_mut(), 0), |ValuePtr| {
-            if cfg!(feature = "odbc_debug") {
+            if cfg!(feature = "runtime_checks") {
                 ValuePtr.assert_zeroed();
             }
 
@@ -1620,9 +1958,8 @@ pub trait Descriptor<'buf, DT, V: OdbcVersion>: Handle {
         });
 
         unsafe {
-            ffi::SQLGetDescFieldA(
-                DescriptorHandle,
-                RecNumber,
+            ffi::SQLGetConnectAttrA(
+                self.as_SQLHANDLE(),
                 A::IDENTIFIER,
                 ValuePtr.0,
                 ValuePtr.1,
@@ -1631,29 +1968,28 @@ pub trait Descriptor<'buf, DT, V: OdbcVersion>: Handle {
         }
     }
 
-    /// Returns the current setting or value of a single field of a descriptor record.
+    /// Returns the current setting of a connection attribute.
     ///
-    /// For complete documentation on SQLGetDescFieldW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlgetdescfield-function).
+    /// For complete documentation on SQLGetConnectAttrW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlgetconnectattr-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, SQL_NO_DATA, or SQL_INVALID_HANDLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NO_DATA, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    fn SQLGetDescFieldW<
-        A: Ident<Type = SQLSMALLINT>,
-        T: DescField<'buf, Self, DT, A, V> + AttrGet<A> + Unicode + ?Sized,
+    pub fn SQLGetConnectAttrW<
+        A: Ident<Type = SQLINTEGER>,
+        T: ConnAttr<C, A, V> + AttrGet<A> + Unicode + ?Sized,
     >(
+        // TODO: Not really sure whether attributes should be checked when getting them with SQLGetConnectAttr
         &self,
-        RecNumber: SQLSMALLINT,
-        #[expect(unused_variables)] FieldIdentifier: A,
+        #[expect(unused_variables)] Attribute: A,
         ValuePtr: Option<&mut T>,
         StringLengthPtr: Option<&mut MaybeUninit<T::StrLen>>,
     ) -> SQLRETURN
     where
         MaybeUninit<T::StrLen>: StrLen<SQLINTEGER>,
     {
-        let DescriptorHandle = self.as_SQLHANDLE();
         let ValuePtr = ValuePtr.map_or((ptr::null_mut(), 0), |ValuePtr| {
-            if cfg!(feature = "odbc_debug") {
+            if cfg!(feature = "runtime_checks") {
                 ValuePtr.assert_zeroed();
             }
 
@@ -1661,9 +1997,8 @@ pub trait Descriptor<'buf, DT, V: OdbcVersion>: Handle {
         });
 
         unsafe {
-            ffi::SQLGetDescFieldW(
-                DescriptorHandle,
-                RecNumber,
+            ffi::SQLGetConnectAttrW(
+                self.as_SQLHANDLE(),
                 A::IDENTIFIER,
                 ValuePtr.0,
                 ValuePtr.1,
@@ -1672,1207 +2007,3659 @@ pub trait Descriptor<'buf, DT, V: OdbcVersion>: Handle {
         }
     }
 
-    /// Returns the current settings or values of multiple fields of a descriptor record. The fields returned describe the name, data type, and storage of column or parameter data.
+    /// Same as [`SQLHDBC::SQLGetConnectAttrA`] except that the buffer for the attribute value is
+    /// allocated by the function and reallocated, followed by a repeated call, until the whole
+    /// value fits into it.
     ///
-    /// For complete documentation on SQLGetDescRecA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlgetdescrec-function).
+    /// For complete documentation on SQLGetConnectAttrA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlgetconnectattr-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, SQL_NO_DATA, or SQL_INVALID_HANDLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NO_DATA, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    #[expect(clippy::too_many_arguments)]
-    fn SQLGetDescRecA<ST: SqlType<V>>(
+    #[cfg(feature = "std")]
+    pub fn get_connect_attr_a<A: Ident<Type = SQLINTEGER>>(
         &self,
-        RecNumber: SQLSMALLINT,
-        Name: Option<&mut OdbcStr<MaybeUninit<SQLCHAR>>>,
-        StringLengthPtr: &mut impl AsMutPtr<SQLSMALLINT>,
-        TypePtr: &mut impl AsMutPtr<ST>,
-        SubTypePtr: &mut impl AsMutPtr<DatetimeIntervalCode>,
-        LengthPtr: &mut impl AsMutPtr<SQLLEN>,
-        PrecisionPtr: &mut impl AsMutPtr<SQLSMALLINT>,
-        ScalePtr: &mut impl AsMutPtr<SQLSMALLINT>,
-        NullablePtr: &mut impl AsMutPtr<NullAllowed>,
-    ) -> SQLRETURN {
-        let DescriptorHandle = self.as_SQLHANDLE();
-        let Name = Name.map_or((ptr::null_mut(), 0), AsMutRawSlice::as_mut_raw_slice);
+        #[expect(unused_variables)] Attribute: A,
+    ) -> (Option<OdbcString<SQLCHAR>>, SQLRETURN)
+    where
+        OdbcStr<MaybeUninit<SQLCHAR>>: ConnAttr<C, A, V> + AttrGet<A> + Ansi,
+    {
+        retry_truncated(|ValuePtr| {
+            let mut StringLength = 0;
+
+            let sql_return = unsafe {
+                ffi::SQLGetConnectAttrA(
+                    self.as_SQLHANDLE(),
+                    A::IDENTIFIER,
+                    ValuePtr.as_mut_SQLPOINTER(),
+                    slice_len::<_, SQLINTEGER>(ValuePtr) * size_of::<SQLCHAR>() as SQLINTEGER,
+                    &mut StringLength,
+                )
+            };
 
-        unsafe {
-            ffi::SQLGetDescRecA(
-                DescriptorHandle,
-                RecNumber,
-                Name.0,
-                Name.1,
-                StringLengthPtr.as_mut_ptr(),
-                TypePtr.as_mut_ptr().cast(),
-                SubTypePtr.as_mut_ptr().cast(),
-                LengthPtr.as_mut_ptr(),
-                PrecisionPtr.as_mut_ptr(),
-                ScalePtr.as_mut_ptr(),
-                NullablePtr.as_mut_ptr().cast(),
+            (
+                sql_return,
+                StringLength as SQLLEN / size_of::<SQLCHAR>() as SQLLEN,
             )
-        }
+        })
     }
 
-    /// Returns the current settings or values of multiple fields of a descriptor record. The fields returned describe the name, data type, and storage of column or parameter data.
+    /// Same as [`SQLHDBC::SQLGetConnectAttrW`] except that the buffer for the attribute value is
+    /// allocated by the function and reallocated, followed by a repeated call, until the whole
+    /// value fits into it.
     ///
-    /// For complete documentation on SQLGetDescRecW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlgetdescrec-function).
+    /// For complete documentation on SQLGetConnectAttrW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlgetconnectattr-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, SQL_NO_DATA, or SQL_INVALID_HANDLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NO_DATA, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    #[expect(clippy::too_many_arguments)]
-    fn SQLGetDescRecW<ST: SqlType<V>>(
+    #[cfg(feature = "std")]
+    pub fn get_connect_attr_w<A: Ident<Type = SQLINTEGER>>(
         &self,
-        RecNumber: SQLSMALLINT,
-        Name: Option<&mut OdbcStr<MaybeUninit<SQLWCHAR>>>,
-        StringLengthPtr: &mut impl AsMutPtr<SQLSMALLINT>,
-        TypePtr: &mut impl AsMutPtr<ST>,
-        SubTypePtr: &mut impl AsMutPtr<DatetimeIntervalCode>,
-        LengthPtr: &mut impl AsMutPtr<SQLLEN>,
-        PrecisionPtr: &mut impl AsMutPtr<SQLSMALLINT>,
-        ScalePtr: &mut impl AsMutPtr<SQLSMALLINT>,
-        NullablePtr: &mut impl AsMutPtr<NullAllowed>,
-    ) -> SQLRETURN {
-        let DescriptorHandle = self.as_SQLHANDLE();
-        let Name = Name.map_or((ptr::null_mut(), 0), AsMutRawSlice::as_mut_raw_slice);
+        #[expect(unused_variables)] Attribute: A,
+    ) -> (Option<OdbcString<SQLWCHAR>>, SQLRETURN)
+    where
+        OdbcStr<MaybeUninit<SQLWCHAR>>: ConnAttr<C, A, V> + AttrGet<A> + Unicode,
+    {
+        retry_truncated(|ValuePtr| {
+            let mut StringLength = 0;
+
+            let sql_return = unsafe {
+                ffi::SQLGetConnectAttrW(
+                    self.as_SQLHANDLE(),
+                    A::IDENTIFIER,
+                    ValuePtr.as_mut_SQLPOINTER(),
+                    slice_len::<_, SQLINTEGER>(ValuePtr) * size_of::<SQLWCHAR>() as SQLINTEGER,
+                    &mut StringLength,
+                )
+            };
 
-        unsafe {
-            ffi::SQLGetDescRecW(
-                DescriptorHandle,
-                RecNumber,
-                Name.0,
-                Name.1,
-                StringLengthPtr.as_mut_ptr(),
-                TypePtr.as_mut_ptr().cast(),
-                SubTypePtr.as_mut_ptr().cast(),
-                LengthPtr.as_mut_ptr(),
-                PrecisionPtr.as_mut_ptr(),
-                ScalePtr.as_mut_ptr(),
-                NullablePtr.as_mut_ptr().cast(),
+            (
+                sql_return,
+                StringLength as SQLLEN / size_of::<SQLWCHAR>() as SQLLEN,
             )
-        }
+        })
     }
 
-    /// Sets the value of a single field of a descriptor record.
+    /// Sets attributes that govern aspects of connections.
     ///
-    /// For complete documentation on SQLSetDescFieldA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlsetdescfield-function).
+    /// For complete documentation on SQLSetConnectAttrA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlsetconnectattr-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, SQL_INVALID_HANDLE, or SQL_STILL_EXECUTING.
     #[inline]
-    fn SQLSetDescFieldA<
-        A: Ident<Type = SQLSMALLINT>,
-        T: DescField<'buf, Self, DT, A, V> + AttrSet<A> + Ansi,
+    pub fn SQLSetConnectAttrA<
+        A: Ident<Type = SQLINTEGER>,
+        T: ConnAttr<C, A, V> + AttrSet<A> + Ansi,
     >(
         &self,
-        RecNumber: SQLSMALLINT,
-        #[expect(unused_variables)] FieldIdentifier: A,
-        ValuePtr: Option<T>,
+        #[expect(unused_variables)] Attribute: A,
+        ValuePtr: T,
     ) -> SQLRETURN {
-        let sql_return = unsafe {
-            let ValuePtr = ValuePtr.map_or((ptr::null_mut(), 0), |ValuePtr| {
-                (ValuePtr.into_SQLPOINTER(), ValuePtr.len())
-            });
-
-            ffi::SQLSetDescFieldA(
-                self.as_SQLHANDLE(),
-                RecNumber,
+        let conn_handle = self.as_SQLHANDLE();
+        unsafe {
+            ffi::SQLSetConnectAttrA(
+                conn_handle,
                 A::IDENTIFIER,
-                ValuePtr.0,
-                ValuePtr.1,
+                ValuePtr.into_SQLPOINTER(),
+                ValuePtr.len(),
             )
-        };
-
-        if SQL_SUCCEEDED(sql_return)
-            && let Some(v) = ValuePtr
-        {
-            v.update_handle(self);
         }
-
-        sql_return
     }
 
-    /// Sets the value of a single field of a descriptor record.
+    /// Sets attributes that govern aspects of connections.
     ///
-    /// For complete documentation on SQLSetDescFieldW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlsetdescfield-function).
+    /// For complete documentation on SQLSetConnectAttrW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlsetconnectattr-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, SQL_INVALID_HANDLE, or SQL_STILL_EXECUTING.
     #[inline]
-    fn SQLSetDescFieldW<
-        A: Ident<Type = SQLSMALLINT>,
-        T: DescField<'buf, Self, DT, A, V> + AttrSet<A> + Unicode,
+    pub fn SQLSetConnectAttrW<
+        A: Ident<Type = SQLINTEGER>,
+        T: ConnAttr<C, A, V> + AttrSet<A> + Unicode,
     >(
         &self,
-        RecNumber: SQLSMALLINT,
-        #[expect(unused_variables)] FieldIdentifier: A,
-        ValuePtr: Option<T>,
+        #[expect(unused_variables)] Attribute: A,
+        ValuePtr: T,
     ) -> SQLRETURN {
-        let sql_return = unsafe {
-            let ValuePtr = ValuePtr.map_or((ptr::null_mut(), 0), |ValuePtr| {
-                (ValuePtr.into_SQLPOINTER(), ValuePtr.len())
-            });
-
-            ffi::SQLSetDescFieldW(
+        unsafe {
+            ffi::SQLSetConnectAttrW(
                 self.as_SQLHANDLE(),
-                RecNumber,
                 A::IDENTIFIER,
-                ValuePtr.0,
-                ValuePtr.1,
+                ValuePtr.into_SQLPOINTER(),
+                ValuePtr.len(),
             )
-        };
-
-        if SQL_SUCCEEDED(sql_return)
-            && let Some(v) = ValuePtr
-        {
-            v.update_handle(self);
         }
-
-        sql_return
     }
+}
 
-    /// Sets multiple descriptor fields that affect the data type and buffer bound to a column or parameter data.
+#[expect(non_snake_case)]
+impl<'env, V: OdbcVersion> SQLHDBC<'env, C2, V> {
+    /// Establishes connections to a driver and a data source. The connection handle references storage of all information about the connection to the data source, including status, transaction state, and error information.
     ///
-    /// For complete documentation on SQLSetDescRec, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlsetdescrec-function).
+    /// For complete documentation on SQLConnectA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlconnect-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, SQL_INVALID_HANDLE, or SQL_STILL_EXECUTING.
     #[inline]
-    #[expect(clippy::too_many_arguments)]
-    // TODO: Must not be allowed for IRD. Handle here or with DescField
-    fn SQLSetDescRec<ST: SqlType<V>, PTR>(
-        &self,
-        RecNumber: SQLSMALLINT,
-        Type: ST,
-        SubType: Option<DatetimeIntervalCode>,
-        Length: SQLLEN,
-        Precision: SQLSMALLINT,
-        Scale: SQLSMALLINT,
-        // TODO: Input or Output for both? I guess it depends on which descriptor was given
-        DataPtr: Option<&'buf PTR>,
-        // TODO: Shouldn't following two be UnsafeCell
-        StringLengthPtr: &'buf mut impl AsMutPtr<SQLLEN>,
-        IndicatorPtr: &'buf mut impl AsMutPtr<SQLLEN>,
-    ) -> SQLRETURN
-    where
-        &'buf PTR: IntoSQLPOINTER,
-    {
-        unsafe {
-            ffi::SQLSetDescRec(
+    pub fn SQLConnectA(
+        self,
+        ServerName: &OdbcStr<SQLCHAR>,
+        UserName: &OdbcStr<SQLCHAR>,
+        Authentication: &OdbcStr<SQLCHAR>,
+    ) -> (ConnectResult<'env, V>, SQLRETURN) {
+        let ServerName = ServerName.as_raw_slice();
+        let UserName = UserName.as_raw_slice();
+        let Authentication = Authentication.as_raw_slice();
+
+        let sql_return = unsafe {
+            ffi::SQLConnectA(
                 self.as_SQLHANDLE(),
-                RecNumber,
-                Type.identifier(),
-                SubType.map_or(0, |v| v.identifier()),
-                Length,
-                Precision,
-                Scale,
-                DataPtr.map_or_else(ptr::null_mut, IntoSQLPOINTER::into_SQLPOINTER),
-                StringLengthPtr.as_mut_ptr(),
-                IndicatorPtr.as_mut_ptr(),
+                ServerName.0,
+                ServerName.1,
+                UserName.0,
+                UserName.1,
+                Authentication.0,
+                Authentication.1,
             )
+        };
+
+        if SQL_SUCCEEDED(sql_return) {
+            (Ok(self.connect()), sql_return)
+        } else {
+            (Err(self), sql_return)
         }
     }
-}
 
-#[expect(non_snake_case)]
-pub trait Cancel<V: OdbcVersion>: Handle {
-    /// Cancels the processing on a statement.
-    /// To cancel processing on a connection or statement, use SQLCancelHandle Function.
+    /// Establishes connections to a driver and a data source. The connection handle references storage of all information about the connection to the data source, including status, transaction state, and error information.
     ///
-    /// For complete documentation on SQLCancel, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlcancel-function).
+    /// For complete documentation on SQLConnectW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlconnect-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, SQL_INVALID_HANDLE, or SQL_STILL_EXECUTING.
     #[inline]
-    fn SQLCancel(&self) -> SQLRETURN
-    where
-        Self: Handle<Ident = SQL_HANDLE_STMT>,
-    {
-        unsafe { ffi::SQLCancel(self.as_SQLHANDLE()) }
+    pub fn SQLConnectW(
+        self,
+        ServerName: &OdbcStr<SQLWCHAR>,
+        UserName: &OdbcStr<SQLWCHAR>,
+        Authentication: &OdbcStr<SQLWCHAR>,
+    ) -> (ConnectResult<'env, V>, SQLRETURN) {
+        let ServerName = ServerName.as_raw_slice();
+        let UserName = UserName.as_raw_slice();
+        let Authentication = Authentication.as_raw_slice();
+
+        let sql_return = unsafe {
+            ffi::SQLConnectW(
+                self.as_SQLHANDLE(),
+                ServerName.0,
+                ServerName.1,
+                UserName.0,
+                UserName.1,
+                Authentication.0,
+                Authentication.1,
+            )
+        };
+
+        if SQL_SUCCEEDED(sql_return) {
+            (Ok(self.connect()), sql_return)
+        } else {
+            (Err(self), sql_return)
+        }
     }
 
-    /// Cancels the processing on a connection or statement. The Driver Manager maps a call to **SQLCancelHandle** to a call to **SQLCancel** when `HandleType` is SQL_HANDLE_STMT.
+    /// An alternative to **SQLConnect**. It supports data sources that require more connection information than the three arguments in **SQLConnect**, dialog boxes to prompt the user for all connection information, and data sources that are not defined in the system information. For more information, see Connecting with SQLDriverConnect.
     ///
-    /// For complete documentation on SQLCancelHandle, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlcancelhandle-function).
+    /// For complete documentation on SQLDriverConnectA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqldriverconnect-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NO_DATA, SQL_ERROR, SQL_INVALID_HANDLE, or SQL_STILL_EXECUTING.
     #[inline]
-    fn SQLCancelHandle(&self) -> SQLRETURN {
-        unsafe { ffi::SQLCancelHandle(<Self as Handle>::Ident::IDENTIFIER, self.as_SQLHANDLE()) }
+    pub fn SQLDriverConnectA(
+        self,
+        _WindowHandle: Option<SQLHWND>,
+        InConnectionString: &OdbcStr<SQLCHAR>,
+        OutConnectionString: Option<&mut OdbcStr<MaybeUninit<SQLCHAR>>>,
+        StringLength2Ptr: &mut impl AsMutPtr<SQLSMALLINT>,
+        DriverCompletion: DriverCompletion,
+    ) -> (ConnectResult<'env, V>, SQLRETURN) {
+        let InConnectionString = InConnectionString.as_raw_slice();
+        let OutConnectionString =
+            OutConnectionString.map_or((ptr::null_mut(), 0), AsMutRawSlice::as_mut_raw_slice);
+
+        let sql_return = unsafe {
+            ffi::SQLDriverConnectA(
+                self.as_SQLHANDLE(),
+                // TODO: Fix this
+                ptr::null_mut(),
+                InConnectionString.0,
+                InConnectionString.1,
+                OutConnectionString.0,
+                OutConnectionString.1,
+                StringLength2Ptr.as_mut_ptr(),
+                DriverCompletion as SQLUSMALLINT,
+            )
+        };
+
+        if SQL_SUCCEEDED(sql_return) {
+            (Ok(self.connect()), sql_return)
+        } else {
+            (Err(self), sql_return)
+        }
     }
-}
 
-#[expect(non_snake_case)]
-pub trait Async<V: OdbcVersion>: Handle {
-    /// Can be used to determine when an asynchronous function is complete using either notification- or polling-based processing. For more information about asynchronous operations, see Asynchronous Execution.
-    /// **SQLCompleteAsync** is only implemented in the ODBC Driver Manager.
-    /// In notification based asynchronous processing mode, **SQLCompleteAsync** must be called after the Driver Manager raises the event object used for notification. **SQLCompleteAsync** completes the asynchronous processing and the asynchronous function will generate a return code.
-    /// In polling based asynchronous processing mode, **SQLCompleteAsync** is an alternative to calling the original asynchronous function, without needing to specify the arguments in the original asynchronous function call. **SQLCompleteAsync** can be used regardless whether the ODBC Cursor Library is enabled.
+    /// An alternative to **SQLConnect**. It supports data sources that require more connection information than the three arguments in **SQLConnect**, dialog boxes to prompt the user for all connection information, and data sources that are not defined in the system information. For more information, see Connecting with SQLDriverConnect.
     ///
-    /// For complete documentation on SQLCompleteAsync, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlcompleteasync-function).
+    /// For complete documentation on SQLDriverConnectW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqldriverconnect-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_ERROR, SQL_NO_DATA, or SQL_INVALID_HANDLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NO_DATA, SQL_ERROR, SQL_INVALID_HANDLE, or SQL_STILL_EXECUTING.
     #[inline]
-    // TODO: Should this handle be mutable or not?
-    fn SQLCompleteAsync(&mut self, AsyncRetCodePtr: &mut impl AsMutPtr<RETCODE>) -> SQLRETURN {
-        unsafe {
-            ffi::SQLCompleteAsync(
-                Self::Ident::IDENTIFIER,
+    pub fn SQLDriverConnectW(
+        self,
+        _WindowHandle: Option<SQLHWND>,
+        InConnectionString: &OdbcStr<SQLWCHAR>,
+        OutConnectionString: Option<&mut OdbcStr<MaybeUninit<SQLWCHAR>>>,
+        StringLength2Ptr: &mut impl AsMutPtr<SQLSMALLINT>,
+        DriverCompletion: DriverCompletion,
+    ) -> (ConnectResult<'env, V>, SQLRETURN) {
+        let InConnectionString = InConnectionString.as_raw_slice();
+        let OutConnectionString =
+            OutConnectionString.map_or((ptr::null_mut(), 0), AsMutRawSlice::as_mut_raw_slice);
+
+        let sql_return = unsafe {
+            ffi::SQLDriverConnectW(
                 self.as_SQLHANDLE(),
-                AsyncRetCodePtr.as_mut_ptr(),
+                // TODO: Fix this
+                ptr::null_mut(),
+                InConnectionString.0,
+                InConnectionString.1,
+                OutConnectionString.0,
+                OutConnectionString.1,
+                StringLength2Ptr.as_mut_ptr(),
+                DriverCompletion as SQLUSMALLINT,
             )
+        };
+
+        if SQL_SUCCEEDED(sql_return) {
+            (Ok(self.connect()), sql_return)
+        } else {
+            (Err(self), sql_return)
         }
     }
 }
 
 #[expect(non_snake_case)]
-impl<V: OdbcVersion> SQLHENV<V> {
-    /// Returns information about a data source. This function is implemented only by the Driver Manager.
+impl<'env, V: OdbcVersion> SQLHDBC<'env, C4, V> {
+    /// Returns information about whether a driver supports a specific ODBC function. This function is implemented in the Driver Manager; it can also be implemented in drivers. If a driver implements **SQLGetFunctions**, the Driver Manager calls the function in the driver. Otherwise, it executes the function itself.
     ///
-    /// For complete documentation on SQLDataSourcesA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqldatasources-function).
+    /// For complete documentation on SQLGetFunctions, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlgetfunctions-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NO_DATA, SQL_ERROR, or SQL_INVALID_HANDLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    pub fn SQLDataSourcesA(
+    pub fn SQLGetFunctions(
         &self,
-        Direction: SQLUSMALLINT,
-        ServerName: &mut OdbcStr<MaybeUninit<SQLCHAR>>,
-        NameLength1Ptr: &mut impl AsMutPtr<SQLSMALLINT>,
-        Description: &mut OdbcStr<MaybeUninit<SQLCHAR>>,
-        NameLength2Ptr: &mut impl AsMutPtr<SQLSMALLINT>,
+        FunctionId: FunctionId,
+        SupportedPtr: &mut impl AsMutPtr<SQLUSMALLINT>,
     ) -> SQLRETURN {
-        let ServerName = ServerName.as_mut_raw_slice();
-        let Description = Description.as_mut_raw_slice();
-
         unsafe {
-            ffi::SQLDataSourcesA(
+            ffi::SQLGetFunctions(
                 self.as_SQLHANDLE(),
-                Direction,
-                ServerName.0,
-                ServerName.1,
-                NameLength1Ptr.as_mut_ptr(),
-                Description.0,
-                Description.1,
-                NameLength2Ptr.as_mut_ptr(),
+                FunctionId as SQLUSMALLINT,
+                SupportedPtr.as_mut_ptr(),
             )
         }
     }
 
-    /// Returns information about a data source. This function is implemented only by the Driver Manager.
+    /// Returns general information about the driver and data source associated with a connection.
     ///
-    /// For complete documentation on SQLDataSourcesW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqldatasources-function).
+    /// For complete documentation on SQLGetInfoA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlgetinfo-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NO_DATA, SQL_ERROR, or SQL_INVALID_HANDLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    pub fn SQLDataSourcesW(
+    pub fn SQLGetInfoA<
+        A: Ident<Type = SQLUSMALLINT>,
+        T: InfoType<A, V> + AttrGet<A> + Ansi + ?Sized,
+    >(
+        // TODO: SQL_ODBC_VER can be called on connection that is not open
         &self,
-        Direction: SQLUSMALLINT,
-        ServerName: &mut OdbcStr<MaybeUninit<SQLWCHAR>>,
-        NameLength1Ptr: &mut impl AsMutPtr<SQLSMALLINT>,
-        Description: &mut OdbcStr<MaybeUninit<SQLWCHAR>>,
-        NameLength2Ptr: &mut impl AsMutPtr<SQLSMALLINT>,
-    ) -> SQLRETURN {
-        let ServerName = ServerName.as_mut_raw_slice();
-        let Description = Description.as_mut_raw_slice();
+        #[expect(unused_variables)] InfoType: A,
+        InfoValuePtr: Option<&mut T>,
+        StringLengthPtr: Option<&mut MaybeUninit<T::StrLen>>,
+    ) -> SQLRETURN
+    where
+        MaybeUninit<T::StrLen>: StrLen<SQLSMALLINT>,
+    {
+        let InfoValuePtr = InfoValuePtr.map_or((ptr::null_mut(), 0), |InfoValuePtr| {
+            (InfoValuePtr.as_mut_SQLPOINTER(), InfoValuePtr.len())
+        });
 
         unsafe {
-            ffi::SQLDataSourcesW(
+            ffi::SQLGetInfoA(
                 self.as_SQLHANDLE(),
-                Direction,
-                ServerName.0,
-                ServerName.1,
-                NameLength1Ptr.as_mut_ptr(),
-                Description.0,
-                Description.1,
-                NameLength2Ptr.as_mut_ptr(),
+                A::IDENTIFIER,
+                InfoValuePtr.0,
+                InfoValuePtr.1,
+                StringLengthPtr.map_or_else(ptr::null_mut, StrLen::as_mut_ptr),
             )
         }
     }
 
-    /// Lists driver descriptions and driver attribute keywords. This function is implemented only by the Driver Manager.
+    /// Returns general information about the driver and data source associated with a connection.
     ///
-    /// For complete documentation on SQLDriversA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqldrivers-function).
+    /// For complete documentation on SQLGetInfoW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlgetinfo-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NO_DATA, SQL_ERROR, or SQL_INVALID_HANDLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    pub fn SQLDriversA(
+    pub fn SQLGetInfoW<
+        A: Ident<Type = SQLUSMALLINT>,
+        T: InfoType<A, V> + AttrGet<A> + Unicode + ?Sized,
+    >(
+        // TODO: SQL_ODBC_VER can be called on connection that is not open
         &self,
-        Direction: SQLUSMALLINT,
-        DriverDescription: &mut OdbcStr<MaybeUninit<SQLCHAR>>,
-        DescriptionLengthPtr: &mut impl AsMutPtr<SQLSMALLINT>,
-        DriverAttributes: &mut OdbcStr<MaybeUninit<SQLCHAR>>,
-        AttributesLengthPtr: &mut impl AsMutPtr<SQLSMALLINT>,
-    ) -> SQLRETURN {
-        let DriverDescription = DriverDescription.as_mut_raw_slice();
-        let DriverAttributes = DriverAttributes.as_mut_raw_slice();
+        #[expect(unused_variables)] InfoType: A,
+        InfoValuePtr: Option<&mut T>,
+        StringLengthPtr: Option<&mut MaybeUninit<T::StrLen>>,
+    ) -> SQLRETURN
+    where
+        MaybeUninit<T::StrLen>: StrLen<SQLSMALLINT>,
+    {
+        let InfoValuePtr = InfoValuePtr.map_or((ptr::null_mut(), 0), |InfoValuePtr| {
+            (InfoValuePtr.as_mut_SQLPOINTER(), InfoValuePtr.len())
+        });
 
         unsafe {
-            ffi::SQLDriversA(
+            ffi::SQLGetInfoW(
                 self.as_SQLHANDLE(),
-                Direction,
-                DriverDescription.0,
-                DriverDescription.1,
-                DescriptionLengthPtr.as_mut_ptr(),
-                DriverAttributes.0,
-                DriverAttributes.1,
-                AttributesLengthPtr.as_mut_ptr(),
+                A::IDENTIFIER,
+                InfoValuePtr.0,
+                InfoValuePtr.1,
+                StringLengthPtr.map_or_else(ptr::null_mut, StrLen::as_mut_ptr),
             )
         }
     }
 
-    /// Lists driver descriptions and driver attribute keywords. This function is implemented only by the Driver Manager.
+    /// Same as [`SQLHDBC::SQLGetInfoA`] except that the buffer for the information is allocated by
+    /// the function and reallocated, followed by a repeated call, until the whole value fits into
+    /// it.
     ///
-    /// For complete documentation on SQLDriversW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqldrivers-function).
+    /// For complete documentation on SQLGetInfoA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlgetinfo-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NO_DATA, SQL_ERROR, or SQL_INVALID_HANDLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    pub fn SQLDriversW(
+    #[cfg(feature = "std")]
+    pub fn get_info_a<A: Ident<Type = SQLUSMALLINT>>(
         &self,
-        Direction: SQLUSMALLINT,
-        DriverDescription: &mut OdbcStr<MaybeUninit<SQLWCHAR>>,
-        DescriptionLengthPtr: &mut impl AsMutPtr<SQLSMALLINT>,
-        DriverAttributes: &mut OdbcStr<MaybeUninit<SQLWCHAR>>,
-        AttributesLengthPtr: &mut impl AsMutPtr<SQLSMALLINT>,
+        #[expect(unused_variables)] InfoType: A,
+    ) -> (Option<OdbcString<SQLCHAR>>, SQLRETURN)
+    where
+        OdbcStr<MaybeUninit<SQLCHAR>>: InfoType<A, V> + AttrGet<A> + Ansi,
+    {
+        retry_truncated(|InfoValuePtr| {
+            let mut StringLength = 0;
+
+            let sql_return = unsafe {
+                ffi::SQLGetInfoA(
+                    self.as_SQLHANDLE(),
+                    A::IDENTIFIER,
+                    InfoValuePtr.as_mut_SQLPOINTER(),
+                    slice_len::<_, SQLSMALLINT>(InfoValuePtr) * size_of::<SQLCHAR>() as SQLSMALLINT,
+                    &mut StringLength,
+                )
+            };
+
+            (
+                sql_return,
+                StringLength as SQLLEN / size_of::<SQLCHAR>() as SQLLEN,
+            )
+        })
+    }
+
+    /// Same as [`SQLHDBC::SQLGetInfoW`] except that the buffer for the information is allocated by
+    /// the function and reallocated, followed by a repeated call, until the whole value fits into
+    /// it.
+    ///
+    /// For complete documentation on SQLGetInfoW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlgetinfo-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    #[cfg(feature = "std")]
+    pub fn get_info_w<A: Ident<Type = SQLUSMALLINT>>(
+        &self,
+        #[expect(unused_variables)] InfoType: A,
+    ) -> (Option<OdbcString<SQLWCHAR>>, SQLRETURN)
+    where
+        OdbcStr<MaybeUninit<SQLWCHAR>>: InfoType<A, V> + AttrGet<A> + Unicode,
+    {
+        retry_truncated(|InfoValuePtr| {
+            let mut StringLength = 0;
+
+            let sql_return = unsafe {
+                ffi::SQLGetInfoW(
+                    self.as_SQLHANDLE(),
+                    A::IDENTIFIER,
+                    InfoValuePtr.as_mut_SQLPOINTER(),
+                    slice_len::<_, SQLSMALLINT>(InfoValuePtr)
+                        * size_of::<SQLWCHAR>() as SQLSMALLINT,
+                    &mut StringLength,
+                )
+            };
+
+            (
+                sql_return,
+                StringLength as SQLLEN / size_of::<SQLWCHAR>() as SQLLEN,
+            )
+        })
+    }
+
+    /// Returns the SQL string as modified by the driver. **SQLNativeSql** does not execute the SQL statement.
+    ///
+    /// For complete documentation on SQLNativeSqlA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlnativesql-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub fn SQLNativeSqlA(
+        &self,
+        InStatementText: &OdbcStr<SQLCHAR>,
+        OutStatementText: &mut OdbcStr<MaybeUninit<SQLCHAR>>,
+        TextLength2Ptr: &mut impl AsMutPtr<SQLINTEGER>,
     ) -> SQLRETURN {
-        let DriverDescription = DriverDescription.as_mut_raw_slice();
-        let DriverAttributes = DriverAttributes.as_mut_raw_slice();
+        let InStatementText = InStatementText.as_raw_slice();
+        let OutStatementText = OutStatementText.as_mut_raw_slice();
 
         unsafe {
-            ffi::SQLDriversW(
+            ffi::SQLNativeSqlA(
                 self.as_SQLHANDLE(),
-                Direction,
-                DriverDescription.0,
-                DriverDescription.1,
-                DescriptionLengthPtr.as_mut_ptr(),
-                DriverAttributes.0,
-                DriverAttributes.1,
-                AttributesLengthPtr.as_mut_ptr(),
+                InStatementText.0,
+                InStatementText.1,
+                OutStatementText.0,
+                OutStatementText.1,
+                TextLength2Ptr.as_mut_ptr(),
             )
         }
     }
 
-    /// Returns the current setting of an environment attribute.
+    /// Returns the SQL string as modified by the driver. **SQLNativeSql** does not execute the SQL statement.
     ///
-    /// For complete documentation on SQLGetEnvAttr, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlgetenvattr-function).
+    /// For complete documentation on SQLNativeSqlW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlnativesql-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NO_DATA, SQL_ERROR, or SQL_INVALID_HANDLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    pub fn SQLGetEnvAttr<A: Ident<Type = SQLINTEGER>, T: EnvAttr<A, V> + AttrGet<A> + ?Sized>(
+    pub fn SQLNativeSqlW(
         &self,
-        #[expect(unused_variables)] Attribute: A,
-        ValuePtr: Option<&mut T>,
-        StringLengthPtr: Option<&mut MaybeUninit<T::StrLen>>,
-    ) -> SQLRETURN
-    where
-        MaybeUninit<T::StrLen>: StrLen<SQLINTEGER>,
-    {
-        let ValuePtr = ValuePtr.map_or((ptr::null_mut(), 0), |ValuePtr| {
-            (ValuePtr.as_mut_SQLPOINTER(), ValuePtr.len())
-        });
+        InStatementText: &OdbcStr<SQLWCHAR>,
+        OutStatementText: &mut OdbcStr<MaybeUninit<SQLWCHAR>>,
+        TextLength2Ptr: &mut impl AsMutPtr<SQLINTEGER>,
+    ) -> SQLRETURN {
+        let InStatementText = InStatementText.as_raw_slice();
+        let OutStatementText = OutStatementText.as_mut_raw_slice();
 
         unsafe {
-            ffi::SQLGetEnvAttr(
+            ffi::SQLNativeSqlW(
                 self.as_SQLHANDLE(),
-                A::IDENTIFIER,
-                ValuePtr.0,
-                ValuePtr.1,
-                StringLengthPtr.map_or_else(ptr::null_mut, StrLen::as_mut_ptr),
+                InStatementText.0,
+                InStatementText.1,
+                OutStatementText.0,
+                OutStatementText.1,
+                TextLength2Ptr.as_mut_ptr(),
             )
         }
     }
 
-    /// Sets attributes that govern aspects of environments.
+    /// Same as [`SQLHDBC::SQLNativeSqlA`] except that the buffer for the SQL string is allocated by
+    /// the function and reallocated, followed by a repeated call, until the whole value fits into
+    /// it.
     ///
-    /// For complete documentation on SQLSetEnvAttr, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlsetenvattr-function).
+    /// For complete documentation on SQLNativeSqlA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlnativesql-function).
     ///
     /// # Returns
     /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    pub fn SQLSetEnvAttr<A: Ident<Type = SQLINTEGER>, T: EnvAttr<A, V> + AttrSet<A>>(
-        // Reference to SQLHENV is mutable to make it impossible to have a connection
-        // handle allocated on the environment handle when calling this function
-        &mut self,
-        #[expect(unused_variables)] Attribute: A,
-        ValuePtr: T,
-    ) -> SQLRETURN {
+    #[cfg(feature = "std")]
+    pub fn native_sql_a(
+        &self,
+        InStatementText: &OdbcStr<SQLCHAR>,
+    ) -> (Option<OdbcString<SQLCHAR>>, SQLRETURN) {
+        retry_truncated(|OutStatementText| {
+            let mut TextLength2 = 0;
+            let sql_return =
+                self.SQLNativeSqlA(InStatementText, OutStatementText, &mut TextLength2);
+
+            (sql_return, TextLength2 as SQLLEN)
+        })
+    }
+
+    /// Same as [`SQLHDBC::SQLNativeSqlW`] except that the buffer for the SQL string is allocated by
+    /// the function and reallocated, followed by a repeated call, until the whole value fits into
+    /// it.
+    ///
+    /// For complete documentation on SQLNativeSqlW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlnativesql-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    #[cfg(feature = "std")]
+    pub fn native_sql_w(
+        &self,
+        InStatementText: &OdbcStr<SQLWCHAR>,
+    ) -> (Option<OdbcString<SQLWCHAR>>, SQLRETURN) {
+        retry_truncated(|OutStatementText| {
+            let mut TextLength2 = 0;
+            let sql_return =
+                self.SQLNativeSqlW(InStatementText, OutStatementText, &mut TextLength2);
+
+            (sql_return, TextLength2 as SQLLEN)
+        })
+    }
+
+    /// Requests a commit or rollback operation for all active operations on all statements associated with a connection. **SQLEndTran** can also request that a commit or rollback operation be performed for all connections associated with an environment.
+    ///
+    /// For complete documentation on SQLEndTran, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlendtran-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, SQL_INVALID_HANDLE, or SQL_STILL_EXECUTING.
+    #[inline]
+    pub fn SQLEndTran(&self, CompletionType: CompletionType) -> SQLRETURN {
+        // Not implemented on SQLHENV so as to to avoid confusion, considering that the same
+        // functionality can be achieved by calling SQLEndTran repeatedly on SQLHDBC handle
+
         unsafe {
-            ffi::SQLSetEnvAttr(
+            ffi::SQLEndTran(
+                <Self as Handle>::Ident::IDENTIFIER,
                 self.as_SQLHANDLE(),
-                A::IDENTIFIER,
-                ValuePtr.into_SQLPOINTER(),
-                ValuePtr.len(),
+                CompletionType as SQLSMALLINT,
             )
         }
     }
 }
 
 #[expect(non_snake_case)]
-impl<'env, C: ConnState, V: OdbcVersion> SQLHDBC<'env, C, V> {
-    /// Supports an iterative method of discovering and enumerating the attributes and attribute values required to connect to a data source. Each call to **SQLBrowseConnect** returns successive levels of attributes and attribute values. When all levels have been enumerated, a connection to the data source is completed and a complete connection string is returned by **SQLBrowseConnect**. A return code of SQL_SUCCESS or SQL_SUCCESS_WITH_INFO indicates that all connection information has been specified and the application is now connected to the data source.
+impl<'conn, 'desc, 'buf, S: StmtState, V: OdbcVersion> SQLHSTMT<'conn, 'desc, 'buf, S, V> {
+    /// Prepares an SQL string for execution.
     ///
-    /// For complete documentation on SQLBrowseConnectA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlbrowseconnect-function).
+    /// For complete documentation on SQLPrepareA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlprepare-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NEED_DATA, SQL_ERROR, SQL_INVALID_HANDLE, or SQL_STILL_EXECUTING.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    pub fn SQLBrowseConnectA(
+    pub fn SQLPrepareA(
         self,
-        InConnectionString: &OdbcStr<SQLCHAR>,
-        OutConnectionString: Option<&mut OdbcStr<MaybeUninit<SQLCHAR>>>,
-        StringLength2Ptr: &mut impl AsMutPtr<SQLSMALLINT>,
-    ) -> (
-        Result<SQLHDBC<'env, C4, V>, Result<SQLHDBC<'env, C3, V>, SQLHDBC<'env, C2, V>>>,
-        SQLRETURN,
-    )
+        StatementText: &OdbcStr<SQLCHAR>,
+    ) -> (Result<SQLHSTMT<'conn, 'desc, 'buf, S2, V>, Self>, SQLRETURN)
     where
-        Self: BrowseConnect,
+        Self: Prepare,
     {
-        let InConnectionString = InConnectionString.as_raw_slice();
-        let OutConnectionString =
-            OutConnectionString.map_or((ptr::null_mut(), 0), AsMutRawSlice::as_mut_raw_slice);
-
-        let sql_return = unsafe {
-            ffi::SQLBrowseConnectA(
-                self.as_SQLHANDLE(),
-                InConnectionString.0,
-                InConnectionString.1,
-                OutConnectionString.0,
-                OutConnectionString.1,
-                StringLength2Ptr.as_mut_ptr(),
-            )
-        };
+        let sql_return = self.0.SQLPrepareA(StatementText);
 
         if SQL_SUCCEEDED(sql_return) {
-            (Ok(self.connect()), sql_return)
-        } else if sql_return == SQL_NEED_DATA {
-            (Err(Ok(self.need_data())), sql_return)
-        } else if sql_return == SQL_STILL_EXECUTING {
-            unimplemented!("Asynchronous execution not supported")
+            (Ok(self.transition()), sql_return)
         } else {
-            (Err(Err(self.disconnect())), sql_return)
+            (Err(self), sql_return)
         }
     }
 
-    /// Supports an iterative method of discovering and enumerating the attributes and attribute values required to connect to a data source. Each call to **SQLBrowseConnect** returns successive levels of attributes and attribute values. When all levels have been enumerated, a connection to the data source is completed and a complete connection string is returned by **SQLBrowseConnect**. A return code of SQL_SUCCESS or SQL_SUCCESS_WITH_INFO indicates that all connection information has been specified and the application is now connected to the data source.
+    /// Prepares an SQL string for execution.
     ///
-    /// For complete documentation on SQLBrowseConnectW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlbrowseconnect-function).
+    /// For complete documentation on SQLPrepareW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlprepare-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NEED_DATA, SQL_ERROR, SQL_INVALID_HANDLE, or SQL_STILL_EXECUTING.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    pub fn SQLBrowseConnectW(
+    pub fn SQLPrepareW(
         self,
-        InConnectionString: &OdbcStr<SQLWCHAR>,
-        OutConnectionString: Option<&mut OdbcStr<MaybeUninit<SQLWCHAR>>>,
-        StringLength2Ptr: &mut impl AsMutPtr<SQLSMALLINT>,
-    ) -> (
-        Result<SQLHDBC<'env, C4, V>, Result<SQLHDBC<'env, C3, V>, SQLHDBC<'env, C2, V>>>,
-        SQLRETURN,
-    )
+        StatementText: &OdbcStr<SQLWCHAR>,
+    ) -> (Result<SQLHSTMT<'conn, 'desc, 'buf, S2, V>, Self>, SQLRETURN)
     where
-        Self: BrowseConnect,
+        Self: Prepare,
     {
-        let InConnectionString = InConnectionString.as_raw_slice();
-        let OutConnectionString =
-            OutConnectionString.map_or((ptr::null_mut(), 0), AsMutRawSlice::as_mut_raw_slice);
-
-        let sql_return = unsafe {
-            ffi::SQLBrowseConnectW(
-                self.as_SQLHANDLE(),
-                InConnectionString.0,
-                InConnectionString.1,
-                OutConnectionString.0,
-                OutConnectionString.1,
-                StringLength2Ptr.as_mut_ptr(),
-            )
-        };
+        let sql_return = self.0.SQLPrepareW(StatementText);
 
         if SQL_SUCCEEDED(sql_return) {
-            (Ok(self.connect()), sql_return)
-        } else if sql_return == SQL_NEED_DATA {
-            (Err(Ok(self.need_data())), sql_return)
-        } else if sql_return == SQL_STILL_EXECUTING {
-            unimplemented!("Asynchronous execution not supported")
+            (Ok(self.transition()), sql_return)
         } else {
-            (Err(Err(self.disconnect())), sql_return)
+            (Err(self), sql_return)
         }
     }
 
-    /// Closes the connection associated with a specific connection handle.
+    /// Executes a preparable statement, using the current values of the parameter marker variables if any parameters exist in the statement. **SQLExecDirect** is the fastest way to submit an SQL statement for one-time execution.
     ///
-    /// For complete documentation on SQLDisconnect, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqldisconnect-function).
+    /// For complete documentation on SQLExecDirectA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlexecdirect-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, SQL_INVALID_HANDLE, or SQL_STILL_EXECUTING.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NEED_DATA, SQL_STILL_EXECUTING, SQL_ERROR, SQL_NO_DATA, SQL_INVALID_HANDLE, or SQL_PARAM_DATA_AVAILABLE.
     #[inline]
-    pub fn SQLDisconnect(self) -> (Result<SQLHDBC<'env, C2, V>, Self>, SQLRETURN)
+    pub fn SQLExecDirectA(
+        self,
+        StatementText: &OdbcStr<SQLCHAR>,
+    ) -> (ExecResult<'conn, 'desc, 'buf, S1, Self, V>, SQLRETURN)
     where
-        Self: Disconnect,
+        Self: Prepare,
     {
-        let sql_return = unsafe { ffi::SQLDisconnect(self.as_SQLHANDLE()) };
+        let StatementText = StatementText.as_raw_slice();
 
-        if SQL_SUCCEEDED(sql_return) {
-            (Ok(self.disconnect()), sql_return)
+        let sql_return =
+            unsafe { ffi::SQLExecDirectA(self.as_SQLHANDLE(), StatementText.0, StatementText.1) };
+
+        if SQL_SUCCEEDED(sql_return)
+            || sql_return == SQL_NO_DATA
+            || sql_return == SQL_PARAM_DATA_AVAILABLE
+        {
+            (Ok(self.transition()), sql_return)
+        } else if sql_return == SQL_NEED_DATA {
+            (Err(Ok(self.transition())), sql_return)
         } else {
-            (Err(self), sql_return)
+            (Err(Err(self)), sql_return)
         }
     }
 
-    /// Returns the current setting of a connection attribute.
+    /// Executes a preparable statement, using the current values of the parameter marker variables if any parameters exist in the statement. **SQLExecDirect** is the fastest way to submit an SQL statement for one-time execution.
     ///
-    /// For complete documentation on SQLGetConnectAttrA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlgetconnectattr-function).
+    /// For complete documentation on SQLExecDirectW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlexecdirect-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NO_DATA, SQL_ERROR, or SQL_INVALID_HANDLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NEED_DATA, SQL_STILL_EXECUTING, SQL_ERROR, SQL_NO_DATA, SQL_INVALID_HANDLE, or SQL_PARAM_DATA_AVAILABLE.
     #[inline]
-    pub fn SQLGetConnectAttrA<
-        A: Ident<Type = SQLINTEGER>,
-        T: ConnAttr<C, A, V> + AttrGet<A> + Ansi + ?Sized,
-    >(
-        // TODO: Not sure whether attributes should be checked when getting them with SQLGetConnectAttr
-        &self,
-        #[expect(unused_variables)] Attribute: A,
-        ValuePtr: Option<&mut T>,
-        StringLengthPtr: Option<&mut MaybeUninit<T::StrLen>>,
-    ) -> SQLRETURN
+    pub fn SQLExecDirectW(
+        self,
+        StatementText: &OdbcStr<SQLWCHAR>,
+    ) -> (ExecResult<'conn, 'desc, 'buf, S1, Self, V>, SQLRETURN)
     where
-        MaybeUninit<T::StrLen>: StrLen<SQLINTEGER>,
+        Self: Prepare,
     {
-        let ValuePtr = ValuePtr.map_or((ptr::null_mut(), 0), |ValuePtr| {
-            if cfg!(feature = "odbc_debug") {
-                ValuePtr.assert_zeroed();
-            }
+        let StatementText = StatementText.as_raw_slice();
 
-            (ValuePtr.as_mut_SQLPOINTER(), ValuePtr.len())
-        });
+        let sql_return =
+            unsafe { ffi::SQLExecDirectW(self.as_SQLHANDLE(), StatementText.0, StatementText.1) };
 
-        unsafe {
-            ffi::SQLGetConnectAttrA(
-                self.as_SQLHANDLE(),
-                A::IDENTIFIER,
-                ValuePtr.0,
-                ValuePtr.1,
-                StringLengthPtr.map_or_else(ptr::null_mut, StrLen::as_mut_ptr),
-            )
+        if SQL_SUCCEEDED(sql_return)
+            || sql_return == SQL_NO_DATA
+            || sql_return == SQL_PARAM_DATA_AVAILABLE
+        {
+            (Ok(self.transition()), sql_return)
+        } else if sql_return == SQL_NEED_DATA {
+            (Err(Ok(self.transition())), sql_return)
+        } else {
+            (Err(Err(self)), sql_return)
         }
     }
 
-    /// Returns the current setting of a connection attribute.
+    /// Executes a prepared statement, using the current values of the parameter marker variables if any parameter markers exist in the statement.
     ///
-    /// For complete documentation on SQLGetConnectAttrW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlgetconnectattr-function).
+    /// For complete documentation on SQLExecute, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlexecute-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NO_DATA, SQL_ERROR, or SQL_INVALID_HANDLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NEED_DATA, SQL_STILL_EXECUTING, SQL_ERROR, SQL_NO_DATA, SQL_INVALID_HANDLE, or SQL_PARAM_DATA_AVAILABLE.
     #[inline]
-    pub fn SQLGetConnectAttrW<
-        A: Ident<Type = SQLINTEGER>,
-        T: ConnAttr<C, A, V> + AttrGet<A> + Unicode + ?Sized,
-    >(
-        // TODO: Not really sure whether attributes should be checked when getting them with SQLGetConnectAttr
-        &self,
-        #[expect(unused_variables)] Attribute: A,
-        ValuePtr: Option<&mut T>,
-        StringLengthPtr: Option<&mut MaybeUninit<T::StrLen>>,
-    ) -> SQLRETURN
+    pub fn SQLExecute(self) -> (ExecResult<'conn, 'desc, 'buf, S2, Self, V>, SQLRETURN)
     where
-        MaybeUninit<T::StrLen>: StrLen<SQLINTEGER>,
+        Self: Execute,
     {
-        let ValuePtr = ValuePtr.map_or((ptr::null_mut(), 0), |ValuePtr| {
-            if cfg!(feature = "odbc_debug") {
-                ValuePtr.assert_zeroed();
-            }
-
-            (ValuePtr.as_mut_SQLPOINTER(), ValuePtr.len())
-        });
+        let sql_return = unsafe { ffi::SQLExecute(self.as_SQLHANDLE()) };
 
-        unsafe {
-            ffi::SQLGetConnectAttrW(
-                self.as_SQLHANDLE(),
-                A::IDENTIFIER,
-                ValuePtr.0,
-                ValuePtr.1,
-                StringLengthPtr.map_or_else(ptr::null_mut, StrLen::as_mut_ptr),
-            )
+        if SQL_SUCCEEDED(sql_return)
+            || sql_return == SQL_NO_DATA
+            || sql_return == SQL_PARAM_DATA_AVAILABLE
+        {
+            (Ok(self.transition()), sql_return)
+        } else if sql_return == SQL_NEED_DATA {
+            (Err(Ok(self.transition())), sql_return)
+        } else {
+            (Err(Err(self)), sql_return)
         }
     }
 
-    /// Sets attributes that govern aspects of connections.
+    /// Releases all buffers bound to columns and parameters by calling SQLFreeStmt with
+    /// SQL_UNBIND and SQL_RESET_PARAMS. Explicitly allocated application descriptors are
+    /// dissociated from the statement which reverts to the implicitly allocated ones. Since
+    /// neither previously bound buffers nor descriptors are used by the returned statement,
+    /// it can be bound anew with buffers of a different lifetime. This makes it possible to
+    /// execute a prepared statement repeatedly with buffers allocated for every execution.
     ///
-    /// For complete documentation on SQLSetConnectAttrA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlsetconnectattr-function).
+    /// For complete documentation on SQLFreeStmt, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlfreestmt-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, SQL_INVALID_HANDLE, or SQL_STILL_EXECUTING.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    pub fn SQLSetConnectAttrA<
-        A: Ident<Type = SQLINTEGER>,
-        T: ConnAttr<C, A, V> + AttrSet<A> + Ansi,
-    >(
-        &self,
-        #[expect(unused_variables)] Attribute: A,
-        ValuePtr: T,
-    ) -> SQLRETURN {
-        let conn_handle = self.as_SQLHANDLE();
-        unsafe {
-            ffi::SQLSetConnectAttrA(
-                conn_handle,
-                A::IDENTIFIER,
-                ValuePtr.into_SQLPOINTER(),
-                ValuePtr.len(),
-            )
+    pub fn unbind<'new_desc, 'new_buf>(
+        self,
+    ) -> (
+        Result<SQLHSTMT<'conn, 'new_desc, 'new_buf, S, V>, Self>,
+        SQLRETURN,
+    ) {
+        // Arrays outlive the bindings unless they are released as well
+        let sql_returns = [
+            release_arrays(
+                self.as_SQLHANDLE(),
+                &self.0.bound_cols,
+                SQL_ATTR_ROW_ARRAY_SIZE::IDENTIFIER,
+                [
+                    SQL_ATTR_ROWS_FETCHED_PTR::IDENTIFIER,
+                    SQL_ATTR_ROW_STATUS_PTR::IDENTIFIER,
+                ],
+                ROW_ATTRS,
+            ),
+            release_arrays(
+                self.as_SQLHANDLE(),
+                &self.0.bound_params,
+                SQL_ATTR_PARAMSET_SIZE::IDENTIFIER,
+                [
+                    SQL_ATTR_PARAMS_PROCESSED_PTR::IDENTIFIER,
+                    SQL_ATTR_PARAM_STATUS_PTR::IDENTIFIER,
+                    SQL_ATTR_PARAM_OPERATION_PTR::IDENTIFIER,
+                ],
+                PARAM_ROW_ATTRS,
+            ),
+        ];
+        if let Some(sql_return) = sql_returns
+            .into_iter()
+            .find(|&sql_return| !SQL_SUCCEEDED(sql_return))
+        {
+            return (Err(self), sql_return);
+        }
+
+        // Setting a null handle reverts the statement to the implicitly allocated descriptor
+        let sql_returns = [
+            {
+                let sql_return = unsafe {
+                    ffi::SQLSetStmtAttrA(
+                        self.as_SQLHANDLE(),
+                        SQL_ATTR_APP_ROW_DESC::IDENTIFIER,
+                        ptr::null_mut(),
+                        0,
+                    )
+                };
+                if SQL_SUCCEEDED(sql_return) {
+                    self.0.bound_cols.set_explicit_desc(false);
+                }
+                sql_return
+            },
+            {
+                let sql_return = unsafe {
+                    ffi::SQLSetStmtAttrA(
+                        self.as_SQLHANDLE(),
+                        SQL_ATTR_APP_PARAM_DESC::IDENTIFIER,
+                        ptr::null_mut(),
+                        0,
+                    )
+                };
+                if SQL_SUCCEEDED(sql_return) {
+                    self.0.bound_params.set_explicit_desc(false);
+                }
+                sql_return
+            },
+            self.SQLFreeStmt(UnbindOption::SQL_UNBIND),
+            self.SQLFreeStmt(UnbindOption::SQL_RESET_PARAMS),
+        ];
+
+        match sql_returns
+            .into_iter()
+            .find(|&sql_return| !SQL_SUCCEEDED(sql_return))
+        {
+            Some(sql_return) => (Err(self), sql_return),
+            None => (Ok(unsafe { self.rebind() }), sql_returns[3]),
         }
     }
 
-    /// Sets attributes that govern aspects of connections.
+    /// Binds an array of buffers to a column in the result set for fetching a rowset of multiple
+    /// rows with column-wise binding. Value and length/indicator of the n-th row of the rowset
+    /// are fetched into the n-th element of the arrays. Both arrays must hold at least as many
+    /// rows as the row array size set with [`SQLHSTMT::bind_rowset`].
     ///
-    /// For complete documentation on SQLSetConnectAttrW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlsetconnectattr-function).
+    /// For complete documentation on SQLBindCol, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlbindcol-function).
+    ///
+    /// # Panics
+    /// If any of the arrays holds fewer rows than the row array size.
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, SQL_INVALID_HANDLE, or SQL_STILL_EXECUTING.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    pub fn SQLSetConnectAttrW<
-        A: Ident<Type = SQLINTEGER>,
-        T: ConnAttr<C, A, V> + AttrSet<A> + Unicode,
-    >(
+    pub fn bind_col_array<TT: Ident<Type = SQLSMALLINT>, B: ColumnBuf<TT, V> + ?Sized>(
         &self,
-        #[expect(unused_variables)] Attribute: A,
-        ValuePtr: T,
+        ColumnNumber: SQLUSMALLINT,
+        #[expect(unused_variables)] TargetType: TT,
+        TargetValuePtr: Option<&'buf B>,
+        StrLen_or_IndPtr: Option<&'buf [UnsafeCell<StrLenOrInd>]>,
     ) -> SQLRETURN {
-        unsafe {
-            ffi::SQLSetConnectAttrW(
-                self.as_SQLHANDLE(),
-                A::IDENTIFIER,
-                ValuePtr.into_SQLPOINTER(),
-                ValuePtr.len(),
+        assert!(
+            self.0.bound_cols.row().is_none(),
+            "Column array bound while rows are bound with row-wise binding"
+        );
+
+        let rows = TargetValuePtr
+            .map_or(SQLULEN::MAX, ColumnBuf::rows)
+            .min(StrLen_or_IndPtr.map_or(SQLULEN::MAX, <[_]>::len));
+        assert!(
+            rows >= self.0.bound_cols.array_size(),
+            "Buffer of {} rows bound while the row array size is {}",
+            rows,
+            self.0.bound_cols.array_size()
+        );
+
+        #[cfg(feature = "runtime_checks")]
+        let ARD = match checks::app_desc::<SQL_ATTR_APP_ROW_DESC>(self.as_SQLHANDLE()) {
+            Ok(ARD) => ARD,
+            Err(sql_return) => return sql_return,
+        };
+        #[cfg(feature = "runtime_checks")]
+        let DataPtr = TargetValuePtr.map(|TargetValuePtr| {
+            (
+                TargetValuePtr.as_SQLPOINTER(),
+                core::mem::size_of_val(TargetValuePtr),
             )
-        }
-    }
-}
+        });
+        #[cfg(feature = "runtime_checks")]
+        checks::check_bind(ARD, ColumnNumber as SQLSMALLINT, DataPtr, true);
 
-#[expect(non_snake_case)]
-impl<'env, V: OdbcVersion> SQLHDBC<'env, C2, V> {
-    /// Establishes connections to a driver and a data source. The connection handle references storage of all information about the connection to the data source, including status, transaction state, and error information.
-    ///
-    /// For complete documentation on SQLConnectA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlconnect-function).
-    ///
-    /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, SQL_INVALID_HANDLE, or SQL_STILL_EXECUTING.
-    #[inline]
-    pub fn SQLConnectA(
-        self,
-        ServerName: &OdbcStr<SQLCHAR>,
-        UserName: &OdbcStr<SQLCHAR>,
-        Authentication: &OdbcStr<SQLCHAR>,
-    ) -> (
-        Result<SQLHDBC<'env, C4, V>, SQLHDBC<'env, C2, V>>,
-        SQLRETURN,
-    ) {
-        let ServerName = ServerName.as_raw_slice();
-        let UserName = UserName.as_raw_slice();
-        let Authentication = Authentication.as_raw_slice();
+        let TargetValuePtr = TargetValuePtr
+            .map(|TargetValuePtr| (TargetValuePtr.as_SQLPOINTER(), TargetValuePtr.element_len()));
+        let StrLen_or_IndPtr =
+            StrLen_or_IndPtr.map(|StrLen_or_IndPtr| UnsafeCell::raw_get(StrLen_or_IndPtr.as_ptr()));
 
         let sql_return = unsafe {
-            ffi::SQLConnectA(
+            let (DataPtr, BufferLength) = TargetValuePtr.unwrap_or((ptr::null_mut(), 0));
+
+            ffi::SQLBindCol(
                 self.as_SQLHANDLE(),
-                ServerName.0,
-                ServerName.1,
-                UserName.0,
-                UserName.1,
-                Authentication.0,
-                Authentication.1,
+                ColumnNumber,
+                TT::IDENTIFIER,
+                DataPtr,
+                BufferLength,
+                StrLen_or_IndPtr.map_or_else(ptr::null_mut, <*mut StrLenOrInd>::cast),
             )
         };
 
         if SQL_SUCCEEDED(sql_return) {
-            (Ok(self.connect()), sql_return)
-        } else {
-            (Err(self), sql_return)
+            #[cfg(feature = "runtime_checks")]
+            checks::bind(ARD, ColumnNumber as SQLSMALLINT, DataPtr, true);
+
+            self.0
+                .bound_cols
+                .bind(ColumnNumber, TargetValuePtr, StrLen_or_IndPtr, rows);
         }
+
+        sql_return
     }
 
-    /// Establishes connections to a driver and a data source. The connection handle references storage of all information about the connection to the data source, including status, transaction state, and error information.
+    /// Binds an array of rows for fetching a rowset with row-wise binding. Columns are bound to
+    /// fields of the first row with [`SQLHSTMT::bind_row_col`] and the value of the n-th row of
+    /// the rowset is fetched into the same field of the n-th row of the array. If BindOffsetPtr
+    /// is provided, columns can later be pointed to another array with [`SQLHSTMT::set_rows`].
+    /// Sets SQL_ATTR_ROW_BIND_TYPE and SQL_ATTR_ROW_BIND_OFFSET_PTR statement attributes.
     ///
-    /// For complete documentation on SQLConnectW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlconnect-function).
+    /// For complete documentation on SQLSetStmtAttrA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlsetstmtattr-function).
+    ///
+    /// # Panics
+    /// If any column is bound, if the row is zero-sized or if the array holds fewer rows than
+    /// the row array size.
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, SQL_INVALID_HANDLE, or SQL_STILL_EXECUTING.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    pub fn SQLConnectW(
-        self,
-        ServerName: &OdbcStr<SQLWCHAR>,
-        UserName: &OdbcStr<SQLWCHAR>,
-        Authentication: &OdbcStr<SQLWCHAR>,
-    ) -> (
-        Result<SQLHDBC<'env, C4, V>, SQLHDBC<'env, C2, V>>,
-        SQLRETURN,
-    ) {
-        let ServerName = ServerName.as_raw_slice();
-        let UserName = UserName.as_raw_slice();
-        let Authentication = Authentication.as_raw_slice();
+    pub fn bind_rows<R: RowBuf>(
+        &self,
+        Rows: &'buf [R],
+        BindOffsetPtr: Option<&'buf mut BindOffset>,
+    ) -> SQLRETURN {
+        bind_row_array(
+            self.as_SQLHANDLE(),
+            &self.0.bound_cols,
+            ROW_ATTRS,
+            Rows,
+            BindOffsetPtr,
+        )
+    }
+
+    /// Binds a field of the first row of the array bound with [`SQLHSTMT::bind_rows`] to a
+    /// column in the result set.
+    ///
+    /// For complete documentation on SQLBindCol, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlbindcol-function).
+    ///
+    /// # Panics
+    /// If rows are not bound or if any of the buffers is not within the first row.
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub fn bind_row_col<
+        TT: Ident<Type = SQLSMALLINT>,
+        B: DeferredBuf<RefSQLHDESC<'conn, AppDesc<'buf>, V>, TT, V> + ?Sized,
+    >(
+        &self,
+        ColumnNumber: SQLUSMALLINT,
+        #[expect(unused_variables)] TargetType: TT,
+        TargetValuePtr: Option<&'buf B>,
+        StrLen_or_IndPtr: Option<&'buf UnsafeCell<StrLenOrInd>>,
+    ) -> SQLRETURN {
+        check_row_bufs(
+            &self.0.bound_cols,
+            TargetValuePtr.map(|TargetValuePtr| {
+                (
+                    TargetValuePtr.as_SQLPOINTER(),
+                    core::mem::size_of_val(TargetValuePtr),
+                )
+            }),
+            StrLen_or_IndPtr.map(UnsafeCell::get),
+        );
+
+        #[cfg(feature = "runtime_checks")]
+        let ARD = match checks::app_desc::<SQL_ATTR_APP_ROW_DESC>(self.as_SQLHANDLE()) {
+            Ok(ARD) => ARD,
+            Err(sql_return) => return sql_return,
+        };
+        #[cfg(feature = "runtime_checks")]
+        let DataPtr = TargetValuePtr.map(|TargetValuePtr| {
+            (
+                TargetValuePtr.as_SQLPOINTER(),
+                core::mem::size_of_val(TargetValuePtr),
+            )
+        });
+        #[cfg(feature = "runtime_checks")]
+        checks::check_bind(ARD, ColumnNumber as SQLSMALLINT, DataPtr, true);
+
+        let TargetValuePtr = TargetValuePtr
+            .map(|TargetValuePtr| (TargetValuePtr.as_SQLPOINTER(), TargetValuePtr.len()));
+        let StrLen_or_IndPtr = StrLen_or_IndPtr.map(UnsafeCell::get);
 
         let sql_return = unsafe {
-            ffi::SQLConnectW(
+            let (DataPtr, BufferLength) = TargetValuePtr.unwrap_or((ptr::null_mut(), 0));
+
+            ffi::SQLBindCol(
                 self.as_SQLHANDLE(),
-                ServerName.0,
-                ServerName.1,
-                UserName.0,
-                UserName.1,
-                Authentication.0,
-                Authentication.1,
+                ColumnNumber,
+                TT::IDENTIFIER,
+                DataPtr,
+                BufferLength,
+                StrLen_or_IndPtr.map_or_else(ptr::null_mut, <*mut StrLenOrInd>::cast),
             )
         };
 
         if SQL_SUCCEEDED(sql_return) {
-            (Ok(self.connect()), sql_return)
-        } else {
-            (Err(self), sql_return)
+            #[cfg(feature = "runtime_checks")]
+            checks::bind(ARD, ColumnNumber as SQLSMALLINT, DataPtr, true);
+
+            // Number of rows was recorded when the rows were bound
+            self.0
+                .bound_cols
+                .bind(ColumnNumber, TargetValuePtr, StrLen_or_IndPtr, SQLULEN::MAX);
         }
+
+        sql_return
     }
 
-    /// An alternative to **SQLConnect**. It supports data sources that require more connection information than the three arguments in **SQLConnect**, dialog boxes to prompt the user for all connection information, and data sources that are not defined in the system information. For more information, see Connecting with SQLDriverConnect.
-    ///
-    /// For complete documentation on SQLDriverConnectA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqldriverconnect-function).
+    /// Points columns bound with [`SQLHSTMT::bind_row_col`] to another array of rows by
+    /// updating the offset bound with [`SQLHSTMT::bind_rows`]. Next rowset is fetched into
+    /// the given array without binding the columns again.
     ///
-    /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NO_DATA, SQL_ERROR, SQL_INVALID_HANDLE, or SQL_STILL_EXECUTING.
+    /// # Panics
+    /// If rows are not bound with an offset, if the rows differ in type from the bound rows or
+    /// if the array holds fewer rows than the row array size.
     #[inline]
-    pub fn SQLDriverConnectA(
-        self,
-        _WindowHandle: Option<SQLHWND>,
-        InConnectionString: &OdbcStr<SQLCHAR>,
-        OutConnectionString: Option<&mut OdbcStr<MaybeUninit<SQLCHAR>>>,
-        StringLength2Ptr: &mut impl AsMutPtr<SQLSMALLINT>,
-        DriverCompletion: DriverCompletion,
-    ) -> (
-        Result<SQLHDBC<'env, C4, V>, SQLHDBC<'env, C2, V>>,
-        SQLRETURN,
-    ) {
-        let InConnectionString = InConnectionString.as_raw_slice();
-        let OutConnectionString =
-            OutConnectionString.map_or((ptr::null_mut(), 0), AsMutRawSlice::as_mut_raw_slice);
+    pub fn set_rows<R: RowBuf>(&self, Rows: &'buf [R]) {
+        set_row_array(&self.0.bound_cols, Rows);
+    }
+
+    /// Sets the number of rows in the rowset fetched by SQLFetch and SQLFetchScroll together
+    /// with the buffers into which the number of fetched rows and the status of every row of
+    /// the rowset are written. These correspond to SQL_ATTR_ROW_ARRAY_SIZE,
+    /// SQL_ATTR_ROWS_FETCHED_PTR and SQL_ATTR_ROW_STATUS_PTR statement attributes which are
+    /// always set together so that the driver never writes past the end of bound buffers.
+    ///
+    /// For complete documentation on SQLSetStmtAttrA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlsetstmtattr-function).
+    ///
+    /// # Panics
+    /// If RowArraySize is zero or if any buffer bound to a column or RowStatusPtr holds fewer
+    /// rows than RowArraySize.
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub fn bind_rowset(
+        &self,
+        RowArraySize: SQLULEN,
+        RowsFetchedPtr: Option<&'buf UnsafeCell<SQLULEN>>,
+        RowStatusPtr: Option<&'buf [UnsafeCell<RowStatus>]>,
+    ) -> SQLRETURN {
+        assert_ne!(RowArraySize, 0, "Row array size must be positive");
+        self.0.bound_cols.check_array_size(RowArraySize);
+        if let Some(RowStatusPtr) = RowStatusPtr {
+            assert!(
+                RowStatusPtr.len() >= RowArraySize,
+                "Row status array of {} rows is shorter than the row array size {}",
+                RowStatusPtr.len(),
+                RowArraySize
+            );
+        }
+
+        set_array_attrs(
+            self.as_SQLHANDLE(),
+            &self.0.bound_cols,
+            SQL_ATTR_ROW_ARRAY_SIZE::IDENTIFIER,
+            RowArraySize,
+            [
+                (
+                    SQL_ATTR_ROWS_FETCHED_PTR::IDENTIFIER,
+                    RowsFetchedPtr
+                        .map_or_else(ptr::null_mut, |RowsFetchedPtr| RowsFetchedPtr.get().cast()),
+                ),
+                (
+                    SQL_ATTR_ROW_STATUS_PTR::IDENTIFIER,
+                    RowStatusPtr.map_or_else(ptr::null_mut, |RowStatusPtr| {
+                        UnsafeCell::raw_get(RowStatusPtr.as_ptr()).cast()
+                    }),
+                ),
+            ],
+        )
+    }
+
+    /// Binds an array of buffers to a parameter marker in an SQL statement for executing the
+    /// statement with multiple sets of parameters with column-wise binding. Value and
+    /// length/indicator of the n-th set of parameters are read from the n-th element of the
+    /// arrays. Both arrays must hold at least as many rows as the paramset size set with
+    /// [`SQLHSTMT::bind_paramset`].
+    ///
+    /// For complete documentation on SQLBindParameter, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlbindparameter-function).
+    ///
+    /// # Panics
+    /// If any of the arrays holds fewer rows than the paramset size.
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    #[expect(clippy::too_many_arguments)]
+    pub fn bind_param_array<
+        TT: Ident<Type = SQLSMALLINT>,
+        ST: SqlType<V>,
+        B: ColumnBuf<TT, V> + ?Sized,
+    >(
+        &self,
+        ParameterNumber: SQLUSMALLINT,
+        InputOutputType: IOType,
+        #[expect(unused_variables)] ValueType: TT,
+        ParameterType: ST,
+        ColumnSize: SQLULEN,
+        DecimalDigits: SQLSMALLINT,
+        ParameterValuePtr: Option<&'buf B>,
+        StrLen_or_IndPtr: Option<&'buf [UnsafeCell<StrLenOrInd>]>,
+    ) -> SQLRETURN {
+        assert!(
+            self.0.bound_params.row().is_none(),
+            "Parameter array bound while rows are bound with row-wise binding"
+        );
+
+        let rows = ParameterValuePtr
+            .map_or(SQLULEN::MAX, ColumnBuf::rows)
+            .min(StrLen_or_IndPtr.map_or(SQLULEN::MAX, <[_]>::len));
+        assert!(
+            rows >= self.0.bound_params.array_size(),
+            "Buffer of {} rows bound while the paramset size is {}",
+            rows,
+            self.0.bound_params.array_size()
+        );
+
+        #[cfg(feature = "runtime_checks")]
+        let APD = match checks::app_desc::<SQL_ATTR_APP_PARAM_DESC>(self.as_SQLHANDLE()) {
+            Ok(APD) => APD,
+            Err(sql_return) => return sql_return,
+        };
+        #[cfg(feature = "runtime_checks")]
+        let DataPtr = ParameterValuePtr.map(|ParameterValuePtr| {
+            (
+                ParameterValuePtr.as_SQLPOINTER(),
+                core::mem::size_of_val(ParameterValuePtr),
+            )
+        });
+        #[cfg(feature = "runtime_checks")]
+        let written = InputOutputType != SQL_PARAM_INPUT;
+        #[cfg(feature = "runtime_checks")]
+        checks::check_bind(APD, ParameterNumber as SQLSMALLINT, DataPtr, written);
+
+        let ParameterValuePtr = ParameterValuePtr.map(|ParameterValuePtr| {
+            (
+                ParameterValuePtr.as_SQLPOINTER(),
+                ParameterValuePtr.element_len(),
+            )
+        });
+        let StrLen_or_IndPtr =
+            StrLen_or_IndPtr.map(|StrLen_or_IndPtr| UnsafeCell::raw_get(StrLen_or_IndPtr.as_ptr()));
 
         let sql_return = unsafe {
-            ffi::SQLDriverConnectA(
+            let (DataPtr, BufferLength) = ParameterValuePtr.unwrap_or((ptr::null_mut(), 0));
+
+            ffi::SQLBindParameter(
                 self.as_SQLHANDLE(),
-                // TODO: Fix this
-                ptr::null_mut(),
-                InConnectionString.0,
-                InConnectionString.1,
-                OutConnectionString.0,
-                OutConnectionString.1,
-                StringLength2Ptr.as_mut_ptr(),
-                DriverCompletion as SQLUSMALLINT,
+                ParameterNumber,
+                InputOutputType.identifier(),
+                TT::IDENTIFIER,
+                ParameterType.identifier(),
+                ColumnSize,
+                DecimalDigits,
+                DataPtr,
+                BufferLength,
+                StrLen_or_IndPtr.map_or_else(ptr::null_mut, <*mut StrLenOrInd>::cast),
             )
         };
 
         if SQL_SUCCEEDED(sql_return) {
-            (Ok(self.connect()), sql_return)
-        } else {
-            (Err(self), sql_return)
+            #[cfg(feature = "runtime_checks")]
+            checks::bind(APD, ParameterNumber as SQLSMALLINT, DataPtr, written);
+
+            self.0
+                .bound_params
+                .bind(ParameterNumber, ParameterValuePtr, StrLen_or_IndPtr, rows);
         }
+
+        sql_return
     }
 
-    /// An alternative to **SQLConnect**. It supports data sources that require more connection information than the three arguments in **SQLConnect**, dialog boxes to prompt the user for all connection information, and data sources that are not defined in the system information. For more information, see Connecting with SQLDriverConnect.
+    /// Binds an array of rows for executing the statement with multiple sets of parameters with
+    /// row-wise binding. Parameters are bound to fields of the first row with
+    /// [`SQLHSTMT::bind_row_param`] and the value of the n-th set of parameters is read from the
+    /// same field of the n-th row of the array. If BindOffsetPtr is provided, parameters can
+    /// later be pointed to another array with [`SQLHSTMT::set_param_rows`]. Sets
+    /// SQL_ATTR_PARAM_BIND_TYPE and SQL_ATTR_PARAM_BIND_OFFSET_PTR statement attributes.
     ///
-    /// For complete documentation on SQLDriverConnectW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqldriverconnect-function).
+    /// For complete documentation on SQLSetStmtAttrA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlsetstmtattr-function).
+    ///
+    /// # Panics
+    /// If any parameter is bound, if the row is zero-sized or if the array holds fewer rows than
+    /// the paramset size.
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NO_DATA, SQL_ERROR, SQL_INVALID_HANDLE, or SQL_STILL_EXECUTING.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    pub fn SQLDriverConnectW(
-        self,
-        _WindowHandle: Option<SQLHWND>,
-        InConnectionString: &OdbcStr<SQLWCHAR>,
-        OutConnectionString: Option<&mut OdbcStr<MaybeUninit<SQLWCHAR>>>,
-        StringLength2Ptr: &mut impl AsMutPtr<SQLSMALLINT>,
-        DriverCompletion: DriverCompletion,
-    ) -> (
-        Result<SQLHDBC<'env, C4, V>, SQLHDBC<'env, C2, V>>,
-        SQLRETURN,
-    ) {
-        let InConnectionString = InConnectionString.as_raw_slice();
-        let OutConnectionString =
-            OutConnectionString.map_or((ptr::null_mut(), 0), AsMutRawSlice::as_mut_raw_slice);
+    pub fn bind_param_rows<R: RowBuf>(
+        &self,
+        Rows: &'buf [R],
+        BindOffsetPtr: Option<&'buf mut BindOffset>,
+    ) -> SQLRETURN {
+        bind_row_array(
+            self.as_SQLHANDLE(),
+            &self.0.bound_params,
+            PARAM_ROW_ATTRS,
+            Rows,
+            BindOffsetPtr,
+        )
+    }
+
+    /// Binds a field of the first row of the array bound with [`SQLHSTMT::bind_param_rows`] to
+    /// a parameter marker in an SQL statement.
+    ///
+    /// For complete documentation on SQLBindParameter, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlbindparameter-function).
+    ///
+    /// # Panics
+    /// If rows are not bound or if any of the buffers is not within the first row.
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    #[expect(clippy::too_many_arguments)]
+    pub fn bind_row_param<
+        TT: Ident<Type = SQLSMALLINT>,
+        ST: SqlType<V>,
+        B: DeferredBuf<RefSQLHDESC<'conn, AppDesc<'buf>, V>, TT, V> + ?Sized,
+    >(
+        &self,
+        ParameterNumber: SQLUSMALLINT,
+        InputOutputType: IOType,
+        #[expect(unused_variables)] ValueType: TT,
+        ParameterType: ST,
+        ColumnSize: SQLULEN,
+        DecimalDigits: SQLSMALLINT,
+        ParameterValuePtr: Option<&'buf B>,
+        StrLen_or_IndPtr: Option<&'buf UnsafeCell<StrLenOrInd>>,
+    ) -> SQLRETURN {
+        check_row_bufs(
+            &self.0.bound_params,
+            ParameterValuePtr.map(|ParameterValuePtr| {
+                (
+                    ParameterValuePtr.as_SQLPOINTER(),
+                    core::mem::size_of_val(ParameterValuePtr),
+                )
+            }),
+            StrLen_or_IndPtr.map(UnsafeCell::get),
+        );
+
+        #[cfg(feature = "runtime_checks")]
+        let APD = match checks::app_desc::<SQL_ATTR_APP_PARAM_DESC>(self.as_SQLHANDLE()) {
+            Ok(APD) => APD,
+            Err(sql_return) => return sql_return,
+        };
+        #[cfg(feature = "runtime_checks")]
+        let DataPtr = ParameterValuePtr.map(|ParameterValuePtr| {
+            (
+                ParameterValuePtr.as_SQLPOINTER(),
+                core::mem::size_of_val(ParameterValuePtr),
+            )
+        });
+        #[cfg(feature = "runtime_checks")]
+        let written = InputOutputType != SQL_PARAM_INPUT;
+        #[cfg(feature = "runtime_checks")]
+        checks::check_bind(APD, ParameterNumber as SQLSMALLINT, DataPtr, written);
+
+        let ParameterValuePtr = ParameterValuePtr
+            .map(|ParameterValuePtr| (ParameterValuePtr.as_SQLPOINTER(), ParameterValuePtr.len()));
+        let StrLen_or_IndPtr = StrLen_or_IndPtr.map(UnsafeCell::get);
 
         let sql_return = unsafe {
-            ffi::SQLDriverConnectW(
+            let (DataPtr, BufferLength) = ParameterValuePtr.unwrap_or((ptr::null_mut(), 0));
+
+            ffi::SQLBindParameter(
                 self.as_SQLHANDLE(),
-                // TODO: Fix this
-                ptr::null_mut(),
-                InConnectionString.0,
-                InConnectionString.1,
-                OutConnectionString.0,
-                OutConnectionString.1,
-                StringLength2Ptr.as_mut_ptr(),
-                DriverCompletion as SQLUSMALLINT,
+                ParameterNumber,
+                InputOutputType.identifier(),
+                TT::IDENTIFIER,
+                ParameterType.identifier(),
+                ColumnSize,
+                DecimalDigits,
+                DataPtr,
+                BufferLength,
+                StrLen_or_IndPtr.map_or_else(ptr::null_mut, <*mut StrLenOrInd>::cast),
             )
         };
 
         if SQL_SUCCEEDED(sql_return) {
-            (Ok(self.connect()), sql_return)
-        } else {
-            (Err(self), sql_return)
+            #[cfg(feature = "runtime_checks")]
+            checks::bind(APD, ParameterNumber as SQLSMALLINT, DataPtr, written);
+
+            // Number of rows was recorded when the rows were bound
+            self.0.bound_params.bind(
+                ParameterNumber,
+                ParameterValuePtr,
+                StrLen_or_IndPtr,
+                SQLULEN::MAX,
+            );
         }
+
+        sql_return
     }
-}
 
-#[expect(non_snake_case)]
-impl<'env, V: OdbcVersion> SQLHDBC<'env, C4, V> {
-    /// Returns information about whether a driver supports a specific ODBC function. This function is implemented in the Driver Manager; it can also be implemented in drivers. If a driver implements **SQLGetFunctions**, the Driver Manager calls the function in the driver. Otherwise, it executes the function itself.
+    /// Points parameters bound with [`SQLHSTMT::bind_row_param`] to another array of rows by
+    /// updating the offset bound with [`SQLHSTMT::bind_param_rows`]. Next execution reads the
+    /// parameters from the given array without binding the parameters again.
     ///
-    /// For complete documentation on SQLGetFunctions, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlgetfunctions-function).
+    /// # Panics
+    /// If rows are not bound with an offset, if the rows differ in type from the bound rows or
+    /// if the array holds fewer rows than the paramset size.
+    #[inline]
+    pub fn set_param_rows<R: RowBuf>(&self, Rows: &'buf [R]) {
+        set_row_array(&self.0.bound_params, Rows);
+    }
+
+    /// Sets the number of parameter sets the statement is executed with together with the
+    /// buffers into which the number of processed sets and the status of every set are written
+    /// and from which the driver reads whether to ignore a set. These correspond to
+    /// SQL_ATTR_PARAMSET_SIZE, SQL_ATTR_PARAMS_PROCESSED_PTR, SQL_ATTR_PARAM_STATUS_PTR and
+    /// SQL_ATTR_PARAM_OPERATION_PTR statement attributes which are always set together so that
+    /// the driver never reads or writes past the end of bound buffers.
+    ///
+    /// For complete documentation on SQLSetStmtAttrA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlsetstmtattr-function).
+    ///
+    /// # Panics
+    /// If ParamsetSize is zero or if any buffer bound to a parameter, ParamStatusPtr or
+    /// ParamOperationPtr holds fewer rows than ParamsetSize.
     ///
     /// # Returns
     /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    pub fn SQLGetFunctions(
+    pub fn bind_paramset(
         &self,
-        FunctionId: FunctionId,
-        SupportedPtr: &mut impl AsMutPtr<SQLUSMALLINT>,
+        ParamsetSize: SQLULEN,
+        ParamsProcessedPtr: Option<&'buf UnsafeCell<SQLULEN>>,
+        ParamStatusPtr: Option<&'buf [UnsafeCell<ParamStatus>]>,
+        ParamOperationPtr: Option<&'buf [UnsafeCell<ParamOperation>]>,
     ) -> SQLRETURN {
-        unsafe {
-            ffi::SQLGetFunctions(
-                self.as_SQLHANDLE(),
-                FunctionId as SQLUSMALLINT,
-                SupportedPtr.as_mut_ptr(),
+        assert_ne!(ParamsetSize, 0, "Paramset size must be positive");
+        self.0.bound_params.check_array_size(ParamsetSize);
+        let rows = ParamStatusPtr
+            .map_or(SQLULEN::MAX, <[_]>::len)
+            .min(ParamOperationPtr.map_or(SQLULEN::MAX, <[_]>::len));
+        assert!(
+            rows >= ParamsetSize,
+            "Parameter status or operation array of {} rows is shorter than the paramset size {}",
+            rows,
+            ParamsetSize
+        );
+
+        set_array_attrs(
+            self.as_SQLHANDLE(),
+            &self.0.bound_params,
+            SQL_ATTR_PARAMSET_SIZE::IDENTIFIER,
+            ParamsetSize,
+            [
+                (
+                    SQL_ATTR_PARAMS_PROCESSED_PTR::IDENTIFIER,
+                    ParamsProcessedPtr.map_or_else(ptr::null_mut, |ParamsProcessedPtr| {
+                        ParamsProcessedPtr.get().cast()
+                    }),
+                ),
+                (
+                    SQL_ATTR_PARAM_STATUS_PTR::IDENTIFIER,
+                    ParamStatusPtr.map_or_else(ptr::null_mut, |ParamStatusPtr| {
+                        UnsafeCell::raw_get(ParamStatusPtr.as_ptr()).cast()
+                    }),
+                ),
+                (
+                    SQL_ATTR_PARAM_OPERATION_PTR::IDENTIFIER,
+                    ParamOperationPtr.map_or_else(ptr::null_mut, |ParamOperationPtr| {
+                        UnsafeCell::raw_get(ParamOperationPtr.as_ptr()).cast()
+                    }),
+                ),
+            ],
+        )
+    }
+
+    /// Binds a heap allocated buffer to a column in the result set. Statement takes ownership of
+    /// the buffer and its length/indicator which are released when the column is unbound. Values
+    /// fetched into the buffer can be read through [`SQLHSTMT::owned_cols`].
+    ///
+    /// For complete documentation on SQLBindCol, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlbindcol-function).
+    ///
+    /// While the statement owns buffers bound to its implicitly allocated ARD, the ARD can't be
+    /// retrieved with SQLGetStmtAttr.
+    ///
+    /// # Panics
+    /// If an explicitly allocated ARD is associated with the statement. Such descriptor could
+    /// keep pointing to the buffer after the statement released it.
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    #[cfg(feature = "std")]
+    pub fn bind_col_owned<TT: Ident<Type = SQLSMALLINT>, B: OwnedBuf + ?Sized>(
+        &mut self,
+        ColumnNumber: SQLUSMALLINT,
+        TargetType: TT,
+        TargetValue: Box<B>,
+    ) -> SQLRETURN
+    where
+        B::Bound: DeferredBuf<RefSQLHDESC<'conn, AppDesc<'buf>, V>, TT, V>,
+    {
+        assert!(
+            !self.0.bound_cols.explicit_desc(),
+            "Owned buffer bound while an explicitly allocated ARD is associated with the statement"
+        );
+
+        let TargetValue =
+            unsafe { Box::from_raw(Box::into_raw(TargetValue) as *mut UnsafeCell<B>) };
+        let StrLen_or_Ind = Box::new(UnsafeCell::new(StrLenOrInd(0)));
+
+        // SAFETY: Heap allocations are not moved and are kept alive by
+        // the statement for as long as they are bound to the column
+        let sql_return = unsafe {
+            self.SQLBindCol(
+                ColumnNumber,
+                TargetType,
+                Some(&*(B::as_bound(&TargetValue) as *const B::Bound)),
+                Some(&*(&*StrLen_or_Ind as *const UnsafeCell<StrLenOrInd>)),
             )
+        };
+
+        if SQL_SUCCEEDED(sql_return) {
+            self.0
+                .bound_cols
+                .own(ColumnNumber, OwnedBinding::new(TargetValue, StrLen_or_Ind));
         }
+
+        sql_return
     }
 
-    /// Returns general information about the driver and data source associated with a connection.
+    /// Binds a heap allocated buffer to a parameter marker in an SQL statement. Statement takes
+    /// ownership of the buffer and its length/indicator which are released when parameters are
+    /// reset. Buffer can be modified between executions through [`SQLHSTMT::owned_param_mut`].
     ///
-    /// For complete documentation on SQLGetInfoA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlgetinfo-function).
+    /// For complete documentation on SQLBindParameter, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlbindparameter-function).
+    ///
+    /// While the statement owns buffers bound to its implicitly allocated APD, the APD can't be
+    /// retrieved with SQLGetStmtAttr.
+    ///
+    /// # Panics
+    /// If an explicitly allocated APD is associated with the statement. Such descriptor could
+    /// keep pointing to the buffer after the statement released it.
     ///
     /// # Returns
     /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    pub fn SQLGetInfoA<
-        A: Ident<Type = SQLUSMALLINT>,
-        T: InfoType<A, V> + AttrGet<A> + Ansi + ?Sized,
-    >(
-        // TODO: SQL_ODBC_VER can be called on connection that is not open
-        &self,
-        #[expect(unused_variables)] InfoType: A,
-        InfoValuePtr: Option<&mut T>,
-        StringLengthPtr: Option<&mut MaybeUninit<T::StrLen>>,
+    #[cfg(feature = "std")]
+    #[expect(clippy::too_many_arguments)]
+    pub fn bind_param_owned<TT: Ident<Type = SQLSMALLINT>, ST: SqlType<V>, B: OwnedBuf + ?Sized>(
+        &mut self,
+        ParameterNumber: SQLUSMALLINT,
+        InputOutputType: IOType,
+        ValueType: TT,
+        ParameterType: ST,
+        ColumnSize: SQLULEN,
+        DecimalDigits: SQLSMALLINT,
+        ParameterValue: Box<B>,
+        StrLen_or_Ind: StrLenOrInd,
     ) -> SQLRETURN
     where
-        MaybeUninit<T::StrLen>: StrLen<SQLSMALLINT>,
+        B::Bound: DeferredBuf<RefSQLHDESC<'conn, AppDesc<'buf>, V>, TT, V>,
     {
-        let InfoValuePtr = InfoValuePtr.map_or((ptr::null_mut(), 0), |InfoValuePtr| {
-            (InfoValuePtr.as_mut_SQLPOINTER(), InfoValuePtr.len())
-        });
+        assert!(
+            !self.0.bound_params.explicit_desc(),
+            "Owned buffer bound while an explicitly allocated APD is associated with the statement"
+        );
+
+        let ParameterValue =
+            unsafe { Box::from_raw(Box::into_raw(ParameterValue) as *mut UnsafeCell<B>) };
+        let StrLen_or_Ind = Box::new(UnsafeCell::new(StrLen_or_Ind));
+
+        // SAFETY: Heap allocations are not moved and are kept alive by
+        // the statement for as long as they are bound to the parameter
+        let sql_return = unsafe {
+            self.SQLBindParameter(
+                ParameterNumber,
+                InputOutputType,
+                ValueType,
+                ParameterType,
+                ColumnSize,
+                DecimalDigits,
+                Some(&*(B::as_bound(&ParameterValue) as *const B::Bound)),
+                Some(&*(&*StrLen_or_Ind as *const UnsafeCell<StrLenOrInd>)),
+            )
+        };
+
+        if SQL_SUCCEEDED(sql_return) {
+            self.0.bound_params.own(
+                ParameterNumber,
+                OwnedBinding::new(ParameterValue, StrLen_or_Ind),
+            );
+        }
+
+        sql_return
+    }
+
+    /// Binds an input parameter whose data is sent in parts at statement execution time with
+    /// [`SQLHSTMT::put_data`]. Executing the statement returns SQL_NEED_DATA until the data of
+    /// all such parameters is sent. Data is sent as character or binary data, i.e. ValueType
+    /// is either SQL_C_CHAR or SQL_C_BINARY. NeedLongDataLen should be `true` if the data
+    /// source returns "Y" for SQL_NEED_LONG_DATA_LEN with SQLGetInfo.
+    ///
+    /// For complete documentation on sending long data, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/develop-app/sending-long-data).
+    ///
+    /// # Panics
+    /// If parameters are bound as arrays or if NeedLongDataLen is `true` and the length of the
+    /// data of a SQL_LONGVARCHAR, SQL_WLONGVARCHAR or SQL_LONGVARBINARY parameter is not given
+    /// with [`DataAtExec::with_len`].
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    #[expect(clippy::too_many_arguments)]
+    pub fn bind_param_at_exec<TT: Ident<Type = SQLSMALLINT>, ST: SqlType<V>>(
+        &self,
+        ParameterNumber: SQLUSMALLINT,
+        #[expect(unused_variables)] ValueType: TT,
+        ParameterType: ST,
+        ColumnSize: SQLULEN,
+        DecimalDigits: SQLSMALLINT,
+        StrLen_or_IndPtr: &'buf DataAtExec,
+        NeedLongDataLen: bool,
+    ) -> SQLRETURN
+    where
+        OdbcStr<SQLCHAR>: CData<TT, V>,
+    {
+        let ParameterType = ParameterType.identifier();
+        check_long_data_len(ParameterType, StrLen_or_IndPtr, NeedLongDataLen);
+
+        bind_param_token(
+            self.as_SQLHANDLE(),
+            &self.0.bound_params,
+            ParameterNumber,
+            SQL_PARAM_INPUT,
+            TT::IDENTIFIER,
+            ParameterType,
+            ColumnSize,
+            DecimalDigits,
+            StrLen_or_IndPtr,
+        )
+    }
+
+    /// Closes a cursor that has been opened on a statement and discards pending results.
+    ///
+    /// For complete documentation on SQLCloseCursor, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlclosecursor-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub fn SQLCloseCursor(
+        self,
+    ) -> (
+        Result<SQLHSTMT<'conn, 'desc, 'buf, S::Closed, V>, Self>,
+        SQLRETURN,
+    )
+    where
+        S: CursorState,
+    {
+        let sql_return = self.0.SQLCloseCursor();
+
+        if SQL_SUCCEEDED(sql_return) {
+            (Ok(self.transition()), sql_return)
+        } else {
+            (Err(self), sql_return)
+        }
+    }
+
+    /// Closes the cursor of the statement, if any, and discards pending results by calling
+    /// SQLFreeStmt with SQL_CLOSE. Unlike [`SQLHSTMT::SQLCloseCursor`] it doesn't fail if no
+    /// cursor is open.
+    ///
+    /// For complete documentation on SQLFreeStmt, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlfreestmt-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub fn close_cursor(
+        self,
+    ) -> (
+        Result<SQLHSTMT<'conn, 'desc, 'buf, S::Closed, V>, Self>,
+        SQLRETURN,
+    )
+    where
+        S: CursorState,
+    {
+        let sql_return = unsafe {
+            ffi::SQLFreeStmt(
+                self.as_SQLHANDLE(),
+                FreeStmtOption::SQL_CLOSE as SQLUSMALLINT,
+            )
+        };
+
+        if SQL_SUCCEEDED(sql_return) {
+            (Ok(self.transition()), sql_return)
+        } else {
+            (Err(self), sql_return)
+        }
+    }
+
+    /// Determines whether more results are available on a statement containing **SELECT**, **UPDATE**, **INSERT**, or **DELETE** statements and, if so, initializes processing for those results.
+    ///
+    /// For complete documentation on SQLMoreResults, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlmoreresults-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_NO_DATA, SQL_ERROR, SQL_INVALID_HANDLE, OR SQL_PARAM_DATA_AVAILABLE.
+    #[inline]
+    // TODO: Maybe this fn should be unsafe
+    pub fn SQLMoreResults(
+        self,
+    ) -> (
+        MoreResultsResult<'conn, 'desc, 'buf, S::Closed, Self, V>,
+        SQLRETURN,
+    )
+    where
+        S: CursorState,
+    {
+        let sql_return = self.0.SQLMoreResults();
+
+        if SQL_SUCCEEDED(sql_return) || sql_return == SQL_PARAM_DATA_AVAILABLE {
+            (Ok(self.transition()), sql_return)
+        } else if sql_return == SQL_NO_DATA {
+            (Err(Ok(self.transition())), sql_return)
+        } else {
+            (Err(Err(self)), sql_return)
+        }
+    }
+
+    /// Returns a list of columns and associated privileges for the specified table. The driver returns the information as a result set on the specified `self`.
+    ///
+    /// For complete documentation on SQLColumnPrivilegesA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlcolumnprivileges-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub fn SQLColumnPrivilegesA(
+        self,
+        CatalogName: &OdbcStr<SQLCHAR>,
+        SchemaName: &OdbcStr<SQLCHAR>,
+        TableName: &OdbcStr<SQLCHAR>,
+        ColumnName: &OdbcStr<SQLCHAR>,
+    ) -> (
+        Result<SQLHSTMT<'conn, 'desc, 'buf, S4<S1>, V>, Self>,
+        SQLRETURN,
+    )
+    where
+        Self: Prepare,
+    {
+        let sql_return =
+            self.0
+                .SQLColumnPrivilegesA(CatalogName, SchemaName, TableName, ColumnName);
+
+        if SQL_SUCCEEDED(sql_return) {
+            (Ok(self.transition()), sql_return)
+        } else {
+            (Err(self), sql_return)
+        }
+    }
+
+    /// Returns a list of columns and associated privileges for the specified table. The driver returns the information as a result set on the specified `self`.
+    ///
+    /// For complete documentation on SQLColumnPrivilegesW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlcolumnprivileges-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub fn SQLColumnPrivilegesW(
+        self,
+        CatalogName: &OdbcStr<SQLWCHAR>,
+        SchemaName: &OdbcStr<SQLWCHAR>,
+        TableName: &OdbcStr<SQLWCHAR>,
+        ColumnName: &OdbcStr<SQLWCHAR>,
+    ) -> (
+        Result<SQLHSTMT<'conn, 'desc, 'buf, S4<S1>, V>, Self>,
+        SQLRETURN,
+    )
+    where
+        Self: Prepare,
+    {
+        let sql_return =
+            self.0
+                .SQLColumnPrivilegesW(CatalogName, SchemaName, TableName, ColumnName);
+
+        if SQL_SUCCEEDED(sql_return) {
+            (Ok(self.transition()), sql_return)
+        } else {
+            (Err(self), sql_return)
+        }
+    }
+
+    /// Returns the list of column names in specified tables. The driver returns this information as a result set on the specified `self`.
+    ///
+    /// For complete documentation on SQLColumnsA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlcolumns-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub fn SQLColumnsA(
+        self,
+        CatalogName: &OdbcStr<SQLCHAR>,
+        SchemaName: &OdbcStr<SQLCHAR>,
+        TableName: &OdbcStr<SQLCHAR>,
+        ColumnName: &OdbcStr<SQLCHAR>,
+    ) -> (
+        Result<SQLHSTMT<'conn, 'desc, 'buf, S4<S1>, V>, Self>,
+        SQLRETURN,
+    )
+    where
+        Self: Prepare,
+    {
+        let sql_return = self
+            .0
+            .SQLColumnsA(CatalogName, SchemaName, TableName, ColumnName);
+
+        if SQL_SUCCEEDED(sql_return) {
+            (Ok(self.transition()), sql_return)
+        } else {
+            (Err(self), sql_return)
+        }
+    }
+
+    /// Returns the list of column names in specified tables. The driver returns this information as a result set on the specified `self`.
+    ///
+    /// For complete documentation on SQLColumnsW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlcolumns-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub fn SQLColumnsW(
+        self,
+        CatalogName: &OdbcStr<SQLWCHAR>,
+        SchemaName: &OdbcStr<SQLWCHAR>,
+        TableName: &OdbcStr<SQLWCHAR>,
+        ColumnName: &OdbcStr<SQLWCHAR>,
+    ) -> (
+        Result<SQLHSTMT<'conn, 'desc, 'buf, S4<S1>, V>, Self>,
+        SQLRETURN,
+    )
+    where
+        Self: Prepare,
+    {
+        let sql_return = self
+            .0
+            .SQLColumnsW(CatalogName, SchemaName, TableName, ColumnName);
+
+        if SQL_SUCCEEDED(sql_return) {
+            (Ok(self.transition()), sql_return)
+        } else {
+            (Err(self), sql_return)
+        }
+    }
+
+    /// Can return:
+    ///
+    /// * A list of foreign keys in the specified table (columns in the specified table that refer to primary keys in other tables).
+    /// * A list of foreign keys in other tables that refer to the primary key in the specified table.
+    ///
+    /// The driver returns each list as a result set on the specified statement.
+    ///
+    /// For complete documentation on SQLForeignKeysA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlforeignkeys-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub fn SQLForeignKeysA(
+        self,
+        PKCatalogName: &OdbcStr<SQLCHAR>,
+        PKSchemaName: &OdbcStr<SQLCHAR>,
+        PKTableName: &OdbcStr<SQLCHAR>,
+        FKCatalogName: &OdbcStr<SQLCHAR>,
+        FKSchemaName: &OdbcStr<SQLCHAR>,
+        FKTableName: &OdbcStr<SQLCHAR>,
+    ) -> (
+        Result<SQLHSTMT<'conn, 'desc, 'buf, S4<S1>, V>, Self>,
+        SQLRETURN,
+    )
+    where
+        Self: Prepare,
+    {
+        let sql_return = self.0.SQLForeignKeysA(
+            PKCatalogName,
+            PKSchemaName,
+            PKTableName,
+            FKCatalogName,
+            FKSchemaName,
+            FKTableName,
+        );
+
+        if SQL_SUCCEEDED(sql_return) {
+            (Ok(self.transition()), sql_return)
+        } else {
+            (Err(self), sql_return)
+        }
+    }
+
+    /// Can return:
+    ///
+    /// * A list of foreign keys in the specified table (columns in the specified table that refer to primary keys in other tables).
+    /// * A list of foreign keys in other tables that refer to the primary key in the specified table.
+    ///
+    /// The driver returns each list as a result set on the specified statement.
+    ///
+    /// For complete documentation on SQLForeignKeysW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlforeignkeys-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub fn SQLForeignKeysW(
+        self,
+        PKCatalogName: &OdbcStr<SQLWCHAR>,
+        PKSchemaName: &OdbcStr<SQLWCHAR>,
+        PKTableName: &OdbcStr<SQLWCHAR>,
+        FKCatalogName: &OdbcStr<SQLWCHAR>,
+        FKSchemaName: &OdbcStr<SQLWCHAR>,
+        FKTableName: &OdbcStr<SQLWCHAR>,
+    ) -> (
+        Result<SQLHSTMT<'conn, 'desc, 'buf, S4<S1>, V>, Self>,
+        SQLRETURN,
+    )
+    where
+        Self: Prepare,
+    {
+        let sql_return = self.0.SQLForeignKeysW(
+            PKCatalogName,
+            PKSchemaName,
+            PKTableName,
+            FKCatalogName,
+            FKSchemaName,
+            FKTableName,
+        );
+
+        if SQL_SUCCEEDED(sql_return) {
+            (Ok(self.transition()), sql_return)
+        } else {
+            (Err(self), sql_return)
+        }
+    }
+
+    /// Returns information about data types supported by the data source. The driver returns the information in the form of an SQL result set. The data types are intended for use in Data Definition Language (DDL) statements.
+    ///
+    /// For complete documentation on SQLGetTypeInfoA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlgettypeinfo-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub fn SQLGetTypeInfoA<ST: SqlType<V>>(
+        self,
+        DataType: ST,
+    ) -> (
+        Result<SQLHSTMT<'conn, 'desc, 'buf, S4<S1>, V>, Self>,
+        SQLRETURN,
+    )
+    where
+        Self: Prepare,
+    {
+        let sql_return = self.0.SQLGetTypeInfoA(DataType);
+
+        if SQL_SUCCEEDED(sql_return) {
+            (Ok(self.transition()), sql_return)
+        } else {
+            (Err(self), sql_return)
+        }
+    }
+
+    /// Returns information about data types supported by the data source. The driver returns the information in the form of an SQL result set. The data types are intended for use in Data Definition Language (DDL) statements.
+    ///
+    /// For complete documentation on SQLGetTypeInfoW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlgettypeinfo-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub fn SQLGetTypeInfoW<ST: SqlType<V>>(
+        self,
+        DataType: ST,
+    ) -> (
+        Result<SQLHSTMT<'conn, 'desc, 'buf, S4<S1>, V>, Self>,
+        SQLRETURN,
+    )
+    where
+        Self: Prepare,
+    {
+        let sql_return = self.0.SQLGetTypeInfoW(DataType);
+
+        if SQL_SUCCEEDED(sql_return) {
+            (Ok(self.transition()), sql_return)
+        } else {
+            (Err(self), sql_return)
+        }
+    }
+
+    /// Returns the column names that make up the primary key for a table. The driver returns the information as a result set. This function does not support returning primary keys from multiple tables in a single call.
+    ///
+    /// For complete documentation on SQLPrimaryKeysA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlprimarykeys-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub fn SQLPrimaryKeysA(
+        self,
+        CatalogName: &OdbcStr<SQLCHAR>,
+        SchemaName: &OdbcStr<SQLCHAR>,
+        TableName: &OdbcStr<SQLCHAR>,
+    ) -> (
+        Result<SQLHSTMT<'conn, 'desc, 'buf, S4<S1>, V>, Self>,
+        SQLRETURN,
+    )
+    where
+        Self: Prepare,
+    {
+        let sql_return = self.0.SQLPrimaryKeysA(CatalogName, SchemaName, TableName);
+
+        if SQL_SUCCEEDED(sql_return) {
+            (Ok(self.transition()), sql_return)
+        } else {
+            (Err(self), sql_return)
+        }
+    }
+
+    /// Returns the column names that make up the primary key for a table. The driver returns the information as a result set. This function does not support returning primary keys from multiple tables in a single call.
+    ///
+    /// For complete documentation on SQLPrimaryKeysW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlprimarykeys-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub fn SQLPrimaryKeysW(
+        self,
+        CatalogName: &OdbcStr<SQLWCHAR>,
+        SchemaName: &OdbcStr<SQLWCHAR>,
+        TableName: &OdbcStr<SQLWCHAR>,
+    ) -> (
+        Result<SQLHSTMT<'conn, 'desc, 'buf, S4<S1>, V>, Self>,
+        SQLRETURN,
+    )
+    where
+        Self: Prepare,
+    {
+        let sql_return = self.0.SQLPrimaryKeysW(CatalogName, SchemaName, TableName);
+
+        if SQL_SUCCEEDED(sql_return) {
+            (Ok(self.transition()), sql_return)
+        } else {
+            (Err(self), sql_return)
+        }
+    }
+
+    /// Returns the list of input and output parameters, as well as the columns that make up the result set for the specified procedures. The driver returns the information as a result set on the specified statement.
+    ///
+    /// For complete documentation on SQLProcedureColumnsA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlprocedurecolumns-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub fn SQLProcedureColumnsA(
+        self,
+        CatalogName: &OdbcStr<SQLCHAR>,
+        SchemaName: &OdbcStr<SQLCHAR>,
+        ProcName: &OdbcStr<SQLCHAR>,
+        ColumnName: &OdbcStr<SQLCHAR>,
+    ) -> (
+        Result<SQLHSTMT<'conn, 'desc, 'buf, S4<S1>, V>, Self>,
+        SQLRETURN,
+    )
+    where
+        Self: Prepare,
+    {
+        let sql_return = self
+            .0
+            .SQLProcedureColumnsA(CatalogName, SchemaName, ProcName, ColumnName);
+
+        if SQL_SUCCEEDED(sql_return) {
+            (Ok(self.transition()), sql_return)
+        } else {
+            (Err(self), sql_return)
+        }
+    }
+
+    /// Returns the list of input and output parameters, as well as the columns that make up the result set for the specified procedures. The driver returns the information as a result set on the specified statement.
+    ///
+    /// For complete documentation on SQLProcedureColumnsW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlprocedurecolumns-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub fn SQLProcedureColumnsW(
+        self,
+        CatalogName: &OdbcStr<SQLWCHAR>,
+        SchemaName: &OdbcStr<SQLWCHAR>,
+        ProcName: &OdbcStr<SQLWCHAR>,
+        ColumnName: &OdbcStr<SQLWCHAR>,
+    ) -> (
+        Result<SQLHSTMT<'conn, 'desc, 'buf, S4<S1>, V>, Self>,
+        SQLRETURN,
+    )
+    where
+        Self: Prepare,
+    {
+        let sql_return = self
+            .0
+            .SQLProcedureColumnsW(CatalogName, SchemaName, ProcName, ColumnName);
+
+        if SQL_SUCCEEDED(sql_return) {
+            (Ok(self.transition()), sql_return)
+        } else {
+            (Err(self), sql_return)
+        }
+    }
+
+    /// Returns the list of procedure names stored in a specific data source. `Procedure` is a generic term used to describe an `executable object`, or a named entity that can be invoked using input and output parameters. For more information on procedures, see the Procedures.
+    ///
+    /// For complete documentation on SQLProceduresA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlprocedures-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub fn SQLProceduresA(
+        self,
+        CatalogName: &OdbcStr<SQLCHAR>,
+        SchemaName: &OdbcStr<SQLCHAR>,
+        ProcName: &OdbcStr<SQLCHAR>,
+    ) -> (
+        Result<SQLHSTMT<'conn, 'desc, 'buf, S4<S1>, V>, Self>,
+        SQLRETURN,
+    )
+    where
+        Self: Prepare,
+    {
+        let sql_return = self.0.SQLProceduresA(CatalogName, SchemaName, ProcName);
+
+        if SQL_SUCCEEDED(sql_return) {
+            (Ok(self.transition()), sql_return)
+        } else {
+            (Err(self), sql_return)
+        }
+    }
+
+    /// Returns the list of procedure names stored in a specific data source. `Procedure` is a generic term used to describe an `executable object`, or a named entity that can be invoked using input and output parameters. For more information on procedures, see the Procedures.
+    ///
+    /// For complete documentation on SQLProceduresW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlprocedures-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub fn SQLProceduresW(
+        self,
+        CatalogName: &OdbcStr<SQLWCHAR>,
+        SchemaName: &OdbcStr<SQLWCHAR>,
+        ProcName: &OdbcStr<SQLWCHAR>,
+    ) -> (
+        Result<SQLHSTMT<'conn, 'desc, 'buf, S4<S1>, V>, Self>,
+        SQLRETURN,
+    )
+    where
+        Self: Prepare,
+    {
+        let sql_return = self.0.SQLProceduresW(CatalogName, SchemaName, ProcName);
+
+        if SQL_SUCCEEDED(sql_return) {
+            (Ok(self.transition()), sql_return)
+        } else {
+            (Err(self), sql_return)
+        }
+    }
+
+    /// Retrieves the following information about columns within a specified table:
+    ///
+    /// * The optimal set of columns that uniquely identifies a row in the table.
+    /// * Columns that are automatically updated when any value in the row is updated by a transaction.
+    ///
+    /// For complete documentation on SQLSpecialColumnsA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlspecialcolumns-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub fn SQLSpecialColumnsA(
+        self,
+        IdentifierType: IdentifierType,
+        CatalogName: &OdbcStr<SQLCHAR>,
+        SchemaName: &OdbcStr<SQLCHAR>,
+        TableName: &OdbcStr<SQLCHAR>,
+        Scope: Scope,
+        Nullable: NullAllowed,
+    ) -> (
+        Result<SQLHSTMT<'conn, 'desc, 'buf, S4<S1>, V>, Self>,
+        SQLRETURN,
+    )
+    where
+        Self: Prepare,
+    {
+        let sql_return = self.0.SQLSpecialColumnsA(
+            IdentifierType,
+            CatalogName,
+            SchemaName,
+            TableName,
+            Scope,
+            Nullable,
+        );
+
+        if SQL_SUCCEEDED(sql_return) {
+            (Ok(self.transition()), sql_return)
+        } else {
+            (Err(self), sql_return)
+        }
+    }
+
+    /// Retrieves the following information about columns within a specified table:
+    ///
+    /// * The optimal set of columns that uniquely identifies a row in the table.
+    /// * Columns that are automatically updated when any value in the row is updated by a transaction.
+    ///
+    /// For complete documentation on SQLSpecialColumnsW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlspecialcolumns-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub fn SQLSpecialColumnsW(
+        self,
+        IdentifierType: IdentifierType,
+        CatalogName: &OdbcStr<SQLWCHAR>,
+        SchemaName: &OdbcStr<SQLWCHAR>,
+        TableName: &OdbcStr<SQLWCHAR>,
+        Scope: Scope,
+        Nullable: NullAllowed,
+    ) -> (
+        Result<SQLHSTMT<'conn, 'desc, 'buf, S4<S1>, V>, Self>,
+        SQLRETURN,
+    )
+    where
+        Self: Prepare,
+    {
+        let sql_return = self.0.SQLSpecialColumnsW(
+            IdentifierType,
+            CatalogName,
+            SchemaName,
+            TableName,
+            Scope,
+            Nullable,
+        );
+
+        if SQL_SUCCEEDED(sql_return) {
+            (Ok(self.transition()), sql_return)
+        } else {
+            (Err(self), sql_return)
+        }
+    }
+
+    /// Retrieves a list of statistics about a single table and the indexes associated with the table. The driver returns the information as a result set.
+    ///
+    /// For complete documentation on SQLStatisticsA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlstatistics-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub fn SQLStatisticsA(
+        self,
+        CatalogName: &OdbcStr<SQLCHAR>,
+        SchemaName: &OdbcStr<SQLCHAR>,
+        TableName: &OdbcStr<SQLCHAR>,
+        Unique: Unique,
+        Reserved: Reserved,
+    ) -> (
+        Result<SQLHSTMT<'conn, 'desc, 'buf, S4<S1>, V>, Self>,
+        SQLRETURN,
+    )
+    where
+        Self: Prepare,
+    {
+        let sql_return =
+            self.0
+                .SQLStatisticsA(CatalogName, SchemaName, TableName, Unique, Reserved);
+
+        if SQL_SUCCEEDED(sql_return) {
+            (Ok(self.transition()), sql_return)
+        } else {
+            (Err(self), sql_return)
+        }
+    }
+
+    /// Retrieves a list of statistics about a single table and the indexes associated with the table. The driver returns the information as a result set.
+    ///
+    /// For complete documentation on SQLStatisticsW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlstatistics-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub fn SQLStatisticsW(
+        self,
+        CatalogName: &OdbcStr<SQLWCHAR>,
+        SchemaName: &OdbcStr<SQLWCHAR>,
+        TableName: &OdbcStr<SQLWCHAR>,
+        Unique: Unique,
+        Reserved: Reserved,
+    ) -> (
+        Result<SQLHSTMT<'conn, 'desc, 'buf, S4<S1>, V>, Self>,
+        SQLRETURN,
+    )
+    where
+        Self: Prepare,
+    {
+        let sql_return =
+            self.0
+                .SQLStatisticsW(CatalogName, SchemaName, TableName, Unique, Reserved);
+
+        if SQL_SUCCEEDED(sql_return) {
+            (Ok(self.transition()), sql_return)
+        } else {
+            (Err(self), sql_return)
+        }
+    }
+
+    /// Returns a list of tables and the privileges associated with each table. The driver returns the information as a result set on the specified statement.
+    ///
+    /// For complete documentation on SQLTablePrivilegesA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqltableprivileges-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub fn SQLTablePrivilegesA(
+        self,
+        CatalogName: &OdbcStr<SQLCHAR>,
+        SchemaName: &OdbcStr<SQLCHAR>,
+        TableName: &OdbcStr<SQLCHAR>,
+    ) -> (
+        Result<SQLHSTMT<'conn, 'desc, 'buf, S4<S1>, V>, Self>,
+        SQLRETURN,
+    )
+    where
+        Self: Prepare,
+    {
+        let sql_return = self
+            .0
+            .SQLTablePrivilegesA(CatalogName, SchemaName, TableName);
+
+        if SQL_SUCCEEDED(sql_return) {
+            (Ok(self.transition()), sql_return)
+        } else {
+            (Err(self), sql_return)
+        }
+    }
+
+    /// Returns a list of tables and the privileges associated with each table. The driver returns the information as a result set on the specified statement.
+    ///
+    /// For complete documentation on SQLTablePrivilegesW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqltableprivileges-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub fn SQLTablePrivilegesW(
+        self,
+        CatalogName: &OdbcStr<SQLWCHAR>,
+        SchemaName: &OdbcStr<SQLWCHAR>,
+        TableName: &OdbcStr<SQLWCHAR>,
+    ) -> (
+        Result<SQLHSTMT<'conn, 'desc, 'buf, S4<S1>, V>, Self>,
+        SQLRETURN,
+    )
+    where
+        Self: Prepare,
+    {
+        let sql_return = self
+            .0
+            .SQLTablePrivilegesW(CatalogName, SchemaName, TableName);
+
+        if SQL_SUCCEEDED(sql_return) {
+            (Ok(self.transition()), sql_return)
+        } else {
+            (Err(self), sql_return)
+        }
+    }
+
+    /// Returns the list of table, catalog, or schema names, and table types, stored in a specific data source. The driver returns the information as a result set.
+    ///
+    /// For complete documentation on SQLTablesA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqltables-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub fn SQLTablesA(
+        self,
+        CatalogName: &OdbcStr<SQLCHAR>,
+        SchemaName: &OdbcStr<SQLCHAR>,
+        TableName: &OdbcStr<SQLCHAR>,
+        TableType: &OdbcStr<SQLCHAR>,
+    ) -> (
+        Result<SQLHSTMT<'conn, 'desc, 'buf, S4<S1>, V>, Self>,
+        SQLRETURN,
+    )
+    where
+        Self: Prepare,
+    {
+        let sql_return = self
+            .0
+            .SQLTablesA(CatalogName, SchemaName, TableName, TableType);
+
+        if SQL_SUCCEEDED(sql_return) {
+            (Ok(self.transition()), sql_return)
+        } else {
+            (Err(self), sql_return)
+        }
+    }
+
+    /// Returns the list of table, catalog, or schema names, and table types, stored in a specific data source. The driver returns the information as a result set.
+    ///
+    /// For complete documentation on SQLTablesW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqltables-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub fn SQLTablesW(
+        self,
+        CatalogName: &OdbcStr<SQLWCHAR>,
+        SchemaName: &OdbcStr<SQLWCHAR>,
+        TableName: &OdbcStr<SQLWCHAR>,
+        TableType: &OdbcStr<SQLWCHAR>,
+    ) -> (
+        Result<SQLHSTMT<'conn, 'desc, 'buf, S4<S1>, V>, Self>,
+        SQLRETURN,
+    )
+    where
+        Self: Prepare,
+    {
+        let sql_return = self
+            .0
+            .SQLTablesW(CatalogName, SchemaName, TableName, TableType);
+
+        if SQL_SUCCEEDED(sql_return) {
+            (Ok(self.transition()), sql_return)
+        } else {
+            (Err(self), sql_return)
+        }
+    }
+}
+
+#[expect(non_snake_case)]
+impl<'conn, 'desc, 'buf, P: ClosedState, V: OdbcVersion> SQLHSTMT<'conn, 'desc, 'buf, S4<P>, V> {
+    /// Fetches the next rowset of data from the result set and returns data for all bound columns.
+    ///
+    /// For complete documentation on SQLFetch, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlfetch-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NO_DATA, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub fn SQLFetch(
+        self,
+    ) -> (
+        Result<SQLHSTMT<'conn, 'desc, 'buf, S6<P>, V>, Self>,
+        SQLRETURN,
+    ) {
+        let sql_return = unsafe { ffi::SQLFetch(self.as_SQLHANDLE()) };
+
+        if SQL_SUCCEEDED(sql_return) || sql_return == SQL_NO_DATA {
+            (Ok(self.transition()), sql_return)
+        } else {
+            (Err(self), sql_return)
+        }
+    }
+
+    /// Fetches the specified rowset of data from the result set and returns data for all bound columns. Rowsets can be specified at an absolute or relative position or by bookmark.
+    ///
+    /// For complete documentation on SQLFetchScroll, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlfetchscroll-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NO_DATA, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub fn SQLFetchScroll(
+        self,
+        FetchOrientation: SQLSMALLINT,
+        FetchOffset: SQLLEN,
+    ) -> (
+        Result<SQLHSTMT<'conn, 'desc, 'buf, S6<P>, V>, Self>,
+        SQLRETURN,
+    ) {
+        let sql_return =
+            unsafe { ffi::SQLFetchScroll(self.as_SQLHANDLE(), FetchOrientation, FetchOffset) };
+
+        if SQL_SUCCEEDED(sql_return) || sql_return == SQL_NO_DATA {
+            (Ok(self.transition()), sql_return)
+        } else {
+            (Err(self), sql_return)
+        }
+    }
+
+    /// Collects the number of rows affected by a statement executed with an array of
+    /// parameters bound with [`SQLHSTMT::bind_paramset`] and closes the statement. Drivers
+    /// which report SQL_PARC_BATCH for SQL_PARAM_ARRAY_ROW_COUNTS produce a row count for every
+    /// set of parameters which are retrieved with SQLRowCount and SQLMoreResults into the
+    /// consecutive elements of RowCounts. Drivers which report SQL_PARC_NO_BATCH produce a
+    /// single row count for all sets of parameters which is written into the first element.
+    /// Row counts which don't fit into RowCounts are skipped. If SQLMoreResults returns
+    /// SQL_STILL_EXECUTING, the statement is returned with the row counts collected so far and
+    /// the remaining ones can be retrieved with SQLMoreResults and SQLRowCount.
+    ///
+    /// For complete documentation on SQLRowCount, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlrowcount-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub fn param_row_counts(
+        self,
+        RowCounts: &mut [SQLLEN],
+    ) -> (Result<SQLHSTMT<'conn, 'desc, 'buf, P, V>, Self>, SQLRETURN) {
+        let mut RowCounts = RowCounts.iter_mut();
+
+        loop {
+            let mut RowCount = 0;
+
+            let sql_return = self.SQLRowCount(&mut RowCount);
+            if !SQL_SUCCEEDED(sql_return) {
+                return (Err(self), sql_return);
+            }
+            if let Some(RowCountPtr) = RowCounts.next() {
+                *RowCountPtr = RowCount;
+            }
+
+            let more_results = self.0.SQLMoreResults();
+            if more_results == SQL_NO_DATA {
+                return (Ok(self.transition()), sql_return);
+            } else if !SQL_SUCCEEDED(more_results) {
+                return (Err(self), more_results);
+            }
+        }
+    }
+}
+
+#[expect(non_snake_case)]
+impl<'desc, 'buf, P: ClosedState, V: OdbcVersion> SQLHSTMT<'_, 'desc, 'buf, S6<P>, V> {
+    /// Fetches the next rowset of data from the result set and returns data for all bound columns.
+    ///
+    /// For complete documentation on SQLFetch, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlfetch-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NO_DATA, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub fn SQLFetch(&self) -> SQLRETURN {
+        unsafe { ffi::SQLFetch(self.as_SQLHANDLE()) }
+    }
+
+    /// Fetches the specified rowset of data from the result set and returns data for all bound columns. Rowsets can be specified at an absolute or relative position or by bookmark.
+    ///
+    /// For complete documentation on SQLFetchScroll, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlfetchscroll-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NO_DATA, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub fn SQLFetchScroll(&self, FetchOrientation: SQLSMALLINT, FetchOffset: SQLLEN) -> SQLRETURN {
+        unsafe { ffi::SQLFetchScroll(self.as_SQLHANDLE(), FetchOrientation, FetchOffset) }
+    }
+
+    /// Retrieves data for a single column in the result set or for a single parameter after **SQLParamData** returns SQL_PARAM_DATA_AVAILABLE. It can be called multiple times to retrieve variable-length data in parts.
+    ///
+    /// For complete documentation on SQLGetData, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlgetdata-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NO_DATA, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    // TODO: This function must be unsafe if SQL_ARD_TYPE and SQL_APD_TYPE are allowed to be used
+    pub fn SQLGetData<TT: Ident<Type = SQLSMALLINT>, B: CData<TT, V> + AsMutSQLPOINTER + ?Sized>(
+        &self,
+        Col_or_Param_Num: SQLUSMALLINT,
+        TargetType: TT,
+        TargetValuePtr: &mut B,
+        StrLen_or_IndPtr: Option<&mut MaybeUninit<StrLenOrInd>>,
+    ) -> SQLRETURN
+    where
+        MaybeUninit<StrLenOrInd>: StrLen<SQLLEN>,
+    {
+        self.0.SQLGetData(
+            Col_or_Param_Num,
+            TargetType,
+            TargetValuePtr,
+            StrLen_or_IndPtr,
+        )
+    }
+
+    /// Returns a reader of long data of the columns in the current row which retrieves it in
+    /// parts with SQLGetData. Columns can only be read in the order permitted by
+    /// `GetdataExtensions` which should be the value of SQL_GETDATA_EXTENSIONS returned by
+    /// SQLGetInfo. Statement is mutably borrowed so that the next row can't be fetched while
+    /// columns of the current row are being read.
+    ///
+    /// For complete documentation on SQLGetData, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlgetdata-function).
+    #[inline]
+    #[cfg(feature = "std")]
+    pub fn row_reader(&mut self, GetdataExtensions: GetdataExtensions) -> RowReader<'_> {
+        RowReader::new(self.as_SQLHANDLE(), &self.0.bound_cols, GetdataExtensions)
+    }
+
+    /// Sets the cursor position in a rowset and allows an application to refresh data in the rowset or to update or delete data in the result set.
+    ///
+    /// For complete documentation on SQLSetPos, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlsetpos-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NEED_DATA, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub fn SQLSetPos(
+        &self,
+        RowNumber: SQLSETPOSIROW,
+        Operation: Operation,
+        LockType: LockType,
+    ) -> SQLRETURN {
+        unsafe {
+            ffi::SQLSetPos(
+                self.as_SQLHANDLE(),
+                RowNumber,
+                Operation as SQLUSMALLINT,
+                LockType as SQLUSMALLINT,
+            )
+        }
+    }
+}
+
+#[expect(non_snake_case)]
+impl<'conn, 'desc, 'buf, P: ClosedState, V: OdbcVersion> SQLHSTMT<'conn, 'desc, 'buf, S8<P>, V> {
+    /// Used together with **SQLPutData** to supply parameter data at statement execution time, and with **SQLGetData** to retrieve streamed output parameter data.
+    ///
+    /// For complete documentation on SQLParamData, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlparamdata-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NEED_DATA, SQL_NO_DATA, SQL_STILL_EXECUTING, SQL_ERROR, SQL_INVALID_HANDLE, or SQL_PARAM_DATA_AVAILABLE.
+    #[inline]
+    pub fn SQLParamData(
+        self,
+        ValuePtrPtr: &mut MaybeUninit<SQLPOINTER>,
+    ) -> (
+        ExecResult<'conn, 'desc, 'buf, P, SQLHSTMT<'conn, 'desc, 'buf, P, V>, V>,
+        SQLRETURN,
+    ) {
+        let sql_return = self.0.SQLParamData(ValuePtrPtr);
+
+        if SQL_SUCCEEDED(sql_return)
+            || sql_return == SQL_NO_DATA
+            || sql_return == SQL_PARAM_DATA_AVAILABLE
+        {
+            (Ok(self.transition()), sql_return)
+        } else if sql_return == SQL_NEED_DATA || sql_return == SQL_STILL_EXECUTING {
+            (Err(Ok(self)), sql_return)
+        } else {
+            (Err(Err(self.transition())), sql_return)
+        }
+    }
+
+    /// Allows an application to send data for a parameter or column to the driver at statement execution time. This function can be used to send character or binary data values in parts to a column with a character, binary, or data source-specific data type (for example, parameters of the SQL_LONGVARBINARY or SQL_LONGVARCHAR types). **SQLPutData** supports binding to a Unicode C data type, even if the underlying driver does not support Unicode data.
+    ///
+    /// For complete documentation on SQLPutData, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlputdata-function).
+    ///
+    /// # Safety
+    /// Driver interprets the data according to the C type the parameter was bound with so
+    /// `TT` must be the ValueType given to SQLBindParameter for the parameter whose data is
+    /// requested.
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    // TODO: Is it unsafe if runtime_checks is used?
+    pub unsafe fn SQLPutData<TT: Ident, B: CData<TT, V> + AsSQLPOINTER + ?Sized>(
+        &self,
+        DataPtr: Option<&B>,
+    ) -> SQLRETURN {
+        unsafe { self.0.SQLPutData(DataPtr) }
+    }
+
+    /// Sends the data of parameters bound with [`SQLHSTMT::bind_param_at_exec`] by answering
+    /// every SQL_NEED_DATA returned by SQLParamData with the data read from the source of the
+    /// requested parameter. Sources are given together with the number of their parameter. If
+    /// the total length of the data was given with [`DataAtExec::with_len`], exactly that many
+    /// bytes are read from the source. If a source fails, the data of the requested parameter
+    /// may have been partially sent and the statement, which still needs data, should be
+    /// canceled with [`SQLHSTMT::cancel`]. If the driver fails, the statement returns to the
+    /// state it was in before it was executed. If the statement is executed asynchronously and
+    /// SQL_STILL_EXECUTING is returned, the statement still needs data and this function is
+    /// called again with the same sources to poll for its completion. The part of the data
+    /// whose sending was interrupted is kept by the statement and sent again.
+    ///
+    /// For complete documentation on sending long data, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/develop-app/sending-long-data).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NO_DATA, SQL_STILL_EXECUTING, SQL_ERROR, SQL_INVALID_HANDLE, or
+    /// SQL_PARAM_DATA_AVAILABLE, or the error of the source which couldn't be read.
+    #[cfg(feature = "std")]
+    pub fn put_data(
+        self,
+        Sources: &mut [(SQLUSMALLINT, &mut dyn std::io::Read)],
+    ) -> (
+        ExecResult<'conn, 'desc, 'buf, P, SQLHSTMT<'conn, 'desc, 'buf, P, V>, V>,
+        std::io::Result<SQLRETURN>,
+    ) {
+        // Data whose sending was interrupted by SQL_STILL_EXECUTING is sent first
+        let mut state = self.0.bound_params.take_put_data();
+
+        loop {
+            if let Some(mut state) = state.take() {
+                match put_param_data(
+                    self.as_SQLHANDLE(),
+                    &self.0.bound_params,
+                    Sources,
+                    &mut state,
+                ) {
+                    Ok(SQL_STILL_EXECUTING) => {
+                        self.0.bound_params.set_put_data(Some(state));
+                        return (Err(Ok(self)), Ok(SQL_STILL_EXECUTING));
+                    }
+                    Ok(sql_return) if SQL_SUCCEEDED(sql_return) => {}
+                    // Driver cancels the execution when SQLPutData fails
+                    Ok(SQL_ERROR) => return (Err(Err(self.transition())), Ok(SQL_ERROR)),
+                    result => return (Err(Ok(self)), result),
+                }
+            }
+
+            let mut ValuePtr = MaybeUninit::uninit();
+
+            let sql_return = self.0.SQLParamData(&mut ValuePtr);
+            if sql_return == SQL_NEED_DATA {
+                let ValuePtr = unsafe { ValuePtr.assume_init() };
+
+                state = Some(PutDataState {
+                    ValuePtr,
+                    buf: std::vec![0; PUT_DATA_CHUNK_LEN],
+                    pending: None,
+                    remaining: None,
+                    sent: false,
+                });
+            } else if SQL_SUCCEEDED(sql_return)
+                || sql_return == SQL_NO_DATA
+                || sql_return == SQL_PARAM_DATA_AVAILABLE
+            {
+                return (Ok(self.transition()), Ok(sql_return));
+            } else if sql_return == SQL_STILL_EXECUTING || sql_return == SQL_INVALID_HANDLE {
+                return (Err(Ok(self)), Ok(sql_return));
+            } else {
+                return (Err(Err(self.transition())), Ok(sql_return));
+            }
+        }
+    }
+
+    /// Cancels sending data at statement execution time and returns the statement to the
+    /// state it was in before it was executed.
+    ///
+    /// For complete documentation on SQLCancel, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlcancel-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub fn cancel(self) -> (Result<SQLHSTMT<'conn, 'desc, 'buf, P, V>, Self>, SQLRETURN) {
+        let sql_return = unsafe { ffi::SQLCancel(self.as_SQLHANDLE()) };
+
+        if SQL_SUCCEEDED(sql_return) {
+            #[cfg(feature = "std")]
+            self.0.bound_params.take_put_data();
+            (Ok(self.transition()), sql_return)
+        } else {
+            (Err(self), sql_return)
+        }
+    }
+}
+
+#[expect(non_snake_case)]
+impl<'buf, S: StmtState, V: OdbcVersion<V3_80 = ()>> SQLHSTMT<'_, '_, 'buf, S, V> {
+    /// Binds a streamed output parameter whose data is retrieved in parts with
+    /// [`SQLHSTMT::next_output_param`] after the statement is executed. Executing the
+    /// statement returns SQL_PARAM_DATA_AVAILABLE when the data of such parameters is
+    /// available. InputOutputType is either SQL_PARAM_OUTPUT_STREAM or
+    /// SQL_PARAM_INPUT_OUTPUT_STREAM. Data of the latter is first sent with
+    /// [`SQLHSTMT::put_data`] like the data of parameters bound with
+    /// [`SQLHSTMT::bind_param_at_exec`] and NeedLongDataLen has the same meaning.
+    ///
+    /// For complete documentation on streamed output parameters, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/develop-app/retrieving-output-parameters-using-sqlgetdata).
+    ///
+    /// # Panics
+    /// If InputOutputType is not a streamed parameter type, if parameters are bound as arrays
+    /// or if the length of long data of a SQL_PARAM_INPUT_OUTPUT_STREAM parameter is required
+    /// but not given.
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    #[expect(clippy::too_many_arguments)]
+    pub fn bind_param_stream<TT: Ident<Type = SQLSMALLINT>, ST: SqlType<V>>(
+        &self,
+        ParameterNumber: SQLUSMALLINT,
+        InputOutputType: IOType,
+        #[expect(unused_variables)] ValueType: TT,
+        ParameterType: ST,
+        ColumnSize: SQLULEN,
+        DecimalDigits: SQLSMALLINT,
+        StrLen_or_IndPtr: &'buf DataAtExec,
+        NeedLongDataLen: bool,
+    ) -> SQLRETURN
+    where
+        OdbcStr<SQLCHAR>: CData<TT, V>,
+    {
+        assert!(
+            InputOutputType == SQL_PARAM_OUTPUT_STREAM
+                || InputOutputType == SQL_PARAM_INPUT_OUTPUT_STREAM,
+            "Parameter of type {:?} is not streamed",
+            InputOutputType
+        );
+        let ParameterType = ParameterType.identifier();
+        if InputOutputType == SQL_PARAM_INPUT_OUTPUT_STREAM {
+            check_long_data_len(ParameterType, StrLen_or_IndPtr, NeedLongDataLen);
+        }
+
+        bind_param_token(
+            self.as_SQLHANDLE(),
+            &self.0.bound_params,
+            ParameterNumber,
+            InputOutputType,
+            TT::IDENTIFIER,
+            ParameterType,
+            ColumnSize,
+            DecimalDigits,
+            StrLen_or_IndPtr,
+        )
+    }
+}
+
+#[expect(non_snake_case)]
+impl<P: ClosedState, V: OdbcVersion<V3_80 = ()>> SQLHSTMT<'_, '_, '_, S4<P>, V> {
+    /// Calls SQLParamData to identify the next streamed output parameter whose data is
+    /// available after executing the statement returned SQL_PARAM_DATA_AVAILABLE. Data of the
+    /// returned parameter is read with [`OutputParam::binary`] or [`OutputParam::text`] which
+    /// retrieve it in parts with SQLGetData. Parameter is `None` once the data of all streamed
+    /// output parameters is available in their buffers or has been retrieved, i.e. when
+    /// SQLParamData returns SQL_SUCCESS. If SQLParamData identifies a parameter which isn't
+    /// bound with [`SQLHSTMT::bind_param_stream`], [`UnknownOutputParam`] is returned instead.
+    ///
+    /// For complete documentation on streamed output parameters, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/develop-app/retrieving-output-parameters-using-sqlgetdata).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NO_DATA, SQL_ERROR, SQL_INVALID_HANDLE, or SQL_PARAM_DATA_AVAILABLE.
+    #[cfg(feature = "std")]
+    pub fn next_output_param(
+        &mut self,
+    ) -> (
+        Result<Option<OutputParam<'_>>, UnknownOutputParam>,
+        SQLRETURN,
+    ) {
+        let mut ValuePtr = MaybeUninit::uninit();
+
+        let sql_return = self.0.SQLParamData(&mut ValuePtr);
+        if sql_return != SQL_PARAM_DATA_AVAILABLE {
+            return (Ok(None), sql_return);
+        }
+
+        let ValuePtr = unsafe { ValuePtr.assume_init() };
+        let Some(ParameterNumber) = self.0.bound_params.find_data(ValuePtr) else {
+            return (Err(UnknownOutputParam(())), sql_return);
+        };
+
+        (
+            Ok(Some(OutputParam::new(self.as_SQLHANDLE(), ParameterNumber))),
+            sql_return,
+        )
+    }
+}
+
+#[expect(non_snake_case)]
+impl<'desc, 'buf, V: OdbcVersion> UnsafeSQLHSTMT<'_, 'desc, 'buf, V> {
+    /// Executes a preparable statement, using the current values of the parameter marker variables if any parameters exist in the statement. **SQLExecDirect** is the fastest way to submit an SQL statement for one-time execution.
+    ///
+    /// For complete documentation on SQLExecDirectA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlexecdirect-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NEED_DATA, SQL_STILL_EXECUTING, SQL_ERROR, SQL_NO_DATA, SQL_INVALID_HANDLE, or SQL_PARAM_DATA_AVAILABLE.
+    #[inline]
+    pub unsafe fn SQLExecDirectA(&self, StatementText: &OdbcStr<SQLCHAR>) -> SQLRETURN {
+        let sql_handle = self.as_SQLHANDLE();
+        let StatementText = StatementText.as_raw_slice();
+        unsafe { ffi::SQLExecDirectA(sql_handle, StatementText.0, StatementText.1) }
+    }
+
+    /// Executes a preparable statement, using the current values of the parameter marker variables if any parameters exist in the statement. **SQLExecDirect** is the fastest way to submit an SQL statement for one-time execution.
+    ///
+    /// For complete documentation on SQLExecDirectW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlexecdirect-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NEED_DATA, SQL_STILL_EXECUTING, SQL_ERROR, SQL_NO_DATA, SQL_INVALID_HANDLE, or SQL_PARAM_DATA_AVAILABLE.
+    #[inline]
+    pub unsafe fn SQLExecDirectW(&self, StatementText: &OdbcStr<SQLWCHAR>) -> SQLRETURN {
+        let sql_handle = self.as_SQLHANDLE();
+        let StatementText = StatementText.as_raw_slice();
+        unsafe { ffi::SQLExecDirectW(sql_handle, StatementText.0, StatementText.1) }
+    }
+
+    /// Executes a prepared statement, using the current values of the parameter marker variables if any parameter markers exist in the statement.
+    ///
+    /// For complete documentation on SQLExecute, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlexecute-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NEED_DATA, SQL_STILL_EXECUTING, SQL_ERROR, SQL_NO_DATA, SQL_INVALID_HANDLE, or SQL_PARAM_DATA_AVAILABLE.
+    #[inline]
+    pub unsafe fn SQLExecute(&self) -> SQLRETURN {
+        let sql_handle = self.as_SQLHANDLE();
+        unsafe { ffi::SQLExecute(sql_handle) }
+    }
+
+    /// Fetches the next rowset of data from the result set and returns data for all bound columns.
+    ///
+    /// For complete documentation on SQLFetch, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlfetch-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NO_DATA, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub unsafe fn SQLFetch(&self) -> SQLRETURN {
+        let sql_handle = self.as_SQLHANDLE();
+        unsafe { ffi::SQLFetch(sql_handle) }
+    }
+
+    /// Fetches the specified rowset of data from the result set and returns data for all bound columns. Rowsets can be specified at an absolute or relative position or by bookmark.
+    ///
+    /// For complete documentation on SQLFetchScroll, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlfetchscroll-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NO_DATA, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub unsafe fn SQLFetchScroll(
+        &self,
+        FetchOrientation: SQLSMALLINT,
+        FetchOffset: SQLLEN,
+    ) -> SQLRETURN {
+        let sql_handle = self.as_SQLHANDLE();
+        unsafe { ffi::SQLFetchScroll(sql_handle, FetchOrientation, FetchOffset) }
+    }
+
+    /// Sets the cursor position in a rowset and allows an application to refresh data in the rowset or to update or delete data in the result set.
+    ///
+    /// For complete documentation on SQLSetPos, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlsetpos-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NEED_DATA, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub unsafe fn SQLSetPos(
+        &self,
+        RowNumber: SQLSETPOSIROW,
+        Operation: Operation,
+        LockType: LockType,
+    ) -> SQLRETURN {
+        let sql_handle = self.as_SQLHANDLE();
+
+        unsafe {
+            ffi::SQLSetPos(
+                sql_handle,
+                RowNumber,
+                Operation as SQLUSMALLINT,
+                LockType as SQLUSMALLINT,
+            )
+        }
+    }
+
+    /// Closes a cursor that has been opened on a statement and discards pending results.
+    ///
+    /// For complete documentation on SQLCloseCursor, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlclosecursor-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub fn SQLCloseCursor(&self) -> SQLRETURN {
+        unsafe { ffi::SQLCloseCursor(self.as_SQLHANDLE()) }
+    }
+
+    /// Returns a list of columns and associated privileges for the specified table. The driver returns the information as a result set on the specified `self`.
+    ///
+    /// For complete documentation on SQLColumnPrivilegesA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlcolumnprivileges-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub fn SQLColumnPrivilegesA(
+        &self,
+        CatalogName: &OdbcStr<SQLCHAR>,
+        SchemaName: &OdbcStr<SQLCHAR>,
+        TableName: &OdbcStr<SQLCHAR>,
+        ColumnName: &OdbcStr<SQLCHAR>,
+    ) -> SQLRETURN {
+        let CatalogName = CatalogName.as_raw_slice();
+        let SchemaName = SchemaName.as_raw_slice();
+        let TableName = TableName.as_raw_slice();
+        let ColumnName = ColumnName.as_raw_slice();
+
+        unsafe {
+            ffi::SQLColumnPrivilegesA(
+                self.as_SQLHANDLE(),
+                CatalogName.0,
+                CatalogName.1,
+                SchemaName.0,
+                SchemaName.1,
+                TableName.0,
+                TableName.1,
+                ColumnName.0,
+                ColumnName.1,
+            )
+        }
+    }
+
+    /// Returns a list of columns and associated privileges for the specified table. The driver returns the information as a result set on the specified `self`.
+    ///
+    /// For complete documentation on SQLColumnPrivilegesW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlcolumnprivileges-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub fn SQLColumnPrivilegesW(
+        &self,
+        CatalogName: &OdbcStr<SQLWCHAR>,
+        SchemaName: &OdbcStr<SQLWCHAR>,
+        TableName: &OdbcStr<SQLWCHAR>,
+        ColumnName: &OdbcStr<SQLWCHAR>,
+    ) -> SQLRETURN {
+        let CatalogName = CatalogName.as_raw_slice();
+        let SchemaName = SchemaName.as_raw_slice();
+        let TableName = TableName.as_raw_slice();
+        let ColumnName = ColumnName.as_raw_slice();
+
+        unsafe {
+            ffi::SQLColumnPrivilegesW(
+                self.as_SQLHANDLE(),
+                CatalogName.0,
+                CatalogName.1,
+                SchemaName.0,
+                SchemaName.1,
+                TableName.0,
+                TableName.1,
+                ColumnName.0,
+                ColumnName.1,
+            )
+        }
+    }
+
+    /// Returns the list of column names in specified tables. The driver returns this information as a result set on the specified `self`.
+    ///
+    /// For complete documentation on SQLColumnsA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlcolumns-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub fn SQLColumnsA(
+        &self,
+        CatalogName: &OdbcStr<SQLCHAR>,
+        SchemaName: &OdbcStr<SQLCHAR>,
+        TableName: &OdbcStr<SQLCHAR>,
+        ColumnName: &OdbcStr<SQLCHAR>,
+    ) -> SQLRETURN {
+        let CatalogName = CatalogName.as_raw_slice();
+        let SchemaName = SchemaName.as_raw_slice();
+        let TableName = TableName.as_raw_slice();
+        let ColumnName = ColumnName.as_raw_slice();
+
+        unsafe {
+            ffi::SQLColumnsA(
+                self.as_SQLHANDLE(),
+                CatalogName.0,
+                CatalogName.1,
+                SchemaName.0,
+                SchemaName.1,
+                TableName.0,
+                TableName.1,
+                ColumnName.0,
+                ColumnName.1,
+            )
+        }
+    }
+
+    /// Returns the list of column names in specified tables. The driver returns this information as a result set on the specified `self`.
+    ///
+    /// For complete documentation on SQLColumnsW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlcolumns-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub fn SQLColumnsW(
+        &self,
+        CatalogName: &OdbcStr<SQLWCHAR>,
+        SchemaName: &OdbcStr<SQLWCHAR>,
+        TableName: &OdbcStr<SQLWCHAR>,
+        ColumnName: &OdbcStr<SQLWCHAR>,
+    ) -> SQLRETURN {
+        let CatalogName = CatalogName.as_raw_slice();
+        let SchemaName = SchemaName.as_raw_slice();
+        let TableName = TableName.as_raw_slice();
+        let ColumnName = ColumnName.as_raw_slice();
+
+        unsafe {
+            ffi::SQLColumnsW(
+                self.as_SQLHANDLE(),
+                CatalogName.0,
+                CatalogName.1,
+                SchemaName.0,
+                SchemaName.1,
+                TableName.0,
+                TableName.1,
+                ColumnName.0,
+                ColumnName.1,
+            )
+        }
+    }
+
+    /// Can return:
+    ///
+    /// * A list of foreign keys in the specified table (columns in the specified table that refer to primary keys in other tables).
+    /// * A list of foreign keys in other tables that refer to the primary key in the specified table.
+    ///
+    /// The driver returns each list as a result set on the specified statement.
+    ///
+    /// For complete documentation on SQLForeignKeysA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlforeignkeys-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub fn SQLForeignKeysA(
+        &self,
+        PKCatalogName: &OdbcStr<SQLCHAR>,
+        PKSchemaName: &OdbcStr<SQLCHAR>,
+        PKTableName: &OdbcStr<SQLCHAR>,
+        FKCatalogName: &OdbcStr<SQLCHAR>,
+        FKSchemaName: &OdbcStr<SQLCHAR>,
+        FKTableName: &OdbcStr<SQLCHAR>,
+    ) -> SQLRETURN {
+        let PKCatalogName = PKCatalogName.as_raw_slice();
+        let PKSchemaName = PKSchemaName.as_raw_slice();
+        let PKTableName = PKTableName.as_raw_slice();
+        let FKCatalogName = FKCatalogName.as_raw_slice();
+        let FKSchemaName = FKSchemaName.as_raw_slice();
+        let FKTableName = FKTableName.as_raw_slice();
+
+        unsafe {
+            ffi::SQLForeignKeysA(
+                self.as_SQLHANDLE(),
+                PKCatalogName.0,
+                PKCatalogName.1,
+                PKSchemaName.0,
+                PKSchemaName.1,
+                PKTableName.0,
+                PKTableName.1,
+                FKCatalogName.0,
+                FKCatalogName.1,
+                FKSchemaName.0,
+                FKSchemaName.1,
+                FKTableName.0,
+                FKTableName.1,
+            )
+        }
+    }
+
+    /// Can return:
+    ///
+    /// * A list of foreign keys in the specified table (columns in the specified table that refer to primary keys in other tables).
+    /// * A list of foreign keys in other tables that refer to the primary key in the specified table.
+    ///
+    /// The driver returns each list as a result set on the specified statement.
+    ///
+    /// For complete documentation on SQLForeignKeysW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlforeignkeys-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub fn SQLForeignKeysW(
+        &self,
+        PKCatalogName: &OdbcStr<SQLWCHAR>,
+        PKSchemaName: &OdbcStr<SQLWCHAR>,
+        PKTableName: &OdbcStr<SQLWCHAR>,
+        FKCatalogName: &OdbcStr<SQLWCHAR>,
+        FKSchemaName: &OdbcStr<SQLWCHAR>,
+        FKTableName: &OdbcStr<SQLWCHAR>,
+    ) -> SQLRETURN {
+        let PKCatalogName = PKCatalogName.as_raw_slice();
+        let PKSchemaName = PKSchemaName.as_raw_slice();
+        let PKTableName = PKTableName.as_raw_slice();
+        let FKCatalogName = FKCatalogName.as_raw_slice();
+        let FKSchemaName = FKSchemaName.as_raw_slice();
+        let FKTableName = FKTableName.as_raw_slice();
+
+        unsafe {
+            ffi::SQLForeignKeysW(
+                self.as_SQLHANDLE(),
+                PKCatalogName.0,
+                PKCatalogName.1,
+                PKSchemaName.0,
+                PKSchemaName.1,
+                PKTableName.0,
+                PKTableName.1,
+                FKCatalogName.0,
+                FKCatalogName.1,
+                FKSchemaName.0,
+                FKSchemaName.1,
+                FKTableName.0,
+                FKTableName.1,
+            )
+        }
+    }
+
+    /// Retrieves data for a single column in the result set or for a single parameter after **SQLParamData** returns SQL_PARAM_DATA_AVAILABLE. It can be called multiple times to retrieve variable-length data in parts.
+    ///
+    /// For complete documentation on SQLGetData, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlgetdata-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NO_DATA, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    // TODO: This function must be unsafe if SQL_ARD_TYPE and SQL_APD_TYPE are allowed to be used
+    pub fn SQLGetData<TT: Ident<Type = SQLSMALLINT>, B: CData<TT, V> + AsMutSQLPOINTER + ?Sized>(
+        &self,
+        Col_or_Param_Num: SQLUSMALLINT,
+        #[expect(unused_variables)] TargetType: TT,
+        TargetValuePtr: &mut B,
+        StrLen_or_IndPtr: Option<&mut MaybeUninit<StrLenOrInd>>,
+    ) -> SQLRETURN
+    where
+        MaybeUninit<StrLenOrInd>: StrLen<SQLLEN>,
+    {
+        unsafe {
+            ffi::SQLGetData(
+                self.as_SQLHANDLE(),
+                Col_or_Param_Num,
+                TT::IDENTIFIER,
+                TargetValuePtr.as_mut_SQLPOINTER(),
+                TargetValuePtr.len(),
+                StrLen_or_IndPtr.map_or_else(ptr::null_mut, StrLen::as_mut_ptr),
+            )
+        }
+    }
+
+    /// Returns information about data types supported by the data source. The driver returns the information in the form of an SQL result set. The data types are intended for use in Data Definition Language (DDL) statements.
+    ///
+    /// For complete documentation on SQLGetTypeInfoA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlgettypeinfo-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub fn SQLGetTypeInfoA<ST: SqlType<V>>(&self, DataType: ST) -> SQLRETURN {
+        unsafe { ffi::SQLGetTypeInfoA(self.as_SQLHANDLE(), DataType.identifier()) }
+    }
+
+    /// Returns information about data types supported by the data source. The driver returns the information in the form of an SQL result set. The data types are intended for use in Data Definition Language (DDL) statements.
+    ///
+    /// For complete documentation on SQLGetTypeInfoW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlgettypeinfo-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub fn SQLGetTypeInfoW<ST: SqlType<V>>(&self, DataType: ST) -> SQLRETURN {
+        unsafe { ffi::SQLGetTypeInfoW(self.as_SQLHANDLE(), DataType.identifier()) }
+    }
+
+    /// Determines whether more results are available on a statement containing **SELECT**, **UPDATE**, **INSERT**, or **DELETE** statements and, if so, initializes processing for those results.
+    ///
+    /// For complete documentation on SQLMoreResults, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlmoreresults-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_NO_DATA, SQL_ERROR, SQL_INVALID_HANDLE, OR SQL_PARAM_DATA_AVAILABLE.
+    #[inline]
+    // TODO: Maybe this fn should be unsafe
+    pub fn SQLMoreResults(&self) -> SQLRETURN {
+        unsafe { ffi::SQLMoreResults(self.as_SQLHANDLE()) }
+    }
+
+    /// Used together with **SQLPutData** to supply parameter data at statement execution time, and with **SQLGetData** to retrieve streamed output parameter data.
+    ///
+    /// For complete documentation on SQLParamData, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlparamdata-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NEED_DATA, SQL_NO_DATA, SQL_STILL_EXECUTING, SQL_ERROR, SQL_INVALID_HANDLE, or SQL_PARAM_DATA_AVAILABLE.
+    #[inline]
+    pub fn SQLParamData(&self, ValuePtrPtr: &mut MaybeUninit<SQLPOINTER>) -> SQLRETURN {
+        unsafe { ffi::SQLParamData(self.as_SQLHANDLE(), ValuePtrPtr.as_mut_ptr()) }
+    }
+
+    /// Prepares an SQL string for execution.
+    ///
+    /// For complete documentation on SQLPrepareA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlprepare-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub fn SQLPrepareA(&self, StatementText: &OdbcStr<SQLCHAR>) -> SQLRETURN {
+        let StatementText = StatementText.as_raw_slice();
+
+        unsafe { ffi::SQLPrepareA(self.as_SQLHANDLE(), StatementText.0, StatementText.1) }
+    }
+
+    /// Prepares an SQL string for execution.
+    ///
+    /// For complete documentation on SQLPrepareW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlprepare-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub fn SQLPrepareW(&self, StatementText: &OdbcStr<SQLWCHAR>) -> SQLRETURN {
+        let StatementText = StatementText.as_raw_slice();
+
+        unsafe { ffi::SQLPrepareW(self.as_SQLHANDLE(), StatementText.0, StatementText.1) }
+    }
+
+    /// Returns the column names that make up the primary key for a table. The driver returns the information as a result set. This function does not support returning primary keys from multiple tables in a single call.
+    ///
+    /// For complete documentation on SQLPrimaryKeysA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlprimarykeys-function).
+    ///
+    /// # Returns
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    #[inline]
+    pub fn SQLPrimaryKeysA(
+        &self,
+        CatalogName: &OdbcStr<SQLCHAR>,
+        SchemaName: &OdbcStr<SQLCHAR>,
+        TableName: &OdbcStr<SQLCHAR>,
+    ) -> SQLRETURN {
+        let CatalogName = CatalogName.as_raw_slice();
+        let SchemaName = SchemaName.as_raw_slice();
+        let TableName = TableName.as_raw_slice();
 
         unsafe {
-            ffi::SQLGetInfoA(
+            ffi::SQLPrimaryKeysA(
                 self.as_SQLHANDLE(),
-                A::IDENTIFIER,
-                InfoValuePtr.0,
-                InfoValuePtr.1,
-                StringLengthPtr.map_or_else(ptr::null_mut, StrLen::as_mut_ptr),
+                CatalogName.0,
+                CatalogName.1,
+                SchemaName.0,
+                SchemaName.1,
+                TableName.0,
+                TableName.1,
             )
         }
     }
 
-    /// Returns general information about the driver and data source associated with a connection.
+    /// Returns the column names that make up the primary key for a table. The driver returns the information as a result set. This function does not support returning primary keys from multiple tables in a single call.
     ///
-    /// For complete documentation on SQLGetInfoW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlgetinfo-function).
+    /// For complete documentation on SQLPrimaryKeysW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlprimarykeys-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    pub fn SQLGetInfoW<
-        A: Ident<Type = SQLUSMALLINT>,
-        T: InfoType<A, V> + AttrGet<A> + Unicode + ?Sized,
-    >(
-        // TODO: SQL_ODBC_VER can be called on connection that is not open
+    pub fn SQLPrimaryKeysW(
         &self,
-        #[expect(unused_variables)] InfoType: A,
-        InfoValuePtr: Option<&mut T>,
-        StringLengthPtr: Option<&mut MaybeUninit<T::StrLen>>,
-    ) -> SQLRETURN
-    where
-        MaybeUninit<T::StrLen>: StrLen<SQLSMALLINT>,
-    {
-        let InfoValuePtr = InfoValuePtr.map_or((ptr::null_mut(), 0), |InfoValuePtr| {
-            (InfoValuePtr.as_mut_SQLPOINTER(), InfoValuePtr.len())
-        });
+        CatalogName: &OdbcStr<SQLWCHAR>,
+        SchemaName: &OdbcStr<SQLWCHAR>,
+        TableName: &OdbcStr<SQLWCHAR>,
+    ) -> SQLRETURN {
+        let CatalogName = CatalogName.as_raw_slice();
+        let SchemaName = SchemaName.as_raw_slice();
+        let TableName = TableName.as_raw_slice();
 
         unsafe {
-            ffi::SQLGetInfoW(
+            ffi::SQLPrimaryKeysW(
                 self.as_SQLHANDLE(),
-                A::IDENTIFIER,
-                InfoValuePtr.0,
-                InfoValuePtr.1,
-                StringLengthPtr.map_or_else(ptr::null_mut, StrLen::as_mut_ptr),
+                CatalogName.0,
+                CatalogName.1,
+                SchemaName.0,
+                SchemaName.1,
+                TableName.0,
+                TableName.1,
             )
         }
     }
 
-    /// Returns the SQL string as modified by the driver. **SQLNativeSql** does not execute the SQL statement.
+    /// Returns the list of input and output parameters, as well as the columns that make up the result set for the specified procedures. The driver returns the information as a result set on the specified statement.
     ///
-    /// For complete documentation on SQLNativeSqlA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlnativesql-function).
+    /// For complete documentation on SQLProcedureColumnsA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlprocedurecolumns-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    pub fn SQLNativeSqlA(
+    pub fn SQLProcedureColumnsA(
         &self,
-        InStatementText: &OdbcStr<SQLCHAR>,
-        OutStatementText: &mut OdbcStr<MaybeUninit<SQLCHAR>>,
-        TextLength2Ptr: &mut impl AsMutPtr<SQLINTEGER>,
+        CatalogName: &OdbcStr<SQLCHAR>,
+        SchemaName: &OdbcStr<SQLCHAR>,
+        ProcName: &OdbcStr<SQLCHAR>,
+        ColumnName: &OdbcStr<SQLCHAR>,
     ) -> SQLRETURN {
-        let InStatementText = InStatementText.as_raw_slice();
-        let OutStatementText = OutStatementText.as_mut_raw_slice();
+        let CatalogName = CatalogName.as_raw_slice();
+        let SchemaName = SchemaName.as_raw_slice();
+        let ProcName = ProcName.as_raw_slice();
+        let ColumnName = ColumnName.as_raw_slice();
 
         unsafe {
-            ffi::SQLNativeSqlA(
+            ffi::SQLProcedureColumnsA(
                 self.as_SQLHANDLE(),
-                InStatementText.0,
-                InStatementText.1,
-                OutStatementText.0,
-                OutStatementText.1,
-                TextLength2Ptr.as_mut_ptr(),
+                CatalogName.0,
+                CatalogName.1,
+                SchemaName.0,
+                SchemaName.1,
+                ProcName.0,
+                ProcName.1,
+                ColumnName.0,
+                ColumnName.1,
             )
         }
     }
 
-    /// Returns the SQL string as modified by the driver. **SQLNativeSql** does not execute the SQL statement.
+    /// Returns the list of input and output parameters, as well as the columns that make up the result set for the specified procedures. The driver returns the information as a result set on the specified statement.
     ///
-    /// For complete documentation on SQLNativeSqlW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlnativesql-function).
+    /// For complete documentation on SQLProcedureColumnsW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlprocedurecolumns-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, or SQL_INVALID_HANDLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    pub fn SQLNativeSqlW(
+    pub fn SQLProcedureColumnsW(
         &self,
-        InStatementText: &OdbcStr<SQLWCHAR>,
-        OutStatementText: &mut OdbcStr<MaybeUninit<SQLWCHAR>>,
-        TextLength2Ptr: &mut impl AsMutPtr<SQLINTEGER>,
+        CatalogName: &OdbcStr<SQLWCHAR>,
+        SchemaName: &OdbcStr<SQLWCHAR>,
+        ProcName: &OdbcStr<SQLWCHAR>,
+        ColumnName: &OdbcStr<SQLWCHAR>,
     ) -> SQLRETURN {
-        let InStatementText = InStatementText.as_raw_slice();
-        let OutStatementText = OutStatementText.as_mut_raw_slice();
+        let CatalogName = CatalogName.as_raw_slice();
+        let SchemaName = SchemaName.as_raw_slice();
+        let ProcName = ProcName.as_raw_slice();
+        let ColumnName = ColumnName.as_raw_slice();
 
         unsafe {
-            ffi::SQLNativeSqlW(
+            ffi::SQLProcedureColumnsW(
                 self.as_SQLHANDLE(),
-                InStatementText.0,
-                InStatementText.1,
-                OutStatementText.0,
-                OutStatementText.1,
-                TextLength2Ptr.as_mut_ptr(),
+                CatalogName.0,
+                CatalogName.1,
+                SchemaName.0,
+                SchemaName.1,
+                ProcName.0,
+                ProcName.1,
+                ColumnName.0,
+                ColumnName.1,
             )
         }
     }
 
-    /// Requests a commit or rollback operation for all active operations on all statements associated with a connection. **SQLEndTran** can also request that a commit or rollback operation be performed for all connections associated with an environment.
+    /// Returns the list of procedure names stored in a specific data source. `Procedure` is a generic term used to describe an `executable object`, or a named entity that can be invoked using input and output parameters. For more information on procedures, see the Procedures.
     ///
-    /// For complete documentation on SQLEndTran, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlendtran-function).
+    /// For complete documentation on SQLProceduresA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlprocedures-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_ERROR, SQL_INVALID_HANDLE, or SQL_STILL_EXECUTING.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    pub fn SQLEndTran(&self, CompletionType: CompletionType) -> SQLRETURN {
-        // Not implemented on SQLHENV so as to to avoid confusion, considering that the same
-        // functionality can be achieved by calling SQLEndTran repeatedly on SQLHDBC handle
+    pub fn SQLProceduresA(
+        &self,
+        CatalogName: &OdbcStr<SQLCHAR>,
+        SchemaName: &OdbcStr<SQLCHAR>,
+        ProcName: &OdbcStr<SQLCHAR>,
+    ) -> SQLRETURN {
+        let CatalogName = CatalogName.as_raw_slice();
+        let SchemaName = SchemaName.as_raw_slice();
+        let ProcName = ProcName.as_raw_slice();
 
         unsafe {
-            ffi::SQLEndTran(
-                <Self as Handle>::Ident::IDENTIFIER,
+            ffi::SQLProceduresA(
                 self.as_SQLHANDLE(),
-                CompletionType as SQLSMALLINT,
+                CatalogName.0,
+                CatalogName.1,
+                SchemaName.0,
+                SchemaName.1,
+                ProcName.0,
+                ProcName.1,
             )
         }
     }
-}
 
-#[expect(non_snake_case)]
-impl<'desc, 'buf, V: OdbcVersion> SQLHSTMT<'_, 'desc, 'buf, V> {
-    /// Executes a preparable statement, using the current values of the parameter marker variables if any parameters exist in the statement. **SQLExecDirect** is the fastest way to submit an SQL statement for one-time execution.
+    /// Returns the list of procedure names stored in a specific data source. `Procedure` is a generic term used to describe an `executable object`, or a named entity that can be invoked using input and output parameters. For more information on procedures, see the Procedures.
     ///
-    /// For complete documentation on SQLExecDirectA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlexecdirect-function).
+    /// For complete documentation on SQLProceduresW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlprocedures-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NEED_DATA, SQL_STILL_EXECUTING, SQL_ERROR, SQL_NO_DATA, SQL_INVALID_HANDLE, or SQL_PARAM_DATA_AVAILABLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    pub fn SQLExecDirectA(&self, StatementText: &OdbcStr<SQLCHAR>) -> SQLRETURN {
-        let StatementText = StatementText.as_raw_slice();
+    pub fn SQLProceduresW(
+        &self,
+        CatalogName: &OdbcStr<SQLWCHAR>,
+        SchemaName: &OdbcStr<SQLWCHAR>,
+        ProcName: &OdbcStr<SQLWCHAR>,
+    ) -> SQLRETURN {
+        let CatalogName = CatalogName.as_raw_slice();
+        let SchemaName = SchemaName.as_raw_slice();
+        let ProcName = ProcName.as_raw_slice();
 
-        unsafe { ffi::SQLExecDirectA(self.as_SQLHANDLE(), StatementText.0, StatementText.1) }
+        unsafe {
+            ffi::SQLProceduresW(
+                self.as_SQLHANDLE(),
+                CatalogName.0,
+                CatalogName.1,
+                SchemaName.0,
+                SchemaName.1,
+                ProcName.0,
+                ProcName.1,
+            )
+        }
     }
 
-    /// Executes a preparable statement, using the current values of the parameter marker variables if any parameters exist in the statement. **SQLExecDirect** is the fastest way to submit an SQL statement for one-time execution.
+    /// Allows an application to send data for a parameter or column to the driver at statement execution time. This function can be used to send character or binary data values in parts to a column with a character, binary, or data source-specific data type (for example, parameters of the SQL_LONGVARBINARY or SQL_LONGVARCHAR types). **SQLPutData** supports binding to a Unicode C data type, even if the underlying driver does not support Unicode data.
     ///
-    /// For complete documentation on SQLExecDirectW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlexecdirect-function).
+    /// For complete documentation on SQLPutData, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlputdata-function).
+    ///
+    /// # Safety
+    /// Driver interprets the data according to the C type the parameter was bound with so
+    /// `TT` must be the ValueType given to SQLBindParameter for the parameter whose data is
+    /// requested.
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NEED_DATA, SQL_STILL_EXECUTING, SQL_ERROR, SQL_NO_DATA, SQL_INVALID_HANDLE, or SQL_PARAM_DATA_AVAILABLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    pub fn SQLExecDirectW(&self, StatementText: &OdbcStr<SQLWCHAR>) -> SQLRETURN {
-        let StatementText = StatementText.as_raw_slice();
+    // TODO: Is it unsafe if runtime_checks is used?
+    pub unsafe fn SQLPutData<TT: Ident, B: CData<TT, V> + AsSQLPOINTER + ?Sized>(
+        &self,
+        DataPtr: Option<&B>,
+    ) -> SQLRETURN {
+        let DataPtr = DataPtr.map_or((ptr::null_mut(), 0), |DataPtr| {
+            (DataPtr.as_SQLPOINTER(), DataPtr.len())
+        });
 
-        unsafe { ffi::SQLExecDirectW(self.as_SQLHANDLE(), StatementText.0, StatementText.1) }
+        unsafe { ffi::SQLPutData(self.as_SQLHANDLE(), DataPtr.0, DataPtr.1) }
     }
 
-    /// Executes a prepared statement, using the current values of the parameter marker variables if any parameter markers exist in the statement.
-    ///
-    /// For complete documentation on SQLExecute, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlexecute-function).
+    /// Retrieves the following information about columns within a specified table:
     ///
-    /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NEED_DATA, SQL_STILL_EXECUTING, SQL_ERROR, SQL_NO_DATA, SQL_INVALID_HANDLE, or SQL_PARAM_DATA_AVAILABLE.
-    #[inline]
-    pub fn SQLExecute(&self) -> SQLRETURN {
-        unsafe { ffi::SQLExecute(self.as_SQLHANDLE()) }
-    }
-
-    /// Fetches the next rowset of data from the result set and returns data for all bound columns.
+    /// * The optimal set of columns that uniquely identifies a row in the table.
+    /// * Columns that are automatically updated when any value in the row is updated by a transaction.
     ///
-    /// For complete documentation on SQLFetch, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlfetch-function).
+    /// For complete documentation on SQLSpecialColumnsA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlspecialcolumns-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NO_DATA, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    pub fn SQLFetch(&self) -> SQLRETURN {
-        unsafe { ffi::SQLFetch(self.as_SQLHANDLE()) }
-    }
+    pub fn SQLSpecialColumnsA(
+        &self,
+        IdentifierType: IdentifierType,
+        CatalogName: &OdbcStr<SQLCHAR>,
+        SchemaName: &OdbcStr<SQLCHAR>,
+        TableName: &OdbcStr<SQLCHAR>,
+        Scope: Scope,
+        Nullable: NullAllowed,
+    ) -> SQLRETURN {
+        let CatalogName = CatalogName.as_raw_slice();
+        let SchemaName = SchemaName.as_raw_slice();
+        let TableName = TableName.as_raw_slice();
 
-    /// Fetches the specified rowset of data from the result set and returns data for all bound columns. Rowsets can be specified at an absolute or relative position or by bookmark.
-    ///
-    /// For complete documentation on SQLFetchScroll, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlfetchscroll-function).
-    ///
-    /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NO_DATA, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
-    #[inline]
-    pub fn SQLFetchScroll(&self, FetchOrientation: SQLSMALLINT, FetchOffset: SQLLEN) -> SQLRETURN {
-        unsafe { ffi::SQLFetchScroll(self.as_SQLHANDLE(), FetchOrientation, FetchOffset) }
+        unsafe {
+            ffi::SQLSpecialColumnsA(
+                self.as_SQLHANDLE(),
+                IdentifierType as SQLSMALLINT,
+                CatalogName.0,
+                CatalogName.1,
+                SchemaName.0,
+                SchemaName.1,
+                TableName.0,
+                TableName.1,
+                Scope as SQLSMALLINT,
+                Nullable.identifier(),
+            )
+        }
     }
 
-    /// Sets the cursor position in a rowset and allows an application to refresh data in the rowset or to update or delete data in the result set.
+    /// Retrieves the following information about columns within a specified table:
     ///
-    /// For complete documentation on SQLSetPos, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlsetpos-function).
+    /// * The optimal set of columns that uniquely identifies a row in the table.
+    /// * Columns that are automatically updated when any value in the row is updated by a transaction.
+    ///
+    /// For complete documentation on SQLSpecialColumnsW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlspecialcolumns-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NEED_DATA, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    pub fn SQLSetPos(
+    pub fn SQLSpecialColumnsW(
         &self,
-        RowNumber: SQLSETPOSIROW,
-        Operation: Operation,
-        LockType: LockType,
+        IdentifierType: IdentifierType,
+        CatalogName: &OdbcStr<SQLWCHAR>,
+        SchemaName: &OdbcStr<SQLWCHAR>,
+        TableName: &OdbcStr<SQLWCHAR>,
+        Scope: Scope,
+        Nullable: NullAllowed,
     ) -> SQLRETURN {
+        let CatalogName = CatalogName.as_raw_slice();
+        let SchemaName = SchemaName.as_raw_slice();
+        let TableName = TableName.as_raw_slice();
+
         unsafe {
-            ffi::SQLSetPos(
+            ffi::SQLSpecialColumnsW(
                 self.as_SQLHANDLE(),
-                RowNumber,
-                Operation as SQLUSMALLINT,
-                LockType as SQLUSMALLINT,
+                IdentifierType as SQLSMALLINT,
+                CatalogName.0,
+                CatalogName.1,
+                SchemaName.0,
+                SchemaName.1,
+                TableName.0,
+                TableName.1,
+                Scope as SQLSMALLINT,
+                Nullable.identifier(),
             )
         }
     }
-}
 
-#[expect(non_snake_case)]
-impl<'desc, 'buf, V: OdbcVersion> UnsafeSQLHSTMT<'_, 'desc, 'buf, V> {
-    /// Executes a preparable statement, using the current values of the parameter marker variables if any parameters exist in the statement. **SQLExecDirect** is the fastest way to submit an SQL statement for one-time execution.
+    /// Retrieves a list of statistics about a single table and the indexes associated with the table. The driver returns the information as a result set.
     ///
-    /// For complete documentation on SQLExecDirectA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlexecdirect-function).
+    /// For complete documentation on SQLStatisticsA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlstatistics-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NEED_DATA, SQL_STILL_EXECUTING, SQL_ERROR, SQL_NO_DATA, SQL_INVALID_HANDLE, or SQL_PARAM_DATA_AVAILABLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    pub unsafe fn SQLExecDirectA(&self, StatementText: &OdbcStr<SQLCHAR>) -> SQLRETURN {
-        let sql_handle = self.as_SQLHANDLE();
-        let StatementText = StatementText.as_raw_slice();
-        unsafe { ffi::SQLExecDirectA(sql_handle, StatementText.0, StatementText.1) }
+    pub fn SQLStatisticsA(
+        &self,
+        CatalogName: &OdbcStr<SQLCHAR>,
+        SchemaName: &OdbcStr<SQLCHAR>,
+        TableName: &OdbcStr<SQLCHAR>,
+        Unique: Unique,
+        Reserved: Reserved,
+    ) -> SQLRETURN {
+        let CatalogName = CatalogName.as_raw_slice();
+        let SchemaName = SchemaName.as_raw_slice();
+        let TableName = TableName.as_raw_slice();
+
+        unsafe {
+            ffi::SQLStatisticsA(
+                self.as_SQLHANDLE(),
+                CatalogName.0,
+                CatalogName.1,
+                SchemaName.0,
+                SchemaName.1,
+                TableName.0,
+                TableName.1,
+                Unique as SQLUSMALLINT,
+                Reserved as SQLUSMALLINT,
+            )
+        }
     }
 
-    /// Executes a preparable statement, using the current values of the parameter marker variables if any parameters exist in the statement. **SQLExecDirect** is the fastest way to submit an SQL statement for one-time execution.
+    /// Retrieves a list of statistics about a single table and the indexes associated with the table. The driver returns the information as a result set.
     ///
-    /// For complete documentation on SQLExecDirectW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlexecdirect-function).
+    /// For complete documentation on SQLStatisticsW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlstatistics-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NEED_DATA, SQL_STILL_EXECUTING, SQL_ERROR, SQL_NO_DATA, SQL_INVALID_HANDLE, or SQL_PARAM_DATA_AVAILABLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    pub unsafe fn SQLExecDirectW(&self, StatementText: &OdbcStr<SQLWCHAR>) -> SQLRETURN {
-        let sql_handle = self.as_SQLHANDLE();
-        let StatementText = StatementText.as_raw_slice();
-        unsafe { ffi::SQLExecDirectW(sql_handle, StatementText.0, StatementText.1) }
+    pub fn SQLStatisticsW(
+        &self,
+        CatalogName: &OdbcStr<SQLWCHAR>,
+        SchemaName: &OdbcStr<SQLWCHAR>,
+        TableName: &OdbcStr<SQLWCHAR>,
+        Unique: Unique,
+        Reserved: Reserved,
+    ) -> SQLRETURN {
+        let CatalogName = CatalogName.as_raw_slice();
+        let SchemaName = SchemaName.as_raw_slice();
+        let TableName = TableName.as_raw_slice();
+
+        unsafe {
+            ffi::SQLStatisticsW(
+                self.as_SQLHANDLE(),
+                CatalogName.0,
+                CatalogName.1,
+                SchemaName.0,
+                SchemaName.1,
+                TableName.0,
+                TableName.1,
+                Unique as SQLUSMALLINT,
+                Reserved as SQLUSMALLINT,
+            )
+        }
     }
 
-    /// Executes a prepared statement, using the current values of the parameter marker variables if any parameter markers exist in the statement.
+    /// Returns a list of tables and the privileges associated with each table. The driver returns the information as a result set on the specified statement.
     ///
-    /// For complete documentation on SQLExecute, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlexecute-function).
+    /// For complete documentation on SQLTablePrivilegesA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqltableprivileges-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NEED_DATA, SQL_STILL_EXECUTING, SQL_ERROR, SQL_NO_DATA, SQL_INVALID_HANDLE, or SQL_PARAM_DATA_AVAILABLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    pub unsafe fn SQLExecute(&self) -> SQLRETURN {
-        let sql_handle = self.as_SQLHANDLE();
-        unsafe { ffi::SQLExecute(sql_handle) }
+    pub fn SQLTablePrivilegesA(
+        &self,
+        CatalogName: &OdbcStr<SQLCHAR>,
+        SchemaName: &OdbcStr<SQLCHAR>,
+        TableName: &OdbcStr<SQLCHAR>,
+    ) -> SQLRETURN {
+        let CatalogName = CatalogName.as_raw_slice();
+        let SchemaName = SchemaName.as_raw_slice();
+        let TableName = TableName.as_raw_slice();
+
+        unsafe {
+            ffi::SQLTablePrivilegesA(
+                self.as_SQLHANDLE(),
+                CatalogName.0,
+                CatalogName.1,
+                SchemaName.0,
+                SchemaName.1,
+                TableName.0,
+                TableName.1,
+            )
+        }
     }
 
-    /// Fetches the next rowset of data from the result set and returns data for all bound columns.
+    /// Returns a list of tables and the privileges associated with each table. The driver returns the information as a result set on the specified statement.
     ///
-    /// For complete documentation on SQLFetch, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlfetch-function).
+    /// For complete documentation on SQLTablePrivilegesW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqltableprivileges-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NO_DATA, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    pub unsafe fn SQLFetch(&self) -> SQLRETURN {
-        let sql_handle = self.as_SQLHANDLE();
-        unsafe { ffi::SQLFetch(sql_handle) }
+    pub fn SQLTablePrivilegesW(
+        &self,
+        CatalogName: &OdbcStr<SQLWCHAR>,
+        SchemaName: &OdbcStr<SQLWCHAR>,
+        TableName: &OdbcStr<SQLWCHAR>,
+    ) -> SQLRETURN {
+        let CatalogName = CatalogName.as_raw_slice();
+        let SchemaName = SchemaName.as_raw_slice();
+        let TableName = TableName.as_raw_slice();
+
+        unsafe {
+            ffi::SQLTablePrivilegesW(
+                self.as_SQLHANDLE(),
+                CatalogName.0,
+                CatalogName.1,
+                SchemaName.0,
+                SchemaName.1,
+                TableName.0,
+                TableName.1,
+            )
+        }
     }
 
-    /// Fetches the specified rowset of data from the result set and returns data for all bound columns. Rowsets can be specified at an absolute or relative position or by bookmark.
+    /// Returns the list of table, catalog, or schema names, and table types, stored in a specific data source. The driver returns the information as a result set.
     ///
-    /// For complete documentation on SQLFetchScroll, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlfetchscroll-function).
+    /// For complete documentation on SQLTablesA, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqltables-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NO_DATA, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    pub unsafe fn SQLFetchScroll(
+    pub fn SQLTablesA(
         &self,
-        FetchOrientation: SQLSMALLINT,
-        FetchOffset: SQLLEN,
+        CatalogName: &OdbcStr<SQLCHAR>,
+        SchemaName: &OdbcStr<SQLCHAR>,
+        TableName: &OdbcStr<SQLCHAR>,
+        TableType: &OdbcStr<SQLCHAR>,
     ) -> SQLRETURN {
-        let sql_handle = self.as_SQLHANDLE();
-        unsafe { ffi::SQLFetchScroll(sql_handle, FetchOrientation, FetchOffset) }
+        let CatalogName = CatalogName.as_raw_slice();
+        let SchemaName = SchemaName.as_raw_slice();
+        let TableName = TableName.as_raw_slice();
+        let TableType = TableType.as_raw_slice();
+
+        unsafe {
+            ffi::SQLTablesA(
+                self.as_SQLHANDLE(),
+                CatalogName.0,
+                CatalogName.1,
+                SchemaName.0,
+                SchemaName.1,
+                TableName.0,
+                TableName.1,
+                TableType.0,
+                TableType.1,
+            )
+        }
     }
 
-    /// Sets the cursor position in a rowset and allows an application to refresh data in the rowset or to update or delete data in the result set.
+    /// Returns the list of table, catalog, or schema names, and table types, stored in a specific data source. The driver returns the information as a result set.
     ///
-    /// For complete documentation on SQLSetPos, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlsetpos-function).
+    /// For complete documentation on SQLTablesW, see [API reference](https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqltables-function).
     ///
     /// # Returns
-    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_NEED_DATA, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
+    /// SQL_SUCCESS, SQL_SUCCESS_WITH_INFO, SQL_STILL_EXECUTING, SQL_ERROR, or SQL_INVALID_HANDLE.
     #[inline]
-    pub unsafe fn SQLSetPos(
+    pub fn SQLTablesW(
         &self,
-        RowNumber: SQLSETPOSIROW,
-        Operation: Operation,
-        LockType: LockType,
+        CatalogName: &OdbcStr<SQLWCHAR>,
+        SchemaName: &OdbcStr<SQLWCHAR>,
+        TableName: &OdbcStr<SQLWCHAR>,
+        TableType: &OdbcStr<SQLWCHAR>,
     ) -> SQLRETURN {
-        let sql_handle = self.as_SQLHANDLE();
+        let StatementHandle = self.as_SQLHANDLE();
+
+        let CatalogName = CatalogName.as_raw_slice();
+        let SchemaName = SchemaName.as_raw_slice();
+        let TableName = TableName.as_raw_slice();
+        let TableType = TableType.as_raw_slice();
 
         unsafe {
-            ffi::SQLSetPos(
-                sql_handle,
-                RowNumber,
-                Operation as SQLUSMALLINT,
-                LockType as SQLUSMALLINT,
+            ffi::SQLTablesW(
+                StatementHandle,
+                CatalogName.0,
+                CatalogName.1,
+                SchemaName.0,
+                SchemaName.1,
+                TableName.0,
+                TableName.1,
+                TableType.0,
+                TableType.1,
             )
         }
     }
 }
 
 #[expect(non_snake_case)]
-impl<'conn, 'desc, 'buf, V: OdbcVersion> Statement<'desc, 'buf, V>
-    for SQLHSTMT<'conn, 'desc, 'buf, V>
+impl<'conn, 'desc, 'buf, S: StmtState, V: OdbcVersion> Statement<'desc, 'buf, V>
+    for SQLHSTMT<'conn, 'desc, 'buf, S, V>
 {
     // TODO: When GATs are implemented use 'stmt instead of 'conn
     // because implicit descriptors are managed by the DM
@@ -2884,18 +5671,61 @@ impl<'conn, 'desc, 'buf, V: OdbcVersion> Statement<'desc, 'buf, V>
     type ExplicitARD = SQLHDESC<'conn, AppDesc<'buf>, V>;
     type ExplicitAPD = SQLHDESC<'conn, AppDesc<'buf>, V>;
 
-    fn bind_col<TT: Ident, B: DeferredBuf<Self::ARD, TT, V> + ?Sized>(&self, _: Option<&'buf B>) {
-        //TODO:
-        //self.0.bind_col(TargetValuePtr)
+    type FreeOption = UnbindOption;
+
+    fn bind_col<TT: Ident, B: DeferredBuf<Self::ARD, TT, V> + ?Sized>(
+        &self,
+        ColumnNumber: SQLUSMALLINT,
+        TargetValuePtr: Option<&'buf B>,
+        StrLen_or_IndPtr: Option<&'buf UnsafeCell<StrLenOrInd>>,
+    ) {
+        self.0.bound_cols.bind(
+            ColumnNumber,
+            TargetValuePtr
+                .map(|TargetValuePtr| (TargetValuePtr.as_SQLPOINTER(), TargetValuePtr.len())),
+            StrLen_or_IndPtr.map(UnsafeCell::get),
+            1,
+        );
+    }
+
+    fn bind_param<TT: Ident, B: DeferredBuf<Self::APD, TT, V> + ?Sized>(
+        &self,
+        ParameterNumber: SQLUSMALLINT,
+        ParameterValuePtr: Option<&'buf B>,
+        StrLen_or_IndPtr: Option<&'buf UnsafeCell<StrLenOrInd>>,
+    ) {
+        self.0.bound_params.bind(
+            ParameterNumber,
+            ParameterValuePtr.map(|ParameterValuePtr| {
+                (ParameterValuePtr.as_SQLPOINTER(), ParameterValuePtr.len())
+            }),
+            StrLen_or_IndPtr.map(UnsafeCell::get),
+            1,
+        );
+    }
+
+    fn unbind_cols(&self) {
+        self.0.bound_cols.unbind();
+    }
+
+    fn reset_params(&self) {
+        self.0.bound_params.unbind();
+    }
+
+    fn binds_col_arrays(&self) -> bool {
+        self.0.bound_cols.binds_arrays()
+    }
+
+    fn binds_param_arrays(&self) -> bool {
+        self.0.bound_params.binds_arrays()
     }
 
-    fn bind_param<TT: Ident, B: DeferredBuf<Self::APD, TT, V> + ?Sized>(&self, _: Option<&'buf B>) {
-        // TODO:
-        //self.0.bind_param(TargetValuePtr)
+    fn set_explicit_ard(&self, explicit: bool) {
+        self.0.bound_cols.set_explicit_desc(explicit);
     }
 
-    fn bind_strlen_or_ind(&self, StrLen_or_IndPtr: Option<&'buf UnsafeCell<StrLenOrInd>>) {
-        self.0.bind_strlen_or_ind(StrLen_or_IndPtr)
+    fn set_explicit_apd(&self, explicit: bool) {
+        self.0.bound_params.set_explicit_desc(explicit);
     }
 }
 
@@ -2913,41 +5743,62 @@ impl<'conn, 'desc, 'buf, V: OdbcVersion> Statement<'desc, 'buf, V>
     type ExplicitARD = UnsafeSQLHDESC<'conn, AppDesc<'buf>, V>;
     type ExplicitAPD = UnsafeSQLHDESC<'conn, AppDesc<'buf>, V>;
 
+    type FreeOption = FreeStmtOption;
+
     // TODO: Don't bind (SQLPOINTER, SQLLEN) fat pointer when using raw_api
-    #[cfg(not(feature = "odbc_debug"))]
-    fn bind_col<TT: Ident, B: DeferredBuf<Self::ARD, TT, V> + ?Sized>(&self, _: Option<&'buf B>) {}
-    #[cfg(not(feature = "odbc_debug"))]
-    fn bind_param<TT: Ident, B: DeferredBuf<Self::APD, TT, V> + ?Sized>(&self, _: Option<&'buf B>) {
-    }
-    #[cfg(not(feature = "odbc_debug"))]
-    fn bind_strlen_or_ind(&self, _: Option<&'buf UnsafeCell<StrLenOrInd>>) {}
-
-    #[cfg(feature = "odbc_debug")]
-    fn bind_col<TT: Ident, B: DeferredBuf<Self::ARD, TT, V> + ?Sized>(&self, _: Option<&'buf B>) {
-        if let Some(explicit_ard) = self.explicit_ard.get() {
-            // TODO:
-            //explicit_ard.bind_col(TargetValuePtr);
-        } else {
-            // TODO:
-            //self.ard.bind_col(TargetValuePtr);
-        }
+    fn bind_col<TT: Ident, B: DeferredBuf<Self::ARD, TT, V> + ?Sized>(
+        &self,
+        ColumnNumber: SQLUSMALLINT,
+        TargetValuePtr: Option<&'buf B>,
+        StrLen_or_IndPtr: Option<&'buf UnsafeCell<StrLenOrInd>>,
+    ) {
+        self.bound_cols.bind(
+            ColumnNumber,
+            TargetValuePtr
+                .map(|TargetValuePtr| (TargetValuePtr.as_SQLPOINTER(), TargetValuePtr.len())),
+            StrLen_or_IndPtr.map(UnsafeCell::get),
+            1,
+        );
     }
-    #[cfg(feature = "odbc_debug")]
+
     fn bind_param<TT: Ident, B: DeferredBuf<Self::APD, TT, V> + ?Sized>(
         &self,
-        TargetValuePtr: Option<&'buf B>,
+        ParameterNumber: SQLUSMALLINT,
+        ParameterValuePtr: Option<&'buf B>,
+        StrLen_or_IndPtr: Option<&'buf UnsafeCell<StrLenOrInd>>,
     ) {
-        if let Some(explicit_apd) = self.explicit_apd.get() {
-            // TODO:
-            //explicit_apd.bind_param(TargetValuePtr);
-        } else {
-            // TODO:
-            //self.apd.bind_param(TargetValuePtr);
-        }
+        self.bound_params.bind(
+            ParameterNumber,
+            ParameterValuePtr.map(|ParameterValuePtr| {
+                (ParameterValuePtr.as_SQLPOINTER(), ParameterValuePtr.len())
+            }),
+            StrLen_or_IndPtr.map(UnsafeCell::get),
+            1,
+        );
+    }
+
+    fn unbind_cols(&self) {
+        self.bound_cols.unbind();
+    }
+
+    fn reset_params(&self) {
+        self.bound_params.unbind();
+    }
+
+    fn binds_col_arrays(&self) -> bool {
+        self.bound_cols.binds_arrays()
+    }
+
+    fn binds_param_arrays(&self) -> bool {
+        self.bound_params.binds_arrays()
+    }
+
+    fn set_explicit_ard(&self, explicit: bool) {
+        self.bound_cols.set_explicit_desc(explicit);
     }
-    #[cfg(feature = "odbc_debug")]
-    fn bind_strlen_or_ind(&self, StrLen_or_IndPtr: Option<&'buf UnsafeCell<StrLenOrInd>>) {
-        unimplemented!();
+
+    fn set_explicit_apd(&self, explicit: bool) {
+        self.bound_params.set_explicit_desc(explicit);
     }
 }
 
@@ -2968,6 +5819,23 @@ impl<'conn, 'buf, DT: DescType<'buf>, V: OdbcVersion> Descriptor<'buf, DT, V>
 {
 }
 
+impl<V: OdbcVersion> Free for SQLHENV<V> {}
+impl<V: OdbcVersion> Free for SQLHDBC<'_, C2, V> {}
+impl<S: StmtState, V: OdbcVersion> Free for SQLHSTMT<'_, '_, '_, S, V> {
+    #[inline]
+    fn try_free(self) -> (Result<(), Self>, SQLRETURN) {
+        self.free()
+    }
+}
+impl<V: OdbcVersion> Free for UnsafeSQLHSTMT<'_, '_, '_, V> {
+    #[inline]
+    fn try_free(self) -> (Result<(), Self>, SQLRETURN) {
+        self.free()
+    }
+}
+impl<DT, V: OdbcVersion> Free for SQLHDESC<'_, DT, V> {}
+impl<DT, V: OdbcVersion> Free for UnsafeSQLHDESC<'_, DT, V> {}
+
 // TODO: If Connection trait is introduced implement for all connections
 impl Cancel<SQL_OV_ODBC3_80> for SQLHDBC<'_, C4, SQL_OV_ODBC3_80> {}
 impl Cancel<SQL_OV_ODBC4> for SQLHDBC<'_, C4, SQL_OV_ODBC4> {}
@@ -2978,6 +5846,47 @@ impl Async<SQL_OV_ODBC4> for SQLHDBC<'_, C4, SQL_OV_ODBC4> {}
 impl<'desc, 'buf, S: Statement<'desc, 'buf, SQL_OV_ODBC3_80>> Cancel<SQL_OV_ODBC3_80> for S {}
 impl<'desc, 'buf, S: Statement<'desc, 'buf, SQL_OV_ODBC4>> Cancel<SQL_OV_ODBC4> for S {}
 
+impl Cancel<SQL_OV_ODBC3_80> for RefSQLHDBC<'_, SQL_OV_ODBC3_80> {}
+impl Cancel<SQL_OV_ODBC4> for RefSQLHDBC<'_, SQL_OV_ODBC4> {}
+
+impl Cancel<SQL_OV_ODBC3_80> for RefSQLHSTMT<'_, SQL_OV_ODBC3_80> {}
+impl Cancel<SQL_OV_ODBC4> for RefSQLHSTMT<'_, SQL_OV_ODBC4> {}
+
+#[cfg(feature = "std")]
+impl<'env, V: OdbcVersion> Cancel<V> for WeakSQLHDBC<'env, V>
+where
+    SQLHDBC<'env, C4, V>: Cancel<V>,
+{
+    #[inline]
+    fn SQLCancelHandle(&self) -> SQLRETURN {
+        // Original handle is kept alive for the duration of the call
+        self.parent
+            .upgrade()
+            .map_or(SQL_INVALID_HANDLE, |conn| conn.SQLCancelHandle())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<V: OdbcVersion> Cancel<V> for WeakSQLHSTMT<'_, V>
+where
+    for<'stmt> RefSQLHSTMT<'stmt, V>: Cancel<V> + Handle<Ident = SQL_HANDLE_STMT>,
+{
+    #[inline]
+    fn SQLCancel(&self) -> SQLRETURN
+    where
+        Self: Handle<Ident = SQL_HANDLE_STMT>,
+    {
+        // Original handle is kept alive for the duration of the call
+        self.upgrade(|stmt| stmt.SQLCancel())
+    }
+
+    #[inline]
+    fn SQLCancelHandle(&self) -> SQLRETURN {
+        // Original handle is kept alive for the duration of the call
+        self.upgrade(|stmt| stmt.SQLCancelHandle())
+    }
+}
+
 impl<'desc, 'buf, S: Statement<'desc, 'buf, SQL_OV_ODBC3_80>> Async<SQL_OV_ODBC3_80> for S {}
 impl<'desc, 'buf, S: Statement<'desc, 'buf, SQL_OV_ODBC4>> Async<SQL_OV_ODBC4> for S {}
 
@@ -3000,10 +5909,11 @@ where
     MaybeUninit<T::StrLen>: StrLen<SQLINTEGER>,
 {
     if let Some(ValuePtr) = ValuePtr {
-        if cfg!(feature = "odbc_debug") {
+        if cfg!(feature = "runtime_checks") {
             ValuePtr.assert_zeroed();
         }
 
+        ValuePtr.check_read(Handle);
         ValuePtr.readA(Handle, StringLengthPtr)
     } else {
         unsafe {
@@ -3037,10 +5947,11 @@ where
     MaybeUninit<T::StrLen>: StrLen<SQLINTEGER>,
 {
     if let Some(ValuePtr) = ValuePtr {
-        if cfg!(feature = "odbc_debug") {
+        if cfg!(feature = "runtime_checks") {
             ValuePtr.assert_zeroed();
         }
 
+        ValuePtr.check_read(Handle);
         ValuePtr.readW(Handle, StringLengthPtr)
     } else {
         unsafe {
@@ -3113,915 +6024,1473 @@ fn SQLSetStmtAttrW<
     sql_return
 }
 
-#[cfg_attr(test, automock)]
-pub(crate) mod ffi {
-    use crate::handle::SQLHWND;
-    use crate::{
+/// Initial length, in characters, of buffers allocated for strings returned by the driver
+#[cfg(feature = "std")]
+const INITIAL_BUF_LEN: usize = 256;
+
+/// Calls the function with buffers of increasing size until the whole string fits into the
+/// buffer. Function returns the total length of the string in characters, excluding the null
+/// terminator, which is available when the string was truncated. String is not returned if
+/// the function failed or if the driver returned a negative length
+#[cfg(feature = "std")]
+pub(crate) fn retry_truncated<CH: Copy>(
+    mut f: impl FnMut(&mut OdbcStr<MaybeUninit<CH>>) -> (SQLRETURN, SQLLEN),
+) -> (Option<OdbcString<CH>>, SQLRETURN)
+where
+    [MaybeUninit<CH>]: AsMut<OdbcStr<MaybeUninit<CH>>>,
+{
+    let mut buf = vec![MaybeUninit::uninit(); INITIAL_BUF_LEN];
+
+    loop {
+        let (sql_return, len) = f(buf.as_mut_slice().as_mut());
+
+        let Ok(len) = usize::try_from(len) else {
+            return (None, sql_return);
+        };
+        if !SQL_SUCCEEDED(sql_return) {
+            return (None, sql_return);
+        }
+
+        // Last character in the buffer is reserved for the null terminator
+        if len < buf.len() {
+            // SAFETY: Driver has written the whole string into the buffer
+            let string: Vec<_> = buf[..len]
+                .iter()
+                .map(|c| unsafe { c.assume_init() })
+                .collect();
+            return (Some(string.into()), sql_return);
+        }
+
+        buf.resize(len + 1, MaybeUninit::uninit());
+    }
+}
+
+/// Largest part of the data sent with a single call to SQLPutData
+#[cfg(feature = "std")]
+const PUT_DATA_CHUNK_LEN: usize = 8192;
+
+/// Sends the data of the parameter identified by the value pointer returned from SQLParamData.
+/// Value pointer of a parameter bound with SQLHSTMT::bind_param_at_exec points to its
+/// length/indicator which distinguishes it from parameters bound with other functions. If
+/// SQLPutData returns SQL_STILL_EXECUTING, the chunk is kept in the state and sent again
+/// when this function is called with the same state
+#[cfg(feature = "std")]
+#[expect(non_snake_case)]
+fn put_param_data(
+    StatementHandle: SQLHANDLE,
+    bindings: &Bindings,
+    Sources: &mut [(SQLUSMALLINT, &mut dyn std::io::Read)],
+    state: &mut PutDataState,
+) -> std::io::Result<SQLRETURN> {
+    use std::io::{Error, ErrorKind};
+
+    let ValuePtr = state.ValuePtr;
+    let Some((_, source)) = Sources.iter_mut().find(|(ParameterNumber, _)| {
+        bindings.get(*ParameterNumber).is_some_and(|binding| {
+            binding.data_ptr() == Some((ValuePtr, 0))
+                && binding.strlen_or_ind_ptr() == Some(ValuePtr.cast())
+        })
+    }) else {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "No source for the parameter whose data is requested",
+        ));
+    };
+
+    if !state.sent && state.pending.is_none() {
+        // SAFETY: Length/indicator stays bound until the statement is executed
+        state.remaining = unsafe { &*ValuePtr.cast::<DataAtExec>() }
+            .data_len()
+            .map(|len| len as usize);
+    }
+
+    loop {
+        let len = if let Some(len) = state.pending {
+            len
+        } else {
+            let buf_len = state.buf.len();
+            let chunk_len = state
+                .remaining
+                .map_or(buf_len, |remaining| remaining.min(buf_len));
+            let len = match source.read(&mut state.buf[..chunk_len]) {
+                Ok(len) => len,
+                Err(error) if error.kind() == ErrorKind::Interrupted => continue,
+                Err(error) => return Err(error),
+            };
+
+            if len == 0 {
+                if state.remaining.is_some_and(|remaining| remaining > 0) {
+                    return Err(Error::new(
+                        ErrorKind::UnexpectedEof,
+                        "Source is shorter than the length of its data",
+                    ));
+                }
+                if state.sent {
+                    return Ok(SQL_SUCCESS);
+                }
+            }
+
+            len
+        };
+
+        // Data of zero length is sent with a single call
+        let sql_return =
+            unsafe { ffi::SQLPutData(StatementHandle, state.buf.as_ptr().cast(), len as SQLLEN) };
+        if sql_return == SQL_STILL_EXECUTING {
+            state.pending = Some(len);
+            return Ok(sql_return);
+        }
+
+        state.pending = None;
+        if !SQL_SUCCEEDED(sql_return) || len == 0 {
+            return Ok(sql_return);
+        }
+
+        state.sent = true;
+        if let Some(remaining) = &mut state.remaining {
+            *remaining -= len;
+        }
+    }
+}
+
+/// Panics if the data source requires the length of long data which is not given
+#[expect(non_snake_case)]
+fn check_long_data_len(
+    ParameterType: SQLSMALLINT,
+    StrLen_or_IndPtr: &DataAtExec,
+    NeedLongDataLen: bool,
+) {
+    let is_long = [SQL_LONGVARCHAR, SQL_WLONGVARCHAR, SQL_LONGVARBINARY]
+        .iter()
+        .any(|LongType| LongType.identifier() == ParameterType);
+
+    assert!(
+        !NeedLongDataLen || !is_long || StrLen_or_IndPtr.data_len().is_some(),
+        "Data source requires the length of long data to be given with DataAtExec::with_len"
+    );
+}
+
+/// Binds a parameter whose value pointer is set to its length/indicator. SQLParamData returns
+/// the value pointer to identify the parameter whose data is requested or available so it's
+/// set to the length/indicator which is unique for every parameter
+#[expect(non_snake_case)]
+#[expect(clippy::too_many_arguments)]
+fn bind_param_token(
+    StatementHandle: SQLHANDLE,
+    bindings: &Bindings,
+    ParameterNumber: SQLUSMALLINT,
+    InputOutputType: IOType,
+    ValueType: SQLSMALLINT,
+    ParameterType: SQLSMALLINT,
+    ColumnSize: SQLULEN,
+    DecimalDigits: SQLSMALLINT,
+    StrLen_or_IndPtr: &DataAtExec,
+) -> SQLRETURN {
+    assert!(
+        !bindings.binds_arrays(),
+        "Data at execution bound while parameters are bound as arrays"
+    );
+
+    let StrLen_or_IndPtr = StrLen_or_IndPtr.as_ptr();
+    let ParameterValuePtr: SQLPOINTER = StrLen_or_IndPtr.cast();
+
+    #[cfg(feature = "runtime_checks")]
+    let APD = match checks::app_desc::<SQL_ATTR_APP_PARAM_DESC>(StatementHandle) {
+        Ok(APD) => APD,
+        Err(sql_return) => return sql_return,
+    };
+
+    let sql_return = unsafe {
+        ffi::SQLBindParameter(
+            StatementHandle,
+            ParameterNumber,
+            InputOutputType.identifier(),
+            ValueType,
+            ParameterType,
+            ColumnSize,
+            DecimalDigits,
+            ParameterValuePtr,
+            0,
+            StrLen_or_IndPtr.cast(),
+        )
+    };
+
+    if SQL_SUCCEEDED(sql_return) {
+        #[cfg(feature = "runtime_checks")]
+        checks::bind(
+            APD,
+            ParameterNumber as SQLSMALLINT,
+            None,
+            InputOutputType != SQL_PARAM_INPUT,
+        );
+
+        bindings.bind(
+            ParameterNumber,
+            Some((ParameterValuePtr, 0)),
+            Some(StrLen_or_IndPtr),
+            1,
+        );
+    }
+
+    sql_return
+}
+
+/// Statement attributes for the bind type and bind offset of columns
+const ROW_ATTRS: (SQLINTEGER, SQLINTEGER) = (
+    SQL_ATTR_ROW_BIND_TYPE::IDENTIFIER,
+    SQL_ATTR_ROW_BIND_OFFSET_PTR::IDENTIFIER,
+);
+/// Statement attributes for the bind type and bind offset of parameters
+const PARAM_ROW_ATTRS: (SQLINTEGER, SQLINTEGER) = (
+    SQL_ATTR_PARAM_BIND_TYPE::IDENTIFIER,
+    SQL_ATTR_PARAM_BIND_OFFSET_PTR::IDENTIFIER,
+);
+
+/// Sets precision and scale of a numeric record in the application descriptor associated with
+/// the statement. Drivers default the scale of SQL_C_NUMERIC records to 0 which truncates the
+/// fractional digits. Because setting them unbinds the record, SQL_DESC_DATA_PTR is set again
+#[expect(non_snake_case)]
+fn set_numeric_desc<A: Ident<Type = SQLINTEGER>>(
+    StatementHandle: SQLHANDLE,
+    RecNumber: SQLUSMALLINT,
+    Precision: SQLCHAR,
+    Scale: SQLSCHAR,
+    DataPtr: (SQLPOINTER, SQLLEN),
+) -> SQLRETURN {
+    let mut DescriptorHandle = MaybeUninit::<SQLHANDLE>::uninit();
+
+    let sql_return = unsafe {
+        ffi::SQLGetStmtAttrA(
+            StatementHandle,
+            A::IDENTIFIER,
+            DescriptorHandle.as_mut_ptr().cast(),
+            0,
+            ptr::null_mut(),
+        )
+    };
+    if !SQL_SUCCEEDED(sql_return) {
+        return sql_return;
+    }
+
+    let DescriptorHandle = unsafe { DescriptorHandle.assume_init() };
+    let fields = [
+        (
+            SQL_DESC_PRECISION::IDENTIFIER,
+            SQLSMALLINT::from(Precision).into_SQLPOINTER(),
+        ),
+        (
+            SQL_DESC_SCALE::IDENTIFIER,
+            SQLSMALLINT::from(Scale).into_SQLPOINTER(),
+        ),
+        (SQL_DESC_DATA_PTR::IDENTIFIER, DataPtr.0),
+    ];
+
+    for (FieldIdentifier, ValuePtr) in fields {
+        let sql_return = unsafe {
+            ffi::SQLSetDescFieldA(
+                DescriptorHandle,
+                RecNumber as SQLSMALLINT,
+                FieldIdentifier,
+                ValuePtr,
+                0,
+            )
+        };
+        if !SQL_SUCCEEDED(sql_return) {
+            return sql_return;
+        }
+
+        #[cfg(feature = "runtime_checks")]
+        checks::desc_field(
+            DescriptorHandle,
+            RecNumber as SQLSMALLINT,
+            FieldIdentifier,
+            (ValuePtr, DataPtr.1 as usize),
+        );
+    }
+
+    sql_return
+}
+
+/// Sets the number of rows the driver reads or writes on every call together with the arrays
+/// holding a value for every row, e.g. the status of every row. Array size is first reduced to
+/// a single row so that no bound buffer is shorter than the array size if setting any of the
+/// attributes fails
+#[expect(non_snake_case)]
+fn set_array_attrs<const N: usize>(
+    StatementHandle: SQLHANDLE,
+    bindings: &Bindings,
+    ArraySizeAttr: SQLINTEGER,
+    ArraySize: SQLULEN,
+    ArrayAttrs: [(SQLINTEGER, SQLPOINTER); N],
+) -> SQLRETURN {
+    let attrs = core::iter::once((ArraySizeAttr, 1 as SQLPOINTER))
+        .chain(ArrayAttrs)
+        .chain([(ArraySizeAttr, ArraySize as SQLPOINTER)]);
+
+    let mut sql_return = SQL_SUCCESS;
+    for (Attribute, ValuePtr) in attrs {
+        sql_return = unsafe { ffi::SQLSetStmtAttrA(StatementHandle, Attribute, ValuePtr, 0) };
+
+        if !SQL_SUCCEEDED(sql_return) {
+            return sql_return;
+        }
+
+        if Attribute == ArraySizeAttr {
+            bindings.set_array_size(ValuePtr as SQLULEN);
+        } else if !ValuePtr.is_null() {
+            bindings.set_status_bound(true);
+        }
+    }
+
+    bindings.set_status_bound(ArrayAttrs.iter().any(|(_, ValuePtr)| !ValuePtr.is_null()));
+    sql_return
+}
+
+/// Sets the bind offset together with row-wise binding of the given row or column-wise binding
+/// if no row is given. Offset is set first because the driver adds it to the address of every
+/// bound buffer regardless of the bind type
+#[expect(non_snake_case)]
+fn set_row_attrs(
+    StatementHandle: SQLHANDLE,
+    bindings: &Bindings,
+    (BindTypeAttr, BindOffsetAttr): (SQLINTEGER, SQLINTEGER),
+    row: Option<(usize, usize)>,
+    rows: SQLULEN,
+    BindOffsetPtr: Option<*mut SQLULEN>,
+) -> SQLRETURN {
+    let sql_return = unsafe {
+        ffi::SQLSetStmtAttrA(
+            StatementHandle,
+            BindOffsetAttr,
+            BindOffsetPtr.map_or_else(ptr::null_mut, <*mut SQLULEN>::cast),
+            0,
+        )
+    };
+    if !SQL_SUCCEEDED(sql_return) {
+        return sql_return;
+    }
+    bindings.bind_rows(bindings.row(), rows, BindOffsetPtr);
+
+    let BindType = row.map_or(SQL_BIND_BY_COLUMN, |(_, row_len)| BindType::by_row(row_len));
+    let sql_return = unsafe {
+        ffi::SQLSetStmtAttrA(
+            StatementHandle,
+            BindTypeAttr,
+            BindType.identifier() as SQLULEN as SQLPOINTER,
+            0,
+        )
+    };
+    if SQL_SUCCEEDED(sql_return) {
+        bindings.bind_rows(row, rows, BindOffsetPtr);
+    }
+
+    sql_return
+}
+
+/// Binds an array of rows with row-wise binding and resets the bind offset if one is provided
+#[expect(non_snake_case)]
+fn bind_row_array<R: RowBuf>(
+    StatementHandle: SQLHANDLE,
+    bindings: &Bindings,
+    RowAttrs: (SQLINTEGER, SQLINTEGER),
+    Rows: &[R],
+    BindOffsetPtr: Option<&mut BindOffset>,
+) -> SQLRETURN {
+    assert_ne!(size_of::<R>(), 0, "Row must not be zero-sized");
+    assert!(bindings.is_unbound(), "Rows must be bound before records");
+    assert!(
+        Rows.len() >= bindings.array_size(),
+        "Array of {} rows bound while the array size is {}",
+        Rows.len(),
+        bindings.array_size()
+    );
+
+    let BindOffsetPtr = BindOffsetPtr.map(|BindOffsetPtr| {
+        *BindOffsetPtr = BindOffset::new();
+        BindOffsetPtr.as_mut_ptr()
+    });
+
+    let sql_return = set_row_attrs(
+        StatementHandle,
+        bindings,
+        RowAttrs,
+        Some((Rows.as_ptr() as usize, size_of::<R>())),
+        Rows.len(),
+        BindOffsetPtr,
+    );
+    if SQL_SUCCEEDED(sql_return) {
+        bindings.set_row_type(TypeId::of::<R>());
+    }
+
+    sql_return
+}
+
+/// Points records bound to fields of the first row to another array of rows by updating the
+/// bind offset
+#[expect(non_snake_case)]
+fn set_row_array<R: RowBuf>(bindings: &Bindings, Rows: &[R]) {
+    let (row, row_len) = bindings.row().expect("Rows are not bound");
+    let BindOffsetPtr = bindings
+        .bind_offset()
+        .expect("Rows are bound without an offset");
+
+    // Same type guarantees that fields of every row are at the bound offsets and aligned
+    bindings.check_row_type::<R>();
+    assert!(
+        Rows.len() >= bindings.array_size(),
+        "Array of {} rows bound while the array size is {}",
+        Rows.len(),
+        bindings.array_size()
+    );
+
+    // Driver adds the offset to the address of every bound buffer so it wraps around
+    // if the array is at a lower address than the first array of bound rows
+    unsafe { *BindOffsetPtr = (Rows.as_ptr() as usize).wrapping_sub(row) };
+    bindings.bind_rows(Some((row, row_len)), Rows.len(), Some(BindOffsetPtr));
+}
+
+/// Panics if any of the buffers is not within the first row of the array bound with
+/// row-wise binding
+#[expect(non_snake_case)]
+fn check_row_bufs(
+    bindings: &Bindings,
+    DataPtr: Option<(SQLPOINTER, usize)>,
+    StrLen_or_IndPtr: Option<*mut StrLenOrInd>,
+) {
+    if let Some((DataPtr, len)) = DataPtr {
+        bindings.check_row_field(DataPtr as usize, len);
+    }
+    if let Some(StrLen_or_IndPtr) = StrLen_or_IndPtr {
+        bindings.check_row_field(StrLen_or_IndPtr as usize, size_of::<StrLenOrInd>());
+    }
+}
+
+/// Reverts the array size, arrays and row-wise binding to their defaults because they point to
+/// buffers which don't outlive the bindings
+#[expect(non_snake_case)]
+fn release_arrays<const N: usize>(
+    StatementHandle: SQLHANDLE,
+    bindings: &Bindings,
+    ArraySizeAttr: SQLINTEGER,
+    ArrayAttrs: [SQLINTEGER; N],
+    RowAttrs: (SQLINTEGER, SQLINTEGER),
+) -> SQLRETURN {
+    if bindings.array_size() != 1 || bindings.status_bound() {
+        let sql_return = set_array_attrs(
+            StatementHandle,
+            bindings,
+            ArraySizeAttr,
+            1,
+            ArrayAttrs.map(|Attribute| (Attribute, ptr::null_mut())),
+        );
+
+        if !SQL_SUCCEEDED(sql_return) {
+            return sql_return;
+        }
+    }
+
+    if bindings.row().is_some() || bindings.bind_offset().is_some() {
+        return set_row_attrs(
+            StatementHandle,
+            bindings,
+            RowAttrs,
+            None,
+            SQLULEN::MAX,
+            None,
+        );
+    }
+
+    SQL_SUCCESS
+}
+
+/// Declares functions exported by the driver manager. Functions are either linked against or,
+/// with the `dlopen` feature, resolved at runtime from the driver manager loaded by [`crate::dm`]
+macro_rules! ffi {
+    ($($(#[$meta:meta])* pub fn $name:ident($($arg:ident: $ty:ty),* $(,)?) -> $ret:ty;)*) => {
+        // Mocked functions which are not used by tests shouldn't be reported
+        #[cfg(any(test, not(feature = "dlopen")))]
+        #[cfg_attr(test, allow(dead_code))]
+        mod linked {
+            #[cfg_attr(test, mockall::automock)]
+            pub(crate) mod ffi {
+                use crate::api::ffi_types::*;
+
+                // TODO: static linking is not supported for windows
+                #[cfg_attr(windows, link(name = "odbc32", kind = "dylib"))]
+                #[cfg_attr(
+                    all(not(windows), not(feature = "iodbc"), feature = "static"),
+                    link(name = "odbc", kind = "static")
+                )]
+                #[cfg_attr(
+                    all(not(windows), not(feature = "iodbc"), not(feature = "static")),
+                    link(name = "odbc", kind = "dylib")
+                )]
+                #[cfg_attr(
+                    all(not(windows), feature = "iodbc", feature = "static"),
+                    link(name = "iodbc", kind = "static")
+                )]
+                #[cfg_attr(
+                    all(not(windows), feature = "iodbc", not(feature = "static")),
+                    link(name = "iodbc", kind = "dylib")
+                )]
+                unsafe extern "system" {
+                    $($(#[$meta])* pub fn $name($($arg: $ty),*) -> $ret;)*
+                }
+            }
+        }
+        #[cfg(any(test, not(feature = "dlopen")))]
+        pub(crate) use linked::*;
+
+        #[cfg(all(not(test), feature = "dlopen"))]
+        pub(crate) mod ffi {
+            use super::ffi_types::*;
+
+            $(
+                $(#[$meta])*
+                #[inline]
+                #[allow(clippy::too_many_arguments)]
+                pub unsafe fn $name($($arg: $ty),*) -> $ret {
+                    match crate::dm::symbols() {
+                        Ok(symbols) => unsafe { (symbols.$name)($($arg),*) },
+                        // No handle can be allocated without the driver manager
+                        Err(_) => crate::sqlreturn::SQL_ERROR,
+                    }
+                }
+            )*
+        }
+
+        /// Functions resolved from the driver manager loaded at runtime
+        #[cfg(feature = "dlopen")]
+        pub(crate) mod symbols {
+            use super::ffi_types::*;
+            use crate::dm::LoadError;
+            use libloading::Library;
+
+            #[allow(non_snake_case)]
+            #[cfg_attr(test, allow(dead_code))]
+            pub(crate) struct Symbols {
+                $(pub(crate) $name: unsafe extern "system" fn($($ty),*) -> $ret,)*
+            }
+
+            impl Symbols {
+                /// Resolves all functions from the given driver manager
+                ///
+                /// # Safety
+                ///
+                /// Library must export functions with the signatures defined by the ODBC standard
+                pub(crate) unsafe fn resolve(library: &Library) -> Result<Self, LoadError> {
+                    Ok(Self {
+                        $($name: unsafe { library.get(stringify!($name).as_bytes()) }
+                            .map(|symbol| *symbol)
+                            .map_err(|source| LoadError::Symbol {
+                                name: stringify!($name),
+                                source,
+                            })?,)*
+                    })
+                }
+            }
+        }
+    };
+}
+
+mod ffi_types {
+    pub(super) use crate::handle::SQLHWND;
+    pub(super) use crate::{
         RETCODE, SQLCHAR, SQLINTEGER, SQLLEN, SQLPOINTER, SQLSETPOSIROW, SQLSMALLINT, SQLULEN,
         SQLUSMALLINT, SQLWCHAR, diag::SQLSTATE_SIZE, handle::SQLHANDLE, sqlreturn::SQLRETURN,
     };
 
     #[expect(clippy::upper_case_acronyms)]
-    type HENV = SQLHANDLE;
+    pub(super) type HENV = SQLHANDLE;
     #[expect(clippy::upper_case_acronyms)]
-    type HDBC = SQLHANDLE;
+    pub(super) type HDBC = SQLHANDLE;
     #[expect(clippy::upper_case_acronyms)]
-    type HSTMT = SQLHANDLE;
+    pub(super) type HSTMT = SQLHANDLE;
     #[expect(clippy::upper_case_acronyms)]
-    type HDESC = SQLHANDLE;
-
-    type ConstSQLPOINTER = *const core::ffi::c_void;
-    type MutSQLPOINTER = *mut core::ffi::c_void;
-
-    // TODO: static linking is not supported for windows
-    #[cfg_attr(windows, link(name = "odbc32", kind = "dylib"))]
-    #[cfg_attr(
-        all(not(windows), feature = "static"),
-        link(name = "odbc", kind = "static")
-    )]
-    #[cfg_attr(
-        all(not(windows), not(feature = "static")),
-        link(name = "odbc", kind = "dylib")
-    )]
-    unsafe extern "system" {
-        #[allow(non_snake_case)]
-        pub fn SQLAllocHandle(
-            HandleType: SQLSMALLINT,
-            InputHandle: SQLHANDLE,
-            OutputHandlePtr: *mut SQLHANDLE,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLBindCol(
-            StatementHandle: HSTMT,
-            ColumnNumber: SQLUSMALLINT,
-            TargetType: SQLSMALLINT,
-            TargetValuePtr: MutSQLPOINTER,
-            BufferLength: SQLLEN,
-            StrLen_or_IndPtr: *mut SQLLEN,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLBindParameter(
-            StatementHandle: HSTMT,
-            ParameterNumber: SQLUSMALLINT,
-            InputOutputType: SQLSMALLINT,
-            ValueType: SQLSMALLINT,
-            ParameterType: SQLSMALLINT,
-            ColumnSize: SQLULEN,
-            DecimalDigits: SQLSMALLINT,
-            ParameterValuePtr: SQLPOINTER,
-            BufferLength: SQLLEN,
-            StrLen_or_IndPtr: *const SQLLEN,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLBrowseConnectA(
-            ConnectionHandle: HDBC,
-            InConnectionString: *const SQLCHAR,
-            StringLength1: SQLSMALLINT,
-            OutConnectionString: *mut SQLCHAR,
-            BufferLength: SQLSMALLINT,
-            StringLength2Ptr: *mut SQLSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLBrowseConnectW(
-            ConnectionHandle: HDBC,
-            InConnectionString: *const SQLWCHAR,
-            StringLength1: SQLSMALLINT,
-            OutConnectionString: *mut SQLWCHAR,
-            BufferLength: SQLSMALLINT,
-            StringLength2Ptr: *mut SQLSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLBulkOperations(StatementHandle: HSTMT, Operation: SQLUSMALLINT) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLCancel(StatementHandle: HSTMT) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLCancelHandle(HandleType: SQLSMALLINT, Handle: SQLHANDLE) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLCloseCursor(StatementHandle: HSTMT) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLColAttributeA(
-            StatementHandle: HSTMT,
-            ColumnNumber: SQLUSMALLINT,
-            FieldIdentifier: SQLUSMALLINT,
-            CharacterAttributePtr: MutSQLPOINTER,
-            BufferLength: SQLSMALLINT,
-            StringLengthPtr: *mut SQLSMALLINT,
-            NumericAttributePtr: *mut SQLLEN,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLColAttributeW(
-            StatementHandle: HSTMT,
-            ColumnNumber: SQLUSMALLINT,
-            FieldIdentifier: SQLUSMALLINT,
-            CharacterAttributePtr: MutSQLPOINTER,
-            BufferLength: SQLSMALLINT,
-            StringLengthPtr: *mut SQLSMALLINT,
-            NumericAttributePtr: *mut SQLLEN,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLColumnPrivilegesA(
-            StatementHandle: HSTMT,
-            CatalogName: *const SQLCHAR,
-            NameLength1: SQLSMALLINT,
-            SchemaName: *const SQLCHAR,
-            NameLength2: SQLSMALLINT,
-            TableName: *const SQLCHAR,
-            NameLength3: SQLSMALLINT,
-            ColumnName: *const SQLCHAR,
-            NameLength4: SQLSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLColumnPrivilegesW(
-            StatementHandle: HSTMT,
-            CatalogName: *const SQLWCHAR,
-            NameLength1: SQLSMALLINT,
-            SchemaName: *const SQLWCHAR,
-            NameLength2: SQLSMALLINT,
-            TableName: *const SQLWCHAR,
-            NameLength3: SQLSMALLINT,
-            ColumnName: *const SQLWCHAR,
-            NameLength4: SQLSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLColumnsA(
-            StatementHandle: HSTMT,
-            CatalogName: *const SQLCHAR,
-            NameLength1: SQLSMALLINT,
-            SchemaName: *const SQLCHAR,
-            NameLength2: SQLSMALLINT,
-            TableName: *const SQLCHAR,
-            NameLength3: SQLSMALLINT,
-            ColumnName: *const SQLCHAR,
-            NameLength4: SQLSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLColumnsW(
-            StatementHandle: HSTMT,
-            CatalogName: *const SQLWCHAR,
-            NameLength1: SQLSMALLINT,
-            SchemaName: *const SQLWCHAR,
-            NameLength2: SQLSMALLINT,
-            TableName: *const SQLWCHAR,
-            NameLength3: SQLSMALLINT,
-            ColumnName: *const SQLWCHAR,
-            NameLength4: SQLSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLCompleteAsync(
-            HandleType: SQLSMALLINT,
-            Handle: SQLHANDLE,
-            AsyncRetCodePtr: *mut RETCODE,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLConnectA(
-            ConnectionHandle: HDBC,
-            ServerName: *const SQLCHAR,
-            NameLength1: SQLSMALLINT,
-            UserName: *const SQLCHAR,
-            NameLength2: SQLSMALLINT,
-            Authentication: *const SQLCHAR,
-            NameLength3: SQLSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLConnectW(
-            ConnectionHandle: HDBC,
-            ServerName: *const SQLWCHAR,
-            NameLength1: SQLSMALLINT,
-            UserName: *const SQLWCHAR,
-            NameLength2: SQLSMALLINT,
-            Authentication: *const SQLWCHAR,
-            NameLength3: SQLSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLCopyDesc(SourceDescHandle: HDESC, TargetDescHandle: HDESC) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLDataSourcesA(
-            EnvironmentHandle: HENV,
-            Direction: SQLUSMALLINT,
-            ServerName: *mut SQLCHAR,
-            BufferLength1: SQLSMALLINT,
-            NameLength1Ptr: *mut SQLSMALLINT,
-            Description: *mut SQLCHAR,
-            BufferLength2: SQLSMALLINT,
-            NameLength2Ptr: *mut SQLSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLDataSourcesW(
-            EnvironmentHandle: HENV,
-            Direction: SQLUSMALLINT,
-            ServerName: *mut SQLWCHAR,
-            BufferLength1: SQLSMALLINT,
-            NameLength1Ptr: *mut SQLSMALLINT,
-            Description: *mut SQLWCHAR,
-            BufferLength2: SQLSMALLINT,
-            NameLength2Ptr: *mut SQLSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLDescribeColA(
-            StatementHandle: HSTMT,
-            ColumnNumber: SQLUSMALLINT,
-            ColumnName: *mut SQLCHAR,
-            BufferLength: SQLSMALLINT,
-            NameLengthPtr: *mut SQLSMALLINT,
-            DataTypePtr: *mut SQLSMALLINT,
-            ColumnSizePtr: *mut SQLULEN,
-            DecimalDigitsPtr: *mut SQLSMALLINT,
-            NullablePtr: *mut SQLSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLDescribeColW(
-            StatementHandle: HSTMT,
-            ColumnNumber: SQLUSMALLINT,
-            ColumnName: *mut SQLWCHAR,
-            BufferLength: SQLSMALLINT,
-            NameLengthPtr: *mut SQLSMALLINT,
-            DataTypePtr: *mut SQLSMALLINT,
-            ColumnSizePtr: *mut SQLULEN,
-            DecimalDigitsPtr: *mut SQLSMALLINT,
-            NullablePtr: *mut SQLSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLDescribeParam(
-            StatementHandle: HSTMT,
-            ParameterNumber: SQLUSMALLINT,
-            DataTypePtr: *mut SQLSMALLINT,
-            ParameterSizePtr: *mut SQLULEN,
-            DecimalDigitsPtr: *mut SQLSMALLINT,
-            NullablePtr: *mut SQLSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLDisconnect(ConnectionHandle: HDBC) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLDriverConnectA(
-            ConnectionHandle: HDBC,
-            WindowHandle: SQLHWND,
-            InConnectionString: *const SQLCHAR,
-            StringLength1: SQLSMALLINT,
-            OutConnectionString: *mut SQLCHAR,
-            BufferLength: SQLSMALLINT,
-            StringLength2Ptr: *mut SQLSMALLINT,
-            DriverCompletion: SQLUSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLDriverConnectW(
-            ConnectionHandle: HDBC,
-            WindowHandle: SQLHWND,
-            InConnectionString: *const SQLWCHAR,
-            StringLength1: SQLSMALLINT,
-            OutConnectionString: *mut SQLWCHAR,
-            BufferLength: SQLSMALLINT,
-            StringLength2Ptr: *mut SQLSMALLINT,
-            DriverCompletion: SQLUSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLDriversA(
-            EnvironmentHandle: HENV,
-            Direction: SQLUSMALLINT,
-            DriverDescription: *mut SQLCHAR,
-            BufferLength1: SQLSMALLINT,
-            DescriptionLengthPtr: *mut SQLSMALLINT,
-            DriverAttributes: *mut SQLCHAR,
-            BufferLength2: SQLSMALLINT,
-            AttributesLengthPtr: *mut SQLSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLDriversW(
-            EnvironmentHandle: HENV,
-            Direction: SQLUSMALLINT,
-            DriverDescription: *mut SQLWCHAR,
-            BufferLength1: SQLSMALLINT,
-            DescriptionLengthPtr: *mut SQLSMALLINT,
-            DriverAttributes: *mut SQLWCHAR,
-            BufferLength2: SQLSMALLINT,
-            AttributesLengthPtr: *mut SQLSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLEndTran(
-            HandleType: SQLSMALLINT,
-            Handle: SQLHANDLE,
-            CompletionType: SQLSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLExecDirectA(
-            StatementHandle: HSTMT,
-            StatementText: *const SQLCHAR,
-            TextLength: SQLINTEGER,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLExecDirectW(
-            StatementHandle: HSTMT,
-            StatementText: *const SQLWCHAR,
-            TextLength: SQLINTEGER,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLExecute(StatementHandle: HSTMT) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLFetch(StatementHandle: HSTMT) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLFetchScroll(
-            StatementHandle: HSTMT,
-            FetchOrientation: SQLSMALLINT,
-            FetchOffset: SQLLEN,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLForeignKeysA(
-            StatementHandle: HSTMT,
-            PKCatalogName: *const SQLCHAR,
-            NameLength1: SQLSMALLINT,
-            PKSchemaName: *const SQLCHAR,
-            NameLength2: SQLSMALLINT,
-            PKTableName: *const SQLCHAR,
-            NameLength3: SQLSMALLINT,
-            FKCatalogName: *const SQLCHAR,
-            NameLength4: SQLSMALLINT,
-            FKSchemaName: *const SQLCHAR,
-            NameLength5: SQLSMALLINT,
-            FKTableName: *const SQLCHAR,
-            NameLength6: SQLSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLForeignKeysW(
-            StatementHandle: HSTMT,
-            PKCatalogName: *const SQLWCHAR,
-            NameLength1: SQLSMALLINT,
-            PKSchemaName: *const SQLWCHAR,
-            NameLength2: SQLSMALLINT,
-            PKTableName: *const SQLWCHAR,
-            NameLength3: SQLSMALLINT,
-            FKCatalogName: *const SQLWCHAR,
-            NameLength4: SQLSMALLINT,
-            FKSchemaName: *const SQLWCHAR,
-            NameLength5: SQLSMALLINT,
-            FKTableName: *const SQLWCHAR,
-            NameLength6: SQLSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLFreeHandle(HandleType: SQLSMALLINT, Handle: SQLHANDLE) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLFreeStmt(StatementHandle: HSTMT, Option: SQLUSMALLINT) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLGetConnectAttrA(
-            ConnectionHandle: HDBC,
-            Attribute: SQLINTEGER,
-            ValuePtr: MutSQLPOINTER,
-            BufferLength: SQLINTEGER,
-            StringLengthPtr: *mut SQLINTEGER,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLGetConnectAttrW(
-            ConnectionHandle: HDBC,
-            Attribute: SQLINTEGER,
-            ValuePtr: MutSQLPOINTER,
-            BufferLength: SQLINTEGER,
-            StringLengthPtr: *mut SQLINTEGER,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLGetCursorNameA(
-            StatementHandle: HSTMT,
-            CursorName: *mut SQLCHAR,
-            BufferLength: SQLSMALLINT,
-            NameLengthPtr: *mut SQLSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLGetCursorNameW(
-            StatementHandle: HSTMT,
-            CursorName: *mut SQLWCHAR,
-            BufferLength: SQLSMALLINT,
-            NameLengthPtr: *mut SQLSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLGetData(
-            StatementHandle: HSTMT,
-            Col_or_Param_Num: SQLUSMALLINT,
-            TargetType: SQLSMALLINT,
-            TargetValuePtr: MutSQLPOINTER,
-            BufferLength: SQLLEN,
-            StrLen_or_IndPtr: *mut SQLLEN,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLGetDescFieldA(
-            DescriptorHandle: HDESC,
-            RecNumber: SQLSMALLINT,
-            FieldIdentifier: SQLSMALLINT,
-            ValuePtr: MutSQLPOINTER,
-            BufferLength: SQLINTEGER,
-            StringLengthPtr: *mut SQLINTEGER,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLGetDescFieldW(
-            DescriptorHandle: HDESC,
-            RecNumber: SQLSMALLINT,
-            FieldIdentifier: SQLSMALLINT,
-            ValuePtr: MutSQLPOINTER,
-            BufferLength: SQLINTEGER,
-            StringLengthPtr: *mut SQLINTEGER,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLGetDescRecA(
-            DescriptorHandle: HDESC,
-            RecNumber: SQLSMALLINT,
-            Name: *mut SQLCHAR,
-            BufferLength: SQLSMALLINT,
-            StringLengthPtr: *mut SQLSMALLINT,
-            TypePtr: *mut SQLSMALLINT,
-            SubTypePtr: *mut SQLSMALLINT,
-            LengthPtr: *mut SQLLEN,
-            PrecisionPtr: *mut SQLSMALLINT,
-            ScalePtr: *mut SQLSMALLINT,
-            NullablePtr: *mut SQLSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLGetDescRecW(
-            DescriptorHandle: HDESC,
-            RecNumber: SQLSMALLINT,
-            Name: *mut SQLWCHAR,
-            BufferLength: SQLSMALLINT,
-            StringLengthPtr: *mut SQLSMALLINT,
-            TypePtr: *mut SQLSMALLINT,
-            SubTypePtr: *mut SQLSMALLINT,
-            LengthPtr: *mut SQLLEN,
-            PrecisionPtr: *mut SQLSMALLINT,
-            ScalePtr: *mut SQLSMALLINT,
-            NullablePtr: *mut SQLSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLGetDiagFieldA(
-            HandleType: SQLSMALLINT,
-            Handle: SQLHANDLE,
-            RecNumber: SQLSMALLINT,
-            DiagIdentifier: SQLSMALLINT,
-            DiagInfoPtr: MutSQLPOINTER,
-            BufferLength: SQLSMALLINT,
-            StringLengthPtr: *mut SQLSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLGetDiagFieldW(
-            HandleType: SQLSMALLINT,
-            Handle: SQLHANDLE,
-            RecNumber: SQLSMALLINT,
-            DiagIdentifier: SQLSMALLINT,
-            DiagInfoPtr: MutSQLPOINTER,
-            BufferLength: SQLSMALLINT,
-            StringLengthPtr: *mut SQLSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLGetDiagRecA(
-            HandleType: SQLSMALLINT,
-            Handle: SQLHANDLE,
-            RecNumber: SQLSMALLINT,
-            SQLState: *mut [SQLCHAR; SQLSTATE_SIZE + 1],
-            NativeErrorPtr: *mut SQLINTEGER,
-            MessageText: *mut SQLCHAR,
-            BufferLength: SQLSMALLINT,
-            TextLengthPtr: *mut SQLSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLGetDiagRecW(
-            HandleType: SQLSMALLINT,
-            Handle: SQLHANDLE,
-            RecNumber: SQLSMALLINT,
-            SQLState: *mut [SQLWCHAR; SQLSTATE_SIZE + 1],
-            NativeErrorPtr: *mut SQLINTEGER,
-            MessageText: *mut SQLWCHAR,
-            BufferLength: SQLSMALLINT,
-            TextLengthPtr: *mut SQLSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLGetEnvAttr(
-            EnvironmentHandle: HENV,
-            Attribute: SQLINTEGER,
-            ValuePtr: MutSQLPOINTER,
-            BufferLength: SQLINTEGER,
-            StringLengthPtr: *mut SQLINTEGER,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLGetFunctions(
-            ConnectionHandle: HDBC,
-            FunctionId: SQLUSMALLINT,
-            SupportedPtr: *mut SQLUSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLGetInfoA(
-            ConnectionHandle: HDBC,
-            InfoType: SQLUSMALLINT,
-            InfoValuePtr: MutSQLPOINTER,
-            BufferLength: SQLSMALLINT,
-            StringLengthPtr: *mut SQLSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLGetInfoW(
-            ConnectionHandle: HDBC,
-            InfoType: SQLUSMALLINT,
-            InfoValuePtr: MutSQLPOINTER,
-            BufferLength: SQLSMALLINT,
-            StringLengthPtr: *mut SQLSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLGetStmtAttrA(
-            StatementHandle: HSTMT,
-            Attribute: SQLINTEGER,
-            ValuePtr: MutSQLPOINTER,
-            BufferLength: SQLINTEGER,
-            StringLengthPtr: *mut SQLINTEGER,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLGetStmtAttrW(
-            StatementHandle: HSTMT,
-            Attribute: SQLINTEGER,
-            ValuePtr: MutSQLPOINTER,
-            BufferLength: SQLINTEGER,
-            StringLengthPtr: *mut SQLINTEGER,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLGetTypeInfoA(StatementHandle: HSTMT, DataType: SQLSMALLINT) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLGetTypeInfoW(StatementHandle: HSTMT, DataType: SQLSMALLINT) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLMoreResults(StatementHandle: HSTMT) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLNativeSqlA(
-            ConnectionHandle: HDBC,
-            InStatementText: *const SQLCHAR,
-            TextLength1: SQLINTEGER,
-            OutStatementText: *mut SQLCHAR,
-            BufferLength: SQLINTEGER,
-            TextLength2Ptr: *mut SQLINTEGER,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLNativeSqlW(
-            ConnectionHandle: HDBC,
-            InStatementText: *const SQLWCHAR,
-            TextLength1: SQLINTEGER,
-            OutStatementText: *mut SQLWCHAR,
-            BufferLength: SQLINTEGER,
-            TextLength2Ptr: *mut SQLINTEGER,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLNumParams(
-            StatementHandle: HSTMT,
-            ParameterCountPtr: *mut SQLSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLNumResultCols(
-            StatementHandle: HSTMT,
-            ColumnCountPtr: *mut SQLSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLParamData(StatementHandle: HSTMT, ValuePtrPtr: *mut MutSQLPOINTER) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLPrepareA(
-            StatementHandle: HSTMT,
-            StatementText: *const SQLCHAR,
-            TextLength: SQLINTEGER,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLPrepareW(
-            StatementHandle: HSTMT,
-            StatementText: *const SQLWCHAR,
-            TextLength: SQLINTEGER,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLPrimaryKeysA(
-            StatementHandle: HSTMT,
-            CatalogName: *const SQLCHAR,
-            NameLength1: SQLSMALLINT,
-            SchemaName: *const SQLCHAR,
-            NameLength2: SQLSMALLINT,
-            TableName: *const SQLCHAR,
-            NameLength3: SQLSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLPrimaryKeysW(
-            StatementHandle: HSTMT,
-            CatalogName: *const SQLWCHAR,
-            NameLength1: SQLSMALLINT,
-            SchemaName: *const SQLWCHAR,
-            NameLength2: SQLSMALLINT,
-            TableName: *const SQLWCHAR,
-            NameLength3: SQLSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLProcedureColumnsA(
-            StatementHandle: HSTMT,
-            CatalogName: *const SQLCHAR,
-            NameLength1: SQLSMALLINT,
-            SchemaName: *const SQLCHAR,
-            NameLength2: SQLSMALLINT,
-            ProcName: *const SQLCHAR,
-            NameLength3: SQLSMALLINT,
-            ColumnName: *const SQLCHAR,
-            NameLength4: SQLSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLProcedureColumnsW(
-            StatementHandle: HSTMT,
-            CatalogName: *const SQLWCHAR,
-            NameLength1: SQLSMALLINT,
-            SchemaName: *const SQLWCHAR,
-            NameLength2: SQLSMALLINT,
-            ProcName: *const SQLWCHAR,
-            NameLength3: SQLSMALLINT,
-            ColumnName: *const SQLWCHAR,
-            NameLength4: SQLSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLProceduresA(
-            StatementHandle: HSTMT,
-            CatalogName: *const SQLCHAR,
-            NameLength1: SQLSMALLINT,
-            SchemaName: *const SQLCHAR,
-            NameLength2: SQLSMALLINT,
-            ProcName: *const SQLCHAR,
-            NameLength3: SQLSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLProceduresW(
-            StatementHandle: HSTMT,
-            CatalogName: *const SQLWCHAR,
-            NameLength1: SQLSMALLINT,
-            SchemaName: *const SQLWCHAR,
-            NameLength2: SQLSMALLINT,
-            ProcName: *const SQLWCHAR,
-            NameLength3: SQLSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLPutData(
-            StatementHandle: HSTMT,
-            DataPtr: ConstSQLPOINTER,
-            StrLen_or_Ind: SQLLEN,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLRowCount(StatementHandle: HSTMT, RowCountPtr: *mut SQLLEN) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLSetConnectAttrA(
-            ConnectionHandle: HDBC,
-            Attribute: SQLINTEGER,
-            ValuePtr: ConstSQLPOINTER,
-            StringLength: SQLINTEGER,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLSetConnectAttrW(
-            ConnectionHandle: HDBC,
-            Attribute: SQLINTEGER,
-            ValuePtr: ConstSQLPOINTER,
-            StringLength: SQLINTEGER,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLSetCursorNameA(
-            StatementHandle: HSTMT,
-            CursorName: *const SQLCHAR,
-            NameLength: SQLSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLSetCursorNameW(
-            StatementHandle: HSTMT,
-            CursorName: *const SQLWCHAR,
-            NameLength: SQLSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLSetDescFieldA(
-            DescriptorHandle: HDESC,
-            RecNumber: SQLSMALLINT,
-            FieldIdentifier: SQLSMALLINT,
-            ValuePtr: ConstSQLPOINTER,
-            BufferLength: SQLINTEGER,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLSetDescFieldW(
-            DescriptorHandle: HDESC,
-            RecNumber: SQLSMALLINT,
-            FieldIdentifier: SQLSMALLINT,
-            ValuePtr: ConstSQLPOINTER,
-            BufferLength: SQLINTEGER,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLSetDescRec(
-            DescriptorHandle: HDESC,
-            RecNumber: SQLSMALLINT,
-            Type: SQLSMALLINT,
-            SubType: SQLSMALLINT,
-            Length: SQLLEN,
-            Precision: SQLSMALLINT,
-            Scale: SQLSMALLINT,
-            DataPtr: MutSQLPOINTER,
-            StringLengthPtr: *mut SQLLEN,
-            IndicatorPtr: *mut SQLLEN,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLSetEnvAttr(
-            EnvironmentHandle: HENV,
-            Attribute: SQLINTEGER,
-            ValuePtr: ConstSQLPOINTER,
-            StringLength: SQLINTEGER,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLSetPos(
-            StatementHandle: HSTMT,
-            RowNumber: SQLSETPOSIROW,
-            Operation: SQLUSMALLINT,
-            LockType: SQLUSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLSetStmtAttrA(
-            StatementHandle: HSTMT,
-            Attribute: SQLINTEGER,
-            ValuePtr: ConstSQLPOINTER,
-            StringLength: SQLINTEGER,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLSetStmtAttrW(
-            StatementHandle: HSTMT,
-            Attribute: SQLINTEGER,
-            ValuePtr: ConstSQLPOINTER,
-            StringLength: SQLINTEGER,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLSpecialColumnsA(
-            StatementHandle: HSTMT,
-            IdentifierType: SQLSMALLINT,
-            CatalogName: *const SQLCHAR,
-            NameLength1: SQLSMALLINT,
-            SchemaName: *const SQLCHAR,
-            NameLength2: SQLSMALLINT,
-            TableName: *const SQLCHAR,
-            NameLength3: SQLSMALLINT,
-            Scope: SQLSMALLINT,
-            Nullable: SQLSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLSpecialColumnsW(
-            StatementHandle: HSTMT,
-            IdentifierType: SQLSMALLINT,
-            CatalogName: *const SQLWCHAR,
-            NameLength1: SQLSMALLINT,
-            SchemaName: *const SQLWCHAR,
-            NameLength2: SQLSMALLINT,
-            TableName: *const SQLWCHAR,
-            NameLength3: SQLSMALLINT,
-            Scope: SQLSMALLINT,
-            Nullable: SQLSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLStatisticsA(
-            StatementHandle: HSTMT,
-            CatalogName: *const SQLCHAR,
-            NameLength1: SQLSMALLINT,
-            SchemaName: *const SQLCHAR,
-            NameLength2: SQLSMALLINT,
-            TableName: *const SQLCHAR,
-            NameLength3: SQLSMALLINT,
-            Unique: SQLUSMALLINT,
-            Reserved: SQLUSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLStatisticsW(
-            StatementHandle: HSTMT,
-            CatalogName: *const SQLWCHAR,
-            NameLength1: SQLSMALLINT,
-            SchemaName: *const SQLWCHAR,
-            NameLength2: SQLSMALLINT,
-            TableName: *const SQLWCHAR,
-            NameLength3: SQLSMALLINT,
-            Unique: SQLUSMALLINT,
-            Reserved: SQLUSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLTablePrivilegesA(
-            StatementHandle: HSTMT,
-            CatalogName: *const SQLCHAR,
-            NameLength1: SQLSMALLINT,
-            SchemaName: *const SQLCHAR,
-            NameLength2: SQLSMALLINT,
-            TableName: *const SQLCHAR,
-            NameLength3: SQLSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLTablePrivilegesW(
-            StatementHandle: HSTMT,
-            CatalogName: *const SQLWCHAR,
-            NameLength1: SQLSMALLINT,
-            SchemaName: *const SQLWCHAR,
-            NameLength2: SQLSMALLINT,
-            TableName: *const SQLWCHAR,
-            NameLength3: SQLSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLTablesA(
-            StatementHandle: HSTMT,
-            CatalogName: *const SQLCHAR,
-            NameLength1: SQLSMALLINT,
-            SchemaName: *const SQLCHAR,
-            NameLength2: SQLSMALLINT,
-            TableName: *const SQLCHAR,
-            NameLength3: SQLSMALLINT,
-            TableType: *const SQLCHAR,
-            NameLength4: SQLSMALLINT,
-        ) -> SQLRETURN;
-
-        #[allow(non_snake_case)]
-        pub fn SQLTablesW(
-            StatementHandle: HSTMT,
-            CatalogName: *const SQLWCHAR,
-            NameLength1: SQLSMALLINT,
-            SchemaName: *const SQLWCHAR,
-            NameLength2: SQLSMALLINT,
-            TableName: *const SQLWCHAR,
-            NameLength3: SQLSMALLINT,
-            TableType: *const SQLWCHAR,
-            NameLength4: SQLSMALLINT,
-        ) -> SQLRETURN;
-    }
+    pub(super) type HDESC = SQLHANDLE;
+
+    pub(super) type ConstSQLPOINTER = *const core::ffi::c_void;
+    pub(super) type MutSQLPOINTER = *mut core::ffi::c_void;
+}
+
+ffi! {
+    #[allow(non_snake_case)]
+    pub fn SQLAllocHandle(
+        HandleType: SQLSMALLINT,
+        InputHandle: SQLHANDLE,
+        OutputHandlePtr: *mut SQLHANDLE,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLBindCol(
+        StatementHandle: HSTMT,
+        ColumnNumber: SQLUSMALLINT,
+        TargetType: SQLSMALLINT,
+        TargetValuePtr: MutSQLPOINTER,
+        BufferLength: SQLLEN,
+        StrLen_or_IndPtr: *mut SQLLEN,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLBindParameter(
+        StatementHandle: HSTMT,
+        ParameterNumber: SQLUSMALLINT,
+        InputOutputType: SQLSMALLINT,
+        ValueType: SQLSMALLINT,
+        ParameterType: SQLSMALLINT,
+        ColumnSize: SQLULEN,
+        DecimalDigits: SQLSMALLINT,
+        ParameterValuePtr: SQLPOINTER,
+        BufferLength: SQLLEN,
+        StrLen_or_IndPtr: *const SQLLEN,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLBrowseConnectA(
+        ConnectionHandle: HDBC,
+        InConnectionString: *const SQLCHAR,
+        StringLength1: SQLSMALLINT,
+        OutConnectionString: *mut SQLCHAR,
+        BufferLength: SQLSMALLINT,
+        StringLength2Ptr: *mut SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLBrowseConnectW(
+        ConnectionHandle: HDBC,
+        InConnectionString: *const SQLWCHAR,
+        StringLength1: SQLSMALLINT,
+        OutConnectionString: *mut SQLWCHAR,
+        BufferLength: SQLSMALLINT,
+        StringLength2Ptr: *mut SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLBulkOperations(StatementHandle: HSTMT, Operation: SQLUSMALLINT) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLCancel(StatementHandle: HSTMT) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLCancelHandle(HandleType: SQLSMALLINT, Handle: SQLHANDLE) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLCloseCursor(StatementHandle: HSTMT) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLColAttributeA(
+        StatementHandle: HSTMT,
+        ColumnNumber: SQLUSMALLINT,
+        FieldIdentifier: SQLUSMALLINT,
+        CharacterAttributePtr: MutSQLPOINTER,
+        BufferLength: SQLSMALLINT,
+        StringLengthPtr: *mut SQLSMALLINT,
+        NumericAttributePtr: *mut SQLLEN,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLColAttributeW(
+        StatementHandle: HSTMT,
+        ColumnNumber: SQLUSMALLINT,
+        FieldIdentifier: SQLUSMALLINT,
+        CharacterAttributePtr: MutSQLPOINTER,
+        BufferLength: SQLSMALLINT,
+        StringLengthPtr: *mut SQLSMALLINT,
+        NumericAttributePtr: *mut SQLLEN,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLColumnPrivilegesA(
+        StatementHandle: HSTMT,
+        CatalogName: *const SQLCHAR,
+        NameLength1: SQLSMALLINT,
+        SchemaName: *const SQLCHAR,
+        NameLength2: SQLSMALLINT,
+        TableName: *const SQLCHAR,
+        NameLength3: SQLSMALLINT,
+        ColumnName: *const SQLCHAR,
+        NameLength4: SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLColumnPrivilegesW(
+        StatementHandle: HSTMT,
+        CatalogName: *const SQLWCHAR,
+        NameLength1: SQLSMALLINT,
+        SchemaName: *const SQLWCHAR,
+        NameLength2: SQLSMALLINT,
+        TableName: *const SQLWCHAR,
+        NameLength3: SQLSMALLINT,
+        ColumnName: *const SQLWCHAR,
+        NameLength4: SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLColumnsA(
+        StatementHandle: HSTMT,
+        CatalogName: *const SQLCHAR,
+        NameLength1: SQLSMALLINT,
+        SchemaName: *const SQLCHAR,
+        NameLength2: SQLSMALLINT,
+        TableName: *const SQLCHAR,
+        NameLength3: SQLSMALLINT,
+        ColumnName: *const SQLCHAR,
+        NameLength4: SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLColumnsW(
+        StatementHandle: HSTMT,
+        CatalogName: *const SQLWCHAR,
+        NameLength1: SQLSMALLINT,
+        SchemaName: *const SQLWCHAR,
+        NameLength2: SQLSMALLINT,
+        TableName: *const SQLWCHAR,
+        NameLength3: SQLSMALLINT,
+        ColumnName: *const SQLWCHAR,
+        NameLength4: SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLCompleteAsync(
+        HandleType: SQLSMALLINT,
+        Handle: SQLHANDLE,
+        AsyncRetCodePtr: *mut RETCODE,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLConnectA(
+        ConnectionHandle: HDBC,
+        ServerName: *const SQLCHAR,
+        NameLength1: SQLSMALLINT,
+        UserName: *const SQLCHAR,
+        NameLength2: SQLSMALLINT,
+        Authentication: *const SQLCHAR,
+        NameLength3: SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLConnectW(
+        ConnectionHandle: HDBC,
+        ServerName: *const SQLWCHAR,
+        NameLength1: SQLSMALLINT,
+        UserName: *const SQLWCHAR,
+        NameLength2: SQLSMALLINT,
+        Authentication: *const SQLWCHAR,
+        NameLength3: SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLCopyDesc(SourceDescHandle: HDESC, TargetDescHandle: HDESC) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLDataSourcesA(
+        EnvironmentHandle: HENV,
+        Direction: SQLUSMALLINT,
+        ServerName: *mut SQLCHAR,
+        BufferLength1: SQLSMALLINT,
+        NameLength1Ptr: *mut SQLSMALLINT,
+        Description: *mut SQLCHAR,
+        BufferLength2: SQLSMALLINT,
+        NameLength2Ptr: *mut SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLDataSourcesW(
+        EnvironmentHandle: HENV,
+        Direction: SQLUSMALLINT,
+        ServerName: *mut SQLWCHAR,
+        BufferLength1: SQLSMALLINT,
+        NameLength1Ptr: *mut SQLSMALLINT,
+        Description: *mut SQLWCHAR,
+        BufferLength2: SQLSMALLINT,
+        NameLength2Ptr: *mut SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLDescribeColA(
+        StatementHandle: HSTMT,
+        ColumnNumber: SQLUSMALLINT,
+        ColumnName: *mut SQLCHAR,
+        BufferLength: SQLSMALLINT,
+        NameLengthPtr: *mut SQLSMALLINT,
+        DataTypePtr: *mut SQLSMALLINT,
+        ColumnSizePtr: *mut SQLULEN,
+        DecimalDigitsPtr: *mut SQLSMALLINT,
+        NullablePtr: *mut SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLDescribeColW(
+        StatementHandle: HSTMT,
+        ColumnNumber: SQLUSMALLINT,
+        ColumnName: *mut SQLWCHAR,
+        BufferLength: SQLSMALLINT,
+        NameLengthPtr: *mut SQLSMALLINT,
+        DataTypePtr: *mut SQLSMALLINT,
+        ColumnSizePtr: *mut SQLULEN,
+        DecimalDigitsPtr: *mut SQLSMALLINT,
+        NullablePtr: *mut SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLDescribeParam(
+        StatementHandle: HSTMT,
+        ParameterNumber: SQLUSMALLINT,
+        DataTypePtr: *mut SQLSMALLINT,
+        ParameterSizePtr: *mut SQLULEN,
+        DecimalDigitsPtr: *mut SQLSMALLINT,
+        NullablePtr: *mut SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLDisconnect(ConnectionHandle: HDBC) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLDriverConnectA(
+        ConnectionHandle: HDBC,
+        WindowHandle: SQLHWND,
+        InConnectionString: *const SQLCHAR,
+        StringLength1: SQLSMALLINT,
+        OutConnectionString: *mut SQLCHAR,
+        BufferLength: SQLSMALLINT,
+        StringLength2Ptr: *mut SQLSMALLINT,
+        DriverCompletion: SQLUSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLDriverConnectW(
+        ConnectionHandle: HDBC,
+        WindowHandle: SQLHWND,
+        InConnectionString: *const SQLWCHAR,
+        StringLength1: SQLSMALLINT,
+        OutConnectionString: *mut SQLWCHAR,
+        BufferLength: SQLSMALLINT,
+        StringLength2Ptr: *mut SQLSMALLINT,
+        DriverCompletion: SQLUSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLDriversA(
+        EnvironmentHandle: HENV,
+        Direction: SQLUSMALLINT,
+        DriverDescription: *mut SQLCHAR,
+        BufferLength1: SQLSMALLINT,
+        DescriptionLengthPtr: *mut SQLSMALLINT,
+        DriverAttributes: *mut SQLCHAR,
+        BufferLength2: SQLSMALLINT,
+        AttributesLengthPtr: *mut SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLDriversW(
+        EnvironmentHandle: HENV,
+        Direction: SQLUSMALLINT,
+        DriverDescription: *mut SQLWCHAR,
+        BufferLength1: SQLSMALLINT,
+        DescriptionLengthPtr: *mut SQLSMALLINT,
+        DriverAttributes: *mut SQLWCHAR,
+        BufferLength2: SQLSMALLINT,
+        AttributesLengthPtr: *mut SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLEndTran(
+        HandleType: SQLSMALLINT,
+        Handle: SQLHANDLE,
+        CompletionType: SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLExecDirectA(
+        StatementHandle: HSTMT,
+        StatementText: *const SQLCHAR,
+        TextLength: SQLINTEGER,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLExecDirectW(
+        StatementHandle: HSTMT,
+        StatementText: *const SQLWCHAR,
+        TextLength: SQLINTEGER,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLExecute(StatementHandle: HSTMT) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLFetch(StatementHandle: HSTMT) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLFetchScroll(
+        StatementHandle: HSTMT,
+        FetchOrientation: SQLSMALLINT,
+        FetchOffset: SQLLEN,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLForeignKeysA(
+        StatementHandle: HSTMT,
+        PKCatalogName: *const SQLCHAR,
+        NameLength1: SQLSMALLINT,
+        PKSchemaName: *const SQLCHAR,
+        NameLength2: SQLSMALLINT,
+        PKTableName: *const SQLCHAR,
+        NameLength3: SQLSMALLINT,
+        FKCatalogName: *const SQLCHAR,
+        NameLength4: SQLSMALLINT,
+        FKSchemaName: *const SQLCHAR,
+        NameLength5: SQLSMALLINT,
+        FKTableName: *const SQLCHAR,
+        NameLength6: SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLForeignKeysW(
+        StatementHandle: HSTMT,
+        PKCatalogName: *const SQLWCHAR,
+        NameLength1: SQLSMALLINT,
+        PKSchemaName: *const SQLWCHAR,
+        NameLength2: SQLSMALLINT,
+        PKTableName: *const SQLWCHAR,
+        NameLength3: SQLSMALLINT,
+        FKCatalogName: *const SQLWCHAR,
+        NameLength4: SQLSMALLINT,
+        FKSchemaName: *const SQLWCHAR,
+        NameLength5: SQLSMALLINT,
+        FKTableName: *const SQLWCHAR,
+        NameLength6: SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLFreeHandle(HandleType: SQLSMALLINT, Handle: SQLHANDLE) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLFreeStmt(StatementHandle: HSTMT, Option: SQLUSMALLINT) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLGetConnectAttrA(
+        ConnectionHandle: HDBC,
+        Attribute: SQLINTEGER,
+        ValuePtr: MutSQLPOINTER,
+        BufferLength: SQLINTEGER,
+        StringLengthPtr: *mut SQLINTEGER,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLGetConnectAttrW(
+        ConnectionHandle: HDBC,
+        Attribute: SQLINTEGER,
+        ValuePtr: MutSQLPOINTER,
+        BufferLength: SQLINTEGER,
+        StringLengthPtr: *mut SQLINTEGER,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLGetCursorNameA(
+        StatementHandle: HSTMT,
+        CursorName: *mut SQLCHAR,
+        BufferLength: SQLSMALLINT,
+        NameLengthPtr: *mut SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLGetCursorNameW(
+        StatementHandle: HSTMT,
+        CursorName: *mut SQLWCHAR,
+        BufferLength: SQLSMALLINT,
+        NameLengthPtr: *mut SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLGetData(
+        StatementHandle: HSTMT,
+        Col_or_Param_Num: SQLUSMALLINT,
+        TargetType: SQLSMALLINT,
+        TargetValuePtr: MutSQLPOINTER,
+        BufferLength: SQLLEN,
+        StrLen_or_IndPtr: *mut SQLLEN,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLGetDescFieldA(
+        DescriptorHandle: HDESC,
+        RecNumber: SQLSMALLINT,
+        FieldIdentifier: SQLSMALLINT,
+        ValuePtr: MutSQLPOINTER,
+        BufferLength: SQLINTEGER,
+        StringLengthPtr: *mut SQLINTEGER,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLGetDescFieldW(
+        DescriptorHandle: HDESC,
+        RecNumber: SQLSMALLINT,
+        FieldIdentifier: SQLSMALLINT,
+        ValuePtr: MutSQLPOINTER,
+        BufferLength: SQLINTEGER,
+        StringLengthPtr: *mut SQLINTEGER,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLGetDescRecA(
+        DescriptorHandle: HDESC,
+        RecNumber: SQLSMALLINT,
+        Name: *mut SQLCHAR,
+        BufferLength: SQLSMALLINT,
+        StringLengthPtr: *mut SQLSMALLINT,
+        TypePtr: *mut SQLSMALLINT,
+        SubTypePtr: *mut SQLSMALLINT,
+        LengthPtr: *mut SQLLEN,
+        PrecisionPtr: *mut SQLSMALLINT,
+        ScalePtr: *mut SQLSMALLINT,
+        NullablePtr: *mut SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLGetDescRecW(
+        DescriptorHandle: HDESC,
+        RecNumber: SQLSMALLINT,
+        Name: *mut SQLWCHAR,
+        BufferLength: SQLSMALLINT,
+        StringLengthPtr: *mut SQLSMALLINT,
+        TypePtr: *mut SQLSMALLINT,
+        SubTypePtr: *mut SQLSMALLINT,
+        LengthPtr: *mut SQLLEN,
+        PrecisionPtr: *mut SQLSMALLINT,
+        ScalePtr: *mut SQLSMALLINT,
+        NullablePtr: *mut SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLGetDiagFieldA(
+        HandleType: SQLSMALLINT,
+        Handle: SQLHANDLE,
+        RecNumber: SQLSMALLINT,
+        DiagIdentifier: SQLSMALLINT,
+        DiagInfoPtr: MutSQLPOINTER,
+        BufferLength: SQLSMALLINT,
+        StringLengthPtr: *mut SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLGetDiagFieldW(
+        HandleType: SQLSMALLINT,
+        Handle: SQLHANDLE,
+        RecNumber: SQLSMALLINT,
+        DiagIdentifier: SQLSMALLINT,
+        DiagInfoPtr: MutSQLPOINTER,
+        BufferLength: SQLSMALLINT,
+        StringLengthPtr: *mut SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLGetDiagRecA(
+        HandleType: SQLSMALLINT,
+        Handle: SQLHANDLE,
+        RecNumber: SQLSMALLINT,
+        SQLState: *mut [SQLCHAR; SQLSTATE_SIZE + 1],
+        NativeErrorPtr: *mut SQLINTEGER,
+        MessageText: *mut SQLCHAR,
+        BufferLength: SQLSMALLINT,
+        TextLengthPtr: *mut SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLGetDiagRecW(
+        HandleType: SQLSMALLINT,
+        Handle: SQLHANDLE,
+        RecNumber: SQLSMALLINT,
+        SQLState: *mut [SQLWCHAR; SQLSTATE_SIZE + 1],
+        NativeErrorPtr: *mut SQLINTEGER,
+        MessageText: *mut SQLWCHAR,
+        BufferLength: SQLSMALLINT,
+        TextLengthPtr: *mut SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLGetEnvAttr(
+        EnvironmentHandle: HENV,
+        Attribute: SQLINTEGER,
+        ValuePtr: MutSQLPOINTER,
+        BufferLength: SQLINTEGER,
+        StringLengthPtr: *mut SQLINTEGER,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLGetFunctions(
+        ConnectionHandle: HDBC,
+        FunctionId: SQLUSMALLINT,
+        SupportedPtr: *mut SQLUSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLGetInfoA(
+        ConnectionHandle: HDBC,
+        InfoType: SQLUSMALLINT,
+        InfoValuePtr: MutSQLPOINTER,
+        BufferLength: SQLSMALLINT,
+        StringLengthPtr: *mut SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLGetInfoW(
+        ConnectionHandle: HDBC,
+        InfoType: SQLUSMALLINT,
+        InfoValuePtr: MutSQLPOINTER,
+        BufferLength: SQLSMALLINT,
+        StringLengthPtr: *mut SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLGetStmtAttrA(
+        StatementHandle: HSTMT,
+        Attribute: SQLINTEGER,
+        ValuePtr: MutSQLPOINTER,
+        BufferLength: SQLINTEGER,
+        StringLengthPtr: *mut SQLINTEGER,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLGetStmtAttrW(
+        StatementHandle: HSTMT,
+        Attribute: SQLINTEGER,
+        ValuePtr: MutSQLPOINTER,
+        BufferLength: SQLINTEGER,
+        StringLengthPtr: *mut SQLINTEGER,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLGetTypeInfoA(StatementHandle: HSTMT, DataType: SQLSMALLINT) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLGetTypeInfoW(StatementHandle: HSTMT, DataType: SQLSMALLINT) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLMoreResults(StatementHandle: HSTMT) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLNativeSqlA(
+        ConnectionHandle: HDBC,
+        InStatementText: *const SQLCHAR,
+        TextLength1: SQLINTEGER,
+        OutStatementText: *mut SQLCHAR,
+        BufferLength: SQLINTEGER,
+        TextLength2Ptr: *mut SQLINTEGER,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLNativeSqlW(
+        ConnectionHandle: HDBC,
+        InStatementText: *const SQLWCHAR,
+        TextLength1: SQLINTEGER,
+        OutStatementText: *mut SQLWCHAR,
+        BufferLength: SQLINTEGER,
+        TextLength2Ptr: *mut SQLINTEGER,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLNumParams(
+        StatementHandle: HSTMT,
+        ParameterCountPtr: *mut SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLNumResultCols(
+        StatementHandle: HSTMT,
+        ColumnCountPtr: *mut SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLParamData(StatementHandle: HSTMT, ValuePtrPtr: *mut MutSQLPOINTER) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLPrepareA(
+        StatementHandle: HSTMT,
+        StatementText: *const SQLCHAR,
+        TextLength: SQLINTEGER,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLPrepareW(
+        StatementHandle: HSTMT,
+        StatementText: *const SQLWCHAR,
+        TextLength: SQLINTEGER,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLPrimaryKeysA(
+        StatementHandle: HSTMT,
+        CatalogName: *const SQLCHAR,
+        NameLength1: SQLSMALLINT,
+        SchemaName: *const SQLCHAR,
+        NameLength2: SQLSMALLINT,
+        TableName: *const SQLCHAR,
+        NameLength3: SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLPrimaryKeysW(
+        StatementHandle: HSTMT,
+        CatalogName: *const SQLWCHAR,
+        NameLength1: SQLSMALLINT,
+        SchemaName: *const SQLWCHAR,
+        NameLength2: SQLSMALLINT,
+        TableName: *const SQLWCHAR,
+        NameLength3: SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLProcedureColumnsA(
+        StatementHandle: HSTMT,
+        CatalogName: *const SQLCHAR,
+        NameLength1: SQLSMALLINT,
+        SchemaName: *const SQLCHAR,
+        NameLength2: SQLSMALLINT,
+        ProcName: *const SQLCHAR,
+        NameLength3: SQLSMALLINT,
+        ColumnName: *const SQLCHAR,
+        NameLength4: SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLProcedureColumnsW(
+        StatementHandle: HSTMT,
+        CatalogName: *const SQLWCHAR,
+        NameLength1: SQLSMALLINT,
+        SchemaName: *const SQLWCHAR,
+        NameLength2: SQLSMALLINT,
+        ProcName: *const SQLWCHAR,
+        NameLength3: SQLSMALLINT,
+        ColumnName: *const SQLWCHAR,
+        NameLength4: SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLProceduresA(
+        StatementHandle: HSTMT,
+        CatalogName: *const SQLCHAR,
+        NameLength1: SQLSMALLINT,
+        SchemaName: *const SQLCHAR,
+        NameLength2: SQLSMALLINT,
+        ProcName: *const SQLCHAR,
+        NameLength3: SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLProceduresW(
+        StatementHandle: HSTMT,
+        CatalogName: *const SQLWCHAR,
+        NameLength1: SQLSMALLINT,
+        SchemaName: *const SQLWCHAR,
+        NameLength2: SQLSMALLINT,
+        ProcName: *const SQLWCHAR,
+        NameLength3: SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLPutData(
+        StatementHandle: HSTMT,
+        DataPtr: ConstSQLPOINTER,
+        StrLen_or_Ind: SQLLEN,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLRowCount(StatementHandle: HSTMT, RowCountPtr: *mut SQLLEN) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLSetConnectAttrA(
+        ConnectionHandle: HDBC,
+        Attribute: SQLINTEGER,
+        ValuePtr: ConstSQLPOINTER,
+        StringLength: SQLINTEGER,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLSetConnectAttrW(
+        ConnectionHandle: HDBC,
+        Attribute: SQLINTEGER,
+        ValuePtr: ConstSQLPOINTER,
+        StringLength: SQLINTEGER,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLSetCursorNameA(
+        StatementHandle: HSTMT,
+        CursorName: *const SQLCHAR,
+        NameLength: SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLSetCursorNameW(
+        StatementHandle: HSTMT,
+        CursorName: *const SQLWCHAR,
+        NameLength: SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLSetDescFieldA(
+        DescriptorHandle: HDESC,
+        RecNumber: SQLSMALLINT,
+        FieldIdentifier: SQLSMALLINT,
+        ValuePtr: ConstSQLPOINTER,
+        BufferLength: SQLINTEGER,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLSetDescFieldW(
+        DescriptorHandle: HDESC,
+        RecNumber: SQLSMALLINT,
+        FieldIdentifier: SQLSMALLINT,
+        ValuePtr: ConstSQLPOINTER,
+        BufferLength: SQLINTEGER,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLSetDescRec(
+        DescriptorHandle: HDESC,
+        RecNumber: SQLSMALLINT,
+        Type: SQLSMALLINT,
+        SubType: SQLSMALLINT,
+        Length: SQLLEN,
+        Precision: SQLSMALLINT,
+        Scale: SQLSMALLINT,
+        DataPtr: MutSQLPOINTER,
+        StringLengthPtr: *mut SQLLEN,
+        IndicatorPtr: *mut SQLLEN,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLSetEnvAttr(
+        EnvironmentHandle: HENV,
+        Attribute: SQLINTEGER,
+        ValuePtr: ConstSQLPOINTER,
+        StringLength: SQLINTEGER,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLSetPos(
+        StatementHandle: HSTMT,
+        RowNumber: SQLSETPOSIROW,
+        Operation: SQLUSMALLINT,
+        LockType: SQLUSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLSetStmtAttrA(
+        StatementHandle: HSTMT,
+        Attribute: SQLINTEGER,
+        ValuePtr: ConstSQLPOINTER,
+        StringLength: SQLINTEGER,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLSetStmtAttrW(
+        StatementHandle: HSTMT,
+        Attribute: SQLINTEGER,
+        ValuePtr: ConstSQLPOINTER,
+        StringLength: SQLINTEGER,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLSpecialColumnsA(
+        StatementHandle: HSTMT,
+        IdentifierType: SQLSMALLINT,
+        CatalogName: *const SQLCHAR,
+        NameLength1: SQLSMALLINT,
+        SchemaName: *const SQLCHAR,
+        NameLength2: SQLSMALLINT,
+        TableName: *const SQLCHAR,
+        NameLength3: SQLSMALLINT,
+        Scope: SQLSMALLINT,
+        Nullable: SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLSpecialColumnsW(
+        StatementHandle: HSTMT,
+        IdentifierType: SQLSMALLINT,
+        CatalogName: *const SQLWCHAR,
+        NameLength1: SQLSMALLINT,
+        SchemaName: *const SQLWCHAR,
+        NameLength2: SQLSMALLINT,
+        TableName: *const SQLWCHAR,
+        NameLength3: SQLSMALLINT,
+        Scope: SQLSMALLINT,
+        Nullable: SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLStatisticsA(
+        StatementHandle: HSTMT,
+        CatalogName: *const SQLCHAR,
+        NameLength1: SQLSMALLINT,
+        SchemaName: *const SQLCHAR,
+        NameLength2: SQLSMALLINT,
+        TableName: *const SQLCHAR,
+        NameLength3: SQLSMALLINT,
+        Unique: SQLUSMALLINT,
+        Reserved: SQLUSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLStatisticsW(
+        StatementHandle: HSTMT,
+        CatalogName: *const SQLWCHAR,
+        NameLength1: SQLSMALLINT,
+        SchemaName: *const SQLWCHAR,
+        NameLength2: SQLSMALLINT,
+        TableName: *const SQLWCHAR,
+        NameLength3: SQLSMALLINT,
+        Unique: SQLUSMALLINT,
+        Reserved: SQLUSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLTablePrivilegesA(
+        StatementHandle: HSTMT,
+        CatalogName: *const SQLCHAR,
+        NameLength1: SQLSMALLINT,
+        SchemaName: *const SQLCHAR,
+        NameLength2: SQLSMALLINT,
+        TableName: *const SQLCHAR,
+        NameLength3: SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLTablePrivilegesW(
+        StatementHandle: HSTMT,
+        CatalogName: *const SQLWCHAR,
+        NameLength1: SQLSMALLINT,
+        SchemaName: *const SQLWCHAR,
+        NameLength2: SQLSMALLINT,
+        TableName: *const SQLWCHAR,
+        NameLength3: SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLTablesA(
+        StatementHandle: HSTMT,
+        CatalogName: *const SQLCHAR,
+        NameLength1: SQLSMALLINT,
+        SchemaName: *const SQLCHAR,
+        NameLength2: SQLSMALLINT,
+        TableName: *const SQLCHAR,
+        NameLength3: SQLSMALLINT,
+        TableType: *const SQLCHAR,
+        NameLength4: SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    #[allow(non_snake_case)]
+    pub fn SQLTablesW(
+        StatementHandle: HSTMT,
+        CatalogName: *const SQLWCHAR,
+        NameLength1: SQLSMALLINT,
+        SchemaName: *const SQLWCHAR,
+        NameLength2: SQLSMALLINT,
+        TableName: *const SQLWCHAR,
+        NameLength3: SQLSMALLINT,
+        TableType: *const SQLWCHAR,
+        NameLength4: SQLSMALLINT,
+    ) -> SQLRETURN;
 }
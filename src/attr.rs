@@ -389,39 +389,36 @@ unsafe impl<T: Scalar> StrLen<T> for MaybeUninit<Void> {
     }
 }
 
+// Drivers are allowed to write fewer bytes than the size of the integer attribute
+const ZEROED_MSG: &str = "runtime_checks: integer attribute value must be zero-initialized because the driver may only write to a part of it";
+
 impl AttrZeroAssert for SQLSMALLINT {
     fn assert_zeroed(&self) {
-        // TODO: Add custom message
-        assert_eq!(0, *self);
+        assert_eq!(0, *self, "{ZEROED_MSG}");
     }
 }
 impl AttrZeroAssert for SQLUSMALLINT {
     fn assert_zeroed(&self) {
-        // TODO: Add custom message
-        assert_eq!(0, *self);
+        assert_eq!(0, *self, "{ZEROED_MSG}");
     }
 }
 impl AttrZeroAssert for SQLINTEGER {
     fn assert_zeroed(&self) {
-        // TODO: Add custom message
-        assert_eq!(0, *self);
+        assert_eq!(0, *self, "{ZEROED_MSG}");
     }
 }
 impl AttrZeroAssert for SQLUINTEGER {
     fn assert_zeroed(&self) {
-        // TODO: Add custom message
-        assert_eq!(0, *self);
+        assert_eq!(0, *self, "{ZEROED_MSG}");
     }
 }
 impl AttrZeroAssert for SQLLEN {
     fn assert_zeroed(&self) {
-        // TODO: Add custom message
-        assert_eq!(0, *self);
+        assert_eq!(0, *self, "{ZEROED_MSG}");
     }
 }
 impl AttrZeroAssert for SQLULEN {
     fn assert_zeroed(&self) {
-        // TODO: Add custom message
-        assert_eq!(0, *self);
+        assert_eq!(0, *self, "{ZEROED_MSG}");
     }
 }
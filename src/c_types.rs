@@ -7,9 +7,9 @@ use crate::sql_types::*;
 use crate::str::{OdbcChar, OdbcStr};
 use crate::{
     SQLBIGINT, SQLCHAR, SQLDOUBLE, SQLINTEGER, SQLLEN, SQLREAL, SQLSCHAR, SQLSMALLINT, SQLUBIGINT,
-    SQLUINTEGER, SQLUSMALLINT, SQLWCHAR,
+    SQLUINTEGER, SQLULEN, SQLUSMALLINT, SQLWCHAR,
 };
-use core::{cell::UnsafeCell, mem::MaybeUninit};
+use core::{cell::UnsafeCell, mem::MaybeUninit, time::Duration};
 
 pub trait CData<TT: Ident, V: OdbcVersion>: CDataLen {}
 
@@ -63,6 +63,90 @@ unsafe impl<DT, TT: Ident, V: OdbcVersion> DeferredBuf<RefUnsafeSQLHDESC<'_, DT,
 {
 }
 
+/// Buffer of a column bound with column-wise binding which holds a value for every row of
+/// the rowset
+///
+/// # Safety
+///
+/// Buffer must hold [`ColumnBuf::rows`] contiguous elements whose length in bytes is
+/// [`ColumnBuf::element_len`] or the size of the C type if the type is of fixed length
+pub unsafe trait ColumnBuf<TT: Ident, V: OdbcVersion>: AsSQLPOINTER {
+    /// Number of rows the buffer can hold
+    fn rows(&self) -> SQLULEN;
+
+    /// Length of a single element in bytes as passed in BufferLength of SQLBindCol
+    fn element_len(&self) -> SQLLEN;
+}
+unsafe impl<TT: Ident, T: CScalar, V: OdbcVersion> ColumnBuf<TT, V> for [UnsafeCell<T>]
+where
+    T: CData<TT, V>,
+{
+    fn rows(&self) -> SQLULEN {
+        self.len()
+    }
+
+    fn element_len(&self) -> SQLLEN {
+        // Driver ignores the length of fixed-length data types
+        0
+    }
+}
+/// Column of character data where every row holds at most `N` characters including the
+/// null-terminator
+unsafe impl<TT: Ident, CH: OdbcChar, V: OdbcVersion, const N: usize> ColumnBuf<TT, V>
+    for [UnsafeCell<[CH; N]>]
+where
+    OdbcStr<CH>: CData<TT, V>,
+{
+    fn rows(&self) -> SQLULEN {
+        self.len()
+    }
+
+    fn element_len(&self) -> SQLLEN {
+        size_of::<[CH; N]>()
+            .try_into()
+            .expect("Buffer length greater than SQLLEN max")
+    }
+}
+
+/// Row of a rowset bound with row-wise binding. Columns are bound to fields of the first row
+/// and the driver locates the fields of other rows by adding multiples of the size of the row
+///
+/// # Safety
+///
+/// Every field of the row must be an `UnsafeCell` of a C data type or of [`StrLenOrInd`] so
+/// that the driver can write any bytes to any of the fields through a shared reference
+pub unsafe trait RowBuf: 'static {}
+
+/// Heap allocated buffer which can be handed over to the statement it is bound to.
+/// Statement owns the buffer until it is unbound so that no `UnsafeCell` is required
+pub trait OwnedBuf: Send + 'static {
+    /// Type of the buffer as it is bound to the statement
+    type Bound: ?Sized;
+
+    fn as_bound(buf: &UnsafeCell<Self>) -> &Self::Bound;
+}
+impl<T: CScalar + Send + 'static> OwnedBuf for T {
+    type Bound = UnsafeCell<T>;
+
+    fn as_bound(buf: &UnsafeCell<Self>) -> &Self::Bound {
+        buf
+    }
+}
+impl OwnedBuf for [SQLCHAR] {
+    type Bound = OdbcStr<UnsafeCell<SQLCHAR>>;
+
+    fn as_bound(buf: &UnsafeCell<Self>) -> &Self::Bound {
+        buf.as_ref()
+    }
+}
+impl OwnedBuf for [SQLWCHAR] {
+    type Bound = OdbcStr<UnsafeCell<SQLWCHAR>>;
+
+    fn as_bound(buf: &UnsafeCell<Self>) -> &Self::Bound {
+        buf.as_ref()
+    }
+}
+
 #[repr(transparent)]
 #[derive(Clone, Copy)]
 pub struct StrLenOrInd(pub(crate) SQLLEN);
@@ -83,11 +167,53 @@ pub const SQL_NTS: StrLenOrInd = StrLenOrInd(-3);
 pub const SQL_DATA_AT_EXEC: StrLenOrInd = StrLenOrInd(-2);
 pub const SQL_COLUMN_IGNORE: StrLenOrInd = StrLenOrInd(-6);
 
-// TODO: Do something
-// pub fn SQL_LEN_DATA_AT_EXEC(length: LEN) -> LEN {
-//    const SQL_LEN_DATA_AT_EXEC_OFFSET: LEN = -100;
-//    (-length).checked_add(SQL_LEN_DATA_AT_EXEC_OFFSET).expect()
-//}
+const SQL_LEN_DATA_AT_EXEC_OFFSET: SQLLEN = -100;
+/// Data is sent at statement execution time and its total length is `length` bytes
+#[expect(non_snake_case)]
+pub const fn SQL_LEN_DATA_AT_EXEC(length: SQLLEN) -> StrLenOrInd {
+    if length < 0 {
+        panic!("length must be non-negative");
+    }
+
+    match SQL_LEN_DATA_AT_EXEC_OFFSET.checked_sub(length) {
+        Some(len) => StrLenOrInd(len),
+        None => panic!("length too large"),
+    }
+}
+
+/// Length/indicator of a parameter bound with [`crate::handle::SQLHSTMT::bind_param_at_exec`]
+/// whose data is sent in parts at statement execution time. Data sources which return "Y" for
+/// SQL_NEED_LONG_DATA_LEN require the total length of the data sent to parameters of
+/// SQL_LONGVARCHAR and SQL_LONGVARBINARY types.
+#[repr(transparent)]
+pub struct DataAtExec(UnsafeCell<StrLenOrInd>);
+impl DataAtExec {
+    /// Total length of the data is not known before it is sent
+    pub const fn new() -> Self {
+        Self(UnsafeCell::new(SQL_DATA_AT_EXEC))
+    }
+
+    /// Total length of the data is `len` bytes
+    pub const fn with_len(len: SQLLEN) -> Self {
+        Self(UnsafeCell::new(SQL_LEN_DATA_AT_EXEC(len)))
+    }
+
+    /// Returns the total length of the data or `None` if it's not known
+    pub fn data_len(&self) -> Option<SQLLEN> {
+        // SAFETY: Driver doesn't write to the length/indicator of input parameters
+        let StrLenOrInd(ind) = unsafe { *self.0.get() };
+        (ind <= SQL_LEN_DATA_AT_EXEC_OFFSET).then(|| SQL_LEN_DATA_AT_EXEC_OFFSET - ind)
+    }
+
+    pub(crate) fn as_ptr(&self) -> *mut StrLenOrInd {
+        self.0.get()
+    }
+}
+impl Default for DataAtExec {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 // SQLBindParameter only
 pub const SQL_DEFAULT_PARAM: StrLenOrInd = StrLenOrInd(-5);
@@ -235,6 +361,7 @@ impl Ident for SQL_C_GUID {
     const IDENTIFIER: Self::Type = SqlTypeV3_8::identifier(&SQL_GUID);
 }
 impl CData<SQL_C_GUID, SQL_OV_ODBC3_80> for SQLGUID {}
+impl CData<SQL_C_GUID, SQL_OV_ODBC4> for SQLGUID {}
 
 #[expect(non_camel_case_types)]
 pub struct SQL_C_TYPE_DATE;
@@ -490,22 +617,178 @@ pub struct SQL_TIMESTAMP_WITH_TIMEZONE_STRUCT {
 #[allow(non_camel_case_types)]
 #[derive(Clone, Copy)]
 pub struct SQL_INTERVAL_STRUCT {
-    pub interval_type: SQLINTERVAL,
+    // Not public because it selects the member of `intval` which is initialized
+    pub(crate) interval_type: SQLINTERVAL,
+    /// The sign field is 1 if negative, 0 if positive.
     pub interval_sign: SQLSMALLINT,
-    // TODO: Make public
-    interval: IntervalUnion,
+    /// Member of the union which is in use is determined by the `interval_type`
+    pub(crate) intval: IntervalUnion,
+}
+impl SQL_INTERVAL_STRUCT {
+    /// Creates an interval of one of the year-month kinds. Returns `None` if `interval_type`
+    /// is not a year-month kind
+    pub fn from_year_month(
+        interval_type: SQLINTERVAL,
+        negative: bool,
+        year_month: SQL_YEAR_MONTH_STRUCT,
+    ) -> Option<Self> {
+        interval_type.is_year_month().then_some(Self {
+            interval_type,
+            interval_sign: SQLSMALLINT::from(negative),
+            intval: IntervalUnion { year_month },
+        })
+    }
+
+    /// Creates an interval of one of the day-time kinds. Returns `None` if `interval_type`
+    /// is not a day-time kind
+    pub fn from_day_second(
+        interval_type: SQLINTERVAL,
+        negative: bool,
+        day_second: SQL_DAY_SECOND_STRUCT,
+    ) -> Option<Self> {
+        (!interval_type.is_year_month()).then_some(Self {
+            interval_type,
+            interval_sign: SQLSMALLINT::from(negative),
+            intval: IntervalUnion { day_second },
+        })
+    }
+
+    /// Creates SQL_IS_YEAR_TO_MONTH interval from the total number of months
+    pub fn from_months(months: SQLINTEGER) -> Self {
+        let abs = months.unsigned_abs();
+
+        Self {
+            interval_type: SQLINTERVAL::SQL_IS_YEAR_TO_MONTH,
+            interval_sign: SQLSMALLINT::from(months < 0),
+            intval: IntervalUnion {
+                year_month: SQL_YEAR_MONTH_STRUCT {
+                    year: abs / 12,
+                    month: abs % 12,
+                },
+            },
+        }
+    }
+
+    pub fn interval_type(&self) -> SQLINTERVAL {
+        self.interval_type
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.interval_sign != 0
+    }
+
+    /// Returns the value of the interval if it's of one of the year-month kinds
+    pub fn year_month(&self) -> Option<&SQL_YEAR_MONTH_STRUCT> {
+        if !self.interval_type.is_year_month() {
+            return None;
+        }
+
+        // SAFETY: Union member is selected by the interval type
+        Some(unsafe { &self.intval.year_month })
+    }
+
+    /// Returns the value of the interval if it's of one of the day-time kinds
+    pub fn day_second(&self) -> Option<&SQL_DAY_SECOND_STRUCT> {
+        if self.interval_type.is_year_month() {
+            return None;
+        }
+
+        // SAFETY: Union member is selected by the interval type
+        Some(unsafe { &self.intval.day_second })
+    }
+
+    /// Returns the total number of months, with sign, if the interval is of one of the
+    /// year-month kinds
+    pub fn to_months(&self) -> Option<i64> {
+        self.year_month().map(|year_month| {
+            let months = i64::from(year_month.year) * 12 + i64::from(year_month.month);
+            if self.is_negative() { -months } else { months }
+        })
+    }
 }
 impl Eq for SQL_INTERVAL_STRUCT {}
 impl PartialEq<SQL_INTERVAL_STRUCT> for SQL_INTERVAL_STRUCT {
-    fn eq(&self, _: &SQL_INTERVAL_STRUCT) -> bool {
-        unimplemented!()
+    fn eq(&self, other: &SQL_INTERVAL_STRUCT) -> bool {
+        self.interval_type == other.interval_type
+            && self.interval_sign == other.interval_sign
+            && self.year_month() == other.year_month()
+            && self.day_second() == other.day_second()
     }
 }
 impl core::fmt::Debug for SQL_INTERVAL_STRUCT {
-    fn fmt(&self, _: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
-        unimplemented!()
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+        let mut debug = f.debug_struct("SQL_INTERVAL_STRUCT");
+        debug
+            .field("interval_type", &self.interval_type)
+            .field("interval_sign", &self.interval_sign);
+
+        if let Some(year_month) = self.year_month() {
+            debug.field("year_month", year_month);
+        }
+        if let Some(day_second) = self.day_second() {
+            debug.field("day_second", day_second);
+        }
+
+        debug.finish()
+    }
+}
+
+/// Converts the duration into a positive SQL_IS_DAY_TO_SECOND interval. Fraction holds
+/// nanoseconds, i.e. seconds precision(SQL_DESC_PRECISION) of the interval must be set to 9
+impl TryFrom<Duration> for SQL_INTERVAL_STRUCT {
+    type Error = TryFromIntervalError;
+
+    fn try_from(source: Duration) -> Result<Self, Self::Error> {
+        let secs = source.as_secs();
+
+        let day_second = SQL_DAY_SECOND_STRUCT {
+            day: SQLUINTEGER::try_from(secs / 86400).map_err(|_| TryFromIntervalError(()))?,
+            hour: (secs / 3600 % 24) as SQLUINTEGER,
+            minute: (secs / 60 % 60) as SQLUINTEGER,
+            second: (secs % 60) as SQLUINTEGER,
+            fraction: source.subsec_nanos(),
+        };
+
+        Ok(Self {
+            interval_type: SQLINTERVAL::SQL_IS_DAY_TO_SECOND,
+            interval_sign: 0,
+            intval: IntervalUnion { day_second },
+        })
+    }
+}
+/// Converts a positive interval of one of the day-time kinds into a duration. Fraction is
+/// interpreted as nanoseconds, i.e. seconds precision(SQL_DESC_PRECISION) must be set to 9
+impl TryFrom<SQL_INTERVAL_STRUCT> for Duration {
+    type Error = TryFromIntervalError;
+
+    fn try_from(source: SQL_INTERVAL_STRUCT) -> Result<Self, Self::Error> {
+        let day_second = source
+            .day_second()
+            .filter(|_| !source.is_negative())
+            .ok_or(TryFromIntervalError(()))?;
+
+        let secs = u64::from(day_second.day) * 86400
+            + u64::from(day_second.hour) * 3600
+            + u64::from(day_second.minute) * 60
+            + u64::from(day_second.second);
+
+        Duration::from_secs(secs)
+            .checked_add(Duration::from_nanos(u64::from(day_second.fraction)))
+            .ok_or(TryFromIntervalError(()))
+    }
+}
+
+/// Error returned when a conversion to or from an interval fails because the value is
+/// negative, out of range or of a different interval kind
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TryFromIntervalError(());
+
+impl core::fmt::Display for TryFromIntervalError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "out of range or incompatible interval conversion")
     }
 }
+impl core::error::Error for TryFromIntervalError {}
 
 // TODO: Probably shouldn't use an enum with C FFI
 // But it's literally defined as enum in ODBC
@@ -527,34 +810,38 @@ pub enum SQLINTERVAL {
     SQL_IS_HOUR_TO_SECOND = 12,
     SQL_IS_MINUTE_TO_SECOND = 13,
 }
+impl SQLINTERVAL {
+    /// Returns `true` for SQL_IS_YEAR, SQL_IS_MONTH and SQL_IS_YEAR_TO_MONTH intervals which
+    /// hold [`SQL_YEAR_MONTH_STRUCT`]. Other intervals hold [`SQL_DAY_SECOND_STRUCT`]
+    pub const fn is_year_month(&self) -> bool {
+        matches!(
+            self,
+            Self::SQL_IS_YEAR | Self::SQL_IS_MONTH | Self::SQL_IS_YEAR_TO_MONTH
+        )
+    }
+}
 
 #[repr(C)]
-// TODO: Should this be public?
-// TODO: Remove Copy
 #[derive(Clone, Copy)]
-union IntervalUnion {
-    pub year_month: SQL_YEAR_MONTH_STRUCT,
-    pub day_second: SQL_DAY_SECOND_STRUCT,
+pub(crate) union IntervalUnion {
+    pub(crate) year_month: SQL_YEAR_MONTH_STRUCT,
+    pub(crate) day_second: SQL_DAY_SECOND_STRUCT,
 }
 
-// TODO: Must be copy because it's used in uinon
-// Maybe it would be ok in nightly
 #[repr(C)]
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
 #[allow(non_camel_case_types)]
-struct SQL_YEAR_MONTH_STRUCT {
+pub struct SQL_YEAR_MONTH_STRUCT {
     pub year: SQLUINTEGER,
     pub month: SQLUINTEGER,
 }
 
-// TODO: Must be copy because it's used in uinon
-// Maybe it would be ok in nightly
 #[repr(C)]
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
 #[allow(non_camel_case_types)]
-struct SQL_DAY_SECOND_STRUCT {
+pub struct SQL_DAY_SECOND_STRUCT {
     pub day: SQLUINTEGER,
-    pub month: SQLUINTEGER,
+    pub hour: SQLUINTEGER,
     pub minute: SQLUINTEGER,
     pub second: SQLUINTEGER,
     pub fraction: SQLUINTEGER,
@@ -630,3 +917,66 @@ impl CDataLen for (SQLPOINTER, SQLLEN) {
 //impl<T> ParameterDir<SQL_PARAM_OUTPUT> for [MaybeUninit<T>] where [T]: DeferredBuf {}
 //impl<T> ParameterDir<SQL_PARAM_INPUT_OUTPUT> for [MaybeUninit<T>] where
 //    [T]: ParameterDir<SQL_PARAM_INPUT> {}
+
+//=====================================================================================//
+//----------------------------------------Tests----------------------------------------//
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn interval_duration() {
+        let duration = Duration::new(2 * 86400 + 3 * 3600 + 4 * 60 + 5, 6);
+        let interval = SQL_INTERVAL_STRUCT::try_from(duration).unwrap();
+
+        assert_eq!(SQLINTERVAL::SQL_IS_DAY_TO_SECOND, interval.interval_type());
+        assert_eq!(
+            Some(&SQL_DAY_SECOND_STRUCT {
+                day: 2,
+                hour: 3,
+                minute: 4,
+                second: 5,
+                fraction: 6,
+            }),
+            interval.day_second()
+        );
+        assert_eq!(None, interval.year_month());
+        assert_eq!(Ok(duration), Duration::try_from(interval));
+
+        let negative = SQL_INTERVAL_STRUCT {
+            interval_sign: 1,
+            ..interval
+        };
+        assert_ne!(interval, negative);
+        assert!(Duration::try_from(negative).is_err());
+    }
+
+    #[test]
+    fn interval_months() {
+        let interval = SQL_INTERVAL_STRUCT::from_months(-27);
+
+        assert!(interval.is_negative());
+        assert_eq!(
+            Some(&SQL_YEAR_MONTH_STRUCT { year: 2, month: 3 }),
+            interval.year_month()
+        );
+        assert_eq!(Some(-27), interval.to_months());
+        assert!(Duration::try_from(interval).is_err());
+
+        let year = SQL_INTERVAL_STRUCT::from_year_month(
+            SQLINTERVAL::SQL_IS_YEAR,
+            false,
+            SQL_YEAR_MONTH_STRUCT { year: 4, month: 0 },
+        );
+        assert_eq!(Some(48), year.and_then(|year| year.to_months()));
+        assert!(
+            SQL_INTERVAL_STRUCT::from_year_month(
+                SQLINTERVAL::SQL_IS_DAY,
+                false,
+                SQL_YEAR_MONTH_STRUCT::default()
+            )
+            .is_none()
+        );
+    }
+}
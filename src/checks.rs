@@ -0,0 +1,367 @@
+//! Runtime checks of the ODBC API usage which cannot be verified by the type system.
+//!
+//! State of application descriptors is mirrored on the Rust side and used to validate binds and
+//! descriptor field updates. Descriptors are tracked by their raw handles so that the state is
+//! shared by all handle types wrapping the same descriptor(e.g. `SQLHDESC` and `RefSQLHDESC`)
+#![allow(non_snake_case)]
+
+#[double]
+use crate::api::ffi;
+use crate::desc::{
+    SQL_DESC_ALLOC_TYPE, SQL_DESC_ARRAY_SIZE, SQL_DESC_ARRAY_STATUS_PTR, SQL_DESC_BIND_OFFSET_PTR,
+    SQL_DESC_BIND_TYPE, SQL_DESC_COUNT, SQL_DESC_DATA_PTR, SQL_DESC_OCTET_LENGTH_PTR,
+    SQL_DESC_ROWS_PROCESSED_PTR, SQL_DESC_TYPE,
+};
+use crate::handle::{SQL_HANDLE_DESC, SQL_HANDLE_STMT, SQLHANDLE};
+use crate::sqlreturn::{SQL_SUCCEEDED, SQLRETURN};
+use crate::stmt::{SQL_ATTR_APP_PARAM_DESC, SQL_ATTR_APP_ROW_DESC};
+use crate::{Ident, SQLINTEGER, SQLPOINTER, SQLSMALLINT};
+use core::mem::MaybeUninit;
+use mockall_double::double;
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+// Descriptor fields which are not yet implemented in desc.rs
+const SQL_DESC_CONCISE_TYPE: SQLSMALLINT = 2;
+const SQL_DESC_DATETIME_INTERVAL_CODE: SQLSMALLINT = 1007;
+const SQL_DESC_INDICATOR_PTR: SQLSMALLINT = 1009;
+
+static DESCRIPTORS: Mutex<BTreeMap<usize, Desc>> = Mutex::new(BTreeMap::new());
+/// Implicitly allocated ARD and APD of a statement which are freed together with it
+static STATEMENTS: Mutex<BTreeMap<usize, [usize; 2]>> = Mutex::new(BTreeMap::new());
+/// Descriptors known to be used as an APD. Direction of a parameter is described by the IPD so
+/// buffers bound to these descriptors with descriptor functions are assumed to be only read
+static PARAM_DESCS: Mutex<BTreeSet<usize>> = Mutex::new(BTreeSet::new());
+
+type Desc = BTreeMap<SQLSMALLINT, Record>;
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Record {
+    /// Whether SQL_DESC_TYPE, SQL_DESC_CONCISE_TYPE or SQL_DESC_DATETIME_INTERVAL_CODE was set
+    typed: bool,
+    /// Address and length of the buffer in SQL_DESC_DATA_PTR
+    data: Option<(usize, usize)>,
+    /// Whether the driver writes into the buffer, i.e. it's bound to a column or an output parameter
+    written: bool,
+}
+
+fn descriptors() -> MutexGuard<'static, BTreeMap<usize, Desc>> {
+    // Failed check must not disable checks on other threads
+    DESCRIPTORS.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn param_descs() -> MutexGuard<'static, BTreeSet<usize>> {
+    PARAM_DESCS.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Buffers may only be shared by records whose buffers the driver doesn't write into, e.g.
+/// the same value can be bound to multiple input parameters
+fn overlapping(
+    desc: &Desc,
+    RecNumber: SQLSMALLINT,
+    (ptr, len): (usize, usize),
+    written: bool,
+) -> Option<SQLSMALLINT> {
+    desc.iter()
+        .filter(|&(&rec, record)| rec != RecNumber && (written || record.written))
+        .find_map(|(&rec, record)| {
+            let (other_ptr, other_len) = record.data?;
+            (ptr < other_ptr + other_len.max(1) && other_ptr < ptr + len.max(1)).then_some(rec)
+        })
+}
+
+/// Returns the handle of the application descriptor currently associated with the statement
+pub(crate) fn app_desc<A: Ident<Type = SQLINTEGER>>(
+    StatementHandle: SQLHANDLE,
+) -> Result<SQLHANDLE, SQLRETURN> {
+    let mut DescriptorHandle = MaybeUninit::<SQLHANDLE>::uninit();
+
+    let sql_return = unsafe {
+        ffi::SQLGetStmtAttrA(
+            StatementHandle,
+            A::IDENTIFIER,
+            DescriptorHandle.as_mut_ptr().cast(),
+            0,
+            core::ptr::null_mut(),
+        )
+    };
+    if !SQL_SUCCEEDED(sql_return) {
+        return Err(sql_return);
+    }
+
+    let DescriptorHandle = unsafe { DescriptorHandle.assume_init() };
+    if A::IDENTIFIER == SQL_ATTR_APP_PARAM_DESC::IDENTIFIER {
+        param_descs().insert(DescriptorHandle as usize);
+    }
+
+    Ok(DescriptorHandle)
+}
+
+/// Verifies that the buffer being bound doesn't overlap with a buffer bound to another record
+/// unless neither of the buffers is written into by the driver
+pub(crate) fn check_bind(
+    DescriptorHandle: SQLHANDLE,
+    RecNumber: SQLSMALLINT,
+    DataPtr: Option<(SQLPOINTER, usize)>,
+    written: bool,
+) {
+    let Some((ptr, len)) = DataPtr else {
+        return;
+    };
+
+    if let Some(rec) = descriptors()
+        .get(&(DescriptorHandle as usize))
+        .and_then(|desc| overlapping(desc, RecNumber, (ptr as usize, len), written))
+    {
+        panic!(
+            "runtime_checks: buffer bound to record {RecNumber} overlaps with the buffer bound to record {rec}"
+        );
+    }
+}
+
+/// Records the buffer bound with SQLBindCol, SQLBindParameter or SQLSetDescRec
+pub(crate) fn bind(
+    DescriptorHandle: SQLHANDLE,
+    RecNumber: SQLSMALLINT,
+    DataPtr: Option<(SQLPOINTER, usize)>,
+    written: bool,
+) {
+    let mut descriptors = descriptors();
+    let record = descriptors
+        .entry(DescriptorHandle as usize)
+        .or_default()
+        .entry(RecNumber)
+        .or_default();
+
+    record.typed = true;
+    record.data = DataPtr.map(|(ptr, len)| (ptr as usize, len));
+    record.written = written;
+}
+
+/// Whether the driver writes into buffers bound to the descriptor with descriptor functions
+pub(crate) fn desc_written(DescriptorHandle: SQLHANDLE) -> bool {
+    !param_descs().contains(&(DescriptorHandle as usize))
+}
+
+/// Verifies that the descriptor field is set in the sequence required by the ODBC standard
+pub(crate) fn check_desc_field(
+    DescriptorHandle: SQLHANDLE,
+    RecNumber: SQLSMALLINT,
+    FieldIdentifier: SQLSMALLINT,
+    ValuePtr: (SQLPOINTER, usize),
+) {
+    if FieldIdentifier != SQL_DESC_DATA_PTR::IDENTIFIER || RecNumber <= 0 || ValuePtr.0.is_null() {
+        return;
+    }
+
+    let descriptors = descriptors();
+    let desc = descriptors.get(&(DescriptorHandle as usize));
+
+    if !desc
+        .and_then(|desc| desc.get(&RecNumber))
+        .is_some_and(|record| record.typed)
+    {
+        panic!(
+            "runtime_checks: SQL_DESC_DATA_PTR of record {RecNumber} set before SQL_DESC_TYPE, SQL_DESC_CONCISE_TYPE or SQL_DESC_DATETIME_INTERVAL_CODE"
+        );
+    }
+    let written = desc_written(DescriptorHandle);
+    if let Some(rec) = desc
+        .and_then(|desc| overlapping(desc, RecNumber, (ValuePtr.0 as usize, ValuePtr.1), written))
+    {
+        panic!(
+            "runtime_checks: buffer bound to record {RecNumber} overlaps with the buffer bound to record {rec}"
+        );
+    }
+}
+
+/// Records the descriptor field set with SQLSetDescField
+pub(crate) fn desc_field(
+    DescriptorHandle: SQLHANDLE,
+    RecNumber: SQLSMALLINT,
+    FieldIdentifier: SQLSMALLINT,
+    ValuePtr: (SQLPOINTER, usize),
+) {
+    let written = desc_written(DescriptorHandle);
+    let mut descriptors = descriptors();
+    let desc = descriptors.entry(DescriptorHandle as usize).or_default();
+
+    match FieldIdentifier {
+        SQL_DESC_COUNT::IDENTIFIER => {
+            // Records above SQL_DESC_COUNT are released
+            desc.retain(|&rec, _| rec <= ValuePtr.0 as SQLSMALLINT);
+        }
+        SQL_DESC_DATA_PTR::IDENTIFIER => {
+            let record = desc.entry(RecNumber).or_default();
+            record.data = (!ValuePtr.0.is_null()).then_some((ValuePtr.0 as usize, ValuePtr.1));
+            record.written = written;
+        }
+        SQL_DESC_ALLOC_TYPE::IDENTIFIER
+        | SQL_DESC_ARRAY_SIZE::IDENTIFIER
+        | SQL_DESC_ARRAY_STATUS_PTR::IDENTIFIER
+        | SQL_DESC_BIND_OFFSET_PTR::IDENTIFIER
+        | SQL_DESC_BIND_TYPE::IDENTIFIER
+        | SQL_DESC_ROWS_PROCESSED_PTR::IDENTIFIER => {
+            // Header fields don't affect records
+        }
+        SQL_DESC_OCTET_LENGTH_PTR::IDENTIFIER | SQL_DESC_INDICATOR_PTR => {}
+        SQL_DESC_TYPE::IDENTIFIER | SQL_DESC_CONCISE_TYPE | SQL_DESC_DATETIME_INTERVAL_CODE => {
+            let record = desc.entry(RecNumber).or_default();
+            record.typed = true;
+            record.data = None;
+        }
+        _ => {
+            // Setting any other record field unbinds the record
+            if let Some(record) = desc.get_mut(&RecNumber) {
+                record.data = None;
+            }
+        }
+    }
+}
+
+/// Releases all records of the descriptor as done by SQLFreeStmt with SQL_UNBIND or SQL_RESET_PARAMS
+pub(crate) fn unbind(DescriptorHandle: SQLHANDLE) {
+    descriptors().remove(&(DescriptorHandle as usize));
+}
+
+/// Copies records from one descriptor to another as done by SQLCopyDesc
+pub(crate) fn copy_desc(SourceDescHandle: SQLHANDLE, TargetDescHandle: SQLHANDLE) {
+    let mut descriptors = descriptors();
+
+    if let Some(source) = descriptors.get(&(SourceDescHandle as usize)).cloned() {
+        descriptors.insert(TargetDescHandle as usize, source);
+    } else {
+        descriptors.remove(&(TargetDescHandle as usize));
+    }
+}
+
+/// Starts tracking implicitly allocated descriptors of a newly allocated statement
+pub(crate) fn alloc_stmt(StatementHandle: SQLHANDLE) {
+    // Descriptors are released when the statement is freed only if they could be determined
+    let (Ok(ARD), Ok(APD)) = (
+        app_desc::<SQL_ATTR_APP_ROW_DESC>(StatementHandle),
+        app_desc::<SQL_ATTR_APP_PARAM_DESC>(StatementHandle),
+    ) else {
+        return;
+    };
+    let descs = [ARD as usize, APD as usize];
+
+    STATEMENTS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .insert(StatementHandle as usize, descs);
+}
+
+/// Stops tracking the handle, and descriptors freed together with it, once it's freed
+pub(crate) fn free_handle(HandleType: SQLSMALLINT, Handle: SQLHANDLE) {
+    if HandleType == SQL_HANDLE_DESC::IDENTIFIER {
+        descriptors().remove(&(Handle as usize));
+        param_descs().remove(&(Handle as usize));
+    } else if HandleType == SQL_HANDLE_STMT::IDENTIFIER {
+        let descs = STATEMENTS
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(&(Handle as usize));
+
+        if let Some(descs) = descs {
+            let mut descriptors = descriptors();
+            descs.iter().for_each(|desc| {
+                descriptors.remove(desc);
+            });
+            param_descs().remove(&descs[1]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Each test uses a distinct fake handle because the registry is shared between tests
+    fn handle(addr: usize) -> SQLHANDLE {
+        addr as SQLHANDLE
+    }
+
+    #[test]
+    fn bind_disjoint_buffers() {
+        let desc = handle(0x10);
+        let buf = [0u8; 8];
+
+        check_bind(desc, 1, Some((buf[..4].as_ptr() as SQLPOINTER, 4)), true);
+        bind(desc, 1, Some((buf[..4].as_ptr() as SQLPOINTER, 4)), true);
+        check_bind(desc, 2, Some((buf[4..].as_ptr() as SQLPOINTER, 4)), true);
+
+        free_handle(SQL_HANDLE_DESC::IDENTIFIER, desc);
+    }
+
+    #[test]
+    #[should_panic(expected = "overlaps with the buffer bound to record 1")]
+    fn bind_overlapping_buffers() {
+        let desc = handle(0x20);
+        let buf = [0u8; 8];
+
+        bind(desc, 1, Some((buf.as_ptr() as SQLPOINTER, 8)), true);
+        check_bind(desc, 2, Some((buf[4..].as_ptr() as SQLPOINTER, 4)), true);
+    }
+
+    #[test]
+    fn bind_shared_input_buffers() {
+        let desc = handle(0x60);
+        let buf = [0u8; 8];
+
+        bind(desc, 1, Some((buf.as_ptr() as SQLPOINTER, 8)), false);
+        check_bind(desc, 2, Some((buf.as_ptr() as SQLPOINTER, 8)), false);
+
+        free_handle(SQL_HANDLE_DESC::IDENTIFIER, desc);
+    }
+
+    #[test]
+    #[should_panic(expected = "overlaps with the buffer bound to record 1")]
+    fn bind_output_overlapping_input() {
+        let desc = handle(0x70);
+        let buf = [0u8; 8];
+
+        bind(desc, 1, Some((buf.as_ptr() as SQLPOINTER, 8)), false);
+        check_bind(desc, 2, Some((buf.as_ptr() as SQLPOINTER, 8)), true);
+    }
+
+    #[test]
+    fn rebind_after_unbind() {
+        let desc = handle(0x30);
+        let buf = [0u8; 8];
+
+        bind(desc, 1, Some((buf.as_ptr() as SQLPOINTER, 8)), true);
+        unbind(desc);
+        check_bind(desc, 2, Some((buf.as_ptr() as SQLPOINTER, 8)), true);
+    }
+
+    #[test]
+    #[should_panic(expected = "set before SQL_DESC_TYPE")]
+    fn data_ptr_before_type() {
+        let desc = handle(0x40);
+        let buf = [0u8; 8];
+
+        check_desc_field(
+            desc,
+            1,
+            SQL_DESC_DATA_PTR::IDENTIFIER,
+            (buf.as_ptr() as SQLPOINTER, 8),
+        );
+    }
+
+    #[test]
+    fn data_ptr_after_type() {
+        let desc = handle(0x50);
+        let buf = [0u8; 8];
+
+        desc_field(desc, 1, SQL_DESC_TYPE::IDENTIFIER, (4 as SQLPOINTER, 0));
+        check_desc_field(
+            desc,
+            1,
+            SQL_DESC_DATA_PTR::IDENTIFIER,
+            (buf.as_ptr() as SQLPOINTER, 8),
+        );
+
+        free_handle(SQL_HANDLE_DESC::IDENTIFIER, desc);
+    }
+}
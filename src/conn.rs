@@ -118,7 +118,7 @@ mod private {
     #[double]
     use crate::api::ffi;
     use crate::convert::AsSQLHANDLE;
-    use crate::handle::SQLHDBC;
+    use crate::handle::{self, SQLHDBC};
     use crate::{env, sqlreturn};
     use core::any;
     use mockall_double::double;
@@ -132,17 +132,12 @@ mod private {
         {
             let sql_return = unsafe { ffi::SQLDisconnect(handle.as_SQLHANDLE()) };
 
-            #[cfg(feature = "std")]
-            if std::thread::panicking() {
-                return;
-            }
-
-            if sql_return != sqlreturn::SQL_SUCCESS {
-                panic!(
-                    "{}: SQLDisconnect returned {:?}",
-                    any::type_name::<Self>(),
-                    sql_return
-                )
+            if !sqlreturn::SQL_SUCCEEDED(sql_return) {
+                handle::drop_failed(handle::DropError {
+                    handle: any::type_name::<SQLHDBC<Self, V>>(),
+                    function: "SQLDisconnect",
+                    sql_return,
+                })
             }
         }
     }
@@ -327,7 +322,7 @@ unsafe impl AttrSet<SQL_ATTR_TXN_ISOLATION> for TxnIsolation {}
 //    type DefinedBy = OdbcDefined;
 //}
 //impl ConnAttr<C, SQL_ATTR_TRANSLATE_OPTION, SQL_OV_ODBC3> for SQLUINTEGER {
-//    #[cfg(feature = "odbc_debug")]
+//    #[cfg(feature = "runtime_checks")]
 //    fn check_attr(&self, ConnectionHandle: &SQLHDBC<SQL_OV_ODBC3>) {
 //        ConnectionHandle.assert_connected();
 //    }
@@ -343,7 +338,7 @@ unsafe impl AttrSet<SQL_ATTR_TXN_ISOLATION> for TxnIsolation {}
 //    type DefinedBy = OdbcDefined;
 //}
 //impl ConnAttr<C, SQL_ATTR_DBC_INFO_TOKEN, SQL_OV_ODBC3_80> for SQLPOINTER {
-//    #[cfg(feature = "odbc_debug")]
+//    #[cfg(feature = "runtime_checks")]
 //    fn check_attr(&self, ConnectionHandle: &SQLHDBC<SQL_OV_ODBC3_80>) {
 //        assert_connected(ConnectionHandle);
 //    }
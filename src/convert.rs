@@ -10,6 +10,7 @@ use crate::{
         SQLHSTMT, UnsafeSQLHDESC, UnsafeSQLHSTMT,
     },
     slice_len,
+    stmt::StmtState,
     str::{Ansi, OdbcChar, OdbcStr, Unicode},
 };
 use core::{cell::UnsafeCell, fmt::Debug, mem::MaybeUninit};
@@ -253,11 +254,6 @@ unsafe impl AsMutSQLPOINTER for SQLULEN {
 }
 unsafe impl<DT, V: OdbcVersion> AsMutSQLPOINTER for MaybeUninit<RefUnsafeSQLHDESC<'_, DT, V>> {
     fn as_mut_SQLPOINTER(&mut self) -> SQLPOINTER {
-        if cfg!(feature = "odbc_debug") {
-            // SQLHDESC is not transparent
-            unimplemented!("This method should never be called")
-        }
-
         // SQLHDESC is transparent
         self.as_mut_ptr().cast()
     }
@@ -290,7 +286,7 @@ unsafe impl<C: ConnState, V: OdbcVersion> AsSQLHANDLE for SQLHDBC<'_, C, V> {
         self.handle
     }
 }
-unsafe impl<V: OdbcVersion> AsSQLHANDLE for SQLHSTMT<'_, '_, '_, V> {
+unsafe impl<S: StmtState, V: OdbcVersion> AsSQLHANDLE for SQLHSTMT<'_, '_, '_, S, V> {
     fn as_SQLHANDLE(&self) -> SQLHANDLE {
         self.0.as_SQLHANDLE()
     }
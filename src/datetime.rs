@@ -0,0 +1,282 @@
+//! Parsing of ODBC canonical date, time and timestamp literals and, with the `chrono` or `time`
+//! feature enabled, conversions between the C date and time structs and the types of those crates.
+//!
+//! Drivers which return temporal data as `SQL_C_CHAR` use the canonical formats `yyyy-mm-dd`,
+//! `hh:mm:ss` and `yyyy-mm-dd hh:mm:ss[.f...]`. These can be parsed with [`str::parse`]:
+//!
+//! ```
+//! use rs_odbc::SQL_TIMESTAMP_STRUCT;
+//!
+//! let timestamp: SQL_TIMESTAMP_STRUCT = "2021-03-04 05:06:07.25".parse().unwrap();
+//!
+//! assert_eq!(250_000_000, timestamp.fraction);
+//! assert_eq!("2021-03-04 05:06:07.250000000", timestamp.to_string());
+//! ```
+
+#[cfg(feature = "chrono")]
+mod chrono;
+#[cfg(feature = "time")]
+mod time;
+
+use crate::{
+    SQL_DATE_STRUCT, SQL_TIME_STRUCT, SQL_TIMESTAMP_STRUCT, SQLSMALLINT, SQLUINTEGER, SQLUSMALLINT,
+};
+use core::{fmt, str::FromStr};
+
+/// Largest valid value of the `fraction` field, in nanoseconds
+const MAX_FRACTION: SQLUINTEGER = 999_999_999;
+
+/// Error returned when a date, time or timestamp is out of range of the target type or when
+/// a string is not a valid ODBC canonical literal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidDatetime(());
+
+impl fmt::Display for InvalidDatetime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid or out of range date, time or timestamp")
+    }
+}
+impl core::error::Error for InvalidDatetime {}
+
+fn is_leap_year(year: SQLSMALLINT) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(year: SQLSMALLINT, month: SQLUSMALLINT) -> SQLUSMALLINT {
+    match month {
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 31,
+    }
+}
+
+impl SQL_DATE_STRUCT {
+    /// Returns `true` if the struct holds an existing date of the Gregorian calendar
+    pub fn is_valid(&self) -> bool {
+        (1..=12).contains(&self.month)
+            && (1..=days_in_month(self.year, self.month)).contains(&self.day)
+    }
+}
+impl SQL_TIME_STRUCT {
+    /// Returns `true` if the struct holds a valid time of the day
+    pub fn is_valid(&self) -> bool {
+        self.hour < 24 && self.minute < 60 && self.second < 60
+    }
+}
+impl SQL_TIMESTAMP_STRUCT {
+    /// Returns `true` if the struct holds an existing date of the Gregorian calendar and a
+    /// valid time of the day
+    pub fn is_valid(&self) -> bool {
+        let (date, time) = split_timestamp(self);
+        date.is_valid() && time.is_valid() && self.fraction <= MAX_FRACTION
+    }
+}
+
+fn split_timestamp(source: &SQL_TIMESTAMP_STRUCT) -> (SQL_DATE_STRUCT, SQL_TIME_STRUCT) {
+    let date = SQL_DATE_STRUCT {
+        year: source.year,
+        month: source.month,
+        day: source.day,
+    };
+    let time = SQL_TIME_STRUCT {
+        hour: source.hour,
+        minute: source.minute,
+        second: source.second,
+    };
+
+    (date, time)
+}
+
+/// Parses an unsigned decimal number of exactly `len` digits
+fn parse_digits<T: FromStr>(source: &str, len: usize) -> Result<T, InvalidDatetime> {
+    if source.len() != len || !source.bytes().all(|c| c.is_ascii_digit()) {
+        return Err(InvalidDatetime(()));
+    }
+
+    source.parse().map_err(|_| InvalidDatetime(()))
+}
+
+/// Parses fractional seconds of up to 9 digits into nanoseconds
+fn parse_fraction(source: &str) -> Result<SQLUINTEGER, InvalidDatetime> {
+    if source.is_empty() || source.len() > 9 {
+        return Err(InvalidDatetime(()));
+    }
+
+    let fraction: SQLUINTEGER = parse_digits(source, source.len())?;
+    Ok(fraction * 10_u32.pow(9 - source.len() as u32))
+}
+
+impl FromStr for SQL_DATE_STRUCT {
+    type Err = InvalidDatetime;
+
+    /// Parses the date from the `yyyy-mm-dd` format
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        let mut parts = source.split('-');
+
+        let date = match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(year), Some(month), Some(day), None) => Self {
+                year: parse_digits(year, 4)?,
+                month: parse_digits(month, 2)?,
+                day: parse_digits(day, 2)?,
+            },
+            _ => return Err(InvalidDatetime(())),
+        };
+
+        date.is_valid().then_some(date).ok_or(InvalidDatetime(()))
+    }
+}
+impl FromStr for SQL_TIME_STRUCT {
+    type Err = InvalidDatetime;
+
+    /// Parses the time from the `hh:mm:ss` format
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        let mut parts = source.split(':');
+
+        let time = match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(hour), Some(minute), Some(second), None) => Self {
+                hour: parse_digits(hour, 2)?,
+                minute: parse_digits(minute, 2)?,
+                second: parse_digits(second, 2)?,
+            },
+            _ => return Err(InvalidDatetime(())),
+        };
+
+        time.is_valid().then_some(time).ok_or(InvalidDatetime(()))
+    }
+}
+impl FromStr for SQL_TIMESTAMP_STRUCT {
+    type Err = InvalidDatetime;
+
+    /// Parses the timestamp from the `yyyy-mm-dd hh:mm:ss[.f...]` format where the fraction
+    /// can have from 1 up to 9 digits
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        let (date, time) = source.split_once(' ').ok_or(InvalidDatetime(()))?;
+        let (time, fraction) = match time.split_once('.') {
+            Some((time, fraction)) => (time, parse_fraction(fraction)?),
+            None => (time, 0),
+        };
+
+        let date: SQL_DATE_STRUCT = date.parse()?;
+        let time: SQL_TIME_STRUCT = time.parse()?;
+
+        Ok(Self {
+            year: date.year,
+            month: date.month,
+            day: date.day,
+            hour: time.hour,
+            minute: time.minute,
+            second: time.second,
+            fraction,
+        })
+    }
+}
+
+impl fmt::Display for SQL_DATE_STRUCT {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+impl fmt::Display for SQL_TIME_STRUCT {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}:{:02}", self.hour, self.minute, self.second)
+    }
+}
+impl fmt::Display for SQL_TIMESTAMP_STRUCT {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (date, time) = split_timestamp(self);
+        write!(f, "{date} {time}.{:09}", self.fraction)
+    }
+}
+
+/// Splits the timezone offset, in seconds, into signed hours and minutes. Offsets whose hours
+/// are zero can't be represented if they are negative because the minutes field is unsigned
+#[cfg(any(feature = "chrono", feature = "time"))]
+fn split_offset(offset: i32) -> Result<(SQLSMALLINT, SQLUSMALLINT), InvalidDatetime> {
+    if offset % 60 != 0 || (offset < 0 && offset > -3600) {
+        return Err(InvalidDatetime(()));
+    }
+
+    let hours = SQLSMALLINT::try_from(offset / 3600).map_err(|_| InvalidDatetime(()))?;
+    let minutes = (offset.unsigned_abs() / 60 % 60) as SQLUSMALLINT;
+
+    Ok((hours, minutes))
+}
+
+/// Joins signed timezone hours and minutes into the offset in seconds. Minutes take the sign
+/// of the hours
+#[cfg(any(feature = "chrono", feature = "time"))]
+fn join_offset(hours: SQLSMALLINT, minutes: SQLUSMALLINT) -> Result<i32, InvalidDatetime> {
+    if minutes >= 60 {
+        return Err(InvalidDatetime(()));
+    }
+
+    let minutes = i32::from(minutes) * 60;
+    let hours = i32::from(hours) * 3600;
+
+    Ok(if hours < 0 {
+        hours - minutes
+    } else {
+        hours + minutes
+    })
+}
+
+//=====================================================================================//
+//----------------------------------------Tests----------------------------------------//
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_timestamp() {
+        let timestamp: SQL_TIMESTAMP_STRUCT = "2020-02-29 23:59:58.123".parse().unwrap();
+
+        assert_eq!(
+            SQL_TIMESTAMP_STRUCT {
+                year: 2020,
+                month: 2,
+                day: 29,
+                hour: 23,
+                minute: 59,
+                second: 58,
+                fraction: 123_000_000,
+            },
+            timestamp
+        );
+        assert_eq!(Ok(timestamp), timestamp.to_string().parse());
+        assert_eq!(
+            Ok(0),
+            "2020-02-29 23:59:58"
+                .parse::<SQL_TIMESTAMP_STRUCT>()
+                .map(|timestamp| timestamp.fraction)
+        );
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert!("2021-02-29".parse::<SQL_DATE_STRUCT>().is_err());
+        assert!("2021-2-28".parse::<SQL_DATE_STRUCT>().is_err());
+        assert!("24:00:00".parse::<SQL_TIME_STRUCT>().is_err());
+        assert!("12:00".parse::<SQL_TIME_STRUCT>().is_err());
+        assert!(
+            "2021-01-01 00:00:00.1234567890"
+                .parse::<SQL_TIMESTAMP_STRUCT>()
+                .is_err()
+        );
+        assert!(
+            "2021-01-01T00:00:00"
+                .parse::<SQL_TIMESTAMP_STRUCT>()
+                .is_err()
+        );
+    }
+
+    #[test]
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    fn timezone_offset() {
+        assert_eq!(Ok((-5, 30)), split_offset(-5 * 3600 - 30 * 60));
+        assert_eq!(Ok(-5 * 3600 - 30 * 60), join_offset(-5, 30));
+        assert_eq!(Ok((0, 30)), split_offset(30 * 60));
+        assert!(split_offset(-30 * 60).is_err());
+    }
+}
@@ -0,0 +1,228 @@
+use super::{InvalidDatetime, MAX_FRACTION, join_offset, split_offset};
+use crate::{
+    SQL_DATE_STRUCT, SQL_TIME_STRUCT, SQL_TIME_WITH_TIMEZONE_STRUCT, SQL_TIMESTAMP_STRUCT,
+    SQL_TIMESTAMP_WITH_TIMEZONE_STRUCT, SQLSMALLINT, SQLUSMALLINT,
+};
+use ::chrono::{DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+
+/// Leap second is represented by chrono as a nanosecond value above 999,999,999
+fn split_nanosecond(time: &NaiveTime) -> (SQLUSMALLINT, u32) {
+    let second = time.second() as SQLUSMALLINT;
+
+    match time.nanosecond() {
+        nanosecond if nanosecond > MAX_FRACTION => (second + 1, nanosecond - MAX_FRACTION - 1),
+        nanosecond => (second, nanosecond),
+    }
+}
+
+fn naive_time(
+    hour: SQLUSMALLINT,
+    minute: SQLUSMALLINT,
+    second: SQLUSMALLINT,
+    fraction: u32,
+) -> Result<NaiveTime, InvalidDatetime> {
+    if fraction > MAX_FRACTION {
+        return Err(InvalidDatetime(()));
+    }
+
+    let (second, fraction) = match second {
+        60 => (59, fraction + MAX_FRACTION + 1),
+        second => (second, fraction),
+    };
+
+    NaiveTime::from_hms_nano_opt(hour.into(), minute.into(), second.into(), fraction)
+        .ok_or(InvalidDatetime(()))
+}
+
+fn fixed_offset(hours: SQLSMALLINT, minutes: SQLUSMALLINT) -> Result<FixedOffset, InvalidDatetime> {
+    FixedOffset::east_opt(join_offset(hours, minutes)?).ok_or(InvalidDatetime(()))
+}
+
+impl TryFrom<NaiveDate> for SQL_DATE_STRUCT {
+    type Error = InvalidDatetime;
+
+    fn try_from(source: NaiveDate) -> Result<Self, Self::Error> {
+        Ok(Self {
+            year: SQLSMALLINT::try_from(source.year()).map_err(|_| InvalidDatetime(()))?,
+            month: source.month() as SQLUSMALLINT,
+            day: source.day() as SQLUSMALLINT,
+        })
+    }
+}
+impl TryFrom<SQL_DATE_STRUCT> for NaiveDate {
+    type Error = InvalidDatetime;
+
+    fn try_from(source: SQL_DATE_STRUCT) -> Result<Self, Self::Error> {
+        NaiveDate::from_ymd_opt(source.year.into(), source.month.into(), source.day.into())
+            .ok_or(InvalidDatetime(()))
+    }
+}
+
+/// Fractional seconds are truncated because `SQL_TIME_STRUCT` doesn't hold them
+impl From<NaiveTime> for SQL_TIME_STRUCT {
+    fn from(source: NaiveTime) -> Self {
+        Self {
+            hour: source.hour() as SQLUSMALLINT,
+            minute: source.minute() as SQLUSMALLINT,
+            second: split_nanosecond(&source).0,
+        }
+    }
+}
+impl TryFrom<SQL_TIME_STRUCT> for NaiveTime {
+    type Error = InvalidDatetime;
+
+    fn try_from(source: SQL_TIME_STRUCT) -> Result<Self, Self::Error> {
+        naive_time(source.hour, source.minute, source.second, 0)
+    }
+}
+
+impl TryFrom<NaiveDateTime> for SQL_TIMESTAMP_STRUCT {
+    type Error = InvalidDatetime;
+
+    fn try_from(source: NaiveDateTime) -> Result<Self, Self::Error> {
+        let date = SQL_DATE_STRUCT::try_from(source.date())?;
+        let (second, fraction) = split_nanosecond(&source.time());
+
+        Ok(Self {
+            year: date.year,
+            month: date.month,
+            day: date.day,
+            hour: source.hour() as SQLUSMALLINT,
+            minute: source.minute() as SQLUSMALLINT,
+            second,
+            fraction,
+        })
+    }
+}
+impl TryFrom<SQL_TIMESTAMP_STRUCT> for NaiveDateTime {
+    type Error = InvalidDatetime;
+
+    fn try_from(source: SQL_TIMESTAMP_STRUCT) -> Result<Self, Self::Error> {
+        let date =
+            NaiveDate::from_ymd_opt(source.year.into(), source.month.into(), source.day.into())
+                .ok_or(InvalidDatetime(()))?;
+        let time = naive_time(source.hour, source.minute, source.second, source.fraction)?;
+
+        Ok(NaiveDateTime::new(date, time))
+    }
+}
+
+/// Fractional seconds are truncated because `SQL_TIME_WITH_TIMEZONE_STRUCT` doesn't hold them
+impl TryFrom<(NaiveTime, FixedOffset)> for SQL_TIME_WITH_TIMEZONE_STRUCT {
+    type Error = InvalidDatetime;
+
+    fn try_from((time, offset): (NaiveTime, FixedOffset)) -> Result<Self, Self::Error> {
+        let time = SQL_TIME_STRUCT::from(time);
+        let (timezone_hours, timezone_minutes) = split_offset(offset.local_minus_utc())?;
+
+        Ok(Self {
+            hour: time.hour,
+            minute: time.minute,
+            second: time.second,
+            timezone_hours,
+            timezone_minutes,
+        })
+    }
+}
+impl TryFrom<SQL_TIME_WITH_TIMEZONE_STRUCT> for (NaiveTime, FixedOffset) {
+    type Error = InvalidDatetime;
+
+    fn try_from(source: SQL_TIME_WITH_TIMEZONE_STRUCT) -> Result<Self, Self::Error> {
+        let time = naive_time(source.hour, source.minute, source.second, 0)?;
+        let offset = fixed_offset(source.timezone_hours, source.timezone_minutes)?;
+
+        Ok((time, offset))
+    }
+}
+
+/// Timestamp holds the local time in the offset of the given date time
+impl TryFrom<DateTime<FixedOffset>> for SQL_TIMESTAMP_WITH_TIMEZONE_STRUCT {
+    type Error = InvalidDatetime;
+
+    fn try_from(source: DateTime<FixedOffset>) -> Result<Self, Self::Error> {
+        let timestamp = SQL_TIMESTAMP_STRUCT::try_from(source.naive_local())?;
+        let (timezone_hours, timezone_minutes) = split_offset(source.offset().local_minus_utc())?;
+
+        Ok(Self {
+            year: timestamp.year,
+            month: timestamp.month,
+            day: timestamp.day,
+            hour: timestamp.hour,
+            minute: timestamp.minute,
+            second: timestamp.second,
+            fraction: timestamp.fraction,
+            timezone_hours,
+            timezone_minutes,
+        })
+    }
+}
+impl TryFrom<SQL_TIMESTAMP_WITH_TIMEZONE_STRUCT> for DateTime<FixedOffset> {
+    type Error = InvalidDatetime;
+
+    fn try_from(source: SQL_TIMESTAMP_WITH_TIMEZONE_STRUCT) -> Result<Self, Self::Error> {
+        let local = NaiveDateTime::try_from(SQL_TIMESTAMP_STRUCT {
+            year: source.year,
+            month: source.month,
+            day: source.day,
+            hour: source.hour,
+            minute: source.minute,
+            second: source.second,
+            fraction: source.fraction,
+        })?;
+        let offset = fixed_offset(source.timezone_hours, source.timezone_minutes)?;
+
+        local
+            .and_local_timezone(offset)
+            .single()
+            .ok_or(InvalidDatetime(()))
+    }
+}
+
+//=====================================================================================//
+//----------------------------------------Tests----------------------------------------//
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn timestamp_roundtrip() {
+        let source = NaiveDate::from_ymd_opt(2021, 3, 4)
+            .unwrap()
+            .and_hms_nano_opt(5, 6, 7, 8)
+            .unwrap();
+        let timestamp = SQL_TIMESTAMP_STRUCT::try_from(source).unwrap();
+
+        assert_eq!(8, timestamp.fraction);
+        assert_eq!(Ok(source), NaiveDateTime::try_from(timestamp));
+        assert!(
+            NaiveDateTime::try_from(SQL_TIMESTAMP_STRUCT {
+                month: 13,
+                ..timestamp
+            })
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn timestamp_with_timezone_roundtrip() {
+        let offset = FixedOffset::west_opt(3600 + 1800).unwrap();
+        let source = NaiveDate::from_ymd_opt(2021, 3, 4)
+            .unwrap()
+            .and_hms_opt(23, 30, 0)
+            .unwrap()
+            .and_local_timezone(offset)
+            .unwrap();
+        let timestamp = SQL_TIMESTAMP_WITH_TIMEZONE_STRUCT::try_from(source).unwrap();
+
+        assert_eq!(
+            (23, -1, 30),
+            (
+                timestamp.hour,
+                timestamp.timezone_hours,
+                timestamp.timezone_minutes
+            )
+        );
+        assert_eq!(Ok(source), DateTime::try_from(timestamp));
+    }
+}
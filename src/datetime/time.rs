@@ -0,0 +1,219 @@
+use super::{InvalidDatetime, MAX_FRACTION, join_offset, split_offset};
+use crate::{
+    SQL_DATE_STRUCT, SQL_TIME_STRUCT, SQL_TIME_WITH_TIMEZONE_STRUCT, SQL_TIMESTAMP_STRUCT,
+    SQL_TIMESTAMP_WITH_TIMEZONE_STRUCT, SQLSMALLINT, SQLUSMALLINT,
+};
+use ::time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+fn time(
+    hour: SQLUSMALLINT,
+    minute: SQLUSMALLINT,
+    second: SQLUSMALLINT,
+    fraction: u32,
+) -> Result<Time, InvalidDatetime> {
+    let [hour, minute, second] = [hour, minute, second].map(u8::try_from);
+
+    if fraction > MAX_FRACTION {
+        return Err(InvalidDatetime(()));
+    }
+
+    match (hour, minute, second) {
+        (Ok(hour), Ok(minute), Ok(second)) => {
+            Time::from_hms_nano(hour, minute, second, fraction).map_err(|_| InvalidDatetime(()))
+        }
+        _ => Err(InvalidDatetime(())),
+    }
+}
+
+fn utc_offset(hours: SQLSMALLINT, minutes: SQLUSMALLINT) -> Result<UtcOffset, InvalidDatetime> {
+    UtcOffset::from_whole_seconds(join_offset(hours, minutes)?).map_err(|_| InvalidDatetime(()))
+}
+
+impl TryFrom<Date> for SQL_DATE_STRUCT {
+    type Error = InvalidDatetime;
+
+    fn try_from(source: Date) -> Result<Self, Self::Error> {
+        Ok(Self {
+            year: SQLSMALLINT::try_from(source.year()).map_err(|_| InvalidDatetime(()))?,
+            month: u8::from(source.month()).into(),
+            day: source.day().into(),
+        })
+    }
+}
+impl TryFrom<SQL_DATE_STRUCT> for Date {
+    type Error = InvalidDatetime;
+
+    fn try_from(source: SQL_DATE_STRUCT) -> Result<Self, Self::Error> {
+        let month = u8::try_from(source.month)
+            .ok()
+            .and_then(|month| Month::try_from(month).ok())
+            .ok_or(InvalidDatetime(()))?;
+        let day = u8::try_from(source.day).map_err(|_| InvalidDatetime(()))?;
+
+        Date::from_calendar_date(source.year.into(), month, day).map_err(|_| InvalidDatetime(()))
+    }
+}
+
+/// Fractional seconds are truncated because `SQL_TIME_STRUCT` doesn't hold them
+impl From<Time> for SQL_TIME_STRUCT {
+    fn from(source: Time) -> Self {
+        Self {
+            hour: source.hour().into(),
+            minute: source.minute().into(),
+            second: source.second().into(),
+        }
+    }
+}
+impl TryFrom<SQL_TIME_STRUCT> for Time {
+    type Error = InvalidDatetime;
+
+    fn try_from(source: SQL_TIME_STRUCT) -> Result<Self, Self::Error> {
+        time(source.hour, source.minute, source.second, 0)
+    }
+}
+
+impl TryFrom<PrimitiveDateTime> for SQL_TIMESTAMP_STRUCT {
+    type Error = InvalidDatetime;
+
+    fn try_from(source: PrimitiveDateTime) -> Result<Self, Self::Error> {
+        let date = SQL_DATE_STRUCT::try_from(source.date())?;
+        let time = SQL_TIME_STRUCT::from(source.time());
+
+        Ok(Self {
+            year: date.year,
+            month: date.month,
+            day: date.day,
+            hour: time.hour,
+            minute: time.minute,
+            second: time.second,
+            fraction: source.nanosecond(),
+        })
+    }
+}
+impl TryFrom<SQL_TIMESTAMP_STRUCT> for PrimitiveDateTime {
+    type Error = InvalidDatetime;
+
+    fn try_from(source: SQL_TIMESTAMP_STRUCT) -> Result<Self, Self::Error> {
+        let date = Date::try_from(SQL_DATE_STRUCT {
+            year: source.year,
+            month: source.month,
+            day: source.day,
+        })?;
+        let time = time(source.hour, source.minute, source.second, source.fraction)?;
+
+        Ok(PrimitiveDateTime::new(date, time))
+    }
+}
+
+/// Fractional seconds are truncated because `SQL_TIME_WITH_TIMEZONE_STRUCT` doesn't hold them
+impl TryFrom<(Time, UtcOffset)> for SQL_TIME_WITH_TIMEZONE_STRUCT {
+    type Error = InvalidDatetime;
+
+    fn try_from((time, offset): (Time, UtcOffset)) -> Result<Self, Self::Error> {
+        let time = SQL_TIME_STRUCT::from(time);
+        let (timezone_hours, timezone_minutes) = split_offset(offset.whole_seconds())?;
+
+        Ok(Self {
+            hour: time.hour,
+            minute: time.minute,
+            second: time.second,
+            timezone_hours,
+            timezone_minutes,
+        })
+    }
+}
+impl TryFrom<SQL_TIME_WITH_TIMEZONE_STRUCT> for (Time, UtcOffset) {
+    type Error = InvalidDatetime;
+
+    fn try_from(source: SQL_TIME_WITH_TIMEZONE_STRUCT) -> Result<Self, Self::Error> {
+        let time = time(source.hour, source.minute, source.second, 0)?;
+        let offset = utc_offset(source.timezone_hours, source.timezone_minutes)?;
+
+        Ok((time, offset))
+    }
+}
+
+/// Timestamp holds the local time in the offset of the given date time
+impl TryFrom<OffsetDateTime> for SQL_TIMESTAMP_WITH_TIMEZONE_STRUCT {
+    type Error = InvalidDatetime;
+
+    fn try_from(source: OffsetDateTime) -> Result<Self, Self::Error> {
+        let timestamp =
+            SQL_TIMESTAMP_STRUCT::try_from(PrimitiveDateTime::new(source.date(), source.time()))?;
+        let (timezone_hours, timezone_minutes) = split_offset(source.offset().whole_seconds())?;
+
+        Ok(Self {
+            year: timestamp.year,
+            month: timestamp.month,
+            day: timestamp.day,
+            hour: timestamp.hour,
+            minute: timestamp.minute,
+            second: timestamp.second,
+            fraction: timestamp.fraction,
+            timezone_hours,
+            timezone_minutes,
+        })
+    }
+}
+impl TryFrom<SQL_TIMESTAMP_WITH_TIMEZONE_STRUCT> for OffsetDateTime {
+    type Error = InvalidDatetime;
+
+    fn try_from(source: SQL_TIMESTAMP_WITH_TIMEZONE_STRUCT) -> Result<Self, Self::Error> {
+        let local = PrimitiveDateTime::try_from(SQL_TIMESTAMP_STRUCT {
+            year: source.year,
+            month: source.month,
+            day: source.day,
+            hour: source.hour,
+            minute: source.minute,
+            second: source.second,
+            fraction: source.fraction,
+        })?;
+        let offset = utc_offset(source.timezone_hours, source.timezone_minutes)?;
+
+        Ok(local.assume_offset(offset))
+    }
+}
+
+//=====================================================================================//
+//----------------------------------------Tests----------------------------------------//
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn timestamp_roundtrip() {
+        let date = Date::from_calendar_date(2021, Month::March, 4).unwrap();
+        let source = PrimitiveDateTime::new(date, Time::from_hms_nano(5, 6, 7, 8).unwrap());
+        let timestamp = SQL_TIMESTAMP_STRUCT::try_from(source).unwrap();
+
+        assert_eq!(8, timestamp.fraction);
+        assert_eq!(Ok(source), PrimitiveDateTime::try_from(timestamp));
+        assert!(
+            PrimitiveDateTime::try_from(SQL_TIMESTAMP_STRUCT {
+                day: 32,
+                ..timestamp
+            })
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn timestamp_with_timezone_roundtrip() {
+        let date = Date::from_calendar_date(2021, Month::March, 4).unwrap();
+        let offset = UtcOffset::from_hms(-1, -30, 0).unwrap();
+        let source =
+            PrimitiveDateTime::new(date, Time::from_hms(23, 30, 0).unwrap()).assume_offset(offset);
+        let timestamp = SQL_TIMESTAMP_WITH_TIMEZONE_STRUCT::try_from(source).unwrap();
+
+        assert_eq!(
+            (23, -1, 30),
+            (
+                timestamp.hour,
+                timestamp.timezone_hours,
+                timestamp.timezone_minutes
+            )
+        );
+        assert_eq!(Ok(source), OffsetDateTime::try_from(timestamp));
+    }
+}
@@ -425,38 +425,40 @@ impl<'buf, D: Descriptor<'buf, AppDesc<'buf>, SQL_OV_ODBC3>>
 {
 }
 unsafe impl AttrGet<SQL_DESC_ARRAY_SIZE> for SQLULEN {}
-unsafe impl AttrSet<SQL_DESC_ARRAY_SIZE> for SQLULEN {}
+// TODO: Setting is not supported because bound buffers must not be shorter than the array
+// size. Use SQLHSTMT::bind_rowset or SQLHSTMT::bind_paramset instead
 
-//#[derive(Ident)]
-//#[identifier(SQLSMALLINT, 21)]
-//#[expect(non_camel_case_types)]
-//pub struct SQL_DESC_ARRAY_STATUS_PTR;
-//unsafe impl Attr<SQL_DESC_ARRAY_STATUS_PTR> for [UnsafeCell<>] {
-//    type DefinedBy = OdbcDefined;
-//}
-//impl<DT> DescField<SQL_DESC_ARRAY_STATUS_PTR, DT> for [UnsafeCell<>] {
-//    fn update_handle<V: OdbcVersion>(&self, _: &UnsafeSQLHDESC<DT, V>) where Self: AttrSet<A> {
-//        // TODO: Do something
-//    }
-//}
-//unsafe impl AttrGet<SQL_DESC_ARRAY_STATUS_PTR> for [UnsafeCell<>] {}
-//unsafe impl AttrSet<SQL_DESC_ARRAY_STATUS_PTR> for &[UnsafeCell<>] {}
+#[derive(Ident)]
+#[identifier(SQLSMALLINT, 21)]
+#[expect(non_camel_case_types)]
+pub struct SQL_DESC_ARRAY_STATUS_PTR;
+unsafe impl Attr<SQL_DESC_ARRAY_STATUS_PTR> for [UnsafeCell<RowStatus>] {
+    type DefinedBy = OdbcDefined;
+}
+impl<'buf, D: Descriptor<'buf, IRD, SQL_OV_ODBC3>>
+    DescField<'buf, D, IRD, SQL_DESC_ARRAY_STATUS_PTR, SQL_OV_ODBC3> for [UnsafeCell<RowStatus>]
+{
+}
+unsafe impl AttrGet<SQL_DESC_ARRAY_STATUS_PTR> for [UnsafeCell<RowStatus>] {}
+unsafe impl Attr<SQL_DESC_ARRAY_STATUS_PTR> for [UnsafeCell<ParamStatus>] {
+    type DefinedBy = OdbcDefined;
+}
+impl<'buf, D: Descriptor<'buf, IPD, SQL_OV_ODBC3>>
+    DescField<'buf, D, IPD, SQL_DESC_ARRAY_STATUS_PTR, SQL_OV_ODBC3>
+    for [UnsafeCell<ParamStatus>]
+{
+}
+unsafe impl AttrGet<SQL_DESC_ARRAY_STATUS_PTR> for [UnsafeCell<ParamStatus>] {}
+// TODO: Setting is not supported because the length of the array must not be less than
+// SQL_DESC_ARRAY_SIZE of the ARD or APD. Use SQLHSTMT::bind_rowset or
+// SQLHSTMT::bind_paramset instead
 
-//#[derive(Ident)]
-//#[identifier(SQLSMALLINT, 24)]
-//#[expect(non_camel_case_types)]
-//pub struct SQL_DESC_BIND_OFFSET_PTR;
-//unsafe impl Attr<SQL_DESC_BIND_OFFSET_PTR> for UnsafeCell<SQLLEN> {
-//    type DefinedBy = OdbcDefined;
-//}
-//impl<'buf, V: OdbcVersion> UnsafeDescField<'buf, SQL_DESC_BIND_OFFSET_PTR, UnsafeSQLHDESC<'buf, AppDesc<'buf>, V>, AppDesc<'buf>, V> for UnsafeCell<SQLLEN> {
-//    fn update_handle<V: OdbcVersion>(&self, DescriptorHandle: &UnsafeSQLHDESC<'buf, AppDesc<'buf>, V>)
-//    {
-//        DescriptorHandle.bind_offset.set(*self);
-//    }
-//}
-//unsafe impl AttrGet<SQL_DESC_BIND_OFFSET_PTR> for UnsafeCell<SQLLEN> {}
-//unsafe impl AttrSet<SQL_DESC_BIND_OFFSET_PTR> for &UnsafeCell<SQLLEN> {}
+#[derive(Ident)]
+#[identifier(SQLSMALLINT, 24)]
+#[expect(non_camel_case_types)]
+pub struct SQL_DESC_BIND_OFFSET_PTR;
+// TODO: Setting is not supported because the offset must keep bound buffers within the
+// row array. Use SQLHSTMT::bind_rows and SQLHSTMT::set_rows instead
 
 #[derive(Ident)]
 #[identifier(SQLSMALLINT, 25)]
@@ -470,7 +472,8 @@ impl<'buf, D: Descriptor<'buf, AppDesc<'buf>, SQL_OV_ODBC3>>
 {
 }
 unsafe impl AttrGet<SQL_DESC_BIND_TYPE> for BindType {}
-unsafe impl AttrSet<SQL_DESC_BIND_TYPE> for BindType {}
+// TODO: Setting is not supported because bound buffers must be within a row when binding
+// row-wise. Use SQLHSTMT::bind_rows or SQLHSTMT::bind_param_rows instead
 
 #[derive(Ident)]
 #[identifier(SQLSMALLINT, 1001)]
@@ -499,19 +502,11 @@ unsafe impl Attr<SQL_DESC_ROWS_PROCESSED_PTR> for [UnsafeCell<SQLULEN>] {
 impl<'buf, D: Descriptor<'buf, IRD, SQL_OV_ODBC3>>
     DescField<'buf, D, IRD, SQL_DESC_ROWS_PROCESSED_PTR, SQL_OV_ODBC3> for [UnsafeCell<SQLULEN>]
 {
-    #[cfg(feature = "odbc_debug")]
-    fn update_handle<V: OdbcVersion>(&self, DescriptorHandle: &UnsafeSQLHDESC<'conn, IRD, V>) {
-        unimplemented!()
-    }
 }
 impl<'buf, D: Descriptor<'buf, IPD, SQL_OV_ODBC3>>
     DescField<'buf, D, IPD, SQL_DESC_ROWS_PROCESSED_PTR, SQL_OV_ODBC3>
     for [UnsafeCell<SQLUINTEGER>]
 {
-    #[cfg(feature = "odbc_debug")]
-    fn update_handle<V: OdbcVersion>(&self, DescriptorHandle: &UnsafeSQLHDESC<'conn, IPD, V>) {
-        unimplemented!()
-    }
 }
 unsafe impl AttrGet<SQL_DESC_ROWS_PROCESSED_PTR> for [UnsafeCell<SQLUINTEGER>] {}
 unsafe impl AttrGet<SQL_DESC_ROWS_PROCESSED_PTR> for [UnsafeCell<SQLULEN>] {}
@@ -811,17 +806,6 @@ unsafe impl AttrGet<SQL_DESC_OCTET_LENGTH> for OdbcBool {}
 //#[cfg(feature = "v4")]
 //SQL_DESC_MIME_TYPE = 36,
 
-//    pub enum SQL_DESC_ARRAY_STATUS_PTR {
-//        SQL_PARAM_SUCCESS = 0,
-//        SQL_PARAM_SUCCESS_WITH_INFO = 6,
-//        SQL_PARAM_ERROR = 5,
-//        SQL_PARAM_UNUSED = 7,
-//        SQL_PARAM_DIAG_UNAVAILABLE = 1,
-//        // TODO: What are these?
-//        //SQL_PARAM_PROCEED = 0,
-//        //SQL_PARAM_IGNORE = 1,
-//    }
-
 //=====================================================================================//
 
 #[odbc_type(SQLSMALLINT)]
@@ -832,4 +816,38 @@ pub const SQL_DESC_ALLOC_USER: AllocType = AllocType(2);
 // TODO: May be SQLINTEGER?
 #[odbc_type(SQLUINTEGER)]
 pub struct BindType;
-pub const SQL_BIND_BY_COLUMN: BindType = BindType(1);
+pub const SQL_BIND_BY_COLUMN: BindType = BindType(0);
+pub const SQL_BIND_TYPE_DEFAULT: BindType = SQL_BIND_BY_COLUMN;
+
+impl BindType {
+    /// Row-wise binding where the length of a row is `row_len` bytes
+    pub(crate) const fn by_row(row_len: usize) -> Self {
+        Self(row_len as SQLUINTEGER)
+    }
+}
+
+/// Status of a row in the rowset as written to the SQL_DESC_ARRAY_STATUS_PTR of the IRD
+#[odbc_type(SQLUSMALLINT)]
+pub struct RowStatus;
+pub const SQL_ROW_SUCCESS: RowStatus = RowStatus(0);
+pub const SQL_ROW_DELETED: RowStatus = RowStatus(1);
+pub const SQL_ROW_UPDATED: RowStatus = RowStatus(2);
+pub const SQL_ROW_NOROW: RowStatus = RowStatus(3);
+pub const SQL_ROW_ADDED: RowStatus = RowStatus(4);
+pub const SQL_ROW_ERROR: RowStatus = RowStatus(5);
+pub const SQL_ROW_SUCCESS_WITH_INFO: RowStatus = RowStatus(6);
+
+/// Status of a set of parameters as written to the SQL_DESC_ARRAY_STATUS_PTR of the IPD
+#[odbc_type(SQLUSMALLINT)]
+pub struct ParamStatus;
+pub const SQL_PARAM_SUCCESS: ParamStatus = ParamStatus(0);
+pub const SQL_PARAM_SUCCESS_WITH_INFO: ParamStatus = ParamStatus(6);
+pub const SQL_PARAM_ERROR: ParamStatus = ParamStatus(5);
+pub const SQL_PARAM_UNUSED: ParamStatus = ParamStatus(7);
+pub const SQL_PARAM_DIAG_UNAVAILABLE: ParamStatus = ParamStatus(1);
+
+/// Whether a set of parameters is processed as read from the SQL_DESC_ARRAY_STATUS_PTR of the APD
+#[odbc_type(SQLUSMALLINT)]
+pub struct ParamOperation;
+pub const SQL_PARAM_PROCEED: ParamOperation = ParamOperation(0);
+pub const SQL_PARAM_IGNORE: ParamOperation = ParamOperation(1);
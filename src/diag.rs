@@ -1,15 +1,29 @@
 use crate::api::Handle;
+#[cfg(feature = "std")]
+#[double]
+use crate::api::ffi;
+#[cfg(feature = "std")]
+use crate::api::{Diagnostics, retry_truncated};
 use crate::attr::{Attr, AttrGet, AttrLen, AttrZeroAssert, Void};
 use crate::convert::AsMutSQLPOINTER;
 use crate::env::OdbcVersion;
 use crate::handle::SQLHSTMT;
+use crate::stmt::StmtState;
 use crate::str::{OdbcChar, OdbcStr};
 use crate::{
     Def, Ident, OdbcDefined, SQLCHAR, SQLINTEGER, SQLLEN, SQLPOINTER, SQLSMALLINT, SQLWCHAR,
     Scalar, sqlreturn::SQLRETURN,
 };
-use core::mem::MaybeUninit;
+#[cfg(feature = "std")]
+use crate::{handle::SQL_HANDLE_STMT, slice_len, sqlreturn::SQL_SUCCEEDED, str::Ansi};
+use core::{fmt, mem::MaybeUninit};
+#[cfg(feature = "std")]
+use core::{num::NonZeroI16, ptr};
+#[cfg(feature = "std")]
+use mockall_double::double;
 use rs_odbc_derive::{Ident, odbc_type};
+#[cfg(feature = "std")]
+use std::string::String;
 
 pub trait DiagField<H: Handle, D: Ident>: Attr<D> + AttrLen<Self::DefinedBy, SQLSMALLINT> {
     // TODO: These could be checked by the type system
@@ -58,7 +72,7 @@ impl SQLSTATE<SQLWCHAR> {
 
         let mut sqlstate = [SQLWCHAR::default(); SQLSTATE_SIZE + 1];
         for (s, i) in sqlstate.iter_mut().zip(bytes.iter()) {
-            *s = *i as u16;
+            *s = *i as SQLWCHAR;
         }
 
         Self(sqlstate)
@@ -124,6 +138,393 @@ unsafe impl<AD: Def> AttrLen<AD, SQLSMALLINT> for MaybeUninit<SQLSTATE<SQLWCHAR>
     }
 }
 
+/// SQLSTATE value, consisting of a two character class and a three character subclass, which
+/// can be inspected and matched against the standard ODBC 3.x SQLSTATEs
+///
+/// ```
+/// use rs_odbc::diag::SqlState;
+///
+/// let sqlstate: SqlState = "40001".parse().unwrap();
+///
+/// assert_eq!(SqlState::SERIALIZATION_FAILURE, sqlstate);
+/// assert_eq!("40", sqlstate.class());
+/// assert!(sqlstate.is_retryable());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SqlState([u8; SQLSTATE_SIZE]);
+
+impl SqlState {
+    /// 01000: General warning
+    pub const GENERAL_WARNING: SqlState = SqlState(*b"01000");
+    /// 01001: Cursor operation conflict
+    pub const CURSOR_OPERATION_CONFLICT: SqlState = SqlState(*b"01001");
+    /// 01002: Disconnect error
+    pub const DISCONNECT_ERROR: SqlState = SqlState(*b"01002");
+    /// 01003: NULL value eliminated in set function
+    pub const NULL_VALUE_ELIMINATED: SqlState = SqlState(*b"01003");
+    /// 01004: String data, right truncated
+    pub const STRING_DATA_RIGHT_TRUNCATED: SqlState = SqlState(*b"01004");
+    /// 01006: Privilege not revoked
+    pub const PRIVILEGE_NOT_REVOKED: SqlState = SqlState(*b"01006");
+    /// 01007: Privilege not granted
+    pub const PRIVILEGE_NOT_GRANTED: SqlState = SqlState(*b"01007");
+    /// 01S00: Invalid connection string attribute
+    pub const INVALID_CONNECTION_STRING_ATTRIBUTE: SqlState = SqlState(*b"01S00");
+    /// 01S01: Error in row
+    pub const ERROR_IN_ROW: SqlState = SqlState(*b"01S01");
+    /// 01S02: Option value changed
+    pub const OPTION_VALUE_CHANGED: SqlState = SqlState(*b"01S02");
+    /// 01S06: Attempt to fetch before the result set returned the first rowset
+    pub const FETCH_BEFORE_FIRST_ROWSET: SqlState = SqlState(*b"01S06");
+    /// 01S07: Fractional truncation
+    pub const FRACTIONAL_TRUNCATION: SqlState = SqlState(*b"01S07");
+    /// 01S08: Error saving File DSN
+    pub const ERROR_SAVING_FILE_DSN: SqlState = SqlState(*b"01S08");
+    /// 01S09: Invalid keyword
+    pub const INVALID_KEYWORD: SqlState = SqlState(*b"01S09");
+    /// 07001: Wrong number of parameters
+    pub const WRONG_NUMBER_OF_PARAMETERS: SqlState = SqlState(*b"07001");
+    /// 07002: COUNT field incorrect
+    pub const COUNT_FIELD_INCORRECT: SqlState = SqlState(*b"07002");
+    /// 07005: Prepared statement not a cursor-specification
+    pub const NOT_A_CURSOR_SPECIFICATION: SqlState = SqlState(*b"07005");
+    /// 07006: Restricted data type attribute violation
+    pub const RESTRICTED_DATA_TYPE_ATTRIBUTE_VIOLATION: SqlState = SqlState(*b"07006");
+    /// 07007: Restricted parameter value violation
+    pub const RESTRICTED_PARAMETER_VALUE_VIOLATION: SqlState = SqlState(*b"07007");
+    /// 07009: Invalid descriptor index
+    pub const INVALID_DESCRIPTOR_INDEX: SqlState = SqlState(*b"07009");
+    /// 07S01: Invalid use of default parameter
+    pub const INVALID_USE_OF_DEFAULT_PARAMETER: SqlState = SqlState(*b"07S01");
+    /// 08001: Client unable to establish connection
+    pub const UNABLE_TO_ESTABLISH_CONNECTION: SqlState = SqlState(*b"08001");
+    /// 08002: Connection name in use
+    pub const CONNECTION_NAME_IN_USE: SqlState = SqlState(*b"08002");
+    /// 08003: Connection not open
+    pub const CONNECTION_NOT_OPEN: SqlState = SqlState(*b"08003");
+    /// 08004: Server rejected the connection
+    pub const CONNECTION_REJECTED: SqlState = SqlState(*b"08004");
+    /// 08007: Connection failure during transaction
+    pub const CONNECTION_FAILURE_DURING_TRANSACTION: SqlState = SqlState(*b"08007");
+    /// 08S01: Communication link failure
+    pub const COMMUNICATION_LINK_FAILURE: SqlState = SqlState(*b"08S01");
+    /// 21S01: Insert value list does not match column list
+    pub const INSERT_VALUE_LIST_MISMATCH: SqlState = SqlState(*b"21S01");
+    /// 21S02: Degree of derived table does not match column list
+    pub const DERIVED_TABLE_DEGREE_MISMATCH: SqlState = SqlState(*b"21S02");
+    /// 22001: String data, right truncation
+    pub const STRING_DATA_RIGHT_TRUNCATION: SqlState = SqlState(*b"22001");
+    /// 22002: Indicator variable required but not supplied
+    pub const INDICATOR_VARIABLE_REQUIRED: SqlState = SqlState(*b"22002");
+    /// 22003: Numeric value out of range
+    pub const NUMERIC_VALUE_OUT_OF_RANGE: SqlState = SqlState(*b"22003");
+    /// 22007: Invalid datetime format
+    pub const INVALID_DATETIME_FORMAT: SqlState = SqlState(*b"22007");
+    /// 22008: Datetime field overflow
+    pub const DATETIME_FIELD_OVERFLOW: SqlState = SqlState(*b"22008");
+    /// 22012: Division by zero
+    pub const DIVISION_BY_ZERO: SqlState = SqlState(*b"22012");
+    /// 22015: Interval field overflow
+    pub const INTERVAL_FIELD_OVERFLOW: SqlState = SqlState(*b"22015");
+    /// 22018: Invalid character value for cast specification
+    pub const INVALID_CHARACTER_VALUE_FOR_CAST: SqlState = SqlState(*b"22018");
+    /// 22019: Invalid escape character
+    pub const INVALID_ESCAPE_CHARACTER: SqlState = SqlState(*b"22019");
+    /// 22025: Invalid escape sequence
+    pub const INVALID_ESCAPE_SEQUENCE: SqlState = SqlState(*b"22025");
+    /// 22026: String data, length mismatch
+    pub const STRING_DATA_LENGTH_MISMATCH: SqlState = SqlState(*b"22026");
+    /// 23000: Integrity constraint violation
+    pub const INTEGRITY_CONSTRAINT_VIOLATION: SqlState = SqlState(*b"23000");
+    /// 24000: Invalid cursor state
+    pub const INVALID_CURSOR_STATE: SqlState = SqlState(*b"24000");
+    /// 25000: Invalid transaction state
+    pub const INVALID_TRANSACTION_STATE: SqlState = SqlState(*b"25000");
+    /// 25S01: Transaction state
+    pub const TRANSACTION_STATE_UNKNOWN: SqlState = SqlState(*b"25S01");
+    /// 25S02: Transaction is still active
+    pub const TRANSACTION_STILL_ACTIVE: SqlState = SqlState(*b"25S02");
+    /// 25S03: Transaction is rolled back
+    pub const TRANSACTION_ROLLED_BACK: SqlState = SqlState(*b"25S03");
+    /// 28000: Invalid authorization specification
+    pub const INVALID_AUTHORIZATION_SPECIFICATION: SqlState = SqlState(*b"28000");
+    /// 34000: Invalid cursor name
+    pub const INVALID_CURSOR_NAME: SqlState = SqlState(*b"34000");
+    /// 3C000: Duplicate cursor name
+    pub const DUPLICATE_CURSOR_NAME: SqlState = SqlState(*b"3C000");
+    /// 3D000: Invalid catalog name
+    pub const INVALID_CATALOG_NAME: SqlState = SqlState(*b"3D000");
+    /// 3F000: Invalid schema name
+    pub const INVALID_SCHEMA_NAME: SqlState = SqlState(*b"3F000");
+    /// 40001: Serialization failure, e.g. the transaction was chosen as a deadlock victim
+    pub const SERIALIZATION_FAILURE: SqlState = SqlState(*b"40001");
+    /// 40002: Integrity constraint violation on commit
+    pub const TRANSACTION_INTEGRITY_CONSTRAINT_VIOLATION: SqlState = SqlState(*b"40002");
+    /// 40003: Statement completion unknown
+    pub const STATEMENT_COMPLETION_UNKNOWN: SqlState = SqlState(*b"40003");
+    /// 42000: Syntax error or access violation
+    pub const SYNTAX_ERROR_OR_ACCESS_VIOLATION: SqlState = SqlState(*b"42000");
+    /// 42S01: Base table or view already exists
+    pub const TABLE_ALREADY_EXISTS: SqlState = SqlState(*b"42S01");
+    /// 42S02: Base table or view not found
+    pub const TABLE_NOT_FOUND: SqlState = SqlState(*b"42S02");
+    /// 42S11: Index already exists
+    pub const INDEX_ALREADY_EXISTS: SqlState = SqlState(*b"42S11");
+    /// 42S12: Index not found
+    pub const INDEX_NOT_FOUND: SqlState = SqlState(*b"42S12");
+    /// 42S21: Column already exists
+    pub const COLUMN_ALREADY_EXISTS: SqlState = SqlState(*b"42S21");
+    /// 42S22: Column not found
+    pub const COLUMN_NOT_FOUND: SqlState = SqlState(*b"42S22");
+    /// 44000: WITH CHECK OPTION violation
+    pub const WITH_CHECK_OPTION_VIOLATION: SqlState = SqlState(*b"44000");
+    /// HY000: General error
+    pub const GENERAL_ERROR: SqlState = SqlState(*b"HY000");
+    /// HY001: Memory allocation error
+    pub const MEMORY_ALLOCATION_ERROR: SqlState = SqlState(*b"HY001");
+    /// HY003: Invalid application buffer type
+    pub const INVALID_APPLICATION_BUFFER_TYPE: SqlState = SqlState(*b"HY003");
+    /// HY004: Invalid SQL data type
+    pub const INVALID_SQL_DATA_TYPE: SqlState = SqlState(*b"HY004");
+    /// HY007: Associated statement is not prepared
+    pub const STATEMENT_NOT_PREPARED: SqlState = SqlState(*b"HY007");
+    /// HY008: Operation canceled
+    pub const OPERATION_CANCELED: SqlState = SqlState(*b"HY008");
+    /// HY009: Invalid use of null pointer
+    pub const INVALID_USE_OF_NULL_POINTER: SqlState = SqlState(*b"HY009");
+    /// HY010: Function sequence error
+    pub const FUNCTION_SEQUENCE_ERROR: SqlState = SqlState(*b"HY010");
+    /// HY011: Attribute cannot be set now
+    pub const ATTRIBUTE_CANNOT_BE_SET_NOW: SqlState = SqlState(*b"HY011");
+    /// HY012: Invalid transaction operation code
+    pub const INVALID_TRANSACTION_OPERATION_CODE: SqlState = SqlState(*b"HY012");
+    /// HY013: Memory management error
+    pub const MEMORY_MANAGEMENT_ERROR: SqlState = SqlState(*b"HY013");
+    /// HY014: Limit on the number of handles exceeded
+    pub const HANDLE_LIMIT_EXCEEDED: SqlState = SqlState(*b"HY014");
+    /// HY015: No cursor name available
+    pub const NO_CURSOR_NAME_AVAILABLE: SqlState = SqlState(*b"HY015");
+    /// HY016: Cannot modify an implementation row descriptor
+    pub const CANNOT_MODIFY_IRD: SqlState = SqlState(*b"HY016");
+    /// HY017: Invalid use of an automatically allocated descriptor handle
+    pub const INVALID_USE_OF_IMPLICIT_DESCRIPTOR: SqlState = SqlState(*b"HY017");
+    /// HY018: Server declined cancel request
+    pub const CANCEL_REQUEST_DECLINED: SqlState = SqlState(*b"HY018");
+    /// HY019: Non-character and non-binary data sent in pieces
+    pub const NON_CHARACTER_DATA_SENT_IN_PIECES: SqlState = SqlState(*b"HY019");
+    /// HY020: Attempt to concatenate a null value
+    pub const NULL_VALUE_CONCATENATION: SqlState = SqlState(*b"HY020");
+    /// HY021: Inconsistent descriptor information
+    pub const INCONSISTENT_DESCRIPTOR_INFORMATION: SqlState = SqlState(*b"HY021");
+    /// HY024: Invalid attribute value
+    pub const INVALID_ATTRIBUTE_VALUE: SqlState = SqlState(*b"HY024");
+    /// HY090: Invalid string or buffer length
+    pub const INVALID_STRING_OR_BUFFER_LENGTH: SqlState = SqlState(*b"HY090");
+    /// HY091: Invalid descriptor field identifier
+    pub const INVALID_DESCRIPTOR_FIELD_IDENTIFIER: SqlState = SqlState(*b"HY091");
+    /// HY092: Invalid attribute/option identifier
+    pub const INVALID_ATTRIBUTE_IDENTIFIER: SqlState = SqlState(*b"HY092");
+    /// HY095: Function type out of range
+    pub const FUNCTION_TYPE_OUT_OF_RANGE: SqlState = SqlState(*b"HY095");
+    /// HY096: Invalid information type
+    pub const INVALID_INFORMATION_TYPE: SqlState = SqlState(*b"HY096");
+    /// HY097: Column type out of range
+    pub const COLUMN_TYPE_OUT_OF_RANGE: SqlState = SqlState(*b"HY097");
+    /// HY098: Scope type out of range
+    pub const SCOPE_TYPE_OUT_OF_RANGE: SqlState = SqlState(*b"HY098");
+    /// HY099: Nullable type out of range
+    pub const NULLABLE_TYPE_OUT_OF_RANGE: SqlState = SqlState(*b"HY099");
+    /// HY100: Uniqueness option type out of range
+    pub const UNIQUENESS_OPTION_TYPE_OUT_OF_RANGE: SqlState = SqlState(*b"HY100");
+    /// HY101: Accuracy option type out of range
+    pub const ACCURACY_OPTION_TYPE_OUT_OF_RANGE: SqlState = SqlState(*b"HY101");
+    /// HY103: Invalid retrieval code
+    pub const INVALID_RETRIEVAL_CODE: SqlState = SqlState(*b"HY103");
+    /// HY104: Invalid precision or scale value
+    pub const INVALID_PRECISION_OR_SCALE: SqlState = SqlState(*b"HY104");
+    /// HY105: Invalid parameter type
+    pub const INVALID_PARAMETER_TYPE: SqlState = SqlState(*b"HY105");
+    /// HY106: Fetch type out of range
+    pub const FETCH_TYPE_OUT_OF_RANGE: SqlState = SqlState(*b"HY106");
+    /// HY107: Row value out of range
+    pub const ROW_VALUE_OUT_OF_RANGE: SqlState = SqlState(*b"HY107");
+    /// HY109: Invalid cursor position
+    pub const INVALID_CURSOR_POSITION: SqlState = SqlState(*b"HY109");
+    /// HY110: Invalid driver completion
+    pub const INVALID_DRIVER_COMPLETION: SqlState = SqlState(*b"HY110");
+    /// HY111: Invalid bookmark value
+    pub const INVALID_BOOKMARK_VALUE: SqlState = SqlState(*b"HY111");
+    /// HYC00: Optional feature not implemented
+    pub const OPTIONAL_FEATURE_NOT_IMPLEMENTED: SqlState = SqlState(*b"HYC00");
+    /// HYT00: Timeout expired
+    pub const TIMEOUT_EXPIRED: SqlState = SqlState(*b"HYT00");
+    /// HYT01: Connection timeout expired
+    pub const CONNECTION_TIMEOUT_EXPIRED: SqlState = SqlState(*b"HYT01");
+    /// IM001: Driver does not support this function
+    pub const DRIVER_DOES_NOT_SUPPORT_FUNCTION: SqlState = SqlState(*b"IM001");
+    /// IM002: Data source name not found and no default driver specified
+    pub const DATA_SOURCE_NOT_FOUND: SqlState = SqlState(*b"IM002");
+    /// IM003: Specified driver could not be loaded
+    pub const DRIVER_COULD_NOT_BE_LOADED: SqlState = SqlState(*b"IM003");
+    /// IM004: Driver's SQLAllocHandle on SQL_HANDLE_ENV failed
+    pub const DRIVER_ENV_ALLOCATION_FAILED: SqlState = SqlState(*b"IM004");
+    /// IM005: Driver's SQLAllocHandle on SQL_HANDLE_DBC failed
+    pub const DRIVER_DBC_ALLOCATION_FAILED: SqlState = SqlState(*b"IM005");
+    /// IM006: Driver's SQLSetConnectAttr failed
+    pub const DRIVER_SET_CONNECT_ATTR_FAILED: SqlState = SqlState(*b"IM006");
+    /// IM007: No data source or driver specified; dialog prohibited
+    pub const NO_DATA_SOURCE_OR_DRIVER: SqlState = SqlState(*b"IM007");
+    /// IM008: Dialog failed
+    pub const DIALOG_FAILED: SqlState = SqlState(*b"IM008");
+    /// IM009: Unable to load translation DLL
+    pub const UNABLE_TO_LOAD_TRANSLATION_DLL: SqlState = SqlState(*b"IM009");
+    /// IM010: Data source name too long
+    pub const DATA_SOURCE_NAME_TOO_LONG: SqlState = SqlState(*b"IM010");
+    /// IM011: Driver name too long
+    pub const DRIVER_NAME_TOO_LONG: SqlState = SqlState(*b"IM011");
+    /// IM012: DRIVER keyword syntax error
+    pub const DRIVER_KEYWORD_SYNTAX_ERROR: SqlState = SqlState(*b"IM012");
+    /// IM014: Invalid name of File DSN or architecture mismatch
+    pub const ARCHITECTURE_MISMATCH: SqlState = SqlState(*b"IM014");
+    /// IM015: Corrupt file data source
+    pub const CORRUPT_FILE_DATA_SOURCE: SqlState = SqlState(*b"IM015");
+
+    /// Returns the SQLSTATE as a 5 character string
+    pub fn as_str(&self) -> &str {
+        // SAFETY: SQLSTATE is validated to consist only of ASCII characters
+        unsafe { core::str::from_utf8_unchecked(&self.0) }
+    }
+
+    /// Returns the 2 character class of the SQLSTATE
+    pub fn class(&self) -> &str {
+        &self.as_str()[..2]
+    }
+
+    /// Returns the 3 character subclass of the SQLSTATE. Subclass "000" denotes that there
+    /// is no subclass for the SQLSTATE
+    pub fn subclass(&self) -> &str {
+        &self.as_str()[2..]
+    }
+
+    /// Returns `true` if the SQLSTATE is of class 01 (warning) which is returned along with
+    /// SQL_SUCCESS_WITH_INFO
+    pub fn is_warning(&self) -> bool {
+        self.class() == "01"
+    }
+
+    /// Returns `true` if the SQLSTATE is of class 08 (connection exception) or if the
+    /// connection timed out
+    pub fn is_connection_failure(&self) -> bool {
+        self.class() == "08" || *self == Self::CONNECTION_TIMEOUT_EXPIRED
+    }
+
+    /// Returns `true` if the SQLSTATE is of class 23 (integrity constraint violation) or if
+    /// the transaction was rolled back because of an integrity constraint violation
+    pub fn is_integrity_violation(&self) -> bool {
+        self.class() == "23" || *self == Self::TRANSACTION_INTEGRITY_CONSTRAINT_VIOLATION
+    }
+
+    /// Returns `true` if the failed operation may succeed when retried, e.g. after a deadlock,
+    /// a timeout or a dropped connection. The transaction may have to be rolled back and the
+    /// connection reestablished before the operation is retried
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            *self,
+            Self::SERIALIZATION_FAILURE
+                | Self::TIMEOUT_EXPIRED
+                | Self::CONNECTION_TIMEOUT_EXPIRED
+                | Self::UNABLE_TO_ESTABLISH_CONNECTION
+                | Self::CONNECTION_FAILURE_DURING_TRANSACTION
+                | Self::COMMUNICATION_LINK_FAILURE
+        )
+    }
+
+    fn parse<C: Copy + Into<u32>>(source: &[C]) -> Result<Self, InvalidSqlState> {
+        // Null terminator is optional
+        let source = match source {
+            [sqlstate @ .., nul] if source.len() == SQLSTATE_SIZE + 1 && (*nul).into() == 0 => {
+                sqlstate
+            }
+            sqlstate => sqlstate,
+        };
+
+        if source.len() != SQLSTATE_SIZE {
+            return Err(InvalidSqlState);
+        }
+
+        let mut sqlstate = [0; SQLSTATE_SIZE];
+        for (s, &c) in sqlstate.iter_mut().zip(source) {
+            *s = u8::try_from(c.into())
+                .ok()
+                .filter(|c| c.is_ascii_digit() || c.is_ascii_uppercase())
+                .ok_or(InvalidSqlState)?;
+        }
+
+        Ok(Self(sqlstate))
+    }
+}
+impl fmt::Display for SqlState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl core::str::FromStr for SqlState {
+    type Err = InvalidSqlState;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        Self::parse(source.as_bytes())
+    }
+}
+impl TryFrom<&[SQLCHAR]> for SqlState {
+    type Error = InvalidSqlState;
+
+    fn try_from(source: &[SQLCHAR]) -> Result<Self, Self::Error> {
+        Self::parse(source)
+    }
+}
+impl TryFrom<&[SQLWCHAR]> for SqlState {
+    type Error = InvalidSqlState;
+
+    fn try_from(source: &[SQLWCHAR]) -> Result<Self, Self::Error> {
+        Self::parse(source)
+    }
+}
+impl<C: OdbcChar + Copy + Into<u32>> TryFrom<&SQLSTATE<C>> for SqlState {
+    type Error = InvalidSqlState;
+
+    fn try_from(source: &SQLSTATE<C>) -> Result<Self, Self::Error> {
+        Self::parse(&source.0)
+    }
+}
+impl From<SqlState> for SQLSTATE<SQLCHAR> {
+    fn from(source: SqlState) -> Self {
+        let mut sqlstate = [0; SQLSTATE_SIZE + 1];
+        sqlstate[..SQLSTATE_SIZE].copy_from_slice(&source.0);
+        Self(sqlstate)
+    }
+}
+impl From<SqlState> for SQLSTATE<SQLWCHAR> {
+    fn from(source: SqlState) -> Self {
+        let mut sqlstate = [0; SQLSTATE_SIZE + 1];
+        for (s, c) in sqlstate.iter_mut().zip(source.0) {
+            *s = c.into();
+        }
+        Self(sqlstate)
+    }
+}
+
+/// Error returned when a string is not a valid SQLSTATE, i.e. it doesn't consist of exactly 5
+/// digits or uppercase ASCII letters
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidSqlState;
+
+impl fmt::Display for InvalidSqlState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid SQLSTATE")
+    }
+}
+impl core::error::Error for InvalidSqlState {}
+
 // Implement DiagField for uninitialized diagnostic attributes
 impl<D: Ident, T: Scalar, H: Handle> DiagField<H, D> for MaybeUninit<T>
 where
@@ -148,6 +549,149 @@ impl<D: Ident, H: Handle> DiagField<H, D> for OdbcStr<MaybeUninit<SQLWCHAR>> whe
 {
 }
 
+/// Diagnostic record, with its message and optional fields decoded, returned by
+/// [`Diagnostics::diagnostics`](crate::api::Diagnostics::diagnostics)
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg(feature = "std")]
+pub struct DiagRecord {
+    pub sqlstate: SQLSTATE<SQLCHAR>,
+    pub native_error: SQLINTEGER,
+    pub message: String,
+    /// Row in the rowset or in the set of parameters to which the record applies. Only
+    /// available on statement handles and if the driver knows the row
+    pub row_number: Option<SQLLEN>,
+    /// Column in the result set or parameter in the set of parameters to which the record
+    /// applies. Only available on statement handles and if the driver knows the column
+    pub column_number: Option<SQLINTEGER>,
+    pub class_origin: Option<String>,
+    pub server_name: Option<String>,
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for DiagRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sqlstate = &self.sqlstate.0[..SQLSTATE_SIZE];
+
+        write!(
+            f,
+            "[{}] {}",
+            String::from_utf8_lossy(sqlstate),
+            self.message
+        )
+    }
+}
+#[cfg(feature = "std")]
+impl std::error::Error for DiagRecord {}
+
+/// Iterator over the diagnostic records of a handle, see
+/// [`Diagnostics::diagnostics`](crate::api::Diagnostics::diagnostics)
+#[cfg(feature = "std")]
+pub struct DiagRecords<'handle, H: Diagnostics> {
+    handle: &'handle H,
+    rec_number: SQLSMALLINT,
+}
+
+#[cfg(feature = "std")]
+impl<'handle, H: Diagnostics> DiagRecords<'handle, H> {
+    pub(crate) fn new(handle: &'handle H) -> Self {
+        Self {
+            handle,
+            rec_number: 0,
+        }
+    }
+
+    /// Queries a character field. Empty values are treated as if the field was not set
+    fn get_str_field<A: Ident<Type = SQLSMALLINT>>(&self, rec_number: NonZeroI16) -> Option<String>
+    where
+        OdbcStr<SQLCHAR>: DiagField<H, A> + AttrGet<A> + Ansi,
+    {
+        let (field, _) = retry_truncated::<SQLCHAR>(|diag_info| {
+            let mut string_length = 0;
+
+            let sql_return = unsafe {
+                ffi::SQLGetDiagFieldA(
+                    H::Ident::IDENTIFIER,
+                    self.handle.as_SQLHANDLE(),
+                    rec_number.get(),
+                    A::IDENTIFIER,
+                    diag_info.as_mut_SQLPOINTER(),
+                    slice_len(diag_info),
+                    &mut string_length,
+                )
+            };
+
+            (sql_return, string_length as SQLLEN)
+        });
+
+        field
+            .filter(|field| !field.is_empty())
+            .map(|field| field.to_string_lossy().into_owned())
+    }
+
+    /// Queries a statement-only integer field. Negative values denote that the field is not
+    /// applicable or unknown to the driver
+    fn get_stmt_field<T: Scalar + Default + PartialOrd>(
+        &self,
+        rec_number: NonZeroI16,
+        identifier: SQLSMALLINT,
+    ) -> Option<T> {
+        if H::Ident::IDENTIFIER != SQL_HANDLE_STMT::IDENTIFIER {
+            return None;
+        }
+
+        let mut value = T::default();
+        let sql_return = unsafe {
+            ffi::SQLGetDiagFieldA(
+                H::Ident::IDENTIFIER,
+                self.handle.as_SQLHANDLE(),
+                rec_number.get(),
+                identifier,
+                (&mut value as *mut T).cast(),
+                0,
+                ptr::null_mut(),
+            )
+        };
+
+        (SQL_SUCCEEDED(sql_return) && value >= T::default()).then_some(value)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<H: Diagnostics> Iterator for DiagRecords<'_, H> {
+    type Item = DiagRecord;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.rec_number = self.rec_number.checked_add(1)?;
+        let rec_number = NonZeroI16::new(self.rec_number)?;
+
+        let mut sqlstate = MaybeUninit::<SQLSTATE<SQLWCHAR>>::zeroed();
+        let mut native_error = 0;
+        let (message, _) = self
+            .handle
+            .get_diag_rec_w(rec_number, &mut sqlstate, &mut native_error);
+
+        let Some(message) = message else {
+            // Iteration is stopped on SQL_NO_DATA or any error
+            self.rec_number = SQLSMALLINT::MAX;
+            return None;
+        };
+
+        // SAFETY: SQLSTATE was zero-initialized
+        let sqlstate = unsafe { sqlstate.assume_init() };
+
+        Some(DiagRecord {
+            // SQLSTATE consists of alphanumeric ASCII characters
+            sqlstate: SQLSTATE(sqlstate.0.map(|c| c as SQLCHAR)),
+            native_error,
+            message: message.to_string_lossy().into_owned(),
+            row_number: self.get_stmt_field(rec_number, SQL_DIAG_ROW_NUMBER::IDENTIFIER),
+            column_number: self.get_stmt_field(rec_number, SQL_DIAG_COLUMN_NUMBER::IDENTIFIER),
+            class_origin: self.get_str_field::<SQL_DIAG_CLASS_ORIGIN>(rec_number),
+            server_name: self.get_str_field::<SQL_DIAG_SERVER_NAME>(rec_number),
+        })
+    }
+}
+
 //=====================================================================================//
 //-------------------------------------Attributes--------------------------------------//
 
@@ -162,7 +706,10 @@ pub struct SQL_DIAG_CURSOR_ROW_COUNT;
 unsafe impl Attr<SQL_DIAG_CURSOR_ROW_COUNT> for SQLLEN {
     type DefinedBy = OdbcDefined;
 }
-impl<V: OdbcVersion> DiagField<SQLHSTMT<'_, '_, '_, V>, SQL_DIAG_CURSOR_ROW_COUNT> for SQLLEN {}
+impl<S: StmtState, V: OdbcVersion> DiagField<SQLHSTMT<'_, '_, '_, S, V>, SQL_DIAG_CURSOR_ROW_COUNT>
+    for SQLLEN
+{
+}
 unsafe impl AttrGet<SQL_DIAG_CURSOR_ROW_COUNT> for SQLLEN {}
 
 #[derive(Ident)]
@@ -172,7 +719,7 @@ pub struct SQL_DIAG_DYNAMIC_FUNCTION;
 unsafe impl Attr<SQL_DIAG_DYNAMIC_FUNCTION> for OdbcStr<SQLCHAR> {
     type DefinedBy = OdbcDefined;
 }
-impl<V: OdbcVersion> DiagField<SQLHSTMT<'_, '_, '_, V>, SQL_DIAG_DYNAMIC_FUNCTION>
+impl<S: StmtState, V: OdbcVersion> DiagField<SQLHSTMT<'_, '_, '_, S, V>, SQL_DIAG_DYNAMIC_FUNCTION>
     for OdbcStr<SQLCHAR>
 {
 }
@@ -185,7 +732,8 @@ pub struct SQL_DIAG_DYNAMIC_FUNCTION_CODE;
 unsafe impl Attr<SQL_DIAG_DYNAMIC_FUNCTION_CODE> for DiagDynamicFunctionCode {
     type DefinedBy = OdbcDefined;
 }
-impl<V: OdbcVersion> DiagField<SQLHSTMT<'_, '_, '_, V>, SQL_DIAG_DYNAMIC_FUNCTION_CODE>
+impl<S: StmtState, V: OdbcVersion>
+    DiagField<SQLHSTMT<'_, '_, '_, S, V>, SQL_DIAG_DYNAMIC_FUNCTION_CODE>
     for DiagDynamicFunctionCode
 {
 }
@@ -218,7 +766,10 @@ pub struct SQL_DIAG_ROW_COUNT;
 unsafe impl Attr<SQL_DIAG_ROW_COUNT> for SQLLEN {
     type DefinedBy = OdbcDefined;
 }
-impl<V: OdbcVersion> DiagField<SQLHSTMT<'_, '_, '_, V>, SQL_DIAG_ROW_COUNT> for SQLLEN {}
+impl<S: StmtState, V: OdbcVersion> DiagField<SQLHSTMT<'_, '_, '_, S, V>, SQL_DIAG_ROW_COUNT>
+    for SQLLEN
+{
+}
 unsafe impl AttrGet<SQL_DIAG_ROW_COUNT> for SQLLEN {}
 
 /////////////////////////////////////////////////////////////////////////////////////////
@@ -242,7 +793,7 @@ pub struct SQL_DIAG_COLUMN_NUMBER;
 unsafe impl Attr<SQL_DIAG_COLUMN_NUMBER> for DiagColumnNumber {
     type DefinedBy = OdbcDefined;
 }
-impl<V: OdbcVersion> DiagField<SQLHSTMT<'_, '_, '_, V>, SQL_DIAG_COLUMN_NUMBER>
+impl<S: StmtState, V: OdbcVersion> DiagField<SQLHSTMT<'_, '_, '_, S, V>, SQL_DIAG_COLUMN_NUMBER>
     for DiagColumnNumber
 {
 }
@@ -285,7 +836,10 @@ pub struct SQL_DIAG_ROW_NUMBER;
 unsafe impl Attr<SQL_DIAG_ROW_NUMBER> for DiagRowNumber {
     type DefinedBy = OdbcDefined;
 }
-impl<V: OdbcVersion> DiagField<SQLHSTMT<'_, '_, '_, V>, SQL_DIAG_ROW_NUMBER> for DiagRowNumber {}
+impl<S: StmtState, V: OdbcVersion> DiagField<SQLHSTMT<'_, '_, '_, S, V>, SQL_DIAG_ROW_NUMBER>
+    for DiagRowNumber
+{
+}
 unsafe impl AttrGet<SQL_DIAG_ROW_NUMBER> for DiagRowNumber {}
 
 #[derive(Ident)]
@@ -385,7 +939,10 @@ mod test {
     fn new_sqlstate_SQLWCHAR() {
         let sqlstate = SQLSTATE::<SQLWCHAR>::new("12345");
 
-        assert_eq!(12, sqlstate.len());
+        assert_eq!(
+            6 * core::mem::size_of::<SQLWCHAR>(),
+            sqlstate.len() as usize
+        );
         assert_eq!([49, 50, 51, 52, 53, 0].as_ref(), sqlstate.0);
     }
 
@@ -416,4 +973,56 @@ mod test {
         assert_eq!("12345", sqlstate);
         assert_eq!(sqlstate, "12345");
     }
+
+    #[test]
+    fn parse_SqlState() {
+        let sqlstate = SQLSTATE::<SQLCHAR>::new("08S01");
+        assert_eq!(
+            Ok(SqlState::COMMUNICATION_LINK_FAILURE),
+            SqlState::try_from(&sqlstate)
+        );
+        let sqlstate = SQLSTATE::<SQLWCHAR>::new("08S01");
+        assert_eq!(
+            Ok(SqlState::COMMUNICATION_LINK_FAILURE),
+            SqlState::try_from(&sqlstate)
+        );
+
+        assert_eq!(Ok(SqlState::GENERAL_ERROR), "HY000".parse());
+        assert_eq!(Err(InvalidSqlState), "HY00".parse::<SqlState>());
+        assert_eq!(Err(InvalidSqlState), "hy000".parse::<SqlState>());
+        assert_eq!(
+            Err(InvalidSqlState),
+            SqlState::try_from(&[0x48, 0x59, 0x30, 0x30, 0x130 as SQLWCHAR][..])
+        );
+        assert_eq!(
+            Err(InvalidSqlState),
+            SqlState::try_from(&SQLSTATE::<SQLCHAR>([0; 6]))
+        );
+    }
+
+    #[test]
+    fn SqlState_class() {
+        let sqlstate = SqlState::INVALID_DESCRIPTOR_INDEX;
+
+        assert_eq!("07009", sqlstate.to_string());
+        assert_eq!("07", sqlstate.class());
+        assert_eq!("009", sqlstate.subclass());
+        assert_eq!(
+            SQLSTATE::<SQLWCHAR>::new("07009"),
+            SQLSTATE::<SQLWCHAR>::from(sqlstate)
+        );
+    }
+
+    #[test]
+    fn SqlState_predicates() {
+        assert!(SqlState::STRING_DATA_RIGHT_TRUNCATED.is_warning());
+        assert!(SqlState::UNABLE_TO_ESTABLISH_CONNECTION.is_connection_failure());
+        assert!(SqlState::INTEGRITY_CONSTRAINT_VIOLATION.is_integrity_violation());
+        assert!(SqlState::SERIALIZATION_FAILURE.is_retryable());
+        assert!(SqlState::TIMEOUT_EXPIRED.is_retryable());
+
+        assert!(!SqlState::GENERAL_ERROR.is_retryable());
+        assert!(!SqlState::OPERATION_CANCELED.is_connection_failure());
+        assert!(!SqlState::SYNTAX_ERROR_OR_ACCESS_VIOLATION.is_integrity_violation());
+    }
 }
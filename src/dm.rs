@@ -0,0 +1,162 @@
+//! Runtime loading of the ODBC driver manager
+//!
+//! With the `dlopen` feature enabled this crate doesn't link against the driver manager.
+//! Instead, the driver manager is loaded either explicitly with [`load`] or implicitly,
+//! from one of the [`DEFAULT_LIBRARIES`], when the first ODBC function is called.
+
+use crate::api::symbols::Symbols;
+use libloading::Library;
+use std::{error::Error, ffi::OsStr, fmt, sync::OnceLock};
+
+/// Names of the driver manager libraries which are tried in order by [`load_default`]
+#[cfg(windows)]
+pub const DEFAULT_LIBRARIES: &[&str] = &["odbc32.dll"];
+/// Names of the driver manager libraries which are tried in order by [`load_default`]
+#[cfg(all(target_os = "macos", not(feature = "iodbc")))]
+pub const DEFAULT_LIBRARIES: &[&str] = &["libodbc.2.dylib", "libodbc.dylib"];
+/// Names of the driver manager libraries which are tried in order by [`load_default`]
+#[cfg(all(target_os = "macos", feature = "iodbc"))]
+pub const DEFAULT_LIBRARIES: &[&str] = &["libiodbc.2.dylib", "libiodbc.dylib"];
+/// Names of the driver manager libraries which are tried in order by [`load_default`]
+#[cfg(all(unix, not(target_os = "macos"), not(feature = "iodbc")))]
+pub const DEFAULT_LIBRARIES: &[&str] = &["libodbc.so.2", "libodbc.so"];
+/// Names of the driver manager libraries which are tried in order by [`load_default`]
+#[cfg(all(unix, not(target_os = "macos"), feature = "iodbc"))]
+pub const DEFAULT_LIBRARIES: &[&str] = &["libiodbc.so.2", "libiodbc.so"];
+
+// Library is never unloaded because handles can be dropped at any point of program's execution
+static DRIVER_MANAGER: OnceLock<(Library, Symbols)> = OnceLock::new();
+
+/// Error returned when the driver manager could not be loaded
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum LoadError {
+    /// Driver manager library could not be found or opened
+    Library(libloading::Error),
+    /// Driver manager library doesn't export the required ODBC function
+    Symbol {
+        name: &'static str,
+        source: libloading::Error,
+    },
+    /// None of the driver manager libraries was tried
+    NotFound,
+    /// Driver manager was already loaded
+    AlreadyLoaded,
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadError::Library(_) => write!(f, "unable to load the ODBC driver manager"),
+            LoadError::Symbol { name, .. } => {
+                write!(f, "ODBC driver manager doesn't export `{name}`")
+            }
+            LoadError::NotFound => write!(f, "no ODBC driver manager library to load"),
+            LoadError::AlreadyLoaded => write!(f, "ODBC driver manager is already loaded"),
+        }
+    }
+}
+
+impl Error for LoadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            LoadError::Library(source) | LoadError::Symbol { source, .. } => Some(source),
+            LoadError::NotFound | LoadError::AlreadyLoaded => None,
+        }
+    }
+}
+
+/// Loads the driver manager from the given path, e.g. `/opt/odbc/lib/libodbc.so.2`, and
+/// resolves all ODBC functions from it. Wide characters of the driver manager must match
+/// `SQLWCHAR` so iODBC can only be loaded with the `iodbc` feature enabled. Must be called
+/// before any handle is allocated. Applications can use it to probe whether ODBC is available
+/// on the host.
+///
+/// # Safety
+///
+/// Library must be an ODBC driver manager. Its initialization routines are run when it's
+/// loaded and the functions it exports are called with the signatures defined by the standard
+///
+/// # Errors
+///
+/// Returns an error if the library can't be loaded, if it doesn't export some of the ODBC
+/// functions or if the driver manager was already loaded
+pub unsafe fn load<P: AsRef<OsStr>>(path: P) -> Result<(), LoadError> {
+    if is_loaded() {
+        return Err(LoadError::AlreadyLoaded);
+    }
+
+    // SAFETY: Caller guarantees that the library is a driver manager
+    let library = unsafe { Library::new(path) }.map_err(LoadError::Library)?;
+    // SAFETY: Driver manager exports ODBC functions with the signatures defined by the standard
+    let symbols = unsafe { Symbols::resolve(&library)? };
+
+    DRIVER_MANAGER
+        .set((library, symbols))
+        .map_err(|_| LoadError::AlreadyLoaded)
+}
+
+/// Loads the first of the [`DEFAULT_LIBRARIES`] which can be found on the host
+///
+/// # Errors
+///
+/// Returns the error of the last library that was tried or [`LoadError::NotFound`] if there
+/// are no libraries to try
+pub fn load_default() -> Result<(), LoadError> {
+    load_first(DEFAULT_LIBRARIES)
+}
+
+fn load_first(libraries: &[&str]) -> Result<(), LoadError> {
+    let mut result = Err(LoadError::NotFound);
+
+    for library in libraries {
+        // SAFETY: Default libraries are the names under which driver managers are installed
+        result = unsafe { load(library) };
+
+        if !matches!(result, Err(LoadError::Library(_))) {
+            break;
+        }
+    }
+
+    result
+}
+
+/// Returns `true` if the driver manager was loaded
+pub fn is_loaded() -> bool {
+    DRIVER_MANAGER.get().is_some()
+}
+
+/// Returns functions of the driver manager, loading one of the [`DEFAULT_LIBRARIES`] if no
+/// driver manager was loaded. The error can be inspected by calling [`load_default`]
+#[cfg(not(test))]
+pub(crate) fn symbols() -> Result<&'static Symbols, LoadError> {
+    if let Some((_, symbols)) = DRIVER_MANAGER.get() {
+        return Ok(symbols);
+    }
+
+    match load_default() {
+        Ok(()) | Err(LoadError::AlreadyLoaded) => Ok(&DRIVER_MANAGER.get().unwrap().1),
+        Err(error) => Err(error),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn load_missing_library() {
+        let error = unsafe { load("librs_odbc_missing.so") }.unwrap_err();
+
+        assert!(matches!(error, LoadError::Library(_)));
+        assert!(!is_loaded());
+    }
+
+    #[test]
+    fn load_no_library() {
+        let error = load_first(&[]).unwrap_err();
+
+        assert!(matches!(error, LoadError::NotFound));
+        assert!(!is_loaded());
+    }
+}
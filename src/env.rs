@@ -1,7 +1,8 @@
 use crate::attr::{Attr, AttrGet, AttrLen, AttrSet};
+use crate::sqlreturn::Availability;
 use crate::str::{OdbcChar, OdbcStr};
 use crate::{Ident, OdbcDefined, SQLCHAR, SQLINTEGER, SQLUINTEGER, SQLWCHAR, Scalar};
-use core::mem::MaybeUninit;
+use core::{convert::Infallible, mem::MaybeUninit};
 use rs_odbc_derive::{Ident, odbc_type};
 
 pub trait EnvAttr<A: Ident, V: OdbcVersion>:
@@ -115,27 +116,41 @@ unsafe impl AttrSet<SQL_ATTR_CONNECTION_POOLING> for ConnectionPooling {}
 pub trait OdbcVersion {
     type PrevVersion: OdbcVersion;
     const IDENTIFIER: SQLUINTEGER;
+
+    /// Uninhabited unless return codes introduced in ODBC 3.80 are available
+    type V3_80: Availability;
+    /// Uninhabited unless return codes introduced in ODBC 4.0 are available
+    type V4: Availability;
 }
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[expect(non_camel_case_types)]
 pub enum SQL_OV_ODBC3 {}
 impl OdbcVersion for SQL_OV_ODBC3 {
     type PrevVersion = SQL_OV_ODBC3;
     const IDENTIFIER: SQLUINTEGER = 3;
+
+    type V3_80 = Infallible;
+    type V4 = Infallible;
 }
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[expect(non_camel_case_types)]
 pub enum SQL_OV_ODBC3_80 {}
 impl OdbcVersion for SQL_OV_ODBC3_80 {
     type PrevVersion = SQL_OV_ODBC3;
     const IDENTIFIER: SQLUINTEGER = 380;
+
+    type V3_80 = ();
+    type V4 = Infallible;
 }
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[expect(non_camel_case_types)]
 pub enum SQL_OV_ODBC4 {}
 impl OdbcVersion for SQL_OV_ODBC4 {
     type PrevVersion = SQL_OV_ODBC3_80;
     const IDENTIFIER: SQLUINTEGER = 400;
+
+    type V3_80 = ();
+    type V4 = ();
 }
 
 #[odbc_type(SQLUINTEGER)]
@@ -0,0 +1,163 @@
+//! Conversions of `SQLGUID` and, with the `uuid` feature enabled, conversions between it and
+//! [`uuid::Uuid`](https://docs.rs/uuid).
+//!
+//! `SQLGUID` mirrors the layout of the Windows `GUID` where `Data1`, `Data2` and `Data3` are
+//! stored in the native byte order. Therefore, its memory doesn't match the big-endian byte
+//! order of RFC 4122 and GUIDs should be bound as `SQLGUID` and converted afterwards or, with
+//! the `uuid` feature, as `UuidBuf` which converts the value on every read and write. Drivers
+//! which return `SQL_GUID` columns as `SQL_C_CHAR` use the 36 character string form:
+//!
+//! ```
+//! use rs_odbc::SQLGUID;
+//!
+//! let guid: SQLGUID = "00112233-4455-6677-8899-AABBCCDDEEFF".parse().unwrap();
+//!
+//! assert_eq!(0x00112233, guid.Data1);
+//! assert_eq!([0x00, 0x11, 0x22, 0x33], guid.to_be_bytes()[..4]);
+//! assert_eq!("00112233-4455-6677-8899-aabbccddeeff", guid.to_string());
+//! ```
+
+#[cfg(feature = "uuid")]
+mod uuid;
+
+#[cfg(feature = "uuid")]
+pub use self::uuid::UuidBuf;
+
+use crate::{SQLCHAR, SQLGUID};
+use core::{fmt, str::FromStr};
+
+/// Error returned when a string is not a valid GUID
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidGuid(());
+
+impl fmt::Display for InvalidGuid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid GUID string")
+    }
+}
+impl core::error::Error for InvalidGuid {}
+
+impl SQLGUID {
+    /// Creates a GUID from bytes in the big-endian byte order of RFC 4122
+    pub fn from_be_bytes(bytes: [u8; 16]) -> Self {
+        let mut data4 = [0; 8];
+        data4.copy_from_slice(&bytes[8..]);
+
+        Self {
+            Data1: u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+            Data2: u16::from_be_bytes([bytes[4], bytes[5]]),
+            Data3: u16::from_be_bytes([bytes[6], bytes[7]]),
+            Data4: data4,
+        }
+    }
+
+    /// Returns bytes of the GUID in the big-endian byte order of RFC 4122
+    pub fn to_be_bytes(&self) -> [u8; 16] {
+        let mut bytes = [0; 16];
+
+        bytes[..4].copy_from_slice(&self.Data1.to_be_bytes());
+        bytes[4..6].copy_from_slice(&self.Data2.to_be_bytes());
+        bytes[6..8].copy_from_slice(&self.Data3.to_be_bytes());
+        bytes[8..].copy_from_slice(&self.Data4);
+
+        bytes
+    }
+}
+
+/// Parses the `xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx` form, optionally enclosed in braces. Hex
+/// digits are case insensitive
+impl FromStr for SQLGUID {
+    type Err = InvalidGuid;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s
+            .strip_prefix('{')
+            .and_then(|s| s.strip_suffix('}'))
+            .unwrap_or(s)
+            .as_bytes();
+
+        if s.len() != 36 || [8, 13, 18, 23].iter().any(|&i| s[i] != b'-') {
+            return Err(InvalidGuid(()));
+        }
+
+        let mut bytes = [0; 16];
+        let mut digits = s.iter().filter(|&&c| c != b'-').map(|&c| match c {
+            b'0'..=b'9' => Ok(c - b'0'),
+            b'a'..=b'f' => Ok(c - b'a' + 10),
+            b'A'..=b'F' => Ok(c - b'A' + 10),
+            _ => Err(InvalidGuid(())),
+        });
+
+        for byte in &mut bytes {
+            // Length and dashes were checked so there are exactly 32 digits
+            let (high, low) = (digits.next().unwrap()?, digits.next().unwrap()?);
+            *byte = high << 4 | low;
+        }
+
+        Ok(Self::from_be_bytes(bytes))
+    }
+}
+
+impl TryFrom<&[SQLCHAR]> for SQLGUID {
+    type Error = InvalidGuid;
+
+    fn try_from(source: &[SQLCHAR]) -> Result<Self, Self::Error> {
+        core::str::from_utf8(source)
+            .map_err(|_| InvalidGuid(()))?
+            .parse()
+    }
+}
+
+impl fmt::Display for SQLGUID {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, byte) in self.to_be_bytes().iter().enumerate() {
+            if matches!(i, 4 | 6 | 8 | 10) {
+                f.write_str("-")?;
+            }
+
+            write!(f, "{byte:02x}")?;
+        }
+
+        Ok(())
+    }
+}
+
+//=====================================================================================//
+//----------------------------------------Tests----------------------------------------//
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_guid() {
+        let guid = SQLGUID {
+            Data1: 0x6b29fc40,
+            Data2: 0xca47,
+            Data3: 0x1067,
+            Data4: [0xb3, 0x1d, 0x00, 0xdd, 0x01, 0x06, 0x62, 0xda],
+        };
+
+        assert_eq!(Ok(guid), "6B29FC40-CA47-1067-B31D-00DD010662DA".parse());
+        assert_eq!(Ok(guid), "{6b29fc40-ca47-1067-b31d-00dd010662da}".parse());
+        assert_eq!(
+            Ok(guid),
+            SQLGUID::try_from(&b"6b29fc40-ca47-1067-b31d-00dd010662da"[..])
+        );
+        assert_eq!("6b29fc40-ca47-1067-b31d-00dd010662da", guid.to_string());
+        assert_eq!(guid, SQLGUID::from_be_bytes(guid.to_be_bytes()));
+    }
+
+    #[test]
+    fn parse_invalid() {
+        for s in [
+            "",
+            "6b29fc40-ca47-1067-b31d-00dd010662d",
+            "6b29fc40-ca47-1067-b31d-00dd010662dx",
+            "6b29fc40ca47-1067-b31d-00dd010662da0",
+            "{6b29fc40-ca47-1067-b31d-00dd010662da",
+        ] {
+            assert_eq!(Err(InvalidGuid(())), s.parse::<SQLGUID>(), "{s}");
+        }
+    }
+}
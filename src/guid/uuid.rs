@@ -0,0 +1,114 @@
+use crate::{OwnedBuf, SQLGUID};
+use ::uuid::Uuid;
+use core::cell::UnsafeCell;
+
+/// Owned buffer which binds a [`Uuid`] to a SQL_C_GUID column or parameter with
+/// [`SQLHSTMT::bind_col_owned`](crate::handle::SQLHSTMT::bind_col_owned) or
+/// [`SQLHSTMT::bind_param_owned`](crate::handle::SQLHSTMT::bind_param_owned).
+///
+/// `Uuid` itself can't be bound because its bytes are in the big-endian order of RFC 4122
+/// while the driver reads and writes `SQLGUID` whose first three fields are in native byte
+/// order. Buffer holds `SQLGUID` and converts the value when it's read or written
+///
+/// ```
+/// use rs_odbc::env::SQL_OV_ODBC3_80;
+/// use rs_odbc::guid::UuidBuf;
+/// use rs_odbc::handle::SQLHSTMT;
+/// use rs_odbc::stmt::S1;
+/// use rs_odbc::SQL_C_GUID;
+/// use uuid::Uuid;
+///
+/// fn bind_id(stmt: &mut SQLHSTMT<S1, SQL_OV_ODBC3_80>) -> Option<Uuid> {
+///     stmt.bind_col_owned(1, SQL_C_GUID, Box::new(UuidBuf::default()));
+///
+///     stmt.owned_cols().get::<UuidBuf>(1).map(|(id, _)| id.get())
+/// }
+/// ```
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UuidBuf(SQLGUID);
+
+impl UuidBuf {
+    pub fn new(value: Uuid) -> Self {
+        Self(value.into())
+    }
+
+    /// Returns the value which was fetched into the buffer
+    pub fn get(&self) -> Uuid {
+        self.0.into()
+    }
+
+    /// Sets the value which is sent to the driver on the next execution
+    pub fn set(&mut self, value: Uuid) {
+        self.0 = value.into();
+    }
+}
+impl Default for UuidBuf {
+    fn default() -> Self {
+        Self::new(Uuid::nil())
+    }
+}
+impl From<Uuid> for UuidBuf {
+    fn from(source: Uuid) -> Self {
+        Self::new(source)
+    }
+}
+impl OwnedBuf for UuidBuf {
+    type Bound = UnsafeCell<SQLGUID>;
+
+    fn as_bound(buf: &UnsafeCell<Self>) -> &Self::Bound {
+        // SAFETY: Both `UuidBuf` and `UnsafeCell` are transparent wrappers
+        unsafe { &*(buf as *const UnsafeCell<Self>).cast::<UnsafeCell<SQLGUID>>() }
+    }
+}
+
+impl From<Uuid> for SQLGUID {
+    fn from(source: Uuid) -> Self {
+        let (data1, data2, data3, data4) = source.as_fields();
+
+        Self {
+            Data1: data1,
+            Data2: data2,
+            Data3: data3,
+            Data4: *data4,
+        }
+    }
+}
+impl From<SQLGUID> for Uuid {
+    fn from(source: SQLGUID) -> Self {
+        Uuid::from_fields(source.Data1, source.Data2, source.Data3, &source.Data4)
+    }
+}
+
+//=====================================================================================//
+//----------------------------------------Tests----------------------------------------//
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn uuid_roundtrip() {
+        let source = Uuid::parse_str("6b29fc40-ca47-1067-b31d-00dd010662da").unwrap();
+        let guid = SQLGUID::from(source);
+
+        assert_eq!(0x6b29fc40, guid.Data1);
+        assert_eq!(*source.as_bytes(), guid.to_be_bytes());
+        assert_eq!(source.to_string(), guid.to_string());
+        assert_eq!(source, Uuid::from(guid));
+    }
+
+    #[test]
+    fn uuid_buf() {
+        let source = Uuid::parse_str("6b29fc40-ca47-1067-b31d-00dd010662da").unwrap();
+        let buf = UnsafeCell::new(UuidBuf::default());
+
+        // Driver writes SQLGUID into the bound buffer
+        unsafe { *UuidBuf::as_bound(&buf).get() = SQLGUID::from(source) };
+        let mut buf = buf.into_inner();
+        assert_eq!(source, buf.get());
+
+        buf.set(Uuid::nil());
+        assert_eq!(UuidBuf::default(), buf);
+    }
+}
@@ -1,18 +1,25 @@
+#[cfg(feature = "std")]
+use core::cell::OnceCell;
+use core::sync::atomic::{AtomicPtr, Ordering};
 use core::{any::type_name, cell::Cell, marker::PhantomData, mem::ManuallyDrop, ptr::NonNull};
 
 #[double]
 use crate::api::ffi;
 use crate::api::{Allocate, Diagnostics, Handle};
+#[cfg(feature = "runtime_checks")]
+use crate::checks;
 use crate::conn::{C2, C3, C4, ConnState};
 use crate::convert::{AsSQLHANDLE, IntoSQLPOINTER};
 use crate::desc::{AppDesc, IPD, IRD};
 use crate::env::{OdbcVersion, SQL_ATTR_ODBC_VERSION, SQL_OV_ODBC3_80};
-#[cfg(feature = "odbc_debug")]
-use crate::stmt::{
-    SQL_ATTR_APP_PARAM_DESC, SQL_ATTR_APP_ROW_DESC, SQL_ATTR_IMP_PARAM_DESC, SQL_ATTR_IMP_ROW_DESC,
-};
-use crate::{Ident, SQLPOINTER, sqlreturn::SQL_SUCCESS};
+#[cfg(feature = "std")]
+use crate::sqlreturn::SQL_INVALID_HANDLE;
+use crate::sqlreturn::{SQL_SUCCEEDED, SQL_SUCCESS, SQLRETURN};
+use crate::stmt::{Bindings, S1, StmtState};
+use crate::{Ident, SQLPOINTER};
 use mockall_double::double;
+#[cfg(feature = "std")]
+use std::sync::{Arc, Mutex, PoisonError, Weak};
 
 /// Environment handle id
 #[derive(rs_odbc_derive::Ident)]
@@ -81,6 +88,7 @@ pub struct SQLHENV<V: OdbcVersion = SQL_OV_ODBC3_80> {
 
 impl<V: OdbcVersion> Handle for SQLHENV<V> {
     type Ident = SQL_HANDLE_ENV;
+    type Version = V;
 }
 
 impl<V: OdbcVersion> Allocate<'_, SQL_NULL_HANDLE> for SQLHENV<V> {
@@ -152,6 +160,7 @@ pub struct SQLHDBC<'env, C: ConnState, V: OdbcVersion = SQL_OV_ODBC3_80> {
 
 impl<C: ConnState, V: OdbcVersion> Handle for SQLHDBC<'_, C, V> {
     type Ident = SQL_HANDLE_DBC;
+    type Version = V;
 }
 
 impl<'env, V: OdbcVersion> Allocate<'env, SQLHENV<V>> for SQLHDBC<'env, C2, V> {
@@ -218,6 +227,81 @@ impl<'env, OC: ConnState, V: OdbcVersion> SQLHDBC<'env, OC, V> {
     }
 }
 
+/// Connection handle derived from a reference to a connected `SQLHDBC`. Unlike the original
+/// handle it is `Sync` and it can only be used to cancel functions running on the connection
+/// from another thread.
+#[derive(Debug)]
+pub struct RefSQLHDBC<'conn, V: OdbcVersion = SQL_OV_ODBC3_80> {
+    handle: SQLHANDLE,
+
+    parent: PhantomData<&'conn ()>,
+    version: PhantomData<V>,
+}
+
+impl<'conn, V: OdbcVersion> From<&'conn SQLHDBC<'_, C4, V>> for RefSQLHDBC<'conn, V> {
+    fn from(source: &'conn SQLHDBC<'_, C4, V>) -> Self {
+        Self {
+            handle: source.as_SQLHANDLE(),
+
+            parent: PhantomData,
+            version: PhantomData,
+        }
+    }
+}
+
+unsafe impl<V: OdbcVersion> AsSQLHANDLE for RefSQLHDBC<'_, V> {
+    fn as_SQLHANDLE(&self) -> SQLHANDLE {
+        self.handle
+    }
+}
+impl<V: OdbcVersion> Handle for RefSQLHDBC<'_, V> {
+    type Ident = SQL_HANDLE_DBC;
+    type Version = V;
+}
+
+// Canceling is defined by the ODBC standard to be a thread safe operation
+unsafe impl<V: OdbcVersion> Send for RefSQLHDBC<'_, V> {}
+unsafe impl<V: OdbcVersion> Sync for RefSQLHDBC<'_, V> {}
+
+/// Connection handle derived from a connected `SQLHDBC` wrapped in an `Arc`. Unlike the
+/// original handle it is `Sync` and it can only be used to cancel functions running on the
+/// connection from another thread. Once the original handle is dropped, cancel functions
+/// return SQL_INVALID_HANDLE.
+#[derive(Debug)]
+#[cfg(feature = "std")]
+pub struct WeakSQLHDBC<'env, V: OdbcVersion = SQL_OV_ODBC3_80> {
+    handle: SQLHANDLE,
+    pub(crate) parent: Weak<SQLHDBC<'env, C4, V>>,
+}
+
+#[cfg(feature = "std")]
+impl<'env, V: OdbcVersion> From<&Arc<SQLHDBC<'env, C4, V>>> for WeakSQLHDBC<'env, V> {
+    fn from(source: &Arc<SQLHDBC<'env, C4, V>>) -> Self {
+        Self {
+            handle: source.as_SQLHANDLE(),
+            parent: Arc::downgrade(source),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+unsafe impl<V: OdbcVersion> AsSQLHANDLE for WeakSQLHDBC<'_, V> {
+    fn as_SQLHANDLE(&self) -> SQLHANDLE {
+        self.handle
+    }
+}
+#[cfg(feature = "std")]
+impl<V: OdbcVersion> Handle for WeakSQLHDBC<'_, V> {
+    type Ident = SQL_HANDLE_DBC;
+    type Version = V;
+}
+
+// Canceling is defined by the ODBC standard to be a thread safe operation
+#[cfg(feature = "std")]
+unsafe impl<V: OdbcVersion> Send for WeakSQLHDBC<'_, V> {}
+#[cfg(feature = "std")]
+unsafe impl<V: OdbcVersion> Sync for WeakSQLHDBC<'_, V> {}
+
 /// Statement handle consists of all of the information associated with a SQL statement,
 /// such as any result sets created by the statement and parameters used in the execution
 /// of the statement. A statement is associated with a single connection, and there can be
@@ -242,139 +326,301 @@ impl<'env, OC: ConnState, V: OdbcVersion> SQLHDBC<'env, OC, V> {
 /// https://docs.microsoft.com/en-us/sql/odbc/reference/develop-app/statement-handles
 #[derive(Debug)]
 #[repr(transparent)]
-pub struct SQLHSTMT<'conn, 'desc, 'buf, V: OdbcVersion = SQL_OV_ODBC3_80>(
+pub struct SQLHSTMT<'conn, 'desc, 'buf, S: StmtState, V: OdbcVersion = SQL_OV_ODBC3_80>(
     pub(crate) UnsafeSQLHSTMT<'conn, 'desc, 'buf, V>,
+    PhantomData<S>,
 );
 
-impl<'conn, 'desc, 'buf, V: OdbcVersion> Handle for SQLHSTMT<'conn, 'desc, 'buf, V> {
+impl<'conn, 'desc, 'buf, S: StmtState, V: OdbcVersion> Handle
+    for SQLHSTMT<'conn, 'desc, 'buf, S, V>
+{
     type Ident = <UnsafeSQLHSTMT<'conn, 'desc, 'buf, V> as Handle>::Ident;
+    type Version = V;
 }
 
 impl<'env, 'conn, V: OdbcVersion> Allocate<'conn, SQLHDBC<'env, C4, V>>
-    for SQLHSTMT<'conn, '_, '_, V>
+    for SQLHSTMT<'conn, '_, '_, S1, V>
 {
     unsafe fn from_raw(handle: NonNull<RawHandle>) -> Self {
-        Self(unsafe { UnsafeSQLHSTMT::from_raw(handle) })
+        Self(unsafe { UnsafeSQLHSTMT::from_raw(handle) }, PhantomData)
+    }
+}
+
+impl<S: StmtState, V: OdbcVersion> Diagnostics for SQLHSTMT<'_, '_, '_, S, V> {}
+
+unsafe impl<S: StmtState, V: OdbcVersion> Send for SQLHSTMT<'_, '_, '_, S, V> {}
+
+impl<'conn, 'desc, 'buf, OS: StmtState, V: OdbcVersion> SQLHSTMT<'conn, 'desc, 'buf, OS, V> {
+    pub(crate) fn transition<S: StmtState>(self) -> SQLHSTMT<'conn, 'desc, 'buf, S, V> {
+        SQLHSTMT(self.0, PhantomData)
+    }
+
+    pub(crate) fn free(self) -> (Result<(), Self>, SQLRETURN) {
+        let (stmt, sql_return) = self.0.free();
+        (stmt.map_err(|stmt| SQLHSTMT(stmt, PhantomData)), sql_return)
+    }
+
+    /// Must only be called once neither buffers nor explicitly allocated
+    /// descriptors are bound to the statement
+    pub(crate) unsafe fn rebind<'new_desc, 'new_buf>(
+        self,
+    ) -> SQLHSTMT<'conn, 'new_desc, 'new_buf, OS, V> {
+        let mut stmt = ManuallyDrop::new(self);
+
+        SQLHSTMT(
+            UnsafeSQLHSTMT {
+                handle: stmt.0.handle,
+
+                parent: PhantomData,
+                version: PhantomData,
+
+                explicit_ard: PhantomData,
+                explicit_apd: PhantomData,
+
+                ard: PhantomData,
+                apd: PhantomData,
+                ird: PhantomData,
+                ipd: PhantomData,
+
+                bound_cols: core::mem::take(&mut stmt.0.bound_cols),
+                bound_params: core::mem::take(&mut stmt.0.bound_params),
+
+                #[cfg(feature = "std")]
+                alive: core::mem::take(&mut stmt.0.alive),
+            },
+            PhantomData,
+        )
+    }
+}
+
+/// Statement handle derived from a reference to a `SQLHSTMT`. Unlike the original handle it
+/// is `Sync` and it can only be used to cancel functions running on the statement from
+/// another thread.
+#[derive(Debug)]
+pub struct RefSQLHSTMT<'stmt, V: OdbcVersion = SQL_OV_ODBC3_80> {
+    handle: SQLHANDLE,
+
+    parent: PhantomData<&'stmt ()>,
+    version: PhantomData<V>,
+}
+
+impl<'stmt, S: StmtState, V: OdbcVersion> From<&'stmt SQLHSTMT<'_, '_, '_, S, V>>
+    for RefSQLHSTMT<'stmt, V>
+{
+    fn from(source: &'stmt SQLHSTMT<'_, '_, '_, S, V>) -> Self {
+        Self {
+            handle: source.as_SQLHANDLE(),
+
+            parent: PhantomData,
+            version: PhantomData,
+        }
+    }
+}
+
+unsafe impl<V: OdbcVersion> AsSQLHANDLE for RefSQLHSTMT<'_, V> {
+    fn as_SQLHANDLE(&self) -> SQLHANDLE {
+        self.handle
+    }
+}
+impl<V: OdbcVersion> Handle for RefSQLHSTMT<'_, V> {
+    type Ident = SQL_HANDLE_STMT;
+    type Version = V;
+}
+
+// Canceling is defined by the ODBC standard to be a thread safe operation
+unsafe impl<V: OdbcVersion> Send for RefSQLHSTMT<'_, V> {}
+unsafe impl<V: OdbcVersion> Sync for RefSQLHSTMT<'_, V> {}
+
+/// Statement handle derived from a `SQLHSTMT`. Unlike the original handle it is `Sync` and
+/// it can only be used to cancel functions running on the statement from another thread.
+/// Unlike `RefSQLHSTMT` it doesn't borrow the original handle so it remains usable while
+/// the statement goes through state transitions, e.g. while it is being executed. Once the
+/// original handle is dropped, cancel functions return SQL_INVALID_HANDLE.
+#[derive(Debug)]
+#[cfg(feature = "std")]
+pub struct WeakSQLHSTMT<'conn, V: OdbcVersion = SQL_OV_ODBC3_80> {
+    handle: SQLHANDLE,
+    alive: Arc<Mutex<bool>>,
+
+    parent: PhantomData<&'conn ()>,
+    version: PhantomData<V>,
+}
+
+#[cfg(feature = "std")]
+impl<V: OdbcVersion> Clone for WeakSQLHSTMT<'_, V> {
+    fn clone(&self) -> Self {
+        Self {
+            handle: self.handle,
+            alive: Arc::clone(&self.alive),
+
+            parent: PhantomData,
+            version: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'conn, S: StmtState, V: OdbcVersion> From<&SQLHSTMT<'conn, '_, '_, S, V>>
+    for WeakSQLHSTMT<'conn, V>
+{
+    fn from(source: &SQLHSTMT<'conn, '_, '_, S, V>) -> Self {
+        Self::from(&source.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'conn, V: OdbcVersion> From<&UnsafeSQLHSTMT<'conn, '_, '_, V>> for WeakSQLHSTMT<'conn, V> {
+    fn from(source: &UnsafeSQLHSTMT<'conn, '_, '_, V>) -> Self {
+        let alive = source.alive.get_or_init(|| Arc::new(Mutex::new(true)));
+
+        Self {
+            handle: source.as_SQLHANDLE(),
+            alive: Arc::clone(alive),
+
+            parent: PhantomData,
+            version: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<V: OdbcVersion> WeakSQLHSTMT<'_, V> {
+    /// Calls `f` with a reference to the original handle which is kept from being freed for
+    /// the duration of the call. Returns SQL_INVALID_HANDLE if it was already freed
+    pub(crate) fn upgrade(&self, f: impl FnOnce(&RefSQLHSTMT<'_, V>) -> SQLRETURN) -> SQLRETURN {
+        let alive = self.alive.lock().unwrap_or_else(PoisonError::into_inner);
+
+        if !*alive {
+            return SQL_INVALID_HANDLE;
+        }
+
+        f(&RefSQLHSTMT {
+            handle: self.handle,
+
+            parent: PhantomData,
+            version: PhantomData,
+        })
     }
 }
 
-impl<V: OdbcVersion> Diagnostics for SQLHSTMT<'_, '_, '_, V> {}
+#[cfg(feature = "std")]
+unsafe impl<V: OdbcVersion> AsSQLHANDLE for WeakSQLHSTMT<'_, V> {
+    fn as_SQLHANDLE(&self) -> SQLHANDLE {
+        self.handle
+    }
+}
+#[cfg(feature = "std")]
+impl<V: OdbcVersion> Handle for WeakSQLHSTMT<'_, V> {
+    type Ident = SQL_HANDLE_STMT;
+    type Version = V;
+}
 
-unsafe impl<V: OdbcVersion> Send for SQLHSTMT<'_, '_, '_, V> {}
+// Valid because the handle is only ever passed to SQLCancel or SQLCancelHandle, which are
+// defined by the ODBC standard to be thread safe, and only while holding the lock that the
+// original handle acquires before it is freed
+#[cfg(feature = "std")]
+unsafe impl<V: OdbcVersion> Send for WeakSQLHSTMT<'_, V> {}
+#[cfg(feature = "std")]
+unsafe impl<V: OdbcVersion> Sync for WeakSQLHSTMT<'_, V> {}
 
 /// SQLHSTMT which allows for the use of ODBC API whose safety cannot be determined by the type system (e.g. SQL_DESC_BIND_OFFSET_PTR)
 ///
 #[derive(Debug)]
-#[cfg_attr(not(feature = "odbc_debug"), repr(transparent))]
 pub struct UnsafeSQLHSTMT<'conn, 'desc, 'buf, V: OdbcVersion = SQL_OV_ODBC3_80> {
     pub(crate) handle: SQLHANDLE,
 
     parent: PhantomData<&'conn ()>,
     version: PhantomData<V>,
 
-    #[cfg(feature = "odbc_debug")]
-    pub(crate) explicit_ard: Cell<Option<&'desc UnsafeSQLHDESC<'desc, AppDesc<'buf>, V>>>,
-    #[cfg(feature = "odbc_debug")]
-    pub(crate) explicit_apd: Cell<Option<&'desc UnsafeSQLHDESC<'desc, AppDesc<'buf>, V>>>,
-
-    #[cfg(feature = "odbc_debug")]
-    pub(crate) ard: ManuallyDrop<UnsafeSQLHDESC<'desc, AppDesc<'buf>, V>>,
-    #[cfg(feature = "odbc_debug")]
-    pub(crate) apd: ManuallyDrop<UnsafeSQLHDESC<'desc, AppDesc<'buf>, V>>,
-    #[cfg(feature = "odbc_debug")]
-    pub(crate) ird: ManuallyDrop<UnsafeSQLHDESC<'desc, IRD, V>>,
-    #[cfg(feature = "odbc_debug")]
-    pub(crate) ipd: ManuallyDrop<UnsafeSQLHDESC<'desc, IPD, V>>,
-
-    #[cfg(not(feature = "odbc_debug"))]
-    pub(crate) explicit_ard: Cell<PhantomData<&'desc UnsafeSQLHDESC<'desc, AppDesc<'buf>, V>>>,
-    #[cfg(not(feature = "odbc_debug"))]
-    pub(crate) explicit_apd: Cell<PhantomData<&'desc UnsafeSQLHDESC<'desc, AppDesc<'buf>, V>>>,
-
-    #[cfg(not(feature = "odbc_debug"))]
+    // Cell makes the statement invariant over 'desc and 'buf so that explicitly allocated
+    // descriptors and bound buffers can't be released while they are used by the statement
+    pub(crate) explicit_ard: PhantomData<Cell<&'desc UnsafeSQLHDESC<'desc, AppDesc<'buf>, V>>>,
+    pub(crate) explicit_apd: PhantomData<Cell<&'desc UnsafeSQLHDESC<'desc, AppDesc<'buf>, V>>>,
+
     pub(crate) ard: PhantomData<UnsafeSQLHDESC<'desc, AppDesc<'buf>, V>>,
-    #[cfg(not(feature = "odbc_debug"))]
     pub(crate) apd: PhantomData<UnsafeSQLHDESC<'desc, AppDesc<'buf>, V>>,
-    #[cfg(not(feature = "odbc_debug"))]
     pub(crate) ird: PhantomData<UnsafeSQLHDESC<'desc, IRD, V>>,
-    #[cfg(not(feature = "odbc_debug"))]
     pub(crate) ipd: PhantomData<UnsafeSQLHDESC<'desc, IPD, V>>,
+
+    pub(crate) bound_cols: Bindings,
+    pub(crate) bound_params: Bindings,
+
+    // Shared with the derived `WeakSQLHSTMT` handles, cleared once the handle is freed
+    #[cfg(feature = "std")]
+    pub(crate) alive: OnceCell<Arc<Mutex<bool>>>,
 }
 
 impl<V: OdbcVersion> Handle for UnsafeSQLHSTMT<'_, '_, '_, V> {
     type Ident = SQL_HANDLE_STMT;
+    type Version = V;
 }
 
 impl<'env, 'conn, V: OdbcVersion> Allocate<'conn, SQLHDBC<'env, C4, V>>
     for UnsafeSQLHSTMT<'conn, '_, '_, V>
 {
-    #[cfg(feature = "odbc_debug")]
     unsafe fn from_raw(handle: NonNull<RawHandle>) -> Self {
-        unsafe {
-            let ard = UnsafeSQLHSTMT::<V>::get_descriptor_handle::<SQL_ATTR_APP_ROW_DESC>(handle);
-            let apd = UnsafeSQLHSTMT::<V>::get_descriptor_handle::<SQL_ATTR_APP_PARAM_DESC>(handle);
-            let ird = UnsafeSQLHSTMT::<V>::get_descriptor_handle::<SQL_ATTR_IMP_ROW_DESC>(handle);
-            let ipd = UnsafeSQLHSTMT::<V>::get_descriptor_handle::<SQL_ATTR_IMP_PARAM_DESC>(handle);
-
-            Self {
-                parent: PhantomData,
-                version: PhantomData,
-
-                handle,
-
-                ard: ManuallyDrop::new(UnsafeSQLHDESC::from_raw(ard)),
-                apd: ManuallyDrop::new(UnsafeSQLHDESC::from_raw(apd)),
-                ird: ManuallyDrop::new(UnsafeSQLHDESC::from_raw(ird)),
-                ipd: ManuallyDrop::new(UnsafeSQLHDESC::from_raw(ipd)),
-
-                explicit_ard: Cell::new(None),
-                explicit_apd: Cell::new(None),
-            }
-        }
-    }
+        #[cfg(feature = "runtime_checks")]
+        checks::alloc_stmt(handle.as_ptr());
 
-    #[cfg(not(feature = "odbc_debug"))]
-    unsafe fn from_raw(handle: NonNull<RawHandle>) -> Self {
         Self {
             handle: handle.as_ptr(),
 
             parent: PhantomData,
             version: PhantomData,
 
+            explicit_ard: PhantomData,
+            explicit_apd: PhantomData,
+
             ard: PhantomData,
             apd: PhantomData,
             ird: PhantomData,
             ipd: PhantomData,
 
-            explicit_ard: Cell::new(PhantomData),
-            explicit_apd: Cell::new(PhantomData),
+            bound_cols: Bindings::default(),
+            bound_params: Bindings::default(),
+
+            #[cfg(feature = "std")]
+            alive: OnceCell::new(),
         }
     }
 }
 
 impl<V: OdbcVersion> Diagnostics for UnsafeSQLHSTMT<'_, '_, '_, V> {}
 
-impl<'buf, V: OdbcVersion> UnsafeSQLHSTMT<'_, '_, 'buf, V> {
-    #[cfg(feature = "odbc_debug")]
-    unsafe fn get_descriptor_handle<A: Ident<Type = SQLINTEGER>>(handle: SQLHANDLE) -> SQLHANDLE {
-        let mut descriptor_handle = MaybeUninit::uninit();
-
-        let sql_return = ffi::SQLGetStmtAttrA(
-            handle,
-            A::IDENTIFIER,
-            descriptor_handle.as_mut_ptr() as SQLPOINTER,
-            0,
-            &mut 0,
-        );
-        if sql_return != SQL_SUCCESS {
-            panic!(
-                "{}: SQLGetStmtAttr returned {:?}",
-                type_name::<A>(),
-                sql_return
-            );
+impl<V: OdbcVersion> UnsafeSQLHSTMT<'_, '_, '_, V> {
+    /// Frees the handle. If freeing succeeds, the state kept on the Rust side is released
+    /// and the handle is not freed again on drop, otherwise the handle is returned intact
+    pub(crate) fn free(self) -> (Result<(), Self>, SQLRETURN) {
+        // Lock is held until the handle is freed so that it can't be canceled concurrently
+        #[cfg(feature = "std")]
+        let alive = self.alive.get().cloned();
+        #[cfg(feature = "std")]
+        let mut alive = alive
+            .as_ref()
+            .map(|alive| alive.lock().unwrap_or_else(PoisonError::into_inner));
+
+        let sql_return =
+            unsafe { ffi::SQLFreeHandle(SQL_HANDLE_STMT::IDENTIFIER, self.as_SQLHANDLE()) };
+
+        if !SQL_SUCCEEDED(sql_return) {
+            return (Err(self), sql_return);
+        }
+
+        #[cfg(feature = "runtime_checks")]
+        checks::free_handle(SQL_HANDLE_STMT::IDENTIFIER, self.as_SQLHANDLE());
+
+        #[cfg(feature = "std")]
+        if let Some(alive) = alive.as_mut() {
+            **alive = false;
         }
 
-        descriptor_handle.assume_init()
+        let mut stmt = ManuallyDrop::new(self);
+        drop(core::mem::take(&mut stmt.bound_cols));
+        drop(core::mem::take(&mut stmt.bound_params));
+        #[cfg(feature = "std")]
+        drop(stmt.alive.take());
+
+        (Ok(()), sql_return)
     }
 }
 
@@ -382,6 +628,16 @@ unsafe impl<V: OdbcVersion> Send for UnsafeSQLHSTMT<'_, '_, '_, V> {}
 
 impl<V: OdbcVersion> Drop for UnsafeSQLHSTMT<'_, '_, '_, V> {
     fn drop(&mut self) {
+        // Lock is held until the handle is freed so that it can't be canceled concurrently
+        #[cfg(feature = "std")]
+        let alive = self.alive.take();
+        #[cfg(feature = "std")]
+        let _alive = alive.as_ref().map(|alive| {
+            let mut alive = alive.lock().unwrap_or_else(PoisonError::into_inner);
+            *alive = false;
+            alive
+        });
+
         drop_handle(self);
     }
 }
@@ -416,6 +672,7 @@ pub struct SQLHDESC<'conn, DT, V: OdbcVersion = SQL_OV_ODBC3_80>(
 
 impl<DT, V: OdbcVersion> Handle for SQLHDESC<'_, DT, V> {
     type Ident = SQL_HANDLE_DESC;
+    type Version = V;
 }
 
 impl<'env, 'conn, 'buf, V: OdbcVersion> Allocate<'conn, SQLHDBC<'env, C4, V>>
@@ -433,22 +690,19 @@ unsafe impl<DT, V: OdbcVersion> Send for SQLHDESC<'_, DT, V> {}
 /// SQLHDESC which allows for the use of ODBC API whose safety cannot be determined by the type system (e.g. SQL_DESC_BIND_OFFSET_PTR)
 ///
 #[derive(Debug)]
-#[cfg_attr(not(feature = "odbc_debug"), repr(transparent))]
+#[repr(transparent)]
 pub struct UnsafeSQLHDESC<'conn, T, V: OdbcVersion = SQL_OV_ODBC3_80> {
     pub(crate) handle: SQLHANDLE,
 
     parent: PhantomData<&'conn ()>,
     version: PhantomData<V>,
 
-    #[cfg(feature = "odbc_debug")]
-    // TODO: Implement properly
-    pub(crate) inner: PhantomData<T>,
-    #[cfg(not(feature = "odbc_debug"))]
     pub(crate) inner: PhantomData<T>,
 }
 
 impl<V: OdbcVersion, T> Handle for UnsafeSQLHDESC<'_, T, V> {
     type Ident = SQL_HANDLE_DESC;
+    type Version = V;
 }
 
 // Valid because SQLHDBC is covariant
@@ -489,6 +743,7 @@ unsafe impl<DT, V: OdbcVersion> AsSQLHANDLE for RefUnsafeSQLHDESC<'_, DT, V> {
 }
 impl<V: OdbcVersion, DT> Handle for RefUnsafeSQLHDESC<'_, DT, V> {
     type Ident = SQL_HANDLE_DESC;
+    type Version = V;
 }
 
 impl<DT, V: OdbcVersion> Diagnostics for RefUnsafeSQLHDESC<'_, DT, V> {}
@@ -505,32 +760,155 @@ unsafe impl<'conn, DT, V: OdbcVersion> AsSQLHANDLE for RefSQLHDESC<'conn, DT, V>
 }
 impl<V: OdbcVersion, DT> Handle for RefSQLHDESC<'_, DT, V> {
     type Ident = SQL_HANDLE_DESC;
+    type Version = V;
 }
 
 impl<DT, V: OdbcVersion> Diagnostics for RefSQLHDESC<'_, DT, V> {}
 
-fn drop_handle<H: Handle>(handle: &mut H) {
-    let sql_return = unsafe { ffi::SQLFreeHandle(H::Ident::IDENTIFIER, handle.as_SQLHANDLE()) };
+/// Action taken when disconnecting or freeing a handle fails while the handle is being dropped.
+/// To handle such failures explicitly, use `Free::try_free` or `SQLHDBC::close` instead.
+#[derive(Debug, Clone, Copy)]
+pub enum DropPolicy {
+    /// Panic unless the thread is already panicking. This is the default with the `std` feature
+    Panic,
+    /// Report failure to the given hook, e.g. to log it
+    Hook(fn(&DropError)),
+    /// Ignore failure. This is the default without the `std` feature because, in that case,
+    /// it can't be determined whether the thread is already panicking
+    Ignore,
+}
 
-    #[cfg(feature = "std")]
-    if std::thread::panicking() {
-        return;
-    }
+/// Failure to disconnect or free a handle while it was being dropped
+#[derive(Debug, Clone, Copy)]
+pub struct DropError {
+    /// Type name of the handle that was dropped
+    pub handle: &'static str,
+    /// ODBC function that failed, i.e. SQLDisconnect or SQLFreeHandle
+    pub function: &'static str,
+    pub sql_return: SQLRETURN,
+}
 
-    if sql_return != SQL_SUCCESS {
-        panic!(
-            "{}: SQLFreeHandle returned: {:?}",
-            type_name::<H>(),
-            sql_return
+impl core::fmt::Display for DropError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{}: {} returned {:?}",
+            self.handle, self.function, self.sql_return
         )
     }
 }
 
+// Addresses of these statics are unique and stand for the policies without a hook
+static DROP_POLICY_PANIC: u8 = 0;
+static DROP_POLICY_IGNORE: u8 = 0;
+
+// Any other value is a pointer to the hook function
+#[cfg(feature = "std")]
+static DROP_POLICY: AtomicPtr<()> = AtomicPtr::new(drop_policy_ptr(&DROP_POLICY_PANIC));
+#[cfg(not(feature = "std"))]
+static DROP_POLICY: AtomicPtr<()> = AtomicPtr::new(drop_policy_ptr(&DROP_POLICY_IGNORE));
+
+const fn drop_policy_ptr(policy: &'static u8) -> *mut () {
+    (policy as *const u8).cast_mut().cast()
+}
+
+/// Sets the process-wide action taken when a handle fails to be disconnected or freed on drop
+pub fn set_drop_policy(policy: DropPolicy) {
+    let policy = match policy {
+        DropPolicy::Panic => drop_policy_ptr(&DROP_POLICY_PANIC),
+        DropPolicy::Hook(hook) => hook as *mut (),
+        DropPolicy::Ignore => drop_policy_ptr(&DROP_POLICY_IGNORE),
+    };
+
+    DROP_POLICY.store(policy, Ordering::Relaxed);
+}
+
+/// Returns the process-wide action taken when a handle fails to be disconnected or freed on drop
+pub fn drop_policy() -> DropPolicy {
+    match DROP_POLICY.load(Ordering::Relaxed) {
+        policy if policy == drop_policy_ptr(&DROP_POLICY_PANIC) => DropPolicy::Panic,
+        policy if policy == drop_policy_ptr(&DROP_POLICY_IGNORE) => DropPolicy::Ignore,
+        // Valid because only pointers to hook functions are stored besides the sentinels
+        hook => DropPolicy::Hook(unsafe { core::mem::transmute::<*mut (), fn(&DropError)>(hook) }),
+    }
+}
+
+pub(crate) fn drop_failed(error: DropError) {
+    match drop_policy() {
+        DropPolicy::Panic => {
+            #[cfg(feature = "std")]
+            if std::thread::panicking() {
+                return;
+            }
+
+            panic!("{}", error)
+        }
+        DropPolicy::Hook(hook) => hook(&error),
+        DropPolicy::Ignore => {}
+    }
+}
+
+fn drop_handle<H: Handle>(handle: &mut H) {
+    let sql_return = unsafe { ffi::SQLFreeHandle(H::Ident::IDENTIFIER, handle.as_SQLHANDLE()) };
+
+    if SQL_SUCCEEDED(sql_return) {
+        #[cfg(feature = "runtime_checks")]
+        checks::free_handle(H::Ident::IDENTIFIER, handle.as_SQLHANDLE());
+    } else {
+        drop_failed(DropError {
+            handle: type_name::<H>(),
+            function: "SQLFreeHandle",
+            sql_return,
+        })
+    }
+}
+
 #[cfg(test)]
 mod test {
     #![allow(non_snake_case)]
 
     use super::*;
+    use crate::api::{Cancel, Diagnostics, Free, Statement};
+    use crate::desc::{
+        ParamStatus, RowStatus, SQL_DESC_DATA_PTR, SQL_DESC_PRECISION, SQL_DESC_SCALE,
+        SQL_PARAM_UNUSED, SQL_ROW_NOROW,
+    };
+    use crate::info::{SQL_GD_ANY_COLUMN, SQL_GD_ANY_ORDER};
+    use crate::sqlreturn::{
+        ReturnCode, SQL_ERROR, SQL_INVALID_HANDLE, SQL_NEED_DATA, SQL_NO_DATA,
+        SQL_PARAM_DATA_AVAILABLE, SQL_STILL_EXECUTING, SQL_SUCCESS_WITH_INFO,
+    };
+    use crate::stmt::{
+        BindOffset, SQL_ATTR_APP_PARAM_DESC, SQL_ATTR_APP_ROW_DESC, SQL_ATTR_PARAM_OPERATION_PTR,
+        SQL_ATTR_PARAM_STATUS_PTR, SQL_ATTR_PARAMS_PROCESSED_PTR, SQL_ATTR_PARAMSET_SIZE,
+        SQL_ATTR_ROW_ARRAY_SIZE, SQL_ATTR_ROW_BIND_OFFSET_PTR, SQL_ATTR_ROW_BIND_TYPE,
+        SQL_ATTR_ROW_STATUS_PTR, SQL_ATTR_ROWS_FETCHED_PTR, UnknownOutputParam,
+    };
+    use crate::str::OdbcString;
+    use crate::{
+        DataAtExec, RowBuf, SQL_C_BINARY, SQL_C_CHAR, SQL_C_NUMERIC, SQL_C_SLONG, SQL_CLOSE,
+        SQL_INTEGER, SQL_LONGVARBINARY, SQL_NULL_DATA, SQL_NUMERIC_STRUCT, SQL_PARAM_INPUT,
+        SQL_PARAM_OUTPUT_STREAM, SQL_RESET_PARAMS, SQL_UNBIND, SQL_VARCHAR, SQLCHAR, SQLINTEGER,
+        SQLLEN, SQLSMALLINT, SQLUSMALLINT, SQLWCHAR, StrLenOrInd, UnbindOption,
+    };
+    use core::cell::UnsafeCell;
+    use core::mem::MaybeUninit;
+    use core::sync::atomic::AtomicUsize;
+
+    // Implicit application descriptors are queried when the statement is allocated
+    #[cfg(feature = "runtime_checks")]
+    fn expect_app_descs() -> impl Sized {
+        let SQLGetStmtAttrA_ctx = ffi::SQLGetStmtAttrA_context();
+
+        SQLGetStmtAttrA_ctx
+            .expect()
+            .returning(|_, _, ValuePtr, _, _| {
+                unsafe { *ValuePtr.cast::<usize>() = 42 };
+                SQL_SUCCESS
+            });
+
+        SQLGetStmtAttrA_ctx
+    }
 
     #[test]
     fn env_SQL_OV_ODBC3_80_version_set() {
@@ -632,7 +1010,1738 @@ mod test {
         };
     }
 
-    // TODO: Mockall is buggy and these tests fail more often
+    #[test]
+    fn cancel_RefSQLHSTMT() {
+        let stmt_raw_handle = NonNull::new(13 as SQLHANDLE).unwrap();
+
+        let SQLCancel_ctx = ffi::SQLCancel_context();
+        let SQLFreeHandle_ctx = ffi::SQLFreeHandle_context();
+
+        SQLCancel_ctx
+            .expect()
+            .once()
+            .withf_st(move |x| *x == stmt_raw_handle.as_ptr())
+            .return_const(SQL_SUCCESS);
+        SQLFreeHandle_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, y| {
+                *x == SQL_HANDLE_STMT::IDENTIFIER && *y == stmt_raw_handle.as_ptr()
+            })
+            .return_const(SQL_SUCCESS);
+
+        #[cfg(feature = "runtime_checks")]
+        let _SQLGetStmtAttrA_ctx = expect_app_descs();
+
+        let stmt = unsafe { SQLHSTMT::<S1, SQL_OV_ODBC3_80>::from_raw(stmt_raw_handle) };
+        assert_eq!(SQL_SUCCESS, RefSQLHSTMT::from(&stmt).SQLCancel());
+    }
+
+    #[test]
+    fn cancel_WeakSQLHSTMT() {
+        let stmt_raw_handle = NonNull::new(13 as SQLHANDLE).unwrap();
+        let stmt_addr = stmt_raw_handle.as_ptr() as usize;
+
+        let SQLExecDirectA_ctx = ffi::SQLExecDirectA_context();
+        let SQLCancel_ctx = ffi::SQLCancel_context();
+        let SQLFreeHandle_ctx = ffi::SQLFreeHandle_context();
+
+        SQLExecDirectA_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, _, _| *x == stmt_raw_handle.as_ptr())
+            .return_const(SQL_SUCCESS);
+        // Canceled from another thread
+        SQLCancel_ctx
+            .expect()
+            .once()
+            .withf(move |x| *x as usize == stmt_addr)
+            .return_const(SQL_SUCCESS);
+        SQLFreeHandle_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, y| {
+                *x == SQL_HANDLE_STMT::IDENTIFIER && *y == stmt_raw_handle.as_ptr()
+            })
+            .return_const(SQL_SUCCESS);
+
+        #[cfg(feature = "runtime_checks")]
+        let _SQLGetStmtAttrA_ctx = expect_app_descs();
+
+        let stmt = unsafe { SQLHSTMT::<S1, SQL_OV_ODBC3_80>::from_raw(stmt_raw_handle) };
+        let stmt_weak = WeakSQLHSTMT::from(&stmt);
+
+        // Derived handle outlives the state transition
+        let (stmt, _) = stmt.SQLExecDirectA("SELECT 1".as_ref());
+        let stmt = stmt.ok().unwrap();
+
+        std::thread::scope(|s| {
+            let stmt_weak = stmt_weak.clone();
+            s.spawn(move || assert_eq!(SQL_SUCCESS, stmt_weak.SQLCancel()));
+        });
+
+        drop(stmt);
+        assert_eq!(SQL_INVALID_HANDLE, stmt_weak.SQLCancel());
+        assert_eq!(SQL_INVALID_HANDLE, stmt_weak.SQLCancelHandle());
+    }
+
+    #[test]
+    fn try_free_error() {
+        let stmt_raw_handle = NonNull::new(13 as SQLHANDLE).unwrap();
+
+        let SQLFreeHandle_ctx = ffi::SQLFreeHandle_context();
+        let mut seq = mockall::Sequence::new();
+
+        SQLFreeHandle_ctx
+            .expect()
+            .once()
+            .in_sequence(&mut seq)
+            .withf_st(move |x, y| {
+                *x == SQL_HANDLE_STMT::IDENTIFIER && *y == stmt_raw_handle.as_ptr()
+            })
+            .return_const(SQL_ERROR);
+        SQLFreeHandle_ctx
+            .expect()
+            .once()
+            .in_sequence(&mut seq)
+            .withf_st(move |x, y| {
+                *x == SQL_HANDLE_STMT::IDENTIFIER && *y == stmt_raw_handle.as_ptr()
+            })
+            .return_const(SQL_SUCCESS);
+
+        #[cfg(feature = "runtime_checks")]
+        let _SQLGetStmtAttrA_ctx = expect_app_descs();
+
+        let stmt = unsafe { SQLHSTMT::<S1, SQL_OV_ODBC3_80>::from_raw(stmt_raw_handle) };
+        let stmt_weak = WeakSQLHSTMT::from(&stmt);
+
+        let (stmt, sql_return) = stmt.try_free();
+        assert_eq!(SQL_ERROR, sql_return);
+        assert_eq!(SQL_SUCCESS, stmt.unwrap_err().try_free().1);
+        assert_eq!(SQL_INVALID_HANDLE, stmt_weak.SQLCancel());
+    }
+
+    #[test]
+    fn drop_policy_hook() {
+        static HOOK_CALLED: AtomicUsize = AtomicUsize::new(0);
+        fn hook(error: &DropError) {
+            assert_eq!("SQLFreeHandle", error.function);
+            assert_eq!(SQL_ERROR, error.sql_return);
+            HOOK_CALLED.fetch_add(1, Ordering::Relaxed);
+        }
+
+        let desc_raw_handle = NonNull::new(13 as SQLHANDLE).unwrap();
+
+        let SQLFreeHandle_ctx = ffi::SQLFreeHandle_context();
+
+        SQLFreeHandle_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, y| {
+                *x == SQL_HANDLE_DESC::IDENTIFIER && *y == desc_raw_handle.as_ptr()
+            })
+            .return_const(SQL_ERROR);
+
+        set_drop_policy(DropPolicy::Hook(hook));
+        drop(unsafe { SQLHDESC::<AppDesc, SQL_OV_ODBC3_80>::from_raw(desc_raw_handle) });
+        set_drop_policy(DropPolicy::Panic);
+
+        assert_eq!(1, HOOK_CALLED.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn bind_col_tracked() {
+        let stmt_raw_handle = NonNull::new(13 as SQLHANDLE).unwrap();
+
+        let SQLBindCol_ctx = ffi::SQLBindCol_context();
+        let SQLFreeStmt_ctx = ffi::SQLFreeStmt_context();
+        let SQLFreeHandle_ctx = ffi::SQLFreeHandle_context();
+
+        SQLBindCol_ctx
+            .expect()
+            .times(2)
+            .withf_st(move |x, y, z, _, _, _| {
+                *x == stmt_raw_handle.as_ptr() && *y == 1 && *z == SQL_C_SLONG::IDENTIFIER
+            })
+            .return_const(SQL_SUCCESS);
+        SQLFreeStmt_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, y| {
+                *x == stmt_raw_handle.as_ptr() && *y == SQL_UNBIND as SQLUSMALLINT
+            })
+            .return_const(SQL_SUCCESS);
+        SQLFreeHandle_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, y| {
+                *x == SQL_HANDLE_STMT::IDENTIFIER && *y == stmt_raw_handle.as_ptr()
+            })
+            .return_const(SQL_SUCCESS);
+
+        #[cfg(feature = "runtime_checks")]
+        let _SQLGetStmtAttrA_ctx = expect_app_descs();
+
+        let (first, second) = (UnsafeCell::new(0), UnsafeCell::new(0));
+        let ind = UnsafeCell::new(SQL_NULL_DATA);
+        let stmt = unsafe { SQLHSTMT::<S1, SQL_OV_ODBC3_80>::from_raw(stmt_raw_handle) };
+
+        assert_eq!(
+            SQL_SUCCESS,
+            stmt.SQLBindCol(1, SQL_C_SLONG, Some(&first), None)
+        );
+        assert_eq!(
+            SQL_SUCCESS,
+            stmt.SQLBindCol(1, SQL_C_SLONG, Some(&second), Some(&ind))
+        );
+        let binding = stmt.bound_col(1).unwrap();
+        assert_eq!(
+            Some(second.get().cast()),
+            binding.data_ptr().map(|(ptr, _)| ptr)
+        );
+        assert_eq!(Some(ind.get()), binding.strlen_or_ind_ptr());
+        assert_eq!(None, stmt.bound_col(2));
+
+        assert_eq!(SQL_SUCCESS, stmt.SQLFreeStmt(UnbindOption::SQL_UNBIND));
+        assert_eq!(None, stmt.bound_col(1));
+    }
+
+    #[test]
+    fn bind_col_owned() {
+        let stmt_raw_handle = NonNull::new(13 as SQLHANDLE).unwrap();
+
+        let SQLBindCol_ctx = ffi::SQLBindCol_context();
+        let SQLFreeStmt_ctx = ffi::SQLFreeStmt_context();
+        let SQLFreeHandle_ctx = ffi::SQLFreeHandle_context();
+
+        SQLBindCol_ctx
+            .expect()
+            .times(2)
+            .withf_st(move |x, _, _, _, _, z| *x == stmt_raw_handle.as_ptr() && !z.is_null())
+            .return_const(SQL_SUCCESS);
+        SQLFreeStmt_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, y| {
+                *x == stmt_raw_handle.as_ptr() && *y == SQL_UNBIND as SQLUSMALLINT
+            })
+            .return_const(SQL_SUCCESS);
+        SQLFreeHandle_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, y| {
+                *x == SQL_HANDLE_STMT::IDENTIFIER && *y == stmt_raw_handle.as_ptr()
+            })
+            .return_const(SQL_SUCCESS);
+
+        #[cfg(feature = "runtime_checks")]
+        let _SQLGetStmtAttrA_ctx = expect_app_descs();
+
+        let mut stmt = unsafe { SQLHSTMT::<S1, SQL_OV_ODBC3_80>::from_raw(stmt_raw_handle) };
+        assert_eq!(
+            SQL_SUCCESS,
+            stmt.bind_col_owned(1, SQL_C_SLONG, Box::new(0 as SQLINTEGER))
+        );
+        assert_eq!(
+            SQL_SUCCESS,
+            stmt.bind_col_owned(2, SQL_C_CHAR, vec![0 as SQLCHAR; 4].into_boxed_slice())
+        );
+
+        // Simulate driver fetching into the bound buffers
+        let binding = stmt.bound_col(1).unwrap();
+        unsafe {
+            *binding.data_ptr().unwrap().0.cast::<SQLINTEGER>() = 42;
+            *binding.strlen_or_ind_ptr().unwrap() = StrLenOrInd(4);
+        }
+
+        let cols = stmt.owned_cols();
+        assert_eq!(Some(&42), cols.get::<SQLINTEGER>(1).map(|(val, _)| val));
+        assert_eq!(Some(4), cols.get::<SQLINTEGER>(1).map(|(_, ind)| ind.0));
+        assert_eq!(
+            Some(&[0; 4][..]),
+            cols.get::<[SQLCHAR]>(2).map(|(val, _)| val)
+        );
+        assert!(cols.get::<SQLSMALLINT>(1).is_none());
+
+        assert_eq!(SQL_SUCCESS, stmt.SQLFreeStmt(UnbindOption::SQL_UNBIND));
+        assert!(stmt.owned_cols().get::<SQLINTEGER>(1).is_none());
+    }
+
+    #[test]
+    fn bind_col_owned_get_ard() {
+        let stmt_raw_handle = NonNull::new(13 as SQLHANDLE).unwrap();
+
+        let SQLBindCol_ctx = ffi::SQLBindCol_context();
+        let SQLFreeStmt_ctx = ffi::SQLFreeStmt_context();
+        let SQLFreeHandle_ctx = ffi::SQLFreeHandle_context();
+
+        SQLBindCol_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, y, _, _, _, _| *x == stmt_raw_handle.as_ptr() && *y == 1)
+            .return_const(SQL_SUCCESS);
+        SQLFreeStmt_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, y| {
+                *x == stmt_raw_handle.as_ptr() && *y == SQL_UNBIND as SQLUSMALLINT
+            })
+            .return_const(SQL_SUCCESS);
+        SQLFreeHandle_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, y| {
+                *x == SQL_HANDLE_STMT::IDENTIFIER && *y == stmt_raw_handle.as_ptr()
+            })
+            .return_const(SQL_SUCCESS);
+
+        #[cfg(feature = "runtime_checks")]
+        let _SQLGetStmtAttrA_ctx = expect_app_descs();
+        #[cfg(not(feature = "runtime_checks"))]
+        let SQLGetStmtAttrA_ctx = ffi::SQLGetStmtAttrA_context();
+        #[cfg(not(feature = "runtime_checks"))]
+        SQLGetStmtAttrA_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, y, _, _, _| {
+                *x == stmt_raw_handle.as_ptr() && *y == SQL_ATTR_APP_ROW_DESC::IDENTIFIER
+            })
+            .return_const(SQL_SUCCESS);
+
+        let mut stmt = unsafe { SQLHSTMT::<S1, SQL_OV_ODBC3_80>::from_raw(stmt_raw_handle) };
+        assert_eq!(
+            SQL_SUCCESS,
+            stmt.bind_col_owned(1, SQL_C_SLONG, Box::new(0 as SQLINTEGER))
+        );
+
+        // ARD could be copied into an explicit descriptor which outlives the owned buffer
+        let get_ard = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| {
+            let mut desc = MaybeUninit::<RefSQLHDESC<_, _>>::zeroed();
+            stmt.SQLGetStmtAttrA(SQL_ATTR_APP_ROW_DESC, Some(&mut desc), None)
+        }));
+        assert!(get_ard.is_err());
+
+        assert_eq!(SQL_SUCCESS, stmt.SQLFreeStmt(UnbindOption::SQL_UNBIND));
+        let mut desc = MaybeUninit::<RefSQLHDESC<_, _>>::zeroed();
+        assert_eq!(
+            SQL_SUCCESS,
+            stmt.SQLGetStmtAttrA(SQL_ATTR_APP_ROW_DESC, Some(&mut desc), None)
+        );
+    }
+
+    #[test]
+    fn bind_col_owned_explicit_ard() {
+        let stmt_raw_handle = NonNull::new(13 as SQLHANDLE).unwrap();
+        let desc_raw_handle = NonNull::new(14 as SQLHANDLE).unwrap();
+
+        let SQLBindCol_ctx = ffi::SQLBindCol_context();
+        let SQLSetStmtAttrA_ctx = ffi::SQLSetStmtAttrA_context();
+        let SQLFreeStmt_ctx = ffi::SQLFreeStmt_context();
+        let SQLFreeHandle_ctx = ffi::SQLFreeHandle_context();
+
+        SQLBindCol_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, y, _, _, _, _| *x == stmt_raw_handle.as_ptr() && *y == 1)
+            .return_const(SQL_SUCCESS);
+        SQLSetStmtAttrA_ctx
+            .expect()
+            .times(2)
+            .withf_st(move |x, y, _, _| {
+                *x == stmt_raw_handle.as_ptr() && *y == SQL_ATTR_APP_ROW_DESC::IDENTIFIER
+            })
+            .return_const(SQL_SUCCESS);
+        SQLFreeStmt_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, y| {
+                *x == stmt_raw_handle.as_ptr() && *y == SQL_UNBIND as SQLUSMALLINT
+            })
+            .return_const(SQL_SUCCESS);
+        SQLFreeHandle_ctx
+            .expect()
+            .times(2)
+            .withf_st(move |x, y| {
+                (*x == SQL_HANDLE_STMT::IDENTIFIER && *y == stmt_raw_handle.as_ptr())
+                    || (*x == SQL_HANDLE_DESC::IDENTIFIER && *y == desc_raw_handle.as_ptr())
+            })
+            .return_const(SQL_SUCCESS);
+
+        #[cfg(feature = "runtime_checks")]
+        let _SQLGetStmtAttrA_ctx = expect_app_descs();
+
+        let desc = unsafe { SQLHDESC::<AppDesc, SQL_OV_ODBC3_80>::from_raw(desc_raw_handle) };
+        let mut stmt = unsafe { SQLHSTMT::<S1, SQL_OV_ODBC3_80>::from_raw(stmt_raw_handle) };
+        assert_eq!(
+            SQL_SUCCESS,
+            stmt.bind_col_owned(1, SQL_C_SLONG, Box::new(0 as SQLINTEGER))
+        );
+        assert_eq!(
+            SQL_SUCCESS,
+            stmt.SQLSetStmtAttrA(SQL_ATTR_APP_ROW_DESC, Some(&desc))
+        );
+
+        // Explicit descriptor could outlive the buffer owned by the statement
+        let bind_col = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| {
+            stmt.bind_col_owned(2, SQL_C_SLONG, Box::new(0 as SQLINTEGER))
+        }));
+        assert!(bind_col.is_err());
+
+        // Owned buffer is still bound to the implicit descriptor
+        assert_eq!(SQL_SUCCESS, stmt.SQLFreeStmt(UnbindOption::SQL_UNBIND));
+        assert!(stmt.owned_cols().get::<SQLINTEGER>(1).is_some());
+
+        assert_eq!(
+            SQL_SUCCESS,
+            stmt.SQLSetStmtAttrA(SQL_ATTR_APP_ROW_DESC, None)
+        );
+        assert!(!stmt.0.bound_cols.explicit_desc());
+    }
+
+    #[test]
+    fn bind_col_numeric() {
+        let stmt_raw_handle = NonNull::new(13 as SQLHANDLE).unwrap();
+
+        let SQLBindCol_ctx = ffi::SQLBindCol_context();
+        let SQLGetStmtAttrA_ctx = ffi::SQLGetStmtAttrA_context();
+        let SQLSetDescFieldA_ctx = ffi::SQLSetDescFieldA_context();
+        let SQLFreeHandle_ctx = ffi::SQLFreeHandle_context();
+
+        let numeric = UnsafeCell::new(SQL_NUMERIC_STRUCT::from_i128(0, 1, 0).unwrap());
+        let numeric_ptr = numeric.get() as usize;
+
+        SQLBindCol_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, y, z, _, _, _| {
+                *x == stmt_raw_handle.as_ptr() && *y == 1 && *z == SQL_C_NUMERIC::IDENTIFIER
+            })
+            .return_const(SQL_SUCCESS);
+        SQLGetStmtAttrA_ctx
+            .expect()
+            .returning(|_, _, ValuePtr, _, _| {
+                unsafe { *ValuePtr.cast::<usize>() = 42 };
+                SQL_SUCCESS
+            });
+        for (field, value) in [
+            (SQL_DESC_PRECISION::IDENTIFIER, 10),
+            (SQL_DESC_SCALE::IDENTIFIER, 4),
+            (SQL_DESC_DATA_PTR::IDENTIFIER, numeric_ptr),
+        ] {
+            SQLSetDescFieldA_ctx
+                .expect()
+                .once()
+                .withf_st(move |x, y, z, w, _| {
+                    *x as usize == 42 && *y == 1 && *z == field && *w as usize == value
+                })
+                .return_const(SQL_SUCCESS);
+        }
+        SQLFreeHandle_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, y| {
+                *x == SQL_HANDLE_STMT::IDENTIFIER && *y == stmt_raw_handle.as_ptr()
+            })
+            .return_const(SQL_SUCCESS);
+
+        let stmt = unsafe { SQLHSTMT::<S1, SQL_OV_ODBC3_80>::from_raw(stmt_raw_handle) };
+        assert_eq!(
+            SQL_SUCCESS,
+            stmt.bind_col_numeric(1, 10, 4, Some(&numeric), None)
+        );
+    }
+
+    #[test]
+    fn bind_rowset() {
+        let stmt_raw_handle = NonNull::new(13 as SQLHANDLE).unwrap();
+
+        let SQLBindCol_ctx = ffi::SQLBindCol_context();
+        let SQLSetStmtAttrA_ctx = ffi::SQLSetStmtAttrA_context();
+        let SQLFreeStmt_ctx = ffi::SQLFreeStmt_context();
+        let SQLFreeHandle_ctx = ffi::SQLFreeHandle_context();
+
+        let ids: [UnsafeCell<SQLINTEGER>; 10] = Default::default();
+        let names: [UnsafeCell<[SQLCHAR; 16]>; 10] = Default::default();
+        let names_ind: [UnsafeCell<StrLenOrInd>; 10] =
+            [const { UnsafeCell::new(StrLenOrInd(0)) }; 10];
+        let rows_fetched = UnsafeCell::new(0);
+        let row_status: [UnsafeCell<RowStatus>; 10] =
+            [const { UnsafeCell::new(SQL_ROW_NOROW) }; 10];
+
+        let ids_ptr = ids.as_ptr() as usize;
+        let names_ind_ptr = names_ind.as_ptr() as usize;
+        let rows_fetched_ptr = rows_fetched.get() as usize;
+        let row_status_ptr = row_status.as_ptr() as usize;
+
+        SQLBindCol_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, y, z, w, v, u| {
+                *x == stmt_raw_handle.as_ptr()
+                    && *y == 1
+                    && *z == SQL_C_SLONG::IDENTIFIER
+                    && *w as usize == ids_ptr
+                    && *v == 0
+                    && u.is_null()
+            })
+            .return_const(SQL_SUCCESS);
+        SQLBindCol_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, y, z, _, v, u| {
+                *x == stmt_raw_handle.as_ptr()
+                    && *y == 2
+                    && *z == SQL_C_CHAR::IDENTIFIER
+                    && *v == 16
+                    && *u as usize == names_ind_ptr
+            })
+            .return_const(SQL_SUCCESS);
+        for (attr, value, times) in [
+            (SQL_ATTR_ROW_ARRAY_SIZE::IDENTIFIER, 1, 3),
+            (SQL_ATTR_ROWS_FETCHED_PTR::IDENTIFIER, rows_fetched_ptr, 1),
+            (SQL_ATTR_ROW_STATUS_PTR::IDENTIFIER, row_status_ptr, 1),
+            (SQL_ATTR_ROW_ARRAY_SIZE::IDENTIFIER, 10, 1),
+            (SQL_ATTR_ROWS_FETCHED_PTR::IDENTIFIER, 0, 1),
+            (SQL_ATTR_ROW_STATUS_PTR::IDENTIFIER, 0, 1),
+            (SQL_ATTR_APP_ROW_DESC::IDENTIFIER, 0, 1),
+            (SQL_ATTR_APP_PARAM_DESC::IDENTIFIER, 0, 1),
+        ] {
+            SQLSetStmtAttrA_ctx
+                .expect()
+                .times(times)
+                .withf_st(move |x, y, z, _| {
+                    *x == stmt_raw_handle.as_ptr() && *y == attr && *z as usize == value
+                })
+                .return_const(SQL_SUCCESS);
+        }
+        SQLFreeStmt_ctx
+            .expect()
+            .times(2)
+            .withf_st(move |x, _| *x == stmt_raw_handle.as_ptr())
+            .return_const(SQL_SUCCESS);
+        SQLFreeHandle_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, y| {
+                *x == SQL_HANDLE_STMT::IDENTIFIER && *y == stmt_raw_handle.as_ptr()
+            })
+            .return_const(SQL_SUCCESS);
+
+        #[cfg(feature = "runtime_checks")]
+        let _SQLGetStmtAttrA_ctx = expect_app_descs();
+
+        let stmt = unsafe { SQLHSTMT::<S1, SQL_OV_ODBC3_80>::from_raw(stmt_raw_handle) };
+        assert_eq!(
+            SQL_SUCCESS,
+            stmt.bind_col_array(1, SQL_C_SLONG, Some(&ids[..]), None)
+        );
+        assert_eq!(
+            SQL_SUCCESS,
+            stmt.bind_col_array(2, SQL_C_CHAR, Some(&names[..]), Some(&names_ind[..]))
+        );
+        assert_eq!(
+            SQL_SUCCESS,
+            stmt.bind_rowset(10, Some(&rows_fetched), Some(&row_status[..]))
+        );
+        assert_eq!(10, stmt.0.bound_cols.array_size());
+
+        let (stmt, sql_return) = stmt.unbind();
+        assert_eq!(SQL_SUCCESS, sql_return);
+        assert_eq!(1, stmt.unwrap().0.bound_cols.array_size());
+    }
+
+    #[test]
+    fn bind_rowset_larger_than_buffers() {
+        let stmt_raw_handle = NonNull::new(13 as SQLHANDLE).unwrap();
+
+        let SQLBindCol_ctx = ffi::SQLBindCol_context();
+        let SQLFreeHandle_ctx = ffi::SQLFreeHandle_context();
+
+        SQLBindCol_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, y, _, _, _, _| *x == stmt_raw_handle.as_ptr() && *y == 1)
+            .return_const(SQL_SUCCESS);
+        SQLFreeHandle_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, y| {
+                *x == SQL_HANDLE_STMT::IDENTIFIER && *y == stmt_raw_handle.as_ptr()
+            })
+            .return_const(SQL_SUCCESS);
+
+        #[cfg(feature = "runtime_checks")]
+        let _SQLGetStmtAttrA_ctx = expect_app_descs();
+
+        let ids: [UnsafeCell<SQLINTEGER>; 5] = Default::default();
+        let stmt = unsafe { SQLHSTMT::<S1, SQL_OV_ODBC3_80>::from_raw(stmt_raw_handle) };
+        assert_eq!(
+            SQL_SUCCESS,
+            stmt.bind_col_array(1, SQL_C_SLONG, Some(&ids[..]), None)
+        );
+
+        // Panic is caught so that mocked functions are not poisoned for other tests
+        let bind_rowset = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| {
+            stmt.bind_rowset(10, None, None)
+        }));
+        assert!(bind_rowset.is_err());
+        assert_eq!(1, stmt.0.bound_cols.array_size());
+    }
+
+    #[test]
+    fn bind_rows() {
+        #[repr(C)]
+        struct Row {
+            id: UnsafeCell<SQLINTEGER>,
+            id_ind: UnsafeCell<StrLenOrInd>,
+            name: UnsafeCell<[SQLCHAR; 12]>,
+        }
+        unsafe impl RowBuf for Row {}
+        // Same size as Row but fields are at different offsets
+        #[repr(C)]
+        struct OtherRow {
+            id_ind: UnsafeCell<StrLenOrInd>,
+            id: UnsafeCell<SQLINTEGER>,
+            name: UnsafeCell<[SQLCHAR; 20]>,
+        }
+        unsafe impl RowBuf for OtherRow {}
+
+        let stmt_raw_handle = NonNull::new(13 as SQLHANDLE).unwrap();
+
+        let SQLBindCol_ctx = ffi::SQLBindCol_context();
+        let SQLSetStmtAttrA_ctx = ffi::SQLSetStmtAttrA_context();
+        let SQLFreeStmt_ctx = ffi::SQLFreeStmt_context();
+        let SQLFreeHandle_ctx = ffi::SQLFreeHandle_context();
+
+        let row = |_| Row {
+            id: UnsafeCell::new(0),
+            id_ind: UnsafeCell::new(StrLenOrInd(0)),
+            name: UnsafeCell::new([0; 12]),
+        };
+        let rows: [Row; 4] = core::array::from_fn(row);
+        let other_rows: [Row; 4] = core::array::from_fn(row);
+        let mut offset = BindOffset::new();
+
+        let id_ptr = rows[0].id.get() as usize;
+        let id_ind_ptr = rows[0].id_ind.get() as usize;
+        let offset_ptr = &offset as *const BindOffset as usize;
+
+        SQLBindCol_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, y, z, w, _, u| {
+                *x == stmt_raw_handle.as_ptr()
+                    && *y == 1
+                    && *z == SQL_C_SLONG::IDENTIFIER
+                    && *w as usize == id_ptr
+                    && *u as usize == id_ind_ptr
+            })
+            .return_const(SQL_SUCCESS);
+        SQLBindCol_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, y, z, _, v, u| {
+                *x == stmt_raw_handle.as_ptr()
+                    && *y == 2
+                    && *z == SQL_C_CHAR::IDENTIFIER
+                    && *v == 12
+                    && u.is_null()
+            })
+            .return_const(SQL_SUCCESS);
+        for (attr, value) in [
+            (SQL_ATTR_ROW_BIND_OFFSET_PTR::IDENTIFIER, offset_ptr),
+            (SQL_ATTR_ROW_BIND_TYPE::IDENTIFIER, size_of::<Row>()),
+            (SQL_ATTR_ROW_BIND_OFFSET_PTR::IDENTIFIER, 0),
+            (SQL_ATTR_ROW_BIND_TYPE::IDENTIFIER, 0),
+            (SQL_ATTR_APP_ROW_DESC::IDENTIFIER, 0),
+            (SQL_ATTR_APP_PARAM_DESC::IDENTIFIER, 0),
+        ] {
+            SQLSetStmtAttrA_ctx
+                .expect()
+                .once()
+                .withf_st(move |x, y, z, _| {
+                    *x == stmt_raw_handle.as_ptr() && *y == attr && *z as usize == value
+                })
+                .return_const(SQL_SUCCESS);
+        }
+        SQLFreeStmt_ctx
+            .expect()
+            .times(2)
+            .withf_st(move |x, _| *x == stmt_raw_handle.as_ptr())
+            .return_const(SQL_SUCCESS);
+        SQLFreeHandle_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, y| {
+                *x == SQL_HANDLE_STMT::IDENTIFIER && *y == stmt_raw_handle.as_ptr()
+            })
+            .return_const(SQL_SUCCESS);
+
+        #[cfg(feature = "runtime_checks")]
+        let _SQLGetStmtAttrA_ctx = expect_app_descs();
+
+        let stmt = unsafe { SQLHSTMT::<S1, SQL_OV_ODBC3_80>::from_raw(stmt_raw_handle) };
+        assert_eq!(SQL_SUCCESS, stmt.bind_rows(&rows, Some(&mut offset)));
+        assert_eq!(
+            SQL_SUCCESS,
+            stmt.bind_row_col(1, SQL_C_SLONG, Some(&rows[0].id), Some(&rows[0].id_ind))
+        );
+        let name: &UnsafeCell<[SQLCHAR]> = &rows[0].name;
+        assert_eq!(
+            SQL_SUCCESS,
+            stmt.bind_row_col(2, SQL_C_CHAR, Some(name.as_ref()), None)
+        );
+
+        // Only fields of the first row can be bound
+        let bind_row_col = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| {
+            stmt.bind_row_col(3, SQL_C_SLONG, Some(&rows[1].id), None)
+        }));
+        assert!(bind_row_col.is_err());
+
+        // Only rows of the bound type can be set
+        let mismatched_rows: [OtherRow; 4] = core::array::from_fn(|_| OtherRow {
+            id_ind: UnsafeCell::new(StrLenOrInd(0)),
+            id: UnsafeCell::new(0),
+            name: UnsafeCell::new([0; 20]),
+        });
+        assert_eq!(size_of::<Row>(), size_of::<OtherRow>());
+        let set_rows = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| {
+            stmt.set_rows(&mismatched_rows)
+        }));
+        assert!(set_rows.is_err());
+
+        stmt.set_rows(&other_rows);
+        let (stmt, sql_return) = stmt.unbind();
+        assert_eq!(SQL_SUCCESS, sql_return);
+        drop(stmt);
+
+        assert_eq!(
+            (other_rows.as_ptr() as usize).wrapping_sub(rows.as_ptr() as usize),
+            offset.get()
+        );
+    }
+
+    #[test]
+    fn bind_paramset() {
+        let stmt_raw_handle = NonNull::new(13 as SQLHANDLE).unwrap();
+
+        let SQLBindParameter_ctx = ffi::SQLBindParameter_context();
+        let SQLSetStmtAttrA_ctx = ffi::SQLSetStmtAttrA_context();
+        let SQLExecDirectA_ctx = ffi::SQLExecDirectA_context();
+        let SQLRowCount_ctx = ffi::SQLRowCount_context();
+        let SQLMoreResults_ctx = ffi::SQLMoreResults_context();
+        let SQLFreeStmt_ctx = ffi::SQLFreeStmt_context();
+        let SQLFreeHandle_ctx = ffi::SQLFreeHandle_context();
+
+        let ids: [UnsafeCell<SQLINTEGER>; 3] = Default::default();
+        let ids_ind: [UnsafeCell<StrLenOrInd>; 3] =
+            core::array::from_fn(|_| UnsafeCell::new(StrLenOrInd(0)));
+        let params_processed = UnsafeCell::new(0);
+        let param_status: [UnsafeCell<ParamStatus>; 3] =
+            [const { UnsafeCell::new(SQL_PARAM_UNUSED) }; 3];
+        let mut row_counts: [SQLLEN; 3] = [0; 3];
+
+        let ids_ptr = ids.as_ptr() as usize;
+        let ids_ind_ptr = ids_ind.as_ptr() as usize;
+        let params_processed_ptr = params_processed.get() as usize;
+        let param_status_ptr = param_status.as_ptr() as usize;
+
+        SQLBindParameter_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, y, _, z, _, _, _, w, v, u| {
+                *x == stmt_raw_handle.as_ptr()
+                    && *y == 1
+                    && *z == SQL_C_SLONG::IDENTIFIER
+                    && *w as usize == ids_ptr
+                    && *v == 0
+                    && *u as usize == ids_ind_ptr
+            })
+            .return_const(SQL_SUCCESS);
+        for (attr, value, times) in [
+            (SQL_ATTR_PARAMSET_SIZE::IDENTIFIER, 1, 3),
+            (
+                SQL_ATTR_PARAMS_PROCESSED_PTR::IDENTIFIER,
+                params_processed_ptr,
+                1,
+            ),
+            (SQL_ATTR_PARAM_STATUS_PTR::IDENTIFIER, param_status_ptr, 1),
+            (SQL_ATTR_PARAM_OPERATION_PTR::IDENTIFIER, 0, 2),
+            (SQL_ATTR_PARAMSET_SIZE::IDENTIFIER, 3, 1),
+            (SQL_ATTR_PARAMS_PROCESSED_PTR::IDENTIFIER, 0, 1),
+            (SQL_ATTR_PARAM_STATUS_PTR::IDENTIFIER, 0, 1),
+            (SQL_ATTR_APP_ROW_DESC::IDENTIFIER, 0, 1),
+            (SQL_ATTR_APP_PARAM_DESC::IDENTIFIER, 0, 1),
+        ] {
+            SQLSetStmtAttrA_ctx
+                .expect()
+                .times(times)
+                .withf_st(move |x, y, z, _| {
+                    *x == stmt_raw_handle.as_ptr() && *y == attr && *z as usize == value
+                })
+                .return_const(SQL_SUCCESS);
+        }
+        SQLExecDirectA_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, _, _| *x == stmt_raw_handle.as_ptr())
+            .return_const(SQL_SUCCESS);
+        let mut row_count = 0;
+        SQLRowCount_ctx
+            .expect()
+            .times(3)
+            .withf_st(move |x, _| *x == stmt_raw_handle.as_ptr())
+            .returning_st(move |_, RowCountPtr| {
+                row_count += 1;
+                unsafe { *RowCountPtr = row_count };
+                SQL_SUCCESS
+            });
+        let mut more_results = [SQL_NO_DATA, SQL_SUCCESS, SQL_SUCCESS];
+        SQLMoreResults_ctx
+            .expect()
+            .times(3)
+            .withf_st(move |x| *x == stmt_raw_handle.as_ptr())
+            .returning_st(move |_| {
+                more_results.rotate_right(1);
+                more_results[0]
+            });
+        SQLFreeStmt_ctx
+            .expect()
+            .times(2)
+            .withf_st(move |x, _| *x == stmt_raw_handle.as_ptr())
+            .return_const(SQL_SUCCESS);
+        SQLFreeHandle_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, y| {
+                *x == SQL_HANDLE_STMT::IDENTIFIER && *y == stmt_raw_handle.as_ptr()
+            })
+            .return_const(SQL_SUCCESS);
+
+        #[cfg(feature = "runtime_checks")]
+        let _SQLGetStmtAttrA_ctx = expect_app_descs();
+
+        let stmt = unsafe { SQLHSTMT::<S1, SQL_OV_ODBC3_80>::from_raw(stmt_raw_handle) };
+        assert_eq!(
+            SQL_SUCCESS,
+            stmt.bind_param_array(
+                1,
+                SQL_PARAM_INPUT,
+                SQL_C_SLONG,
+                SQL_INTEGER,
+                0,
+                0,
+                Some(&ids[..]),
+                Some(&ids_ind[..])
+            )
+        );
+        assert_eq!(
+            SQL_SUCCESS,
+            stmt.bind_paramset(3, Some(&params_processed), Some(&param_status[..]), None)
+        );
+
+        // Single buffers can't be bound once parameters are bound as arrays
+        let bind_param = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| {
+            stmt.SQLBindParameter(
+                2,
+                SQL_PARAM_INPUT,
+                SQL_C_SLONG,
+                SQL_INTEGER,
+                0,
+                0,
+                Some(&ids[0]),
+                None,
+            )
+        }));
+        assert!(bind_param.is_err());
+
+        let (stmt, sql_return) = stmt.SQLExecDirectA("INSERT INTO t VALUES (?)".as_ref());
+        assert_eq!(SQL_SUCCESS, sql_return);
+        let (stmt, sql_return) = stmt.unwrap().param_row_counts(&mut row_counts);
+        assert_eq!(SQL_SUCCESS, sql_return);
+        assert_eq!([1, 2, 3], row_counts);
+
+        let (stmt, sql_return) = stmt.unwrap().unbind();
+        assert_eq!(SQL_SUCCESS, sql_return);
+        assert_eq!(1, stmt.unwrap().0.bound_params.array_size());
+    }
+
+    #[test]
+    fn param_row_counts_still_executing() {
+        let stmt_raw_handle = NonNull::new(13 as SQLHANDLE).unwrap();
+
+        let SQLExecDirectA_ctx = ffi::SQLExecDirectA_context();
+        let SQLRowCount_ctx = ffi::SQLRowCount_context();
+        let SQLMoreResults_ctx = ffi::SQLMoreResults_context();
+        let SQLFreeHandle_ctx = ffi::SQLFreeHandle_context();
+
+        SQLExecDirectA_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, _, _| *x == stmt_raw_handle.as_ptr())
+            .return_const(SQL_SUCCESS);
+        SQLRowCount_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, _| *x == stmt_raw_handle.as_ptr())
+            .returning_st(|_, RowCountPtr| {
+                unsafe { *RowCountPtr = 7 };
+                SQL_SUCCESS
+            });
+        SQLMoreResults_ctx
+            .expect()
+            .once()
+            .withf_st(move |x| *x == stmt_raw_handle.as_ptr())
+            .return_const(SQL_STILL_EXECUTING);
+        SQLFreeHandle_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, y| {
+                *x == SQL_HANDLE_STMT::IDENTIFIER && *y == stmt_raw_handle.as_ptr()
+            })
+            .return_const(SQL_SUCCESS);
+
+        #[cfg(feature = "runtime_checks")]
+        let _SQLGetStmtAttrA_ctx = expect_app_descs();
+
+        let mut row_counts: [SQLLEN; 2] = [0; 2];
+        let stmt = unsafe { SQLHSTMT::<S1, SQL_OV_ODBC3_80>::from_raw(stmt_raw_handle) };
+        let (stmt, sql_return) = stmt.SQLExecDirectA("INSERT INTO t VALUES (?)".as_ref());
+        assert_eq!(SQL_SUCCESS, sql_return);
+        let (stmt, sql_return) = stmt.unwrap().param_row_counts(&mut row_counts);
+        assert_eq!(SQL_STILL_EXECUTING, sql_return);
+        assert!(stmt.is_err());
+        assert_eq!([7, 0], row_counts);
+    }
+
+    #[test]
+    fn put_data() {
+        let stmt_raw_handle = NonNull::new(13 as SQLHANDLE).unwrap();
+
+        let SQLBindParameter_ctx = ffi::SQLBindParameter_context();
+        let SQLExecDirectA_ctx = ffi::SQLExecDirectA_context();
+        let SQLParamData_ctx = ffi::SQLParamData_context();
+        let SQLPutData_ctx = ffi::SQLPutData_context();
+        let SQLFreeHandle_ctx = ffi::SQLFreeHandle_context();
+
+        let name = DataAtExec::with_len(5);
+        let data = DataAtExec::new();
+        let name_ptr = &name as *const DataAtExec as usize;
+        let data_ptr = &data as *const DataAtExec as usize;
+
+        for (param, ptr) in [(1, name_ptr), (2, data_ptr)] {
+            SQLBindParameter_ctx
+                .expect()
+                .once()
+                .withf_st(move |x, y, _, _, _, _, _, w, v, u| {
+                    *x == stmt_raw_handle.as_ptr()
+                        && *y == param
+                        && *w as usize == ptr
+                        && *v == 0
+                        && *u as usize == ptr
+                })
+                .return_const(SQL_SUCCESS);
+        }
+        SQLExecDirectA_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, _, _| *x == stmt_raw_handle.as_ptr())
+            .return_const(SQL_NEED_DATA);
+        let mut tokens = std::vec![0, data_ptr, name_ptr];
+        SQLParamData_ctx
+            .expect()
+            .times(3)
+            .withf_st(move |x, _| *x == stmt_raw_handle.as_ptr())
+            .returning_st(move |_, ValuePtrPtr| {
+                let token = tokens.pop().unwrap();
+                unsafe { *ValuePtrPtr = token as SQLPOINTER };
+                if token == 0 {
+                    SQL_SUCCESS
+                } else {
+                    SQL_NEED_DATA
+                }
+            });
+        SQLPutData_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, y, z| {
+                *x == stmt_raw_handle.as_ptr()
+                    && *z == 5
+                    && unsafe { core::slice::from_raw_parts(y.cast::<u8>(), 5) } == b"hello"
+            })
+            .return_const(SQL_SUCCESS);
+        SQLPutData_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, _, z| *x == stmt_raw_handle.as_ptr() && *z == 0)
+            .return_const(SQL_SUCCESS);
+        SQLFreeHandle_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, y| {
+                *x == SQL_HANDLE_STMT::IDENTIFIER && *y == stmt_raw_handle.as_ptr()
+            })
+            .return_const(SQL_SUCCESS);
+
+        #[cfg(feature = "runtime_checks")]
+        let _SQLGetStmtAttrA_ctx = expect_app_descs();
+
+        let stmt = unsafe { SQLHSTMT::<S1, SQL_OV_ODBC3_80>::from_raw(stmt_raw_handle) };
+        assert_eq!(
+            SQL_SUCCESS,
+            stmt.bind_param_at_exec(1, SQL_C_CHAR, SQL_VARCHAR, 5, 0, &name, false)
+        );
+        assert_eq!(
+            SQL_SUCCESS,
+            stmt.bind_param_at_exec(2, SQL_C_BINARY, SQL_LONGVARBINARY, 0, 0, &data, false)
+        );
+        assert_eq!(Some(5), name.data_len());
+        assert_eq!(None, data.data_len());
+
+        let (stmt, sql_return) = stmt.SQLExecDirectA("INSERT INTO t VALUES (?, ?)".as_ref());
+        assert_eq!(SQL_NEED_DATA, sql_return);
+        let Err(Ok(stmt)) = stmt else {
+            panic!("Statement doesn't need data")
+        };
+
+        // Source holds more data than the length of the parameter
+        let (stmt, sql_return) =
+            stmt.put_data(&mut [(2, &mut &b""[..]), (1, &mut &b"hello world"[..])]);
+        assert_eq!(SQL_SUCCESS, sql_return.unwrap());
+        assert!(stmt.is_ok());
+    }
+
+    #[test]
+    fn put_data_driver_error() {
+        let stmt_raw_handle = NonNull::new(13 as SQLHANDLE).unwrap();
+
+        let SQLBindParameter_ctx = ffi::SQLBindParameter_context();
+        let SQLExecDirectA_ctx = ffi::SQLExecDirectA_context();
+        let SQLParamData_ctx = ffi::SQLParamData_context();
+        let SQLPutData_ctx = ffi::SQLPutData_context();
+        let SQLFreeHandle_ctx = ffi::SQLFreeHandle_context();
+
+        let unknown_len = DataAtExec::new();
+        let data = DataAtExec::with_len(5);
+        let data_ptr = &data as *const DataAtExec as usize;
+
+        SQLBindParameter_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, y, _, _, _, _, _, w, _, _| {
+                *x == stmt_raw_handle.as_ptr() && *y == 1 && *w as usize == data_ptr
+            })
+            .return_const(SQL_SUCCESS);
+        SQLExecDirectA_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, _, _| *x == stmt_raw_handle.as_ptr())
+            .return_const(SQL_NEED_DATA);
+        SQLParamData_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, _| *x == stmt_raw_handle.as_ptr())
+            .returning_st(move |_, ValuePtrPtr| {
+                unsafe { *ValuePtrPtr = data_ptr as SQLPOINTER };
+                SQL_NEED_DATA
+            });
+        let mut put_data = std::vec![SQL_ERROR, SQL_STILL_EXECUTING];
+        SQLPutData_ctx
+            .expect()
+            .times(2)
+            .withf_st(move |x, _, z| *x == stmt_raw_handle.as_ptr() && *z == 5)
+            .returning_st(move |_, _, _| put_data.pop().unwrap());
+        SQLFreeHandle_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, y| {
+                *x == SQL_HANDLE_STMT::IDENTIFIER && *y == stmt_raw_handle.as_ptr()
+            })
+            .return_const(SQL_SUCCESS);
+
+        #[cfg(feature = "runtime_checks")]
+        let _SQLGetStmtAttrA_ctx = expect_app_descs();
+
+        let stmt = unsafe { SQLHSTMT::<S1, SQL_OV_ODBC3_80>::from_raw(stmt_raw_handle) };
+
+        // Length of long data must be given if the data source requires it
+        let bind_param = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| {
+            stmt.bind_param_at_exec(1, SQL_C_BINARY, SQL_LONGVARBINARY, 0, 0, &unknown_len, true)
+        }));
+        assert!(bind_param.is_err());
+
+        assert_eq!(
+            SQL_SUCCESS,
+            stmt.bind_param_at_exec(1, SQL_C_BINARY, SQL_LONGVARBINARY, 0, 0, &data, true)
+        );
+
+        let (stmt, sql_return) = stmt.SQLExecDirectA("INSERT INTO t VALUES (?)".as_ref());
+        assert_eq!(SQL_NEED_DATA, sql_return);
+        let Err(Ok(stmt)) = stmt else {
+            panic!("Statement doesn't need data")
+        };
+
+        // Chunk whose sending is still executing is sent again without reading the source
+        let (stmt, sql_return) = stmt.put_data(&mut [(1, &mut &b"hello"[..])]);
+        assert_eq!(SQL_STILL_EXECUTING, sql_return.unwrap());
+        let Err(Ok(stmt)) = stmt else {
+            panic!("Statement doesn't need data")
+        };
+
+        // Statement leaves the need data state once the driver fails
+        let (stmt, sql_return) = stmt.put_data(&mut [(1, &mut &b""[..])]);
+        assert_eq!(SQL_ERROR, sql_return.unwrap());
+        assert!(matches!(stmt, Err(Err(_))));
+    }
+
+    #[test]
+    fn row_reader() {
+        use std::io::{ErrorKind, Read};
+
+        let stmt_raw_handle = NonNull::new(13 as SQLHANDLE).unwrap();
+
+        let SQLBindCol_ctx = ffi::SQLBindCol_context();
+        let SQLExecDirectA_ctx = ffi::SQLExecDirectA_context();
+        let SQLFetch_ctx = ffi::SQLFetch_context();
+        let SQLGetData_ctx = ffi::SQLGetData_context();
+        let SQLFreeHandle_ctx = ffi::SQLFreeHandle_context();
+
+        SQLBindCol_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, y, _, _, _, _| *x == stmt_raw_handle.as_ptr() && *y == 2)
+            .return_const(SQL_SUCCESS);
+        SQLExecDirectA_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, _, _| *x == stmt_raw_handle.as_ptr())
+            .return_const(SQL_SUCCESS);
+        SQLFetch_ctx
+            .expect()
+            .once()
+            .withf_st(move |x| *x == stmt_raw_handle.as_ptr())
+            .return_const(SQL_SUCCESS);
+
+        // Text of the third column is split in the middle of a multibyte character
+        let mut parts = std::vec![
+            (&b"\xA9b"[..], 2, SQL_SUCCESS),
+            (&[b'a'; 4096][..], -4, SQL_SUCCESS_WITH_INFO)
+        ];
+        SQLGetData_ctx
+            .expect()
+            .times(2)
+            .withf_st(move |x, y, z, _, _, _| {
+                *x == stmt_raw_handle.as_ptr() && *y == 3 && *z == SQL_C_CHAR::IDENTIFIER
+            })
+            .returning_st(
+                move |_, _, _, TargetValuePtr, BufferLength, StrLen_or_IndPtr| {
+                    let (part, ind, sql_return) = parts.pop().unwrap();
+                    let mut part = part.to_vec();
+                    if ind == -4 {
+                        part.truncate(BufferLength as usize - 2);
+                        part.push(0xC3);
+                    }
+                    part.push(0);
+
+                    unsafe {
+                        core::ptr::copy_nonoverlapping(
+                            part.as_ptr(),
+                            TargetValuePtr.cast(),
+                            part.len(),
+                        );
+                        *StrLen_or_IndPtr = ind;
+                    }
+                    sql_return
+                },
+            );
+        SQLGetData_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, y, z, _, _, _| {
+                *x == stmt_raw_handle.as_ptr() && *y == 4 && *z == SQL_C_BINARY::IDENTIFIER
+            })
+            .returning_st(|_, _, _, _, _, StrLen_or_IndPtr| {
+                unsafe { *StrLen_or_IndPtr = SQL_NULL_DATA.0 };
+                SQL_SUCCESS
+            });
+        SQLFreeHandle_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, y| {
+                *x == SQL_HANDLE_STMT::IDENTIFIER && *y == stmt_raw_handle.as_ptr()
+            })
+            .return_const(SQL_SUCCESS);
+
+        #[cfg(feature = "runtime_checks")]
+        let _SQLGetStmtAttrA_ctx = expect_app_descs();
+
+        let id = UnsafeCell::new(0);
+        let stmt = unsafe { SQLHSTMT::<S1, SQL_OV_ODBC3_80>::from_raw(stmt_raw_handle) };
+        assert_eq!(
+            SQL_SUCCESS,
+            stmt.SQLBindCol(2, SQL_C_SLONG, Some(&id), None)
+        );
+
+        let (stmt, sql_return) = stmt.SQLExecDirectA("SELECT * FROM t".as_ref());
+        assert_eq!(SQL_SUCCESS, sql_return);
+        let (stmt, sql_return) = stmt.ok().unwrap().SQLFetch();
+        assert_eq!(SQL_SUCCESS, sql_return);
+        let mut stmt = stmt.ok().unwrap();
+
+        let mut reader = stmt.row_reader(SQL_GD_ANY_ORDER);
+        let error = reader.binary(2).unwrap_err();
+        assert_eq!(ErrorKind::InvalidInput, error.kind());
+        let error = reader.binary(1).unwrap_err();
+        assert_eq!(ErrorKind::InvalidInput, error.kind());
+
+        let mut text = std::string::String::new();
+        let mut col = reader.text::<SQLCHAR>(3).unwrap();
+        col.read_to_string(&mut text).unwrap();
+        assert!(!col.is_null());
+        assert_eq!(4097, text.len());
+        assert!(text.ends_with("aéb"));
+
+        let mut data = std::vec::Vec::new();
+        let mut col = reader.binary(4).unwrap();
+        col.read_to_end(&mut data).unwrap();
+        assert!(col.is_null());
+        assert!(data.is_empty());
+
+        // Columns can't be read in decreasing order without SQL_GD_ANY_ORDER
+        let mut reader = stmt.row_reader(SQL_GD_ANY_COLUMN);
+        assert!(reader.binary(4).is_ok());
+        let error = reader.binary(3).unwrap_err();
+        assert_eq!(ErrorKind::InvalidInput, error.kind());
+    }
+
+    #[test]
+    fn next_output_param() {
+        use std::io::Read;
+
+        let stmt_raw_handle = NonNull::new(13 as SQLHANDLE).unwrap();
+
+        let SQLBindParameter_ctx = ffi::SQLBindParameter_context();
+        let SQLExecDirectA_ctx = ffi::SQLExecDirectA_context();
+        let SQLParamData_ctx = ffi::SQLParamData_context();
+        let SQLGetData_ctx = ffi::SQLGetData_context();
+        let SQLFreeHandle_ctx = ffi::SQLFreeHandle_context();
+
+        let doc = DataAtExec::new();
+        let doc_ptr = &doc as *const DataAtExec as usize;
+
+        SQLBindParameter_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, y, z, _, _, _, _, w, _, u| {
+                *x == stmt_raw_handle.as_ptr()
+                    && *y == 1
+                    && *z == SQL_PARAM_OUTPUT_STREAM.identifier()
+                    && *w as usize == doc_ptr
+                    && *u as usize == doc_ptr
+            })
+            .return_const(SQL_SUCCESS);
+        SQLExecDirectA_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, _, _| *x == stmt_raw_handle.as_ptr())
+            .return_const(SQL_PARAM_DATA_AVAILABLE);
+        let mut tokens = std::vec![0, doc_ptr, 1];
+        SQLParamData_ctx
+            .expect()
+            .times(3)
+            .withf_st(move |x, _| *x == stmt_raw_handle.as_ptr())
+            .returning_st(move |_, ValuePtrPtr| {
+                let token = tokens.pop().unwrap();
+                unsafe { *ValuePtrPtr = token as SQLPOINTER };
+                if token == 0 {
+                    SQL_SUCCESS
+                } else {
+                    SQL_PARAM_DATA_AVAILABLE
+                }
+            });
+        SQLGetData_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, y, z, _, _, _| {
+                *x == stmt_raw_handle.as_ptr() && *y == 1 && *z == SQL_C_BINARY::IDENTIFIER
+            })
+            .returning_st(|_, _, _, TargetValuePtr, _, StrLen_or_IndPtr| {
+                unsafe {
+                    core::ptr::copy_nonoverlapping(b"<a/>".as_ptr(), TargetValuePtr.cast(), 4);
+                    *StrLen_or_IndPtr = 4;
+                }
+                SQL_SUCCESS
+            });
+        SQLFreeHandle_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, y| {
+                *x == SQL_HANDLE_STMT::IDENTIFIER && *y == stmt_raw_handle.as_ptr()
+            })
+            .return_const(SQL_SUCCESS);
+
+        #[cfg(feature = "runtime_checks")]
+        let _SQLGetStmtAttrA_ctx = expect_app_descs();
+
+        let stmt = unsafe { SQLHSTMT::<S1, SQL_OV_ODBC3_80>::from_raw(stmt_raw_handle) };
+        assert_eq!(
+            SQL_SUCCESS,
+            stmt.bind_param_stream(
+                1,
+                SQL_PARAM_OUTPUT_STREAM,
+                SQL_C_BINARY,
+                SQL_LONGVARBINARY,
+                0,
+                0,
+                &doc,
+                false
+            )
+        );
+
+        let (stmt, sql_return) = stmt.SQLExecDirectA("{CALL p(?)}".as_ref());
+        assert_eq!(SQL_PARAM_DATA_AVAILABLE, sql_return);
+        let mut stmt = stmt.ok().unwrap();
+
+        let (param, sql_return) = stmt.next_output_param();
+        assert_eq!(SQL_PARAM_DATA_AVAILABLE, sql_return);
+        assert_eq!(Some(UnknownOutputParam(())), param.err());
+
+        let (param, sql_return) = stmt.next_output_param();
+        assert_eq!(SQL_PARAM_DATA_AVAILABLE, sql_return);
+        let param = param.unwrap().unwrap();
+        assert_eq!(1, param.parameter_number());
+
+        let mut data = std::vec::Vec::new();
+        param.binary().read_to_end(&mut data).unwrap();
+        assert_eq!(b"<a/>", data.as_slice());
+
+        let (param, sql_return) = stmt.next_output_param();
+        assert_eq!(SQL_SUCCESS, sql_return);
+        assert!(param.unwrap().is_none());
+    }
+
+    #[test]
+    fn get_cursor_name_a_truncated() {
+        let stmt_raw_handle = NonNull::new(13 as SQLHANDLE).unwrap();
+        let cursor_name = [b'c'; 300];
+
+        let SQLGetCursorNameA_ctx = ffi::SQLGetCursorNameA_context();
+        let SQLFreeHandle_ctx = ffi::SQLFreeHandle_context();
+
+        SQLGetCursorNameA_ctx
+            .expect()
+            .times(2)
+            .withf_st(move |x, _, _, _| *x == stmt_raw_handle.as_ptr())
+            .returning(move |_, CursorName, BufferLength, NameLengthPtr| {
+                let len = cursor_name.len().min(BufferLength as usize - 1);
+
+                unsafe {
+                    core::ptr::copy_nonoverlapping(cursor_name.as_ptr(), CursorName, len);
+                    *CursorName.add(len) = 0;
+                    *NameLengthPtr = cursor_name.len() as SQLSMALLINT;
+                }
+
+                if len < cursor_name.len() {
+                    SQL_SUCCESS_WITH_INFO
+                } else {
+                    SQL_SUCCESS
+                }
+            });
+        SQLFreeHandle_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, y| {
+                *x == SQL_HANDLE_STMT::IDENTIFIER && *y == stmt_raw_handle.as_ptr()
+            })
+            .return_const(SQL_SUCCESS);
+
+        #[cfg(feature = "runtime_checks")]
+        let _SQLGetStmtAttrA_ctx = expect_app_descs();
+
+        let stmt = unsafe { SQLHSTMT::<S1, SQL_OV_ODBC3_80>::from_raw(stmt_raw_handle) };
+        let (name, sql_return) = stmt.get_cursor_name_a();
+
+        assert_eq!(SQL_SUCCESS, sql_return);
+        assert_eq!(&cursor_name[..], &name.unwrap()[..]);
+    }
+
+    #[test]
+    fn diagnostics_SQLHSTMT() {
+        let stmt_raw_handle = NonNull::new(13 as SQLHANDLE).unwrap();
+
+        let SQLGetDiagRecW_ctx = ffi::SQLGetDiagRecW_context();
+        let SQLGetDiagFieldA_ctx = ffi::SQLGetDiagFieldA_context();
+        let SQLFreeHandle_ctx = ffi::SQLFreeHandle_context();
+
+        SQLGetDiagRecW_ctx
+            .expect()
+            .withf_st(move |x, y, z, _, _, _, _, _| {
+                *x == SQL_HANDLE_STMT::IDENTIFIER && *y == stmt_raw_handle.as_ptr() && *z == 1
+            })
+            .returning(
+                |_, _, _, SQLState, NativeErrorPtr, MessageText, _, TextLengthPtr| {
+                    let message = OdbcString::<SQLWCHAR>::from("Syntax error");
+
+                    unsafe {
+                        *SQLState = b"42000\0".map(SQLWCHAR::from);
+                        *NativeErrorPtr = 1064;
+                        core::ptr::copy_nonoverlapping(
+                            message.as_ptr(),
+                            MessageText,
+                            message.len(),
+                        );
+                        *MessageText.add(message.len()) = 0;
+                        *TextLengthPtr = message.len() as SQLSMALLINT;
+                    }
+
+                    SQL_SUCCESS
+                },
+            );
+        SQLGetDiagRecW_ctx
+            .expect()
+            .withf_st(|_, _, z, _, _, _, _, _| *z == 2)
+            .return_const(SQL_NO_DATA);
+        SQLGetDiagFieldA_ctx.expect().returning(
+            |_, _, _, DiagIdentifier, DiagInfoPtr, _, StringLengthPtr| {
+                let class_origin = b"ISO 9075";
+
+                unsafe {
+                    match DiagIdentifier {
+                        -1248 => *DiagInfoPtr.cast::<SQLLEN>() = 3,
+                        -1247 => *DiagInfoPtr.cast::<SQLINTEGER>() = -2,
+                        8 => {
+                            let DiagInfoPtr = DiagInfoPtr.cast::<SQLCHAR>();
+                            core::ptr::copy_nonoverlapping(
+                                class_origin.as_ptr(),
+                                DiagInfoPtr,
+                                class_origin.len(),
+                            );
+                            *DiagInfoPtr.add(class_origin.len()) = 0;
+                            *StringLengthPtr = class_origin.len() as SQLSMALLINT;
+                        }
+                        _ => {
+                            *DiagInfoPtr.cast::<SQLCHAR>() = 0;
+                            *StringLengthPtr = 0;
+                        }
+                    }
+                }
+
+                SQL_SUCCESS
+            },
+        );
+        SQLFreeHandle_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, y| {
+                *x == SQL_HANDLE_STMT::IDENTIFIER && *y == stmt_raw_handle.as_ptr()
+            })
+            .return_const(SQL_SUCCESS);
+
+        #[cfg(feature = "runtime_checks")]
+        let _SQLGetStmtAttrA_ctx = expect_app_descs();
+
+        let stmt = unsafe { SQLHSTMT::<S1, SQL_OV_ODBC3_80>::from_raw(stmt_raw_handle) };
+        let records: Vec<_> = stmt.diagnostics().collect();
+
+        assert_eq!(1, records.len());
+        assert_eq!("42000", records[0].sqlstate);
+        assert_eq!(1064, records[0].native_error);
+        assert_eq!("Syntax error", records[0].message);
+        assert_eq!(Some(3), records[0].row_number);
+        assert_eq!(None, records[0].column_number);
+        assert_eq!(Some("ISO 9075"), records[0].class_origin.as_deref());
+        assert_eq!(None, records[0].server_name);
+        assert_eq!("[42000] Syntax error", records[0].to_string());
+    }
+
+    #[test]
+    fn check_SQL_ERROR() {
+        let stmt_raw_handle = NonNull::new(13 as SQLHANDLE).unwrap();
+
+        let SQLGetDiagRecW_ctx = ffi::SQLGetDiagRecW_context();
+        let SQLGetDiagFieldA_ctx = ffi::SQLGetDiagFieldA_context();
+        let SQLFreeHandle_ctx = ffi::SQLFreeHandle_context();
+
+        SQLGetDiagRecW_ctx
+            .expect()
+            .withf_st(|_, _, z, _, _, _, _, _| *z == 1)
+            .returning(|_, _, _, SQLState, _, MessageText, _, TextLengthPtr| {
+                unsafe {
+                    *SQLState = b"HY010\0".map(SQLWCHAR::from);
+                    *MessageText = 0;
+                    *TextLengthPtr = 0;
+                }
+
+                SQL_SUCCESS
+            });
+        SQLGetDiagRecW_ctx
+            .expect()
+            .withf_st(|_, _, z, _, _, _, _, _| *z == 2)
+            .return_const(SQL_NO_DATA);
+        SQLGetDiagFieldA_ctx.expect().return_const(SQL_ERROR);
+        SQLFreeHandle_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, y| {
+                *x == SQL_HANDLE_STMT::IDENTIFIER && *y == stmt_raw_handle.as_ptr()
+            })
+            .return_const(SQL_SUCCESS);
+
+        #[cfg(feature = "runtime_checks")]
+        let _SQLGetStmtAttrA_ctx = expect_app_descs();
+
+        let stmt = unsafe { SQLHSTMT::<S1, SQL_OV_ODBC3_80>::from_raw(stmt_raw_handle) };
+        let outcome = SQL_NO_DATA.check(&stmt).unwrap();
+        assert_eq!(ReturnCode::SQL_NO_DATA, outcome.return_code());
+        assert!(outcome.warnings().is_empty());
+        let outcome = SQL_PARAM_DATA_AVAILABLE.check(&stmt).unwrap();
+        assert_eq!(
+            ReturnCode::SQL_PARAM_DATA_AVAILABLE(()),
+            outcome.return_code()
+        );
+
+        let error = SQL_ERROR.check(&stmt).unwrap_err();
+        assert_eq!(ReturnCode::SQL_ERROR, error.return_code());
+        assert_eq!(1, error.records().len());
+        assert_eq!("HY010", error.records()[0].sqlstate);
+        assert_eq!(None, error.records()[0].class_origin);
+
+        let error = SQL_INVALID_HANDLE.check(&stmt).unwrap_err();
+        assert!(error.records().is_empty());
+    }
+
+    #[test]
+    fn unbind_fresh_buffers() {
+        let stmt_raw_handle = NonNull::new(13 as SQLHANDLE).unwrap();
+
+        let SQLBindParameter_ctx = ffi::SQLBindParameter_context();
+        let SQLSetStmtAttrA_ctx = ffi::SQLSetStmtAttrA_context();
+        let SQLFreeStmt_ctx = ffi::SQLFreeStmt_context();
+        let SQLFreeHandle_ctx = ffi::SQLFreeHandle_context();
+
+        SQLBindParameter_ctx
+            .expect()
+            .times(3)
+            .withf_st(move |x, y, _, _, _, _, _, _, _, _| *x == stmt_raw_handle.as_ptr() && *y == 1)
+            .return_const(SQL_SUCCESS);
+        SQLSetStmtAttrA_ctx
+            .expect()
+            .times(12)
+            .withf_st(move |x, y, z, _| {
+                *x == stmt_raw_handle.as_ptr()
+                    && (*y == SQL_ATTR_APP_ROW_DESC::IDENTIFIER
+                        || *y == SQL_ATTR_APP_PARAM_DESC::IDENTIFIER)
+                    && z.is_null()
+            })
+            .return_const(SQL_SUCCESS);
+        SQLFreeStmt_ctx
+            .expect()
+            .times(12)
+            .withf_st(move |x, y| {
+                *x == stmt_raw_handle.as_ptr()
+                    && (*y == SQL_UNBIND as SQLUSMALLINT || *y == SQL_RESET_PARAMS as SQLUSMALLINT)
+            })
+            .return_const(SQL_SUCCESS);
+        SQLFreeHandle_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, y| {
+                *x == SQL_HANDLE_STMT::IDENTIFIER && *y == stmt_raw_handle.as_ptr()
+            })
+            .return_const(SQL_SUCCESS);
+
+        #[cfg(feature = "runtime_checks")]
+        let _SQLGetStmtAttrA_ctx = expect_app_descs();
+
+        let mut stmt = unsafe { SQLHSTMT::<S1, SQL_OV_ODBC3_80>::from_raw(stmt_raw_handle) };
+        for i in 0..3 {
+            // Buffer only lives for a single iteration
+            let val = UnsafeCell::new(i);
+
+            let iter_stmt = stmt.unbind().0.unwrap();
+            assert_eq!(
+                SQL_SUCCESS,
+                iter_stmt.SQLBindParameter(
+                    1,
+                    SQL_PARAM_INPUT,
+                    SQL_C_SLONG,
+                    SQL_INTEGER,
+                    0,
+                    0,
+                    Some(&val),
+                    None
+                )
+            );
+            assert!(iter_stmt.bound_param(1).is_some());
+
+            stmt = iter_stmt.unbind().0.unwrap();
+            assert_eq!(None, stmt.bound_param(1));
+        }
+    }
+
+    // TODO: Mockall is buggy and these tests fail more often
+    #[test]
+    #[cfg(feature = "runtime_checks")]
+    fn bind_col_app_desc_error() {
+        let stmt_raw_handle = NonNull::new(13 as SQLHANDLE).unwrap();
+
+        let SQLGetStmtAttrA_ctx = ffi::SQLGetStmtAttrA_context();
+        let SQLBindCol_ctx = ffi::SQLBindCol_context();
+        let SQLFreeHandle_ctx = ffi::SQLFreeHandle_context();
+
+        // Implicit descriptors are determined on allocation but not when binding
+        let mut calls = 0;
+        SQLGetStmtAttrA_ctx
+            .expect()
+            .times(3)
+            .returning(move |_, _, ValuePtr, _, _| {
+                calls += 1;
+                if calls > 2 {
+                    return SQL_ERROR;
+                }
+
+                unsafe { *ValuePtr.cast::<usize>() = 42 };
+                SQL_SUCCESS
+            });
+        SQLBindCol_ctx.expect().never();
+        SQLFreeHandle_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, y| {
+                *x == SQL_HANDLE_STMT::IDENTIFIER && *y == stmt_raw_handle.as_ptr()
+            })
+            .return_const(SQL_SUCCESS);
+
+        let id = UnsafeCell::new(0);
+        let stmt = unsafe { SQLHSTMT::<S1, SQL_OV_ODBC3_80>::from_raw(stmt_raw_handle) };
+
+        assert_eq!(SQL_ERROR, stmt.SQLBindCol(1, SQL_C_SLONG, Some(&id), None));
+        assert_eq!(None, stmt.bound_col(1));
+    }
+
+    #[test]
+    #[cfg(feature = "runtime_checks")]
+    fn bind_col_app_desc_with_info() {
+        let stmt_raw_handle = NonNull::new(13 as SQLHANDLE).unwrap();
+
+        let SQLGetStmtAttrA_ctx = ffi::SQLGetStmtAttrA_context();
+        let SQLBindCol_ctx = ffi::SQLBindCol_context();
+        let SQLFreeHandle_ctx = ffi::SQLFreeHandle_context();
+
+        SQLGetStmtAttrA_ctx
+            .expect()
+            .times(3)
+            .returning(|_, _, ValuePtr, _, _| {
+                unsafe { *ValuePtr.cast::<usize>() = 42 };
+                SQL_SUCCESS_WITH_INFO
+            });
+        SQLBindCol_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, y, z, _, _, _| {
+                *x == stmt_raw_handle.as_ptr() && *y == 1 && *z == SQL_C_SLONG::IDENTIFIER
+            })
+            .return_const(SQL_SUCCESS);
+        SQLFreeHandle_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, y| {
+                *x == SQL_HANDLE_STMT::IDENTIFIER && *y == stmt_raw_handle.as_ptr()
+            })
+            .return_const(SQL_SUCCESS);
+
+        let id = UnsafeCell::new(0);
+        let stmt = unsafe { SQLHSTMT::<S1, SQL_OV_ODBC3_80>::from_raw(stmt_raw_handle) };
+
+        assert_eq!(
+            SQL_SUCCESS,
+            stmt.SQLBindCol(1, SQL_C_SLONG, Some(&id), None)
+        );
+        assert!(stmt.bound_col(1).is_some());
+    }
+
+    #[test]
+    fn browse_connect_still_executing() {
+        let conn_raw_handle = 13 as SQLHANDLE;
+
+        let SQLBrowseConnectA_ctx = ffi::SQLBrowseConnectA_context();
+        let SQLDisconnect_ctx = ffi::SQLDisconnect_context();
+        let SQLFreeHandle_ctx = ffi::SQLFreeHandle_context();
+
+        SQLBrowseConnectA_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, _, _, _, _, _| *x == conn_raw_handle)
+            .return_const(SQL_STILL_EXECUTING);
+        SQLDisconnect_ctx.expect().never();
+        SQLFreeHandle_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, y| *x == SQL_HANDLE_DBC::IDENTIFIER && *y == conn_raw_handle)
+            .return_const(SQL_SUCCESS);
+
+        let conn = SQLHDBC::<C2, SQL_OV_ODBC3_80> {
+            handle: conn_raw_handle,
+            parent: PhantomData,
+            connected: PhantomData,
+            version: PhantomData,
+        };
+
+        let (conn, sql_return) = conn.SQLBrowseConnectA("DSN=x".as_ref(), None, &mut 0);
+        assert_eq!(SQL_STILL_EXECUTING, sql_return);
+        let Err(Err(Ok(_))) = conn else {
+            panic!("Connection is not in the state it was in before the call")
+        };
+    }
+
+    #[test]
+    fn exec_direct_transitions() {
+        let stmt_raw_handle = NonNull::new(13 as SQLHANDLE).unwrap();
+
+        let SQLExecDirectA_ctx = ffi::SQLExecDirectA_context();
+        let SQLFreeStmt_ctx = ffi::SQLFreeStmt_context();
+        let SQLFreeHandle_ctx = ffi::SQLFreeHandle_context();
+
+        let mut sql_returns = std::vec![SQL_PARAM_DATA_AVAILABLE, SQL_STILL_EXECUTING];
+        SQLExecDirectA_ctx
+            .expect()
+            .times(2)
+            .withf_st(move |x, _, _| *x == stmt_raw_handle.as_ptr())
+            .returning_st(move |_, _, _| sql_returns.pop().unwrap());
+        SQLFreeStmt_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, y| *x == stmt_raw_handle.as_ptr() && *y == SQL_CLOSE as SQLUSMALLINT)
+            .return_const(SQL_SUCCESS);
+        SQLFreeHandle_ctx
+            .expect()
+            .once()
+            .withf_st(move |x, y| {
+                *x == SQL_HANDLE_STMT::IDENTIFIER && *y == stmt_raw_handle.as_ptr()
+            })
+            .return_const(SQL_SUCCESS);
+
+        #[cfg(feature = "runtime_checks")]
+        let _SQLGetStmtAttrA_ctx = expect_app_descs();
+
+        let stmt = unsafe { SQLHSTMT::<S1, SQL_OV_ODBC3_80>::from_raw(stmt_raw_handle) };
+
+        // Asynchronously executing statement is polled by calling the function again
+        let (stmt, sql_return) = stmt.SQLExecDirectA("{CALL p(?)}".as_ref());
+        assert_eq!(SQL_STILL_EXECUTING, sql_return);
+        let Err(Err(stmt)) = stmt else {
+            panic!("Statement is not in the state it was in before execution")
+        };
+
+        let (stmt, sql_return) = stmt.SQLExecDirectA("{CALL p(?)}".as_ref());
+        assert_eq!(SQL_PARAM_DATA_AVAILABLE, sql_return);
+        let stmt = stmt.ok().unwrap();
+
+        let (stmt, sql_return) = stmt.close_cursor();
+        assert_eq!(SQL_SUCCESS, sql_return);
+        assert!(stmt.is_ok());
+    }
+
     //#[test]
     //#[should_panic]
     //fn disconnect_C3_panic() {
@@ -3,14 +3,21 @@
 pub mod api;
 pub mod attr;
 pub mod c_types;
+#[cfg(feature = "runtime_checks")]
+mod checks;
 pub mod col;
 pub mod conn;
 pub mod convert;
+pub mod datetime;
 pub mod desc;
 pub mod diag;
+#[cfg(feature = "dlopen")]
+pub mod dm;
 pub mod env;
+pub mod guid;
 pub mod handle;
 pub mod info;
+pub mod numeric;
 pub mod sql_types;
 pub mod sqlreturn;
 pub mod stmt;
@@ -43,7 +50,11 @@ pub type SQLCHAR = u8;
 pub type SQLSCHAR = i8;
 
 /// UCS-2 encoded character
+#[cfg(not(feature = "iodbc"))]
 pub type SQLWCHAR = u16;
+/// UCS-4 encoded character as defined by the 4-byte `wchar_t` used by iODBC
+#[cfg(feature = "iodbc")]
+pub type SQLWCHAR = u32;
 
 pub type SQLBIGINT = i64;
 pub type SQLUBIGINT = u64;
@@ -229,6 +240,24 @@ pub enum FreeStmtOption {
     SQL_RESET_PARAMS = 3,
 }
 
+/// Options of SQLFreeStmt accepted by statements which track their state in their type.
+/// Cursor of such statement is closed with `SQLHSTMT::close_cursor`
+#[odbc_type(SQLUSMALLINT)]
+#[expect(non_camel_case_types)]
+pub enum UnbindOption {
+    SQL_UNBIND = 2,
+    SQL_RESET_PARAMS = 3,
+}
+
+impl From<UnbindOption> for FreeStmtOption {
+    fn from(source: UnbindOption) -> Self {
+        match source {
+            UnbindOption::SQL_UNBIND => FreeStmtOption::SQL_UNBIND,
+            UnbindOption::SQL_RESET_PARAMS => FreeStmtOption::SQL_RESET_PARAMS,
+        }
+    }
+}
+
 #[odbc_type(SQLUSMALLINT)]
 #[expect(non_camel_case_types)]
 pub enum Reserved {
@@ -350,11 +379,6 @@ pub enum FunctionId {
 //    SQL_FALSE
 //}
 
-//const SQL_LEN_DATA_AT_EXEC_OFFSET: usize = -100;
-//pub const fn SQL_LEN_DATA_AT_EXEC<LEN>(length: LEN) {
-//    (-length).checked_add(SQL_LEN_DATA_AT_EXEC_OFFSET).expect()
-//}
-
 // TODO: Make it const fn
 pub(crate) fn slice_len<T, LEN: TryFrom<usize>>(slice: &[T]) -> LEN
 where
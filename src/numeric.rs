@@ -0,0 +1,230 @@
+//! Exact conversions of `SQL_NUMERIC_STRUCT` and, with the `rust_decimal` or `bigdecimal`
+//! feature enabled, conversions between it and the decimal types of those crates.
+//!
+//! Value of a numeric is `val * 10^-scale` where `val` is the unsigned magnitude stored as a
+//! 16 byte little-endian integer and `sign` is 1 if positive and 0 if negative. Values are
+//! converted without loss of precision or they are not converted at all:
+//!
+//! ```
+//! use rs_odbc::SQL_NUMERIC_STRUCT;
+//!
+//! let numeric = SQL_NUMERIC_STRUCT::from_i128(-12345, 38, 2).unwrap();
+//!
+//! assert_eq!("-123.45", numeric.to_string());
+//! assert_eq!(Some(-12345), numeric.to_i128());
+//! assert_eq!(Some(-1234500), numeric.rescale(38, 4).and_then(|n| n.to_i128()));
+//! assert_eq!(None, numeric.rescale(38, 1));
+//! ```
+//!
+//! Drivers use the scale of 0 for numerics unless SQL_DESC_PRECISION and SQL_DESC_SCALE are
+//! set on the application descriptor. To bind numeric buffers use
+//! [`Statement::bind_col_numeric`](crate::api::Statement::bind_col_numeric) and
+//! [`Statement::bind_param_numeric`](crate::api::Statement::bind_param_numeric)
+
+#[cfg(feature = "bigdecimal")]
+mod bigdecimal;
+#[cfg(feature = "rust_decimal")]
+mod rust_decimal;
+
+use crate::{SQL_NUMERIC_STRUCT, SQLCHAR, SQLSCHAR};
+use core::fmt;
+
+/// Largest precision of a numeric whose digits always fit into `val`
+pub const SQL_MAX_NUMERIC_PRECISION: SQLCHAR = 38;
+
+/// Error returned when a decimal can't be represented exactly as the target type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TryFromNumericError(());
+
+impl fmt::Display for TryFromNumericError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "numeric value can't be represented exactly by the target type"
+        )
+    }
+}
+impl core::error::Error for TryFromNumericError {}
+
+/// Number of decimal digits of the magnitude, zero has a single digit
+fn digits(magnitude: u128) -> SQLCHAR {
+    magnitude
+        .checked_ilog10()
+        .map_or(1, |digits| digits as SQLCHAR + 1)
+}
+
+impl SQL_NUMERIC_STRUCT {
+    /// Creates a numeric with the value of `mantissa * 10^-scale`. Returns `None` if precision
+    /// is 0, greater than [`SQL_MAX_NUMERIC_PRECISION`] or less than the number of digits of
+    /// the mantissa
+    pub fn from_i128(mantissa: i128, precision: SQLCHAR, scale: SQLSCHAR) -> Option<Self> {
+        Self::from_parts(mantissa < 0, mantissa.unsigned_abs(), precision, scale)
+    }
+
+    /// Creates a numeric with the value of `magnitude * 10^-scale` negated if `is_negative` is
+    /// set. Returns `None` if precision is 0, greater than [`SQL_MAX_NUMERIC_PRECISION`] or less
+    /// than the number of digits of the magnitude
+    pub fn from_parts(
+        is_negative: bool,
+        magnitude: u128,
+        precision: SQLCHAR,
+        scale: SQLSCHAR,
+    ) -> Option<Self> {
+        if precision == 0 || precision > SQL_MAX_NUMERIC_PRECISION || digits(magnitude) > precision
+        {
+            return None;
+        }
+
+        Some(Self {
+            precision,
+            scale,
+            // Zero is always positive
+            sign: (!is_negative || magnitude == 0) as SQLCHAR,
+            val: magnitude.to_le_bytes(),
+        })
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.sign == 0
+    }
+
+    /// Returns the unsigned unscaled value
+    pub fn magnitude(&self) -> u128 {
+        u128::from_le_bytes(self.val)
+    }
+
+    /// Returns the unscaled value, i.e. the mantissa in `mantissa * 10^-scale`. Returns `None`
+    /// if the mantissa doesn't fit into `i128`
+    pub fn to_i128(&self) -> Option<i128> {
+        let magnitude = self.magnitude();
+
+        if self.is_negative() {
+            0i128.checked_sub_unsigned(magnitude)
+        } else {
+            i128::try_from(magnitude).ok()
+        }
+    }
+
+    /// Returns the numeric of the same value with the given precision and scale. Returns `None`
+    /// if the value can't be represented exactly with the given precision and scale
+    pub fn rescale(&self, precision: SQLCHAR, scale: SQLSCHAR) -> Option<Self> {
+        let magnitude = self.magnitude();
+        let shift = i16::from(scale) - i16::from(self.scale);
+
+        let magnitude = if shift >= 0 {
+            10u128
+                .checked_pow(shift as u32)
+                .and_then(|factor| magnitude.checked_mul(factor))?
+        } else {
+            match 10u128.checked_pow(shift.unsigned_abs().into()) {
+                Some(factor) => magnitude
+                    .is_multiple_of(factor)
+                    .then_some(magnitude / factor)?,
+                // Only zero can be divided by a factor larger than any magnitude
+                None => (magnitude == 0).then_some(0)?,
+            }
+        };
+
+        Self::from_parts(self.is_negative(), magnitude, precision, scale)
+    }
+}
+
+impl fmt::Display for SQL_NUMERIC_STRUCT {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = [0; 39];
+        let mut magnitude = self.magnitude();
+        let mut start = buf.len();
+
+        loop {
+            start -= 1;
+            buf[start] = b'0' + (magnitude % 10) as u8;
+            magnitude /= 10;
+
+            if magnitude == 0 {
+                break;
+            }
+        }
+
+        // SAFETY: Buffer contains only ASCII digits
+        let digits = unsafe { core::str::from_utf8_unchecked(&buf[start..]) };
+        let sign = if self.is_negative() && digits != "0" {
+            "-"
+        } else {
+            ""
+        };
+
+        if self.scale <= 0 {
+            let zeros = if digits == "0" {
+                0
+            } else {
+                self.scale.unsigned_abs().into()
+            };
+
+            return write!(f, "{sign}{digits}{:0<zeros$}", "");
+        }
+
+        let scale = self.scale as usize;
+        if digits.len() > scale {
+            let (integer, fraction) = digits.split_at(digits.len() - scale);
+            write!(f, "{sign}{integer}.{fraction}")
+        } else {
+            write!(f, "{sign}0.{digits:0>scale$}")
+        }
+    }
+}
+
+//=====================================================================================//
+//----------------------------------------Tests----------------------------------------//
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn from_i128() {
+        let numeric = SQL_NUMERIC_STRUCT::from_i128(-5, 1, 3).unwrap();
+        assert_eq!((1, 3, 0), (numeric.precision, numeric.scale, numeric.sign));
+        assert_eq!([5, 0, 0, 0], numeric.val[..4]);
+        assert_eq!("-0.005", numeric.to_string());
+
+        let numeric = SQL_NUMERIC_STRUCT::from_i128(-(10i128.pow(38) - 1), 38, 0).unwrap();
+        assert_eq!(Some(-(10i128.pow(38) - 1)), numeric.to_i128());
+        assert_eq!(
+            "1200",
+            SQL_NUMERIC_STRUCT::from_i128(12, 2, -2)
+                .unwrap()
+                .to_string()
+        );
+        assert_eq!(
+            "0",
+            SQL_NUMERIC_STRUCT::from_i128(0, 1, -2).unwrap().to_string()
+        );
+
+        assert_eq!(None, SQL_NUMERIC_STRUCT::from_i128(100, 2, 0));
+        assert_eq!(None, SQL_NUMERIC_STRUCT::from_i128(0, 0, 0));
+        assert_eq!(None, SQL_NUMERIC_STRUCT::from_i128(i128::MAX, 39, 0));
+    }
+
+    #[test]
+    fn to_i128_out_of_range() {
+        let numeric = SQL_NUMERIC_STRUCT {
+            precision: 38,
+            scale: 0,
+            sign: 1,
+            val: u128::MAX.to_le_bytes(),
+        };
+
+        assert_eq!(None, numeric.to_i128());
+        assert_eq!(u128::MAX, numeric.magnitude());
+    }
+
+    #[test]
+    fn rescale() {
+        let numeric = SQL_NUMERIC_STRUCT::from_i128(1250, 4, 3).unwrap();
+
+        assert_eq!("1.25", numeric.rescale(3, 2).unwrap().to_string());
+        assert_eq!("1.250000", numeric.rescale(7, 6).unwrap().to_string());
+        assert_eq!(None, numeric.rescale(1, 1));
+        assert_eq!(None, numeric.rescale(6, 6));
+    }
+}
@@ -0,0 +1,73 @@
+use super::{SQL_MAX_NUMERIC_PRECISION, TryFromNumericError, digits};
+use crate::{SQL_NUMERIC_STRUCT, SQLSCHAR};
+use ::bigdecimal::{
+    BigDecimal, ToPrimitive,
+    num_bigint::{BigInt, BigUint, Sign},
+};
+
+/// Precision is the smallest one which holds both the digits and the scale of the decimal
+impl TryFrom<&BigDecimal> for SQL_NUMERIC_STRUCT {
+    type Error = TryFromNumericError;
+
+    fn try_from(source: &BigDecimal) -> Result<Self, Self::Error> {
+        let (mantissa, scale) = source.as_bigint_and_exponent();
+
+        let scale = SQLSCHAR::try_from(scale).map_err(|_| TryFromNumericError(()))?;
+        let magnitude = mantissa
+            .magnitude()
+            .to_u128()
+            .ok_or(TryFromNumericError(()))?;
+        let precision = digits(magnitude).max(scale.max(0) as u8);
+
+        SQL_NUMERIC_STRUCT::from_parts(
+            mantissa.sign() == Sign::Minus,
+            magnitude,
+            precision.min(SQL_MAX_NUMERIC_PRECISION),
+            scale,
+        )
+        .ok_or(TryFromNumericError(()))
+    }
+}
+impl TryFrom<BigDecimal> for SQL_NUMERIC_STRUCT {
+    type Error = TryFromNumericError;
+
+    fn try_from(source: BigDecimal) -> Result<Self, Self::Error> {
+        Self::try_from(&source)
+    }
+}
+impl From<SQL_NUMERIC_STRUCT> for BigDecimal {
+    fn from(source: SQL_NUMERIC_STRUCT) -> Self {
+        let sign = if source.is_negative() {
+            Sign::Minus
+        } else {
+            Sign::Plus
+        };
+        let magnitude = BigUint::from(source.magnitude());
+
+        BigDecimal::new(BigInt::from_biguint(sign, magnitude), source.scale.into())
+    }
+}
+
+//=====================================================================================//
+//----------------------------------------Tests----------------------------------------//
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn bigdecimal_roundtrip() {
+        let source: BigDecimal = "-12345.6789".parse().unwrap();
+        let numeric = SQL_NUMERIC_STRUCT::try_from(&source).unwrap();
+
+        assert_eq!((9, 4), (numeric.precision, numeric.scale));
+        assert_eq!(source, BigDecimal::from(numeric));
+
+        let numeric = SQL_NUMERIC_STRUCT::from_i128(10i128.pow(38) - 1, 38, -100).unwrap();
+        assert_eq!(
+            numeric.to_string().parse::<BigDecimal>().unwrap(),
+            BigDecimal::from(numeric)
+        );
+        assert!(SQL_NUMERIC_STRUCT::try_from(&"1e-200".parse::<BigDecimal>().unwrap()).is_err());
+    }
+}
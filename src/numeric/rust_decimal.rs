@@ -0,0 +1,53 @@
+use super::{SQL_MAX_NUMERIC_PRECISION, TryFromNumericError, digits};
+use crate::{SQL_NUMERIC_STRUCT, SQLSCHAR};
+use ::rust_decimal::Decimal;
+
+/// Precision is the smallest one which holds both the digits and the scale of the decimal
+impl TryFrom<Decimal> for SQL_NUMERIC_STRUCT {
+    type Error = TryFromNumericError;
+
+    fn try_from(source: Decimal) -> Result<Self, Self::Error> {
+        let mantissa = source.mantissa();
+        // Scale of a decimal is at most 28
+        let scale = source.scale() as SQLSCHAR;
+        let precision = digits(mantissa.unsigned_abs()).max(scale as u8);
+
+        SQL_NUMERIC_STRUCT::from_i128(mantissa, precision.min(SQL_MAX_NUMERIC_PRECISION), scale)
+            .ok_or(TryFromNumericError(()))
+    }
+}
+impl TryFrom<SQL_NUMERIC_STRUCT> for Decimal {
+    type Error = TryFromNumericError;
+
+    fn try_from(source: SQL_NUMERIC_STRUCT) -> Result<Self, Self::Error> {
+        // Decimal can't have a negative scale
+        let source = source
+            .rescale(SQL_MAX_NUMERIC_PRECISION, source.scale.max(0))
+            .ok_or(TryFromNumericError(()))?;
+        let mantissa = source.to_i128().ok_or(TryFromNumericError(()))?;
+
+        Decimal::try_from_i128_with_scale(mantissa, source.scale as u32)
+            .map_err(|_| TryFromNumericError(()))
+    }
+}
+
+//=====================================================================================//
+//----------------------------------------Tests----------------------------------------//
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn decimal_roundtrip() {
+        let source = Decimal::try_from_i128_with_scale(-123_456_789, 4).unwrap();
+        let numeric = SQL_NUMERIC_STRUCT::try_from(source).unwrap();
+
+        assert_eq!((9, 4), (numeric.precision, numeric.scale));
+        assert_eq!("-12345.6789", numeric.to_string());
+        assert_eq!(Ok(source), Decimal::try_from(numeric));
+
+        let numeric = SQL_NUMERIC_STRUCT::from_i128(12, 2, -3).unwrap();
+        assert_eq!(Ok(Decimal::from(12_000)), Decimal::try_from(numeric));
+    }
+}
@@ -1,4 +1,13 @@
+#[cfg(feature = "std")]
+use crate::api::Diagnostics;
+use crate::api::Handle;
+#[cfg(feature = "std")]
+use crate::diag::DiagRecord;
+use crate::env::OdbcVersion;
+use core::{convert::Infallible, fmt::Debug};
 use rs_odbc_derive::odbc_type;
+#[cfg(feature = "std")]
+use std::{fmt, vec::Vec};
 
 /// Each function in ODBC returns a code, known as its return code, which indicates the
 /// overall success or failure of the function. Program logic is generally based on return
@@ -49,23 +58,255 @@ pub const SQL_NEED_DATA: SQLRETURN = SQLRETURN(99);
 pub const SQL_STILL_EXECUTING: SQLRETURN = SQLRETURN(2);
 
 /// Indicates that there are streamed output parameters available for the next set of
-/// parameters to retrieve.
-#[cfg(feature = "v3_8")]
+/// parameters to retrieve. Returned only to applications of ODBC version 3.80 or later.
 pub const SQL_PARAM_DATA_AVAILABLE: SQLRETURN = SQLRETURN(101);
 
-/// Signals data-at-fetch columns are available.
-#[cfg(feature = "v4")]
+/// Signals data-at-fetch columns are available. Returned only to applications of ODBC
+/// version 4.0 or later.
 pub const SQL_DATA_AVAILABLE: SQLRETURN = SQLRETURN(102);
 
-/// The descriptor is changed by the driver when reading a column.
-#[cfg(feature = "v4")]
+/// The descriptor is changed by the driver when reading a column. Returned only to
+/// applications of ODBC version 4.0 or later.
 pub const SQL_METADATA_CHANGED: SQLRETURN = SQLRETURN(103);
 
-/// The driver does not know how much additional data is to be written.
-#[cfg(feature = "v4")]
+/// The driver does not know how much additional data is to be written. Returned only to
+/// applications of ODBC version 4.0 or later.
 pub const SQL_MORE_DATA: SQLRETURN = SQLRETURN(104);
 
+/// Return code of a function called on a handle of ODBC version `V`. Return codes introduced
+/// in a later ODBC version than `V` are uninhabited so they don't have to be matched on
+///
+/// ```
+/// use rs_odbc::env::SQL_OV_ODBC3;
+/// use rs_odbc::sqlreturn::{ReturnCode, SQL_NO_DATA};
+///
+/// match ReturnCode::<SQL_OV_ODBC3>::from(SQL_NO_DATA) {
+///     ReturnCode::SQL_SUCCESS | ReturnCode::SQL_SUCCESS_WITH_INFO => {}
+///     ReturnCode::SQL_NO_DATA => {}
+///     ReturnCode::SQL_NEED_DATA => {}
+///     ReturnCode::SQL_STILL_EXECUTING => {}
+///     ReturnCode::SQL_ERROR | ReturnCode::SQL_INVALID_HANDLE => {}
+///     ReturnCode::Unknown(_) => {}
+/// }
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+#[expect(non_camel_case_types)]
+pub enum ReturnCode<V: OdbcVersion> {
+    SQL_SUCCESS,
+    SQL_SUCCESS_WITH_INFO,
+    SQL_ERROR,
+    SQL_INVALID_HANDLE,
+    SQL_NO_DATA,
+    SQL_NEED_DATA,
+    SQL_STILL_EXECUTING,
+    SQL_PARAM_DATA_AVAILABLE(V::V3_80),
+    SQL_DATA_AVAILABLE(V::V4),
+    SQL_METADATA_CHANGED(V::V4),
+    SQL_MORE_DATA(V::V4),
+    /// Return code which is not defined for the ODBC version `V`
+    Unknown(SQLRETURN),
+}
+
+/// Marks whether return codes introduced in some ODBC version can be returned
+pub trait Availability: Debug + Clone + Copy + PartialEq + Eq {
+    /// `None` if return codes are not available
+    const AVAILABLE: Option<Self>;
+}
+impl Availability for () {
+    const AVAILABLE: Option<Self> = Some(());
+}
+impl Availability for Infallible {
+    const AVAILABLE: Option<Self> = None;
+}
+
+// Implemented manually to not require `V: Copy`
+impl<V: OdbcVersion> Clone for ReturnCode<V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<V: OdbcVersion> Copy for ReturnCode<V> {}
+
+impl<V: OdbcVersion> From<SQLRETURN> for ReturnCode<V> {
+    fn from(source: SQLRETURN) -> Self {
+        let versioned = match source {
+            SQL_SUCCESS => return Self::SQL_SUCCESS,
+            SQL_SUCCESS_WITH_INFO => return Self::SQL_SUCCESS_WITH_INFO,
+            SQL_ERROR => return Self::SQL_ERROR,
+            SQL_INVALID_HANDLE => return Self::SQL_INVALID_HANDLE,
+            SQL_NO_DATA => return Self::SQL_NO_DATA,
+            SQL_NEED_DATA => return Self::SQL_NEED_DATA,
+            SQL_STILL_EXECUTING => return Self::SQL_STILL_EXECUTING,
+            SQL_PARAM_DATA_AVAILABLE => V::V3_80::AVAILABLE.map(Self::SQL_PARAM_DATA_AVAILABLE),
+            SQL_DATA_AVAILABLE => V::V4::AVAILABLE.map(Self::SQL_DATA_AVAILABLE),
+            SQL_METADATA_CHANGED => V::V4::AVAILABLE.map(Self::SQL_METADATA_CHANGED),
+            SQL_MORE_DATA => V::V4::AVAILABLE.map(Self::SQL_MORE_DATA),
+            _ => None,
+        };
+
+        versioned.unwrap_or(Self::Unknown(source))
+    }
+}
+impl<V: OdbcVersion> From<ReturnCode<V>> for SQLRETURN {
+    fn from(source: ReturnCode<V>) -> Self {
+        match source {
+            ReturnCode::SQL_SUCCESS => SQL_SUCCESS,
+            ReturnCode::SQL_SUCCESS_WITH_INFO => SQL_SUCCESS_WITH_INFO,
+            ReturnCode::SQL_ERROR => SQL_ERROR,
+            ReturnCode::SQL_INVALID_HANDLE => SQL_INVALID_HANDLE,
+            ReturnCode::SQL_NO_DATA => SQL_NO_DATA,
+            ReturnCode::SQL_NEED_DATA => SQL_NEED_DATA,
+            ReturnCode::SQL_STILL_EXECUTING => SQL_STILL_EXECUTING,
+            ReturnCode::SQL_PARAM_DATA_AVAILABLE(_) => SQL_PARAM_DATA_AVAILABLE,
+            ReturnCode::SQL_DATA_AVAILABLE(_) => SQL_DATA_AVAILABLE,
+            ReturnCode::SQL_METADATA_CHANGED(_) => SQL_METADATA_CHANGED,
+            ReturnCode::SQL_MORE_DATA(_) => SQL_MORE_DATA,
+            ReturnCode::Unknown(source) => source,
+        }
+    }
+}
+
+impl SQLRETURN {
+    /// Interprets the code returned by a function called on the given handle. As defined by the
+    /// standard, functions return `SQLRETURN` which is converted into a [`ReturnCode`] of the
+    /// ODBC version of the handle so that it can be matched on exhaustively
+    ///
+    /// ```
+    /// use rs_odbc::env::SQL_OV_ODBC4;
+    /// use rs_odbc::handle::SQLHSTMT;
+    /// use rs_odbc::sqlreturn::{ReturnCode, SQLRETURN};
+    /// use rs_odbc::stmt::S1;
+    ///
+    /// fn describe(stmt: &SQLHSTMT<S1, SQL_OV_ODBC4>, sql_return: SQLRETURN) -> &'static str {
+    ///     match sql_return.return_code(stmt) {
+    ///         ReturnCode::SQL_SUCCESS | ReturnCode::SQL_SUCCESS_WITH_INFO => "success",
+    ///         ReturnCode::SQL_NO_DATA => "no data",
+    ///         ReturnCode::SQL_NEED_DATA => "need data",
+    ///         ReturnCode::SQL_STILL_EXECUTING => "still executing",
+    ///         ReturnCode::SQL_PARAM_DATA_AVAILABLE(()) => "param data available",
+    ///         ReturnCode::SQL_DATA_AVAILABLE(()) => "data available",
+    ///         ReturnCode::SQL_METADATA_CHANGED(()) => "metadata changed",
+    ///         ReturnCode::SQL_MORE_DATA(()) => "more data",
+    ///         ReturnCode::SQL_ERROR | ReturnCode::SQL_INVALID_HANDLE => "error",
+    ///         ReturnCode::Unknown(_) => "unknown",
+    ///     }
+    /// }
+    /// ```
+    pub fn return_code<H: Handle>(self, _handle: &H) -> ReturnCode<H::Version> {
+        ReturnCode::from(self)
+    }
+}
+
 #[expect(non_snake_case)]
 pub fn SQL_SUCCEEDED<T: Into<SQLRETURN>>(ret: T) -> bool {
     matches!(ret.into(), SQL_SUCCESS | SQL_SUCCESS_WITH_INFO)
 }
+
+/// Outcome of a function which didn't fail, see [`SQLRETURN::check`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg(feature = "std")]
+pub struct Outcome<V: OdbcVersion> {
+    return_code: ReturnCode<V>,
+    warnings: Vec<DiagRecord>,
+}
+
+#[cfg(feature = "std")]
+impl<V: OdbcVersion> Outcome<V> {
+    /// Returns the code returned by the function. Never SQL_ERROR or SQL_INVALID_HANDLE
+    pub fn return_code(&self) -> ReturnCode<V> {
+        self.return_code
+    }
+
+    /// Returns warnings reported by the function. Empty unless SQL_SUCCESS_WITH_INFO was returned
+    pub fn warnings(&self) -> &[DiagRecord] {
+        &self.warnings
+    }
+
+    pub fn into_warnings(self) -> Vec<DiagRecord> {
+        self.warnings
+    }
+}
+
+/// Error returned by [`SQLRETURN::check`] when the function returned SQL_ERROR or
+/// SQL_INVALID_HANDLE
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg(feature = "std")]
+pub struct OdbcError<V: OdbcVersion> {
+    return_code: ReturnCode<V>,
+    records: Vec<DiagRecord>,
+}
+
+#[cfg(feature = "std")]
+impl<V: OdbcVersion> OdbcError<V> {
+    /// Returns either SQL_ERROR or SQL_INVALID_HANDLE
+    pub fn return_code(&self) -> ReturnCode<V> {
+        self.return_code
+    }
+
+    /// Returns diagnostic records describing the error. Empty for SQL_INVALID_HANDLE
+    pub fn records(&self) -> &[DiagRecord] {
+        &self.records
+    }
+
+    pub fn into_records(self) -> Vec<DiagRecord> {
+        self.records
+    }
+}
+#[cfg(feature = "std")]
+impl<V: OdbcVersion> fmt::Display for OdbcError<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.return_code, self.records.first()) {
+            (ReturnCode::SQL_INVALID_HANDLE, _) => {
+                write!(f, "ODBC function called with an invalid handle")
+            }
+            (_, Some(record)) => write!(f, "ODBC function failed: {record}"),
+            (_, None) => write!(f, "ODBC function failed without diagnostics"),
+        }
+    }
+}
+#[cfg(feature = "std")]
+impl<V: OdbcVersion + Debug> std::error::Error for OdbcError<V> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.records
+            .first()
+            .map(|record| record as &(dyn std::error::Error + 'static))
+    }
+}
+
+#[cfg(feature = "std")]
+impl SQLRETURN {
+    /// Converts the return code into a `Result` of the [`ReturnCode`] of the handle's ODBC
+    /// version, see [`SQLRETURN::return_code`]. Diagnostic records are retrieved from the
+    /// handle on which the function was called, before the next call on the handle clears
+    /// them, if the function returned SQL_SUCCESS_WITH_INFO or SQL_ERROR
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the function returned SQL_ERROR or SQL_INVALID_HANDLE
+    pub fn check<H: Diagnostics>(
+        self,
+        handle: &H,
+    ) -> Result<Outcome<H::Version>, OdbcError<H::Version>> {
+        let return_code = self.return_code(handle);
+
+        match return_code {
+            ReturnCode::SQL_ERROR => Err(OdbcError {
+                return_code,
+                records: handle.diagnostics().collect(),
+            }),
+            // No diagnostics are available for an invalid handle
+            ReturnCode::SQL_INVALID_HANDLE => Err(OdbcError {
+                return_code,
+                records: Vec::new(),
+            }),
+            ReturnCode::SQL_SUCCESS_WITH_INFO => Ok(Outcome {
+                return_code,
+                warnings: handle.diagnostics().collect(),
+            }),
+            _ => Ok(Outcome {
+                return_code,
+                warnings: Vec::new(),
+            }),
+        }
+    }
+}
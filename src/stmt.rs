@@ -8,16 +8,95 @@ use crate::handle::{RefSQLHDESC, RefUnsafeSQLHDESC, SQLHSTMT, UnsafeSQLHSTMT};
 use crate::handle::{SQLHDESC, UnsafeSQLHDESC};
 use crate::str::{Ansi, OdbcChar, OdbcStr, Unicode};
 use crate::{
-    Ident, OdbcBool, OdbcDefined, Ref, SQLCHAR, SQLINTEGER, SQLULEN, SQLWCHAR, Scalar,
-    sqlreturn::SQLRETURN,
+    Ident, OdbcBool, OdbcDefined, Ref, SQLCHAR, SQLINTEGER, SQLLEN, SQLPOINTER, SQLULEN,
+    SQLUSMALLINT, SQLWCHAR, Scalar, StrLenOrInd, sqlreturn::SQLRETURN,
 };
-use core::mem::MaybeUninit;
+#[cfg(feature = "std")]
+use crate::{
+    SQL_C_BINARY, SQL_C_CHAR, SQL_C_WCHAR, SQL_NO_TOTAL, SQL_NULL_DATA, SQLSMALLINT,
+    handle::SQLHANDLE,
+    info::{GetdataExtensions, SQL_GD_ANY_COLUMN, SQL_GD_ANY_ORDER, SQL_GD_BLOCK, SQL_GD_BOUND},
+    sqlreturn::{SQL_NO_DATA, SQL_SUCCEEDED},
+};
+#[cfg(feature = "std")]
+use core::{any::Any, cell::UnsafeCell};
+use core::{any::TypeId, cell::Cell, marker::PhantomData, mem::MaybeUninit};
 use mockall_double::double;
 use rs_odbc_derive::{Ident, odbc_type};
+#[cfg(feature = "std")]
+use std::{boxed::Box, cell::RefCell, collections::BTreeMap, io, vec, vec::Vec};
 
 pub(crate) mod private {
     use super::*;
 
+    pub trait StmtState {}
+
+    impl StmtState for S1 {}
+    impl StmtState for S2 {}
+    impl<P: ClosedState> StmtState for S4<P> {}
+    impl<P: ClosedState> StmtState for S6<P> {}
+    impl<P: ClosedState> StmtState for S8<P> {}
+
+    #[cfg(feature = "std")]
+    pub trait TextChar: OdbcChar + Copy + Default {
+        const C_TYPE: SQLSMALLINT;
+
+        /// Appends the UTF-8 encoding of the characters to the buffer except for the incomplete
+        /// character at the end and returns the number of decoded characters
+        fn decode(chars: &[Self], decoded: &mut Vec<u8>) -> io::Result<usize>;
+    }
+
+    #[cfg(feature = "std")]
+    impl TextChar for SQLCHAR {
+        const C_TYPE: SQLSMALLINT = SQL_C_CHAR::IDENTIFIER;
+
+        fn decode(chars: &[Self], decoded: &mut Vec<u8>) -> io::Result<usize> {
+            let len = match core::str::from_utf8(chars) {
+                Ok(_) => chars.len(),
+                Err(error) if error.error_len().is_none() => error.valid_up_to(),
+                Err(_) => return Err(invalid_data("Column data is not valid UTF-8")),
+            };
+
+            decoded.extend_from_slice(&chars[..len]);
+            Ok(len)
+        }
+    }
+
+    #[cfg(all(feature = "std", not(feature = "iodbc")))]
+    impl TextChar for SQLWCHAR {
+        const C_TYPE: SQLSMALLINT = SQL_C_WCHAR::IDENTIFIER;
+
+        fn decode(chars: &[Self], decoded: &mut Vec<u8>) -> io::Result<usize> {
+            // High surrogate at the end is paired with the first character of the next part
+            let len = match chars.last() {
+                Some(0xD800..=0xDBFF) => chars.len() - 1,
+                _ => chars.len(),
+            };
+
+            for c in char::decode_utf16(chars[..len].iter().copied()) {
+                let c = c.map_err(|_| invalid_data("Column data is not valid UTF-16"))?;
+                decoded.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+            }
+
+            Ok(len)
+        }
+    }
+
+    #[cfg(all(feature = "std", feature = "iodbc"))]
+    impl TextChar for SQLWCHAR {
+        const C_TYPE: SQLSMALLINT = SQL_C_WCHAR::IDENTIFIER;
+
+        fn decode(chars: &[Self], decoded: &mut Vec<u8>) -> io::Result<usize> {
+            for &c in chars {
+                let c = char::from_u32(c)
+                    .ok_or_else(|| invalid_data("Column data is not valid UCS-4"))?;
+                decoded.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+            }
+
+            Ok(chars.len())
+        }
+    }
+
     #[expect(non_snake_case)]
     pub trait BaseStmtAttr<'desc, 'buf, S: Statement<'desc, 'buf, V>, A: Ident, V: OdbcVersion>:
         Attr<A> + AttrLen<Self::DefinedBy, SQLINTEGER>
@@ -28,6 +107,9 @@ pub(crate) mod private {
         {
         }
 
+        /// Verifies that the attribute can be read from the statement
+        fn check_read(&self, _: &S) {}
+
         fn readA<'stmt>(
             &mut self,
             StatementHandle: &'stmt S,
@@ -139,82 +221,935 @@ pub trait StmtAttr<'desc, 'buf, S: Statement<'desc, 'buf, V>, A: Ident, V: OdbcV
 {
 }
 
+/// Statement states are modeled after the statement transitions table in the ODBC specification.
+/// States which can only be distinguished at runtime (e.g. whether the executed statement
+/// created a result set) are merged into a single state.
+///
+/// # Documentation
+/// https://docs.microsoft.com/en-us/sql/odbc/reference/appendixes/statement-transitions
+pub trait StmtState: private::StmtState {}
+
+/// Characters of the text which can be read from a column with [`RowReader::text`]
+#[cfg(feature = "std")]
+pub trait TextChar: private::TextChar {}
+#[cfg(feature = "std")]
+impl TextChar for SQLCHAR {}
+#[cfg(feature = "std")]
+impl TextChar for SQLWCHAR {}
+
+/// States into which the statement transitions when its cursor is closed
+pub trait ClosedState: StmtState {}
+
+/// States in which the statement can have an open cursor
+pub trait CursorState: StmtState {
+    /// State into which the statement transitions when the cursor is closed
+    type Closed: ClosedState;
+}
+
+/// Statement handles on which SQLPrepare, SQLExecDirect or catalog functions can be called
+pub trait Prepare {}
+/// Statement handles on which SQLExecute can be called
+pub trait Execute {}
+
+/// Allocated
+#[derive(Debug)]
+pub enum S1 {}
+
+/// Prepared (S2 and S3)
+#[derive(Debug)]
+pub enum S2 {}
+
+/// Executed (S4 and S5)
+///
+/// `P` is the state into which the statement transitions when the cursor is closed.
+/// It is `S1` for statements executed with SQLExecDirect and `S2` for prepared statements
+#[derive(Debug)]
+pub struct S4<P: ClosedState>(PhantomData<P>);
+
+/// Cursor positioned (S6)
+#[derive(Debug)]
+pub struct S6<P: ClosedState>(PhantomData<P>);
+
+/// Need data (S8, S9 and S10)
+#[derive(Debug)]
+pub struct S8<P: ClosedState>(PhantomData<P>);
+
+impl StmtState for S1 {}
+impl StmtState for S2 {}
+impl<P: ClosedState> StmtState for S4<P> {}
+impl<P: ClosedState> StmtState for S6<P> {}
+impl<P: ClosedState> StmtState for S8<P> {}
+
+impl ClosedState for S1 {}
+impl ClosedState for S2 {}
+
+impl<P: ClosedState> CursorState for S4<P> {
+    type Closed = P;
+}
+impl<P: ClosedState> CursorState for S6<P> {
+    type Closed = P;
+}
+
+impl<V: OdbcVersion> Prepare for SQLHSTMT<'_, '_, '_, S1, V> {}
+impl<V: OdbcVersion> Prepare for SQLHSTMT<'_, '_, '_, S2, V> {}
+impl<P: ClosedState, V: OdbcVersion> Prepare for SQLHSTMT<'_, '_, '_, S4<P>, V> {}
+impl<V: OdbcVersion> Execute for SQLHSTMT<'_, '_, '_, S2, V> {}
+impl<V: OdbcVersion> Execute for SQLHSTMT<'_, '_, '_, S4<S2>, V> {}
+
+/// Buffers bound to a column with SQLBindCol or to a parameter with SQLBindParameter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Binding {
+    data: Option<(SQLPOINTER, SQLLEN)>,
+    strlen_or_ind: Option<*mut StrLenOrInd>,
+}
+
+impl Binding {
+    /// Address and length of the bound data buffer
+    pub fn data_ptr(&self) -> Option<(SQLPOINTER, SQLLEN)> {
+        self.data
+    }
+
+    /// Address of the bound length/indicator buffer
+    pub fn strlen_or_ind_ptr(&self) -> Option<*mut StrLenOrInd> {
+        self.strlen_or_ind
+    }
+}
+
+/// Offset which the driver adds to the address of every buffer bound with row-wise binding.
+/// It is updated by [`SQLHSTMT::set_rows`] so that bound columns can be pointed to another
+/// array of rows without binding them again
+#[derive(Debug, Default)]
+pub struct BindOffset(SQLULEN);
+
+impl BindOffset {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the offset in bytes
+    pub fn get(&self) -> SQLULEN {
+        self.0
+    }
+
+    pub(crate) fn as_mut_ptr(&mut self) -> *mut SQLULEN {
+        &mut self.0
+    }
+}
+
+/// Per-record table of buffers bound to an implicitly allocated application descriptor
+#[derive(Debug)]
+pub(crate) struct Bindings {
+    #[cfg(feature = "std")]
+    bound: RefCell<BTreeMap<SQLUSMALLINT, Binding>>,
+    /// Buffers owned by the statement which are released together with the record
+    #[cfg(feature = "std")]
+    owned: RefCell<BTreeMap<SQLUSMALLINT, OwnedBinding>>,
+    /// Number of rows the driver reads or writes on every call, i.e. SQL_DESC_ARRAY_SIZE
+    array_size: Cell<SQLULEN>,
+    /// Smallest number of rows of any buffer bound since the records were last released
+    rows: Cell<SQLULEN>,
+    /// Whether buffers for the number of processed rows or the status or operation of every
+    /// row are bound
+    status_bound: Cell<bool>,
+    /// Address and size of the first row of the array bound with row-wise binding
+    row: Cell<Option<(usize, usize)>>,
+    /// Type of the rows bound with row-wise binding
+    row_type: Cell<Option<TypeId>>,
+    /// Offset added by the driver to the address of every buffer bound with row-wise binding
+    bind_offset: Cell<Option<*mut SQLULEN>>,
+    /// Whether an explicitly allocated descriptor is associated with the statement in place of
+    /// the implicitly allocated one which keeps pointing to the buffers owned by the statement
+    explicit_desc: Cell<bool>,
+    /// Parameter whose data was being sent at statement execution time when SQLPutData
+    /// returned SQL_STILL_EXECUTING
+    #[cfg(feature = "std")]
+    put_data: RefCell<Option<PutDataState>>,
+}
+
+impl Default for Bindings {
+    fn default() -> Self {
+        Self {
+            #[cfg(feature = "std")]
+            bound: RefCell::default(),
+            #[cfg(feature = "std")]
+            owned: RefCell::default(),
+            array_size: Cell::new(1),
+            rows: Cell::new(SQLULEN::MAX),
+            status_bound: Cell::new(false),
+            row: Cell::new(None),
+            row_type: Cell::new(None),
+            bind_offset: Cell::new(None),
+            explicit_desc: Cell::new(false),
+            #[cfg(feature = "std")]
+            put_data: RefCell::default(),
+        }
+    }
+}
+
+/// Progress of sending the data of a parameter in parts with SQLPutData
+#[cfg(feature = "std")]
+#[derive(Debug)]
+#[allow(non_snake_case)]
+pub(crate) struct PutDataState {
+    /// Value pointer returned by SQLParamData which identifies the parameter
+    pub(crate) ValuePtr: SQLPOINTER,
+    pub(crate) buf: Vec<u8>,
+    /// Length of the chunk in the buffer which wasn't accepted by SQLPutData yet
+    pub(crate) pending: Option<usize>,
+    /// Length of the data which is yet to be read from the source, if given
+    pub(crate) remaining: Option<usize>,
+    /// Whether any data of the parameter was sent
+    pub(crate) sent: bool,
+}
+
+/// Buffer owned by the statement together with its length/indicator buffer
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub(crate) struct OwnedBinding {
+    /// Type erased `Box<UnsafeCell<B>>`
+    buf: Box<dyn Any + Send>,
+    strlen_or_ind: Box<UnsafeCell<StrLenOrInd>>,
+}
+
+#[cfg(feature = "std")]
+impl OwnedBinding {
+    pub(crate) fn new<B: ?Sized + Send + 'static>(
+        buf: Box<UnsafeCell<B>>,
+        strlen_or_ind: Box<UnsafeCell<StrLenOrInd>>,
+    ) -> Self {
+        Self {
+            buf: Box::new(buf),
+            strlen_or_ind,
+        }
+    }
+}
+
+#[allow(non_snake_case)]
+impl Bindings {
+    /// Replaces buffers bound to the record. Record is released if neither buffer is bound
+    pub(crate) fn bind(
+        &self,
+        RecNumber: SQLUSMALLINT,
+        DataPtr: Option<(SQLPOINTER, SQLLEN)>,
+        StrLen_or_IndPtr: Option<*mut StrLenOrInd>,
+        rows: SQLULEN,
+    ) {
+        if DataPtr.is_some() || StrLen_or_IndPtr.is_some() {
+            self.rows.set(self.rows.get().min(rows));
+        }
+
+        #[cfg(feature = "std")]
+        {
+            // Previously owned buffer is no longer used by the driver
+            if !self.explicit_desc.get() {
+                self.owned.borrow_mut().remove(&RecNumber);
+            }
+
+            if DataPtr.is_none() && StrLen_or_IndPtr.is_none() {
+                self.bound.borrow_mut().remove(&RecNumber);
+            } else {
+                self.bound.borrow_mut().insert(
+                    RecNumber,
+                    Binding {
+                        data: DataPtr,
+                        strlen_or_ind: StrLen_or_IndPtr,
+                    },
+                );
+            }
+        }
+    }
+
+    /// Takes ownership of the buffers which were bound to the record
+    #[cfg(feature = "std")]
+    pub(crate) fn own(&self, RecNumber: SQLUSMALLINT, owned: OwnedBinding) {
+        self.owned.borrow_mut().insert(RecNumber, owned);
+    }
+
+    /// Whether any of the records is bound to buffers owned by the statement
+    pub(crate) fn owns_bufs(&self) -> bool {
+        #[cfg(feature = "std")]
+        return !self.owned.borrow().is_empty();
+        #[cfg(not(feature = "std"))]
+        false
+    }
+
+    pub(crate) fn is_unbound(&self) -> bool {
+        self.rows.get() == SQLULEN::MAX
+    }
+
+    /// Releases all records as done by SQLFreeStmt with SQL_UNBIND or SQL_RESET_PARAMS
+    pub(crate) fn unbind(&self) {
+        self.rows.set(SQLULEN::MAX);
+
+        #[cfg(feature = "std")]
+        {
+            self.bound.borrow_mut().clear();
+
+            // Owned buffers remain bound to the implicitly allocated descriptor
+            if !self.explicit_desc.get() {
+                self.owned.borrow_mut().clear();
+            }
+        }
+    }
+
+    /// Stores the progress of sending parameter data which is resumed when polled again
+    #[cfg(feature = "std")]
+    pub(crate) fn set_put_data(&self, state: Option<PutDataState>) {
+        *self.put_data.borrow_mut() = state;
+    }
+
+    #[cfg(feature = "std")]
+    pub(crate) fn take_put_data(&self) -> Option<PutDataState> {
+        self.put_data.borrow_mut().take()
+    }
+
+    pub(crate) fn explicit_desc(&self) -> bool {
+        self.explicit_desc.get()
+    }
+
+    pub(crate) fn set_explicit_desc(&self, explicit_desc: bool) {
+        self.explicit_desc.set(explicit_desc);
+    }
+
+    pub(crate) fn array_size(&self) -> SQLULEN {
+        self.array_size.get()
+    }
+
+    pub(crate) fn set_array_size(&self, array_size: SQLULEN) {
+        self.array_size.set(array_size);
+    }
+
+    pub(crate) fn status_bound(&self) -> bool {
+        self.status_bound.get()
+    }
+
+    pub(crate) fn set_status_bound(&self, status_bound: bool) {
+        self.status_bound.set(status_bound);
+    }
+
+    /// Whether buffers are bound as arrays so that buffers of a single row can't be bound
+    pub(crate) fn binds_arrays(&self) -> bool {
+        self.array_size.get() != 1 || self.bind_offset.get().is_some()
+    }
+
+    pub(crate) fn row(&self) -> Option<(usize, usize)> {
+        self.row.get()
+    }
+
+    pub(crate) fn bind_offset(&self) -> Option<*mut SQLULEN> {
+        self.bind_offset.get()
+    }
+
+    /// Records the array of rows bound with row-wise binding
+    pub(crate) fn bind_rows(
+        &self,
+        row: Option<(usize, usize)>,
+        rows: SQLULEN,
+        bind_offset: Option<*mut SQLULEN>,
+    ) {
+        self.rows.set(self.rows.get().min(rows));
+        if row.is_none() {
+            self.row_type.set(None);
+        }
+        self.row.set(row);
+        self.bind_offset.set(bind_offset);
+    }
+
+    pub(crate) fn set_row_type(&self, row_type: TypeId) {
+        self.row_type.set(Some(row_type));
+    }
+
+    /// Panics if rows of type `R` are not of the type of the rows bound with row-wise binding
+    pub(crate) fn check_row_type<R: 'static>(&self) {
+        assert_eq!(
+            Some(TypeId::of::<R>()),
+            self.row_type.get(),
+            "Rows differ in type from bound rows"
+        );
+    }
+
+    /// Panics if the buffer is not within the first row of the array bound with row-wise binding
+    pub(crate) fn check_row_field(&self, ptr: usize, len: usize) {
+        let (row, row_len) = self.row.get().expect("Rows are not bound");
+
+        assert!(
+            row <= ptr && ptr + len <= row + row_len,
+            "Buffer at {ptr:#x} is not within the first row at {row:#x}"
+        );
+    }
+
+    /// Panics if any bound buffer has fewer rows than the given array size
+    pub(crate) fn check_array_size(&self, array_size: SQLULEN) {
+        assert!(
+            array_size <= self.rows.get(),
+            "Array size {} is larger than bound buffers of {} rows",
+            array_size,
+            self.rows.get()
+        );
+    }
+
+    pub(crate) fn get(&self, RecNumber: SQLUSMALLINT) -> Option<Binding> {
+        #[cfg(feature = "std")]
+        return self.bound.borrow().get(&RecNumber).copied();
+        #[cfg(not(feature = "std"))]
+        None
+    }
+
+    /// Returns the largest number of a bound record
+    #[cfg(feature = "std")]
+    pub(crate) fn last_bound(&self) -> Option<SQLUSMALLINT> {
+        self.bound.borrow().keys().next_back().copied()
+    }
+
+    /// Returns the number of the record whose data buffer is at the given address
+    #[cfg(feature = "std")]
+    pub(crate) fn find_data(&self, DataPtr: SQLPOINTER) -> Option<SQLUSMALLINT> {
+        self.bound
+            .borrow()
+            .iter()
+            .find(|(_, binding)| binding.data.is_some_and(|(ptr, _)| ptr == DataPtr))
+            .map(|(RecNumber, _)| *RecNumber)
+    }
+
+    #[cfg(feature = "std")]
+    pub(crate) fn owned_bufs(&mut self) -> OwnedCols<'_> {
+        OwnedCols(self.owned.get_mut())
+    }
+
+    #[cfg(feature = "std")]
+    pub(crate) fn owned_buf_mut<B: ?Sized + 'static>(
+        &mut self,
+        RecNumber: SQLUSMALLINT,
+    ) -> Option<(&mut B, &mut StrLenOrInd)> {
+        let owned = self.owned.get_mut().get_mut(&RecNumber)?;
+        let buf = owned.buf.downcast_mut::<Box<UnsafeCell<B>>>()?;
+
+        Some((buf.get_mut(), owned.strlen_or_ind.get_mut()))
+    }
+}
+
+/// View of the buffers owned by the statement which are bound to the columns of the result set.
+/// While the view exists no function which could write to the buffers can be called on the
+/// statement
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy)]
+pub struct OwnedCols<'stmt>(&'stmt BTreeMap<SQLUSMALLINT, OwnedBinding>);
+
+#[cfg(feature = "std")]
+#[allow(non_snake_case)]
+impl<'stmt> OwnedCols<'stmt> {
+    /// Returns the value of the column and its length/indicator or `None` if the column
+    /// isn't bound to an owned buffer of type `B`
+    pub fn get<B: ?Sized + 'static>(
+        &self,
+        ColumnNumber: SQLUSMALLINT,
+    ) -> Option<(&'stmt B, StrLenOrInd)> {
+        let owned = self.0.get(&ColumnNumber)?;
+        let buf = owned.buf.downcast_ref::<Box<UnsafeCell<B>>>()?;
+
+        // SAFETY: Driver doesn't write to the buffers while the statement is borrowed
+        unsafe { Some((&*buf.get(), *owned.strlen_or_ind.get())) }
+    }
+}
+
+/// Number of characters requested from the driver with every call to SQLGetData made by
+/// [`TextReader`]
+#[cfg(feature = "std")]
+const TEXT_CHUNK_LEN: usize = 4096;
+
+/// Reads long data of the columns in the current row of the result set with SQLGetData.
+/// Columns are read in the order permitted by the `SQL_GETDATA_EXTENSIONS` of the driver
+/// which are returned by SQLGetInfo
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct RowReader<'stmt> {
+    handle: SQLHANDLE,
+    bound_cols: &'stmt Bindings,
+    getdata_extensions: GetdataExtensions,
+    last_read: Option<SQLUSMALLINT>,
+}
+
+#[cfg(feature = "std")]
+#[allow(non_snake_case)]
+impl<'stmt> RowReader<'stmt> {
+    pub(crate) fn new(
+        handle: SQLHANDLE,
+        bound_cols: &'stmt Bindings,
+        getdata_extensions: GetdataExtensions,
+    ) -> Self {
+        Self {
+            handle,
+            bound_cols,
+            getdata_extensions,
+            last_read: None,
+        }
+    }
+
+    /// Returns a reader of the column data as retrieved with SQL_C_BINARY
+    ///
+    /// # Errors
+    /// Fails with `ErrorKind::InvalidInput` if the driver doesn't support reading the column
+    /// at this point, i.e. if `SQL_GETDATA_EXTENSIONS` don't allow it
+    pub fn binary(&mut self, ColumnNumber: SQLUSMALLINT) -> io::Result<BinaryReader<'_>> {
+        self.check_col(ColumnNumber)?;
+        Ok(BinaryReader::new(self.handle, ColumnNumber))
+    }
+
+    /// Returns a reader of the column data as retrieved with SQL_C_CHAR or SQL_C_WCHAR and
+    /// decoded into UTF-8. Data of SQL_C_CHAR is expected to be UTF-8 encoded
+    ///
+    /// # Errors
+    /// Fails with `ErrorKind::InvalidInput` if the driver doesn't support reading the column
+    /// at this point, i.e. if `SQL_GETDATA_EXTENSIONS` don't allow it
+    pub fn text<CH: TextChar>(
+        &mut self,
+        ColumnNumber: SQLUSMALLINT,
+    ) -> io::Result<TextReader<'_, CH>> {
+        self.check_col(ColumnNumber)?;
+        Ok(TextReader::new(self.handle, ColumnNumber))
+    }
+
+    fn check_col(&mut self, ColumnNumber: SQLUSMALLINT) -> io::Result<()> {
+        let extensions = self.getdata_extensions;
+
+        if self.bound_cols.array_size() > 1 && extensions & SQL_GD_BLOCK == 0 {
+            return Err(invalid_input(
+                "Driver doesn't support SQLGetData with block cursors",
+            ));
+        }
+        if self.bound_cols.get(ColumnNumber).is_some() {
+            if extensions & SQL_GD_BOUND == 0 {
+                return Err(invalid_input(
+                    "Driver doesn't support SQLGetData for bound columns",
+                ));
+            }
+        } else if self
+            .bound_cols
+            .last_bound()
+            .is_some_and(|last_bound| ColumnNumber < last_bound)
+            && extensions & SQL_GD_ANY_COLUMN == 0
+        {
+            return Err(invalid_input(
+                "Driver doesn't support SQLGetData for columns before the last bound column",
+            ));
+        }
+        if self
+            .last_read
+            .is_some_and(|last_read| ColumnNumber < last_read)
+            && extensions & SQL_GD_ANY_ORDER == 0
+        {
+            return Err(invalid_input(
+                "Driver doesn't support SQLGetData in decreasing column order",
+            ));
+        }
+
+        self.last_read = Some(ColumnNumber);
+        Ok(())
+    }
+}
+
+/// Streamed output parameter whose data is available after the statement was executed
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct OutputParam<'stmt> {
+    handle: SQLHANDLE,
+    parameter_number: SQLUSMALLINT,
+    parent: PhantomData<&'stmt mut ()>,
+}
+
+#[cfg(feature = "std")]
+impl<'stmt> OutputParam<'stmt> {
+    pub(crate) fn new(handle: SQLHANDLE, parameter_number: SQLUSMALLINT) -> Self {
+        Self {
+            handle,
+            parameter_number,
+            parent: PhantomData,
+        }
+    }
+
+    /// Returns the number of the parameter whose data is available
+    pub fn parameter_number(&self) -> SQLUSMALLINT {
+        self.parameter_number
+    }
+
+    /// Returns a reader of the parameter data as retrieved with SQL_C_BINARY
+    pub fn binary(self) -> BinaryReader<'stmt> {
+        BinaryReader::new(self.handle, self.parameter_number)
+    }
+
+    /// Returns a reader of the parameter data as retrieved with SQL_C_CHAR or SQL_C_WCHAR and
+    /// decoded into UTF-8. Data of SQL_C_CHAR is expected to be UTF-8 encoded
+    pub fn text<CH: TextChar>(self) -> TextReader<'stmt, CH> {
+        TextReader::new(self.handle, self.parameter_number)
+    }
+}
+
+/// Error returned when SQLParamData identifies a parameter which isn't bound as a streamed
+/// output parameter of the statement
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnknownOutputParam(pub(crate) ());
+
+#[cfg(feature = "std")]
+impl core::fmt::Display for UnknownOutputParam {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "unknown streamed output parameter")
+    }
+}
+#[cfg(feature = "std")]
+impl core::error::Error for UnknownOutputParam {}
+
+/// Reader of the column data retrieved in parts with SQLGetData as SQL_C_BINARY
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct BinaryReader<'rd> {
+    handle: SQLHANDLE,
+    col: SQLUSMALLINT,
+    is_null: bool,
+    done: bool,
+    parent: PhantomData<&'rd mut ()>,
+}
+
+#[cfg(feature = "std")]
+impl BinaryReader<'_> {
+    fn new(handle: SQLHANDLE, col: SQLUSMALLINT) -> Self {
+        Self {
+            handle,
+            col,
+            is_null: false,
+            done: false,
+            parent: PhantomData,
+        }
+    }
+
+    /// Whether the column is NULL. Known only after the first read
+    pub fn is_null(&self) -> bool {
+        self.is_null
+    }
+}
+
+#[cfg(feature = "std")]
+impl io::Read for BinaryReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.done || buf.is_empty() {
+            return Ok(0);
+        }
+
+        let part = get_data_part(
+            self.handle,
+            self.col,
+            SQL_C_BINARY::IDENTIFIER,
+            buf.as_mut_ptr().cast(),
+            buf.len(),
+            0,
+        )?;
+
+        Ok(match part {
+            DataPart::Null => {
+                self.is_null = true;
+                self.done = true;
+                0
+            }
+            DataPart::NoData => {
+                self.done = true;
+                0
+            }
+            DataPart::Data(len, last) => {
+                self.done = last;
+                len
+            }
+        })
+    }
+}
+
+/// Reader of the column data retrieved in parts with SQLGetData as SQL_C_CHAR or SQL_C_WCHAR
+/// and decoded into UTF-8
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct TextReader<'rd, CH> {
+    handle: SQLHANDLE,
+    col: SQLUSMALLINT,
+    /// Characters of the last part retrieved from the driver
+    buf: Vec<CH>,
+    /// Number of characters at the start of `buf` which couldn't be decoded yet
+    pending: usize,
+    decoded: Vec<u8>,
+    /// Position of the first byte of `decoded` which wasn't read yet
+    pos: usize,
+    is_null: bool,
+    done: bool,
+    parent: PhantomData<&'rd mut ()>,
+}
+
+#[cfg(feature = "std")]
+impl<CH: TextChar> TextReader<'_, CH> {
+    fn new(handle: SQLHANDLE, col: SQLUSMALLINT) -> Self {
+        Self {
+            handle,
+            col,
+            buf: vec![CH::default(); TEXT_CHUNK_LEN],
+            pending: 0,
+            decoded: Vec::new(),
+            pos: 0,
+            is_null: false,
+            done: false,
+            parent: PhantomData,
+        }
+    }
+
+    /// Whether the column is NULL. Known only after the first read
+    pub fn is_null(&self) -> bool {
+        self.is_null
+    }
+}
+
+#[cfg(feature = "std")]
+impl<CH: TextChar> io::Read for TextReader<'_, CH> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.pos == self.decoded.len() {
+            if self.done {
+                if self.pending > 0 {
+                    return Err(invalid_data(
+                        "Column data ends with an incomplete character",
+                    ));
+                }
+
+                return Ok(0);
+            }
+
+            let free = &mut self.buf[self.pending..];
+            let part = get_data_part(
+                self.handle,
+                self.col,
+                <CH as private::TextChar>::C_TYPE,
+                free.as_mut_ptr().cast(),
+                size_of_val(free),
+                size_of::<CH>(),
+            )?;
+
+            let len = match part {
+                DataPart::Null => {
+                    self.is_null = true;
+                    self.done = true;
+                    continue;
+                }
+                DataPart::NoData => {
+                    self.done = true;
+                    continue;
+                }
+                DataPart::Data(len, last) => {
+                    self.done = last;
+                    self.pending + len / size_of::<CH>()
+                }
+            };
+
+            self.decoded.clear();
+            self.pos = 0;
+            let decoded_len =
+                <CH as private::TextChar>::decode(&self.buf[..len], &mut self.decoded)?;
+            self.buf.copy_within(decoded_len..len, 0);
+            self.pending = len - decoded_len;
+        }
+
+        let len = buf.len().min(self.decoded.len() - self.pos);
+        buf[..len].copy_from_slice(&self.decoded[self.pos..self.pos + len]);
+        self.pos += len;
+
+        Ok(len)
+    }
+}
+
+/// Part of the column data retrieved with a single call to SQLGetData
+#[cfg(feature = "std")]
+enum DataPart {
+    Null,
+    /// All data of the column has already been retrieved
+    NoData,
+    /// Length of the data in the buffer in bytes and whether it's the last part
+    Data(usize, bool),
+}
+
+/// Retrieves the next part of the column data into the buffer. Parts of character data are
+/// null terminated so the last `null_len` bytes of the buffer are not filled with data
+#[cfg(feature = "std")]
+#[expect(non_snake_case)]
+fn get_data_part(
+    StatementHandle: SQLHANDLE,
+    Col_or_Param_Num: SQLUSMALLINT,
+    TargetType: SQLSMALLINT,
+    TargetValuePtr: SQLPOINTER,
+    BufferLength: usize,
+    null_len: usize,
+) -> io::Result<DataPart> {
+    let mut StrLen_or_Ind = SQL_NO_TOTAL.0;
+
+    let sql_return = unsafe {
+        ffi::SQLGetData(
+            StatementHandle,
+            Col_or_Param_Num,
+            TargetType,
+            TargetValuePtr,
+            BufferLength as SQLLEN,
+            &mut StrLen_or_Ind,
+        )
+    };
+
+    if sql_return == SQL_NO_DATA {
+        return Ok(DataPart::NoData);
+    }
+    if !SQL_SUCCEEDED(sql_return) {
+        return Err(io::Error::other(std::format!(
+            "SQLGetData of column {Col_or_Param_Num} failed with {sql_return:?}"
+        )));
+    }
+    if StrLen_or_Ind == SQL_NULL_DATA.0 {
+        return Ok(DataPart::Null);
+    }
+
+    let capacity = BufferLength - null_len;
+    Ok(match usize::try_from(StrLen_or_Ind) {
+        Ok(len) if len <= capacity => DataPart::Data(len, true),
+        // Data was truncated and its remaining length is either larger or unknown
+        _ => DataPart::Data(capacity, false),
+    })
+}
+
+#[cfg(feature = "std")]
+fn invalid_input(msg: &'static str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, msg)
+}
+
+#[cfg(feature = "std")]
+fn invalid_data(msg: &'static str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+#[allow(non_snake_case)]
+impl<V: OdbcVersion> UnsafeSQLHSTMT<'_, '_, '_, V> {
+    /// Returns buffers bound to the column with SQLBindCol or `None` if the column is unbound.
+    /// Bindings are only tracked with the `std` feature enabled
+    pub fn bound_col(&self, ColumnNumber: SQLUSMALLINT) -> Option<Binding> {
+        self.bound_cols.get(ColumnNumber)
+    }
+
+    /// Returns buffers bound to the parameter with SQLBindParameter or `None` if the parameter
+    /// is unbound. Bindings are only tracked with the `std` feature enabled
+    pub fn bound_param(&self, ParameterNumber: SQLUSMALLINT) -> Option<Binding> {
+        self.bound_params.get(ParameterNumber)
+    }
+}
+
+#[allow(non_snake_case)]
+impl<S: StmtState, V: OdbcVersion> SQLHSTMT<'_, '_, '_, S, V> {
+    /// Returns buffers bound to the column with SQLBindCol or `None` if the column is unbound.
+    /// Bindings are only tracked with the `std` feature enabled
+    pub fn bound_col(&self, ColumnNumber: SQLUSMALLINT) -> Option<Binding> {
+        self.0.bound_col(ColumnNumber)
+    }
+
+    /// Returns buffers bound to the parameter with SQLBindParameter or `None` if the parameter
+    /// is unbound. Bindings are only tracked with the `std` feature enabled
+    pub fn bound_param(&self, ParameterNumber: SQLUSMALLINT) -> Option<Binding> {
+        self.0.bound_param(ParameterNumber)
+    }
+
+    /// Returns a view of the buffers owned by the statement which are bound to columns with
+    /// [`SQLHSTMT::bind_col_owned`]. Statement is mutably borrowed so that the next rowset
+    /// can't be fetched into the buffers while they are being read
+    #[cfg(feature = "std")]
+    pub fn owned_cols(&mut self) -> OwnedCols<'_> {
+        self.0.bound_cols.owned_bufs()
+    }
+
+    /// Returns the buffer owned by the statement which is bound to the parameter with
+    /// [`SQLHSTMT::bind_param_owned`] together with its length/indicator or `None` if the
+    /// parameter isn't bound to an owned buffer of type `B`
+    #[cfg(feature = "std")]
+    pub fn owned_param_mut<B: ?Sized + 'static>(
+        &mut self,
+        ParameterNumber: SQLUSMALLINT,
+    ) -> Option<(&mut B, &mut StrLenOrInd)> {
+        self.0.bound_params.owned_buf_mut(ParameterNumber)
+    }
+}
+
 // Implement StmtAttr for all versions of SQLHSTMT statement attributes
-impl<'conn, 'desc, 'buf, A: Ident, T: Scalar>
-    StmtAttr<'desc, 'buf, SQLHSTMT<'conn, 'desc, 'buf, SQL_OV_ODBC3_80>, A, SQL_OV_ODBC3_80> for T
+impl<'conn, 'desc, 'buf, ST: StmtState, A: Ident, T: Scalar>
+    StmtAttr<'desc, 'buf, SQLHSTMT<'conn, 'desc, 'buf, ST, SQL_OV_ODBC3_80>, A, SQL_OV_ODBC3_80>
+    for T
 where
     T: StmtAttr<
             'desc,
             'buf,
-            SQLHSTMT<'conn, 'desc, 'buf, <SQL_OV_ODBC3_80 as OdbcVersion>::PrevVersion>,
+            SQLHSTMT<'conn, 'desc, 'buf, ST, <SQL_OV_ODBC3_80 as OdbcVersion>::PrevVersion>,
             A,
             <SQL_OV_ODBC3_80 as OdbcVersion>::PrevVersion,
         >,
 {
 }
 
-impl<'conn, 'desc, 'buf, A: Ident, T: Scalar>
-    StmtAttr<'desc, 'buf, SQLHSTMT<'conn, 'desc, 'buf, SQL_OV_ODBC4>, A, SQL_OV_ODBC4> for T
+impl<'conn, 'desc, 'buf, ST: StmtState, A: Ident, T: Scalar>
+    StmtAttr<'desc, 'buf, SQLHSTMT<'conn, 'desc, 'buf, ST, SQL_OV_ODBC4>, A, SQL_OV_ODBC4> for T
 where
     T: StmtAttr<
             'desc,
             'buf,
-            SQLHSTMT<'conn, 'desc, 'buf, <SQL_OV_ODBC4 as OdbcVersion>::PrevVersion>,
+            SQLHSTMT<'conn, 'desc, 'buf, ST, <SQL_OV_ODBC4 as OdbcVersion>::PrevVersion>,
             A,
             <SQL_OV_ODBC4 as OdbcVersion>::PrevVersion,
         >,
 {
 }
 
-impl<'conn, 'desc, 'buf, A: Ident, T: Scalar>
-    StmtAttr<'desc, 'buf, SQLHSTMT<'conn, 'desc, 'buf, SQL_OV_ODBC3_80>, A, SQL_OV_ODBC3_80>
+impl<'conn, 'desc, 'buf, ST: StmtState, A: Ident, T: Scalar>
+    StmtAttr<'desc, 'buf, SQLHSTMT<'conn, 'desc, 'buf, ST, SQL_OV_ODBC3_80>, A, SQL_OV_ODBC3_80>
     for [T]
 where
     [T]: StmtAttr<
             'desc,
             'buf,
-            SQLHSTMT<'conn, 'desc, 'buf, <SQL_OV_ODBC3_80 as OdbcVersion>::PrevVersion>,
+            SQLHSTMT<'conn, 'desc, 'buf, ST, <SQL_OV_ODBC3_80 as OdbcVersion>::PrevVersion>,
             A,
             <SQL_OV_ODBC3_80 as OdbcVersion>::PrevVersion,
         >,
 {
 }
 
-impl<'conn, 'desc, 'buf, A: Ident, T: Scalar>
-    StmtAttr<'desc, 'buf, SQLHSTMT<'conn, 'desc, 'buf, SQL_OV_ODBC4>, A, SQL_OV_ODBC4> for [T]
+impl<'conn, 'desc, 'buf, ST: StmtState, A: Ident, T: Scalar>
+    StmtAttr<'desc, 'buf, SQLHSTMT<'conn, 'desc, 'buf, ST, SQL_OV_ODBC4>, A, SQL_OV_ODBC4> for [T]
 where
     [T]: StmtAttr<
             'desc,
             'buf,
-            SQLHSTMT<'conn, 'desc, 'buf, <SQL_OV_ODBC4 as OdbcVersion>::PrevVersion>,
+            SQLHSTMT<'conn, 'desc, 'buf, ST, <SQL_OV_ODBC4 as OdbcVersion>::PrevVersion>,
             A,
             <SQL_OV_ODBC4 as OdbcVersion>::PrevVersion,
         >,
 {
 }
 
-impl<'conn, 'desc, 'buf, A: Ident, CH: OdbcChar>
-    StmtAttr<'desc, 'buf, SQLHSTMT<'conn, 'desc, 'buf, SQL_OV_ODBC3_80>, A, SQL_OV_ODBC3_80>
+impl<'conn, 'desc, 'buf, ST: StmtState, A: Ident, CH: OdbcChar>
+    StmtAttr<'desc, 'buf, SQLHSTMT<'conn, 'desc, 'buf, ST, SQL_OV_ODBC3_80>, A, SQL_OV_ODBC3_80>
     for OdbcStr<CH>
 where
     OdbcStr<CH>: StmtAttr<
             'desc,
             'buf,
-            SQLHSTMT<'conn, 'desc, 'buf, <SQL_OV_ODBC3_80 as OdbcVersion>::PrevVersion>,
+            SQLHSTMT<'conn, 'desc, 'buf, ST, <SQL_OV_ODBC3_80 as OdbcVersion>::PrevVersion>,
             A,
             <SQL_OV_ODBC3_80 as OdbcVersion>::PrevVersion,
         >,
 {
 }
 
-impl<'conn, 'desc, 'buf, A: Ident, CH: OdbcChar>
-    StmtAttr<'desc, 'buf, SQLHSTMT<'conn, 'desc, 'buf, SQL_OV_ODBC4>, A, SQL_OV_ODBC4>
+impl<'conn, 'desc, 'buf, ST: StmtState, A: Ident, CH: OdbcChar>
+    StmtAttr<'desc, 'buf, SQLHSTMT<'conn, 'desc, 'buf, ST, SQL_OV_ODBC4>, A, SQL_OV_ODBC4>
     for OdbcStr<CH>
 where
     OdbcStr<CH>: StmtAttr<
             'desc,
             'buf,
-            SQLHSTMT<'conn, 'desc, 'buf, <SQL_OV_ODBC4 as OdbcVersion>::PrevVersion>,
+            SQLHSTMT<'conn, 'desc, 'buf, ST, <SQL_OV_ODBC4 as OdbcVersion>::PrevVersion>,
             A,
             <SQL_OV_ODBC4 as OdbcVersion>::PrevVersion,
         >,
@@ -573,84 +1508,124 @@ unsafe impl AttrGet<SQL_ATTR_ROW_NUMBER> for SQLULEN {}
 //#[expect(non_camel_case_types)]
 //pub struct SQL_ATTR_FETCH_BOOKMARK_PTR;
 
-//// The following are Header fields--------------------------------
-//
-//// TODO: This one could be special??
-//// Corresponds to ARD SQL_DESC_BIND_TYPE
-//#[derive(Ident)]
-//#[identifier(SQLINTEGER, 5)]
-//#[expect(non_camel_case_types)]
-//pub struct SQL_ATTR_ROW_BIND_TYPE;
-//
-// TODO: This cannot be supported until SQL_DESC_BIND_OFFSET_PTR is supported in descriptors
-//// Corresponds to APD SQL_DESC_BIND_OFFSET_PTR
-//#[derive(Ident)]
-//#[identifier(SQLINTEGER, 17)]
-//#[expect(non_camel_case_types)]
-//pub struct SQL_ATTR_PARAM_BIND_OFFSET_PTR;
-//
-//// Corresponds to APD SQL_DESC_BIND_TYPE
-//#[derive(Ident)]
-//#[identifier(SQLINTEGER, 18)]
-//#[expect(non_camel_case_types)]
-//pub struct SQL_ATTR_PARAM_BIND_TYPE;
-//
-//// Corresponds to APD SQL_DESC_ARRAY_STATUS_PTR
-//#[derive(Ident)]
-//#[identifier(SQLINTEGER, 18)]
-//#[expect(non_camel_case_types)]
-//pub struct SQL_ATTR_PARAM_OPERATION_PTR;
-//
-//// Corresponds to IPD SQL_DESC_ARRAY_STATUS_PTR
-//#[derive(Ident)]
-//#[identifier(SQLINTEGER, 20)]
-//#[expect(non_camel_case_types)]
-//pub struct SQL_ATTR_PARAM_STATUS_PTR;
-//
-//// Corresponds to IPD SQL_DESC_ROWS_PROCESSED_PTR
-//#[derive(Ident)]
-//#[identifier(SQLINTEGER, 21)]
-//#[expect(non_camel_case_types)]
-//pub struct SQL_ATTR_PARAMS_PROCESSED_PTR;
-//
-//// Corresponds to APD SQL_DESC_ARRAY_SIZE
-//#[derive(Ident)]
-//#[identifier(SQLINTEGER, 22)]
-//#[expect(non_camel_case_types)]
-//pub struct SQL_ATTR_PARAMSET_SIZE;
-//
+// The following are Header fields--------------------------------
 
-// TODO: This cannot be supported until SQL_DESC_BIND_OFFSET_PTR is supported in descriptors
-//// Corresponds to ARD SQL_DESC_BIND_OFFSET_PTR
-//#[derive(Ident)]
-//#[identifier(SQLINTEGER, 23)]
-//#[expect(non_camel_case_types)]
-//pub struct SQL_ATTR_ROW_BIND_OFFSET_PTR;
-//
+// Corresponds to ARD SQL_DESC_BIND_TYPE
+// Can only be set by SQLHSTMT::bind_rows which checks that bound buffers are within a row
+#[derive(Ident)]
+#[identifier(SQLINTEGER, 5)]
+#[expect(non_camel_case_types)]
+pub struct SQL_ATTR_ROW_BIND_TYPE;
+unsafe impl Attr<SQL_ATTR_ROW_BIND_TYPE> for SQLULEN {
+    type DefinedBy = OdbcDefined;
+}
+impl<'desc, 'buf, S: Statement<'desc, 'buf, SQL_OV_ODBC3>>
+    StmtAttr<'desc, 'buf, S, SQL_ATTR_ROW_BIND_TYPE, SQL_OV_ODBC3> for SQLULEN
+{
+}
+unsafe impl AttrGet<SQL_ATTR_ROW_BIND_TYPE> for SQLULEN {}
+
+// Corresponds to APD SQL_DESC_BIND_OFFSET_PTR
+// Set together with the parameter bind type by SQLHSTMT::bind_param_rows
+#[derive(Ident)]
+#[identifier(SQLINTEGER, 17)]
+#[expect(non_camel_case_types)]
+pub struct SQL_ATTR_PARAM_BIND_OFFSET_PTR;
+
+// Corresponds to APD SQL_DESC_BIND_TYPE
+// Can only be set by SQLHSTMT::bind_param_rows which checks that bound buffers are within a row
+#[derive(Ident)]
+#[identifier(SQLINTEGER, 18)]
+#[expect(non_camel_case_types)]
+pub struct SQL_ATTR_PARAM_BIND_TYPE;
+unsafe impl Attr<SQL_ATTR_PARAM_BIND_TYPE> for SQLULEN {
+    type DefinedBy = OdbcDefined;
+}
+impl<'desc, 'buf, S: Statement<'desc, 'buf, SQL_OV_ODBC3>>
+    StmtAttr<'desc, 'buf, S, SQL_ATTR_PARAM_BIND_TYPE, SQL_OV_ODBC3> for SQLULEN
+{
+}
+unsafe impl AttrGet<SQL_ATTR_PARAM_BIND_TYPE> for SQLULEN {}
+
+// Corresponds to APD SQL_DESC_ARRAY_STATUS_PTR
+// Set together with the paramset size by SQLHSTMT::bind_paramset
+#[derive(Ident)]
+#[identifier(SQLINTEGER, 19)]
+#[expect(non_camel_case_types)]
+pub struct SQL_ATTR_PARAM_OPERATION_PTR;
+
+// Corresponds to IPD SQL_DESC_ARRAY_STATUS_PTR
+// Set together with the paramset size by SQLHSTMT::bind_paramset
+#[derive(Ident)]
+#[identifier(SQLINTEGER, 20)]
+#[expect(non_camel_case_types)]
+pub struct SQL_ATTR_PARAM_STATUS_PTR;
+
+// Corresponds to IPD SQL_DESC_ROWS_PROCESSED_PTR
+// Set together with the paramset size by SQLHSTMT::bind_paramset
+#[derive(Ident)]
+#[identifier(SQLINTEGER, 21)]
+#[expect(non_camel_case_types)]
+pub struct SQL_ATTR_PARAMS_PROCESSED_PTR;
+
+// Corresponds to APD SQL_DESC_ARRAY_SIZE
+// Can only be set by SQLHSTMT::bind_paramset which checks that bound buffers are large enough
+#[derive(Ident)]
+#[identifier(SQLINTEGER, 22)]
+#[expect(non_camel_case_types)]
+pub struct SQL_ATTR_PARAMSET_SIZE;
+unsafe impl Attr<SQL_ATTR_PARAMSET_SIZE> for SQLULEN {
+    type DefinedBy = OdbcDefined;
+}
+impl<'desc, 'buf, S: Statement<'desc, 'buf, SQL_OV_ODBC3>>
+    StmtAttr<'desc, 'buf, S, SQL_ATTR_PARAMSET_SIZE, SQL_OV_ODBC3> for SQLULEN
+{
+}
+unsafe impl AttrGet<SQL_ATTR_PARAMSET_SIZE> for SQLULEN {}
+
+// TODO: Row operation arrays are not supported yet
 //// Corresponds to ARD SQL_DESC_ARRAY_STATUS_PTR
 //#[derive(Ident)]
 //#[identifier(SQLINTEGER, 24)]
 //#[expect(non_camel_case_types)]
 //pub struct SQL_ATTR_ROW_OPERATION_PTR;
-//
-//// Corresponds to IRD SQL_DESC_ARRAY_STATUS_PTR
-//#[derive(Ident)]
-//#[identifier(SQLINTEGER, 25)]
-//#[expect(non_camel_case_types)]
-//pub struct SQL_ATTR_ROW_STATUS_PTR;
-//
-//// Corresponds to IRD SQL_DESC_ROWS_PROCESSED_PTR
-//#[derive(Ident)]
-//#[identifier(SQLINTEGER, 26)]
-//#[expect(non_camel_case_types)]
-//pub struct SQL_ATTR_ROWS_FETCHED_PTR;
-//
-//// Corresponds to ARD SQL_DESC_ARRAY_SIZE
-//#[derive(Ident)]
-//#[identifier(SQLINTEGER, 27)]
-//#[expect(non_camel_case_types)]
-//pub struct SQL_ATTR_ROW_ARRAY_SIZE;
-//
+
+// Corresponds to ARD SQL_DESC_BIND_OFFSET_PTR
+// Set together with the row bind type by SQLHSTMT::bind_rows
+#[derive(Ident)]
+#[identifier(SQLINTEGER, 23)]
+#[expect(non_camel_case_types)]
+pub struct SQL_ATTR_ROW_BIND_OFFSET_PTR;
+
+// Corresponds to IRD SQL_DESC_ARRAY_STATUS_PTR
+// Set together with the row array size by SQLHSTMT::bind_rowset
+#[derive(Ident)]
+#[identifier(SQLINTEGER, 25)]
+#[expect(non_camel_case_types)]
+pub struct SQL_ATTR_ROW_STATUS_PTR;
+
+// Corresponds to IRD SQL_DESC_ROWS_PROCESSED_PTR
+// Set together with the row array size by SQLHSTMT::bind_rowset
+#[derive(Ident)]
+#[identifier(SQLINTEGER, 26)]
+#[expect(non_camel_case_types)]
+pub struct SQL_ATTR_ROWS_FETCHED_PTR;
+
+// Corresponds to ARD SQL_DESC_ARRAY_SIZE
+// Can only be set by SQLHSTMT::bind_rowset which checks that bound buffers are large enough
+#[derive(Ident)]
+#[identifier(SQLINTEGER, 27)]
+#[expect(non_camel_case_types)]
+pub struct SQL_ATTR_ROW_ARRAY_SIZE;
+unsafe impl Attr<SQL_ATTR_ROW_ARRAY_SIZE> for SQLULEN {
+    type DefinedBy = OdbcDefined;
+}
+impl<'desc, 'buf, S: Statement<'desc, 'buf, SQL_OV_ODBC3>>
+    StmtAttr<'desc, 'buf, S, SQL_ATTR_ROW_ARRAY_SIZE, SQL_OV_ODBC3> for SQLULEN
+{
+}
+unsafe impl AttrGet<SQL_ATTR_ROW_ARRAY_SIZE> for SQLULEN {}
+
 //#[identifier(SQLINTEGER, 29)]
 //#[derive(Ident)]
 //#[cfg(feature = "v3_8")]
@@ -708,46 +1683,25 @@ impl<'conn, 'desc, 'buf, V: OdbcVersion>
 where
     Self: Attr<SQL_ATTR_APP_ROW_DESC> + AttrLen<Self::DefinedBy, SQLINTEGER>,
 {
-    #[cfg(feature = "odbc_debug")]
-    fn readA<'stmt>(
-        &mut self,
-        StatementHandle: &'stmt UnsafeSQLHSTMT<'conn, 'desc, 'buf, V>,
-        _: Option<&mut MaybeUninit<Self::StrLen>>,
-    ) -> SQLRETURN {
-        get_ard(self, StatementHandle)
-    }
-
-    #[cfg(feature = "odbc_debug")]
-    fn readW<'stmt>(
-        &mut self,
-        StatementHandle: &'stmt UnsafeSQLHSTMT<'conn, 'desc, 'buf, V>,
-        _: Option<&mut MaybeUninit<Self::StrLen>>,
-    ) -> SQLRETURN {
-        get_ard(self, StatementHandle)
-    }
 }
-impl<'conn, 'desc, 'buf, V: OdbcVersion>
-    private::BaseStmtAttr<'desc, 'buf, SQLHSTMT<'conn, 'desc, 'buf, V>, SQL_ATTR_APP_ROW_DESC, V>
-    for MaybeUninit<RefSQLHDESC<'conn, AppDesc<'buf>, V>>
+impl<'conn, 'desc, 'buf, ST: StmtState, V: OdbcVersion>
+    private::BaseStmtAttr<
+        'desc,
+        'buf,
+        SQLHSTMT<'conn, 'desc, 'buf, ST, V>,
+        SQL_ATTR_APP_ROW_DESC,
+        V,
+    > for MaybeUninit<RefSQLHDESC<'conn, AppDesc<'buf>, V>>
 where
     Self: Attr<SQL_ATTR_APP_ROW_DESC> + AttrLen<Self::DefinedBy, SQLINTEGER>,
 {
-    #[cfg(feature = "odbc_debug")]
-    fn readA<'stmt>(
-        &mut self,
-        StatementHandle: &'stmt SQLHSTMT<'conn, 'desc, 'buf, V>,
-        _: Option<&mut MaybeUninit<Self::StrLen>>,
-    ) -> SQLRETURN {
-        get_ard(self, StatementHandle)
-    }
-
-    #[cfg(feature = "odbc_debug")]
-    fn readW<'stmt>(
-        &mut self,
-        StatementHandle: &'stmt SQLHSTMT<'conn, 'desc, 'buf, V>,
-        _: Option<&mut MaybeUninit<Self::StrLen>>,
-    ) -> SQLRETURN {
-        get_ard(self, StatementHandle)
+    // Implicit ARD could be copied into an explicitly allocated one which would keep
+    // pointing to the buffers owned by the statement after the statement released them
+    fn check_read(&self, stmt: &SQLHSTMT<'conn, 'desc, 'buf, ST, V>) {
+        assert!(
+            !stmt.0.bound_cols.owns_bufs(),
+            "Implicitly allocated ARD requested while the statement owns buffers bound to it"
+        );
     }
 }
 impl<'desc, 'buf, S: Statement<'desc, 'buf, V>, V: OdbcVersion>
@@ -756,9 +1710,11 @@ impl<'desc, 'buf, S: Statement<'desc, 'buf, V>, V: OdbcVersion>
 where
     Self: Attr<SQL_ATTR_APP_ROW_DESC> + AttrLen<Self::DefinedBy, SQLINTEGER>,
 {
-    #[cfg(feature = "odbc_debug")]
-    fn update_handle(&self, StatementHandle: &UnsafeSQLHSTMT<'_, 'desc, 'buf, V>) {
-        StatementHandle.explicit_ard.set(*self);
+    fn update_handle(&self, stmt: &S)
+    where
+        Self: AttrSet<SQL_ATTR_APP_ROW_DESC>,
+    {
+        stmt.set_explicit_ard(self.is_some());
     }
 }
 
@@ -769,8 +1725,8 @@ where
     Self: Attr<SQL_ATTR_APP_ROW_DESC> + AttrLen<Self::DefinedBy, SQLINTEGER>,
 {
 }
-impl<'conn, 'desc, 'buf, V: OdbcVersion>
-    StmtAttr<'desc, 'buf, SQLHSTMT<'conn, 'desc, 'buf, V>, SQL_ATTR_APP_ROW_DESC, V>
+impl<'conn, 'desc, 'buf, ST: StmtState, V: OdbcVersion>
+    StmtAttr<'desc, 'buf, SQLHSTMT<'conn, 'desc, 'buf, ST, V>, SQL_ATTR_APP_ROW_DESC, V>
     for MaybeUninit<RefSQLHDESC<'conn, AppDesc<'buf>, V>>
 where
     Self: Attr<SQL_ATTR_APP_ROW_DESC> + AttrLen<Self::DefinedBy, SQLINTEGER>,
@@ -816,44 +1772,23 @@ impl<'conn, 'desc, 'buf, V: OdbcVersion>
         V,
     > for MaybeUninit<RefUnsafeSQLHDESC<'conn, AppDesc<'buf>, V>>
 {
-    #[cfg(feature = "odbc_debug")]
-    fn readA<'stmt>(
-        &mut self,
-        StatementHandle: &'stmt UnsafeSQLHSTMT<'conn, 'desc, 'buf, V>,
-        _: Option<&mut MaybeUninit<Self::StrLen>>,
-    ) -> SQLRETURN {
-        get_ard(self, StatementHandle)
-    }
-
-    #[cfg(feature = "odbc_debug")]
-    fn readW<'stmt>(
-        &mut self,
-        StatementHandle: &'stmt UnsafeSQLHSTMT<'conn, 'desc, 'buf, V>,
-        _: Option<&mut MaybeUninit<Self::StrLen>>,
-    ) -> SQLRETURN {
-        get_ard(self, StatementHandle)
-    }
 }
-impl<'conn, 'desc, 'buf, V: OdbcVersion>
-    private::BaseStmtAttr<'desc, 'buf, SQLHSTMT<'conn, 'desc, 'buf, V>, SQL_ATTR_APP_PARAM_DESC, V>
-    for MaybeUninit<RefSQLHDESC<'conn, AppDesc<'buf>, V>>
-{
-    #[cfg(feature = "odbc_debug")]
-    fn readA<'stmt>(
-        &mut self,
-        StatementHandle: &'stmt UnsafeSQLHSTMT<'conn, 'desc, 'buf, V>,
-        _: Option<&mut MaybeUninit<Self::StrLen>>,
-    ) -> SQLRETURN {
-        get_ard(self, StatementHandle)
-    }
-
-    #[cfg(feature = "odbc_debug")]
-    fn readW<'stmt>(
-        &mut self,
-        StatementHandle: &'stmt UnsafeSQLHSTMT<'conn, 'desc, 'buf, V>,
-        _: Option<&mut MaybeUninit<Self::StrLen>>,
-    ) -> SQLRETURN {
-        get_ard(self, StatementHandle)
+impl<'conn, 'desc, 'buf, ST: StmtState, V: OdbcVersion>
+    private::BaseStmtAttr<
+        'desc,
+        'buf,
+        SQLHSTMT<'conn, 'desc, 'buf, ST, V>,
+        SQL_ATTR_APP_PARAM_DESC,
+        V,
+    > for MaybeUninit<RefSQLHDESC<'conn, AppDesc<'buf>, V>>
+{
+    // Implicit APD could be copied into an explicitly allocated one which would keep
+    // pointing to the buffers owned by the statement after the statement released them
+    fn check_read(&self, stmt: &SQLHSTMT<'conn, 'desc, 'buf, ST, V>) {
+        assert!(
+            !stmt.0.bound_params.owns_bufs(),
+            "Implicitly allocated APD requested while the statement owns buffers bound to it"
+        );
     }
 }
 impl<'desc, 'buf, S: Statement<'desc, 'buf, V>, V: OdbcVersion>
@@ -862,9 +1797,11 @@ impl<'desc, 'buf, S: Statement<'desc, 'buf, V>, V: OdbcVersion>
 where
     Self: Attr<SQL_ATTR_APP_PARAM_DESC> + AttrLen<Self::DefinedBy, SQLINTEGER>,
 {
-    #[cfg(feature = "odbc_debug")]
-    fn update_handle(&self, StatementHandle: &UnsafeSQLHSTMT<'_, 'desc, 'buf, V>) {
-        StatementHandle.explicit_ard.set(*self);
+    fn update_handle(&self, stmt: &S)
+    where
+        Self: AttrSet<SQL_ATTR_APP_PARAM_DESC>,
+    {
+        stmt.set_explicit_apd(self.is_some());
     }
 }
 
@@ -875,8 +1812,8 @@ where
     Self: Attr<SQL_ATTR_APP_PARAM_DESC> + AttrLen<Self::DefinedBy, SQLINTEGER>,
 {
 }
-impl<'conn, 'desc, 'buf, V: OdbcVersion>
-    StmtAttr<'desc, 'buf, SQLHSTMT<'conn, 'desc, 'buf, V>, SQL_ATTR_APP_PARAM_DESC, V>
+impl<'conn, 'desc, 'buf, ST: StmtState, V: OdbcVersion>
+    StmtAttr<'desc, 'buf, SQLHSTMT<'conn, 'desc, 'buf, ST, V>, SQL_ATTR_APP_PARAM_DESC, V>
     for MaybeUninit<RefSQLHDESC<'conn, AppDesc<'buf>, V>>
 where
     Self: Attr<SQL_ATTR_APP_PARAM_DESC> + AttrLen<Self::DefinedBy, SQLINTEGER>,
@@ -918,53 +1855,24 @@ impl<'conn, 'desc, 'buf, V: OdbcVersion>
         V,
     > for MaybeUninit<RefUnsafeSQLHDESC<'conn, IRD, V>>
 {
-    #[cfg(feature = "odbc_debug")]
-    fn readA<'stmt>(
-        &mut self,
-        StatementHandle: &'stmt UnsafeSQLHSTMT<'conn, 'desc, 'buf, V>,
-        _: Option<&mut MaybeUninit<Self::StrLen>>,
-    ) -> SQLRETURN {
-        get_ard(self, StatementHandle)
-    }
-
-    #[cfg(feature = "odbc_debug")]
-    fn readW<'stmt>(
-        &mut self,
-        StatementHandle: &'stmt UnsafeSQLHSTMT<'conn, 'desc, 'buf, V>,
-        _: Option<&mut MaybeUninit<Self::StrLen>>,
-    ) -> SQLRETURN {
-        get_ard(self, StatementHandle)
-    }
 }
-impl<'conn, 'desc, 'buf, V: OdbcVersion>
-    private::BaseStmtAttr<'desc, 'buf, SQLHSTMT<'conn, 'desc, 'buf, V>, SQL_ATTR_IMP_ROW_DESC, V>
-    for MaybeUninit<RefSQLHDESC<'conn, IRD, V>>
+impl<'conn, 'desc, 'buf, ST: StmtState, V: OdbcVersion>
+    private::BaseStmtAttr<
+        'desc,
+        'buf,
+        SQLHSTMT<'conn, 'desc, 'buf, ST, V>,
+        SQL_ATTR_IMP_ROW_DESC,
+        V,
+    > for MaybeUninit<RefSQLHDESC<'conn, IRD, V>>
 {
-    #[cfg(feature = "odbc_debug")]
-    fn readA<'stmt>(
-        &mut self,
-        StatementHandle: &'stmt SQLHSTMT<'conn, 'desc, 'buf, V>,
-        _: Option<&mut MaybeUninit<Self::StrLen>>,
-    ) -> SQLRETURN {
-        get_ard(self, StatementHandle)
-    }
-
-    #[cfg(feature = "odbc_debug")]
-    fn readW<'stmt>(
-        &mut self,
-        StatementHandle: &'stmt SQLHSTMT<'conn, 'desc, 'buf, V>,
-        _: Option<&mut MaybeUninit<Self::StrLen>>,
-    ) -> SQLRETURN {
-        get_ard(self, StatementHandle)
-    }
 }
 impl<'conn, 'desc, 'buf, V: OdbcVersion>
     StmtAttr<'desc, 'buf, UnsafeSQLHSTMT<'conn, 'desc, 'buf, V>, SQL_ATTR_IMP_ROW_DESC, V>
     for MaybeUninit<RefUnsafeSQLHDESC<'conn, IRD, V>>
 {
 }
-impl<'conn, 'desc, 'buf, V: OdbcVersion>
-    StmtAttr<'desc, 'buf, SQLHSTMT<'conn, 'desc, 'buf, V>, SQL_ATTR_IMP_ROW_DESC, V>
+impl<'conn, 'desc, 'buf, ST: StmtState, V: OdbcVersion>
+    StmtAttr<'desc, 'buf, SQLHSTMT<'conn, 'desc, 'buf, ST, V>, SQL_ATTR_IMP_ROW_DESC, V>
     for MaybeUninit<RefSQLHDESC<'conn, IRD, V>>
 {
 }
@@ -994,53 +1902,24 @@ impl<'conn, 'desc, 'buf, V: OdbcVersion>
         V,
     > for MaybeUninit<RefUnsafeSQLHDESC<'conn, IPD, V>>
 {
-    #[cfg(feature = "odbc_debug")]
-    fn readA<'stmt>(
-        &mut self,
-        StatementHandle: &'stmt UnsafeSQLHSTMT<'conn, 'desc, 'buf, V>,
-        _: Option<&mut MaybeUninit<Self::StrLen>>,
-    ) -> SQLRETURN {
-        get_ard(self, StatementHandle)
-    }
-
-    #[cfg(feature = "odbc_debug")]
-    fn readW<'stmt>(
-        &mut self,
-        StatementHandle: &'stmt UnsafeSQLHSTMT<'conn, 'desc, 'buf, V>,
-        _: Option<&mut MaybeUninit<Self::StrLen>>,
-    ) -> SQLRETURN {
-        get_ard(self, StatementHandle)
-    }
 }
-impl<'conn, 'desc, 'buf, V: OdbcVersion>
-    private::BaseStmtAttr<'desc, 'buf, SQLHSTMT<'conn, 'desc, 'buf, V>, SQL_ATTR_IMP_PARAM_DESC, V>
-    for MaybeUninit<RefSQLHDESC<'conn, IPD, V>>
+impl<'conn, 'desc, 'buf, ST: StmtState, V: OdbcVersion>
+    private::BaseStmtAttr<
+        'desc,
+        'buf,
+        SQLHSTMT<'conn, 'desc, 'buf, ST, V>,
+        SQL_ATTR_IMP_PARAM_DESC,
+        V,
+    > for MaybeUninit<RefSQLHDESC<'conn, IPD, V>>
 {
-    #[cfg(feature = "odbc_debug")]
-    fn readA<'stmt>(
-        &mut self,
-        StatementHandle: &'stmt SQLHSTMT<'conn, 'desc, 'buf, V>,
-        _: Option<&mut MaybeUninit<Self::StrLen>>,
-    ) -> SQLRETURN {
-        get_ard(self, StatementHandle)
-    }
-
-    #[cfg(feature = "odbc_debug")]
-    fn readW<'stmt>(
-        &mut self,
-        StatementHandle: &'stmt SQLHSTMT<'conn, 'desc, 'buf, V>,
-        _: Option<&mut MaybeUninit<Self::StrLen>>,
-    ) -> SQLRETURN {
-        get_ard(self, StatementHandle)
-    }
 }
 impl<'conn, 'desc, 'buf, V: OdbcVersion>
     StmtAttr<'desc, 'buf, UnsafeSQLHSTMT<'conn, 'desc, 'buf, V>, SQL_ATTR_IMP_PARAM_DESC, V>
     for MaybeUninit<RefUnsafeSQLHDESC<'conn, IPD, V>>
 {
 }
-impl<'conn, 'desc, 'buf, V: OdbcVersion>
-    StmtAttr<'desc, 'buf, SQLHSTMT<'conn, 'desc, 'buf, V>, SQL_ATTR_IMP_PARAM_DESC, V>
+impl<'conn, 'desc, 'buf, ST: StmtState, V: OdbcVersion>
+    StmtAttr<'desc, 'buf, SQLHSTMT<'conn, 'desc, 'buf, ST, V>, SQL_ATTR_IMP_PARAM_DESC, V>
     for MaybeUninit<RefSQLHDESC<'conn, IPD, V>>
 {
 }
@@ -1,8 +1,11 @@
 use core::{
     cell::UnsafeCell,
+    fmt,
     mem::MaybeUninit,
     ops::{Deref, DerefMut},
 };
+#[cfg(feature = "std")]
+use std::{borrow::Cow, string::String, vec::Vec};
 
 use crate::{
     Ident, SQLCHAR, SQLWCHAR,
@@ -102,6 +105,215 @@ where
     }
 }
 
+/// Owned string of ODBC characters which is not null-terminated. `OdbcString<SQLCHAR>` holds
+/// UTF-8 encoded data while `OdbcString<SQLWCHAR>` holds UTF-16 or, with the `iodbc` feature,
+/// UCS-4 encoded data
+#[cfg(feature = "std")]
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct OdbcString<T>(Vec<T>);
+
+/// Error returned when ODBC string doesn't contain valid UTF-8, UTF-16 or UCS-4 encoded data
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidOdbcStr {
+    valid_up_to: usize,
+}
+
+impl InvalidOdbcStr {
+    /// Returns the number of characters that were successfully decoded before invalid data
+    /// (e.g. unpaired surrogate) was encountered
+    pub fn valid_up_to(&self) -> usize {
+        self.valid_up_to
+    }
+}
+impl fmt::Display for InvalidOdbcStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid ODBC string data at index {}", self.valid_up_to)
+    }
+}
+impl core::error::Error for InvalidOdbcStr {}
+
+#[cfg(feature = "std")]
+impl<T> OdbcString<T> {
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.0
+    }
+}
+#[cfg(feature = "std")]
+impl<T> Deref for OdbcString<T> {
+    type Target = OdbcStr<T>;
+
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: Types are transparent
+        unsafe { &*(self.0.as_slice() as *const [T] as *const OdbcStr<T>) }
+    }
+}
+#[cfg(feature = "std")]
+impl<T> DerefMut for OdbcString<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // SAFETY: Types are transparent
+        unsafe { &mut *(self.0.as_mut_slice() as *mut [T] as *mut OdbcStr<T>) }
+    }
+}
+#[cfg(feature = "std")]
+impl<T> AsRef<OdbcStr<T>> for OdbcString<T> {
+    fn as_ref(&self) -> &OdbcStr<T> {
+        self
+    }
+}
+#[cfg(feature = "std")]
+impl<T> AsMut<OdbcStr<T>> for OdbcString<T> {
+    fn as_mut(&mut self) -> &mut OdbcStr<T> {
+        self
+    }
+}
+#[cfg(feature = "std")]
+impl<T> From<Vec<T>> for OdbcString<T> {
+    fn from(source: Vec<T>) -> Self {
+        Self(source)
+    }
+}
+#[cfg(feature = "std")]
+impl From<&str> for OdbcString<SQLCHAR> {
+    fn from(source: &str) -> Self {
+        Self(source.as_bytes().to_vec())
+    }
+}
+#[cfg(feature = "std")]
+impl From<String> for OdbcString<SQLCHAR> {
+    fn from(source: String) -> Self {
+        Self(source.into_bytes())
+    }
+}
+#[cfg(all(feature = "std", not(feature = "iodbc")))]
+impl From<&str> for OdbcString<SQLWCHAR> {
+    fn from(source: &str) -> Self {
+        Self(source.encode_utf16().collect())
+    }
+}
+#[cfg(all(feature = "std", feature = "iodbc"))]
+impl From<&str> for OdbcString<SQLWCHAR> {
+    fn from(source: &str) -> Self {
+        Self(source.chars().map(SQLWCHAR::from).collect())
+    }
+}
+#[cfg(feature = "std")]
+impl From<String> for OdbcString<SQLWCHAR> {
+    fn from(source: String) -> Self {
+        source.as_str().into()
+    }
+}
+#[cfg(feature = "std")]
+impl TryFrom<OdbcString<SQLCHAR>> for String {
+    type Error = InvalidOdbcStr;
+
+    /// Decodes UTF-8 encoded data. Unlike with [`OdbcStr`], data after a null character is kept
+    fn try_from(source: OdbcString<SQLCHAR>) -> Result<Self, Self::Error> {
+        String::from_utf8(source.0).map_err(|error| InvalidOdbcStr {
+            valid_up_to: error.utf8_error().valid_up_to(),
+        })
+    }
+}
+#[cfg(feature = "std")]
+impl TryFrom<OdbcString<SQLWCHAR>> for String {
+    type Error = InvalidOdbcStr;
+
+    /// Decodes UTF-16 or, with the `iodbc` feature, UCS-4 encoded data. Unlike with
+    /// [`OdbcStr`], data after a null character is kept
+    fn try_from(source: OdbcString<SQLWCHAR>) -> Result<Self, Self::Error> {
+        decode_wide(&source.0)
+    }
+}
+
+/// Strips the null terminator and any data written after it by the driver
+#[cfg(feature = "std")]
+fn until_nul<T: Default + PartialEq>(source: &[T]) -> &[T] {
+    let nul = T::default();
+    let len = source
+        .iter()
+        .position(|c| *c == nul)
+        .unwrap_or(source.len());
+
+    &source[..len]
+}
+
+/// Decodes UTF-16 encoded data
+#[cfg(all(feature = "std", not(feature = "iodbc")))]
+fn decode_wide(source: &[SQLWCHAR]) -> Result<String, InvalidOdbcStr> {
+    let mut valid_up_to = 0;
+
+    char::decode_utf16(source.iter().copied())
+        .map(|c| {
+            let c = c.map_err(|_| InvalidOdbcStr { valid_up_to })?;
+            valid_up_to += c.len_utf16();
+            Ok(c)
+        })
+        .collect()
+}
+/// Decodes UCS-4 encoded data
+#[cfg(all(feature = "std", feature = "iodbc"))]
+fn decode_wide(source: &[SQLWCHAR]) -> Result<String, InvalidOdbcStr> {
+    source
+        .iter()
+        .enumerate()
+        .map(|(valid_up_to, &c)| char::from_u32(c).ok_or(InvalidOdbcStr { valid_up_to }))
+        .collect()
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<&OdbcStr<SQLCHAR>> for String {
+    type Error = InvalidOdbcStr;
+
+    /// Decodes UTF-8 encoded data up to the first null terminator
+    fn try_from(source: &OdbcStr<SQLCHAR>) -> Result<Self, Self::Error> {
+        core::str::from_utf8(until_nul(source))
+            .map(Into::into)
+            .map_err(|error| InvalidOdbcStr {
+                valid_up_to: error.valid_up_to(),
+            })
+    }
+}
+#[cfg(feature = "std")]
+impl TryFrom<&OdbcStr<SQLWCHAR>> for String {
+    type Error = InvalidOdbcStr;
+
+    /// Decodes UTF-16 or, with the `iodbc` feature, UCS-4 encoded data up to the first null
+    /// terminator
+    fn try_from(source: &OdbcStr<SQLWCHAR>) -> Result<Self, Self::Error> {
+        decode_wide(until_nul(source))
+    }
+}
+
+#[cfg(feature = "std")]
+impl OdbcStr<SQLCHAR> {
+    /// Decodes UTF-8 encoded data up to the first null terminator. Invalid data is
+    /// replaced with `U+FFFD REPLACEMENT CHARACTER`
+    pub fn to_string_lossy(&self) -> Cow<'_, str> {
+        String::from_utf8_lossy(until_nul(self))
+    }
+}
+#[cfg(feature = "std")]
+impl OdbcStr<SQLWCHAR> {
+    /// Decodes UTF-16 or, with the `iodbc` feature, UCS-4 encoded data up to the first null
+    /// terminator. Invalid data is replaced with `U+FFFD REPLACEMENT CHARACTER`
+    pub fn to_string_lossy(&self) -> Cow<'_, str> {
+        #[cfg(not(feature = "iodbc"))]
+        let decoded = char::decode_utf16(until_nul(self).iter().copied())
+            .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+            .collect();
+        #[cfg(feature = "iodbc")]
+        let decoded = until_nul(self)
+            .iter()
+            .map(|&c| char::from_u32(c).unwrap_or(char::REPLACEMENT_CHARACTER))
+            .collect();
+
+        Cow::Owned(decoded)
+    }
+}
+
 impl<T: Ident> Ansi for T {} // TODO: This coincidentally implements it for SQLWCHAR as well. May not be a problem?
 impl<T: Ident> Unicode for T {} // TODO: This coincidentally implements it for SQLWCHAR as well. May not be a problem?
 
@@ -128,3 +340,75 @@ impl<DT, V: OdbcVersion> Unicode for Option<&SQLHDESC<'_, DT, V>> {}
 
 impl<DT, V: OdbcVersion> Ansi for Option<&UnsafeSQLHDESC<'_, DT, V>> {}
 impl<DT, V: OdbcVersion> Unicode for Option<&UnsafeSQLHDESC<'_, DT, V>> {}
+
+//----------------------------------------Tests----------------------------------------//
+
+#[cfg(all(test, feature = "std"))]
+mod test {
+    #![expect(non_snake_case)]
+
+    use super::*;
+
+    #[test]
+    fn odbc_string_SQLCHAR_roundtrip() {
+        let string = OdbcString::<SQLCHAR>::from("čćž");
+
+        assert_eq!("čćž".as_bytes(), &**string);
+        assert_eq!(Ok("čćž".to_owned()), String::try_from(string));
+    }
+
+    #[test]
+    fn odbc_string_SQLWCHAR_roundtrip() {
+        let string = OdbcString::<SQLWCHAR>::from("a😀");
+
+        #[cfg(not(feature = "iodbc"))]
+        assert_eq!(3, string.len());
+        #[cfg(feature = "iodbc")]
+        assert_eq!(2, string.len());
+        assert_eq!(Ok("a😀".to_owned()), String::try_from(string));
+    }
+
+    #[test]
+    fn odbc_string_keeps_nul() {
+        let string = OdbcString::<SQLCHAR>::from("a\0b");
+        assert_eq!(Ok("a\0b".to_owned()), String::try_from(string));
+
+        let string = OdbcString::<SQLWCHAR>::from("a\0b");
+        assert_eq!(Ok("a\0b".to_owned()), String::try_from(string));
+    }
+
+    #[test]
+    fn decode_until_nul() {
+        let mut buf = [0 as SQLWCHAR; 8];
+        buf[..2].copy_from_slice(&OdbcString::<SQLWCHAR>::from("ab"));
+        buf[3] = SQLWCHAR::from(b'c');
+
+        let string: &OdbcStr<SQLWCHAR> = buf[..].as_ref();
+        assert_eq!(Ok("ab".to_owned()), String::try_from(string));
+    }
+
+    #[test]
+    #[cfg(not(feature = "iodbc"))]
+    fn decode_unpaired_surrogate() {
+        let buf = [SQLWCHAR::from(b'a'), 0xD800, SQLWCHAR::from(b'b')];
+        let string: &OdbcStr<SQLWCHAR> = buf[..].as_ref();
+
+        assert_eq!(
+            Err(InvalidOdbcStr { valid_up_to: 1 }),
+            String::try_from(string)
+        );
+        assert_eq!("a\u{FFFD}b", string.to_string_lossy());
+    }
+
+    #[test]
+    fn decode_invalid_utf8() {
+        let buf = [b'a', 0xFF];
+        let string: &OdbcStr<SQLCHAR> = buf[..].as_ref();
+
+        assert_eq!(
+            Err(InvalidOdbcStr { valid_up_to: 1 }),
+            String::try_from(string)
+        );
+        assert_eq!("a\u{FFFD}", string.to_string_lossy());
+    }
+}
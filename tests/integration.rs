@@ -130,11 +130,10 @@ fn stmt_get_desc_handle() {
     let res = stmt.SQLGetStmtAttrA(SQL_ATTR_APP_ROW_DESC, Some(&mut desc), None);
     assert_eq!(SQL_SUCCESS, res);
 
-    let val = 10;
-    let desc = unsafe { desc.assume_init() };
-    let res = desc.SQLSetDescFieldA(0, SQL_DESC_ARRAY_SIZE, Some(val));
+    let res = stmt.bind_rowset(10, None, None);
     assert_eq!(SQL_SUCCESS, res);
 
+    let desc = unsafe { desc.assume_init() };
     let mut val = 0;
     let res = desc.SQLGetDescFieldA(0, SQL_DESC_ARRAY_SIZE, Some(&mut val), None);
     assert_eq!(SQL_SUCCESS, res);
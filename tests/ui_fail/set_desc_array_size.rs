@@ -0,0 +1,42 @@
+use core::mem::MaybeUninit;
+use rs_odbc::api::{Allocate, Descriptor, Statement};
+use rs_odbc::conn::C4;
+use rs_odbc::desc::SQL_DESC_ARRAY_SIZE;
+use rs_odbc::env::SQL_OV_ODBC3_80;
+use rs_odbc::handle::{SQLHDBC, SQLHENV, SQLHSTMT, RefSQLHDESC, SQL_NULL_HANDLE};
+use rs_odbc::stmt::SQL_ATTR_APP_ROW_DESC;
+use rs_odbc::SQL_DRIVER_COMPLETE;
+
+fn get_env_handle() -> SQLHENV<SQL_OV_ODBC3_80> {
+    let (env, _) = SQLHENV::SQLAllocHandle(&SQL_NULL_HANDLE);
+    env.unwrap()
+}
+
+fn connect_to_test_db<'env>(
+    env: &'env SQLHENV<SQL_OV_ODBC3_80>,
+) -> SQLHDBC<'env, C4, SQL_OV_ODBC3_80> {
+    let (conn, _) = SQLHDBC::SQLAllocHandle(env);
+    let conn = conn.unwrap();
+
+    let mut outstrlen = MaybeUninit::uninit();
+    let (conn, _) =
+        conn.SQLDriverConnectA(None, "".as_ref(), None, &mut outstrlen, SQL_DRIVER_COMPLETE);
+
+    conn.unwrap()
+}
+
+fn main() {
+    let env = get_env_handle();
+    let conn = connect_to_test_db(&env);
+
+    let (stmt, _) = SQLHSTMT::SQLAllocHandle(&conn);
+    let stmt = stmt.unwrap();
+
+    let mut desc = MaybeUninit::<RefSQLHDESC<_, _>>::zeroed();
+    stmt.SQLGetStmtAttrA(SQL_ATTR_APP_ROW_DESC, Some(&mut desc), None);
+
+    let desc = unsafe { desc.assume_init() };
+    desc.SQLSetDescFieldA(0, SQL_DESC_ARRAY_SIZE, Some(10));
+
+    stmt.SQLFreeHandle();
+}